@@ -0,0 +1,31 @@
+//! 质数检查和质数筛的简单基准测试
+//!
+//! 项目不使用外部依赖，所以这里不用基准测试框架，只打印每一项的用时：
+//! cargo bench --bench primes
+
+use std::os::raw::c_long;
+use std::time::Instant;
+use rust_math::math::is_prime;
+use rust_math::math::sieve::{nth_prime, prime_count, primes_between, NTH_PRIME_LIMIT, PRIME_COUNT_LIMIT};
+
+fn main() {
+    let start = Instant::now();
+    let count = (c_long::MAX - 100_000..=c_long::MAX).filter(|&n| is_prime(n)).count();
+    println!("最大的 100000 个 64 位整数中有 {count} 个质数，用时 {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let count = (0..1_000_000).filter(|&n| is_prime(n)).count();
+    println!("1000000 以内有 {count} 个质数，用时 {:?}", start.elapsed());
+
+    let start = Instant::now();
+    assert_eq!(prime_count(PRIME_COUNT_LIMIT).value, 50_847_534);
+    println!("π(10^9) 用时 {:?}", start.elapsed());
+
+    let start = Instant::now();
+    assert_eq!(nth_prime(NTH_PRIME_LIMIT).value, 2_038_074_743);
+    println!("第 10^8 个质数用时 {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let count = primes_between(c_long::MAX - 10_000_000, c_long::MAX).value.len();
+    println!("最大的 10^7 个 64 位整数中有 {count} 个质数，用时 {:?}", start.elapsed());
+}
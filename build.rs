@@ -0,0 +1,852 @@
+//! 构建脚本：根据 Rust 导出函数生成 C 代码
+//!
+//! 扫描 `src/` 下所有 `.rs` 文件，生成以下文件（都在 `c_src/` 中，请勿手动修改）：
+//!
+//! - `rust_math_ffi.h`: `#[repr(C)]` 类型和所有 `#[no_mangle]` 函数的 C 声明
+//! - `php_rust_math_arginfo.h`: PHP 函数的参数信息和 `zend_function_entry` 列表
+//! - `php_rust_math_generated.c`: 简单函数的 `PHP_FUNCTION` 包装
+//!
+//! 在导出函数的文档注释中添加标记即可把它注册为 PHP 函数：
+//!
+//! - `/// @php rust_gcd(int $a, int $b): int` - 自动生成参数信息和包装函数
+//! - `/// @php-manual rust_fibonacci(int $n): array` - 只生成参数信息，包装函数在
+//!   `php_rust_math.c` 中手写（矩阵函数在 `rust_math_matrix.c` 中）
+//!
+//! 没有标记的导出函数（例如释放函数、句柄操作）只生成 C 声明
+
+use std::collections::{BTreeMap, HashSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 解析出的 `#[repr(C)]` 枚举
+struct CEnum {
+    name: String,
+    docs: Vec<String>,
+    /// (变体名, 值, 文档)
+    variants: Vec<(String, i64, String)>,
+}
+
+/// 解析出的 `#[repr(C)]` 结构体
+struct CStruct {
+    name: String,
+    docs: Vec<String>,
+    /// (字段名, Rust 类型, 文档)
+    fields: Vec<(String, String, String)>,
+}
+
+/// 解析出的导出函数
+struct ExportFn {
+    name: String,
+    docs: Vec<String>,
+    /// (参数名, Rust 类型)
+    params: Vec<(String, String)>,
+    /// Rust 返回类型，没有返回值时为 "()"
+    ret: String,
+    /// PHP 签名，例如 `rust_gcd(int $a, int $b): int`
+    php: Option<PhpSignature>,
+    /// 所在文件（相对于 crate 根目录）
+    file: String,
+}
+
+/// PHP 函数签名
+struct PhpSignature {
+    name: String,
+    params: Vec<PhpParam>,
+    ret: String,
+    /// 是否需要手写包装函数
+    manual: bool,
+}
+
+/// PHP 函数参数
+struct PhpParam {
+    name: String,
+    ty: String,
+    default: Option<String>,
+}
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("缺少 CARGO_MANIFEST_DIR"));
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src");
+
+    let mut files = Vec::new();
+    collect_rust_files(&root.join("src"), &mut files);
+    files.sort();
+
+    // lib.rs 中是扩展最早的函数，放在最前面
+    files.sort_by_key(|path| !path.ends_with("lib.rs"));
+
+    let mut enums = Vec::new();
+    let mut structs = Vec::new();
+    let mut functions = Vec::new();
+    for path in &files {
+        let source = fs::read_to_string(path).expect("无法读取源文件");
+        let relative = path
+            .strip_prefix(&root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        parse_source(&source, &relative, &mut enums, &mut structs, &mut functions);
+    }
+
+    let ctx = TypeContext::new(&enums, &structs);
+
+    let c_src = root.join("c_src");
+    write_if_changed(&c_src.join("rust_math_ffi.h"), &render_ffi_header(&ctx, &enums, &structs, &functions));
+    write_if_changed(&c_src.join("php_rust_math_arginfo.h"), &render_arginfo(&functions));
+    write_if_changed(&c_src.join("php_rust_math_generated.c"), &render_wrappers(&ctx, &functions));
+}
+
+/// 递归收集目录下的 .rs 文件
+fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir).expect("无法读取 src 目录");
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_rust_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// 只在内容变化时写入文件，避免无意义地触发 C 代码重新编译
+fn write_if_changed(path: &Path, content: &str) {
+    if fs::read_to_string(path).map(|old| old == content).unwrap_or(false) {
+        return;
+    }
+    fs::write(path, content).expect("无法写入生成的文件");
+}
+
+// ============================================================================
+// Rust 源码解析
+// ============================================================================
+
+/// 逐行解析源文件中的 `#[repr(C)]` 类型和 `#[no_mangle]` 函数
+///
+/// 这里只处理本项目自己的代码风格，不是完整的 Rust 解析器
+fn parse_source(
+    source: &str,
+    file: &str,
+    enums: &mut Vec<CEnum>,
+    structs: &mut Vec<CStruct>,
+    functions: &mut Vec<ExportFn>,
+) {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut docs: Vec<String> = Vec::new();
+    let mut attrs: Vec<String> = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        if let Some(doc) = line.strip_prefix("///") {
+            docs.push(doc.strip_prefix(' ').unwrap_or(doc).trim_end().to_string());
+            i += 1;
+            continue;
+        }
+
+        if line.starts_with("#[") {
+            attrs.push(line.to_string());
+            i += 1;
+            continue;
+        }
+
+        let is_repr_c = attrs.iter().any(|attr| attr == "#[repr(C)]");
+        let is_no_mangle = attrs.iter().any(|attr| attr == "#[no_mangle]");
+
+        if is_repr_c && line.starts_with("pub enum ") {
+            let name = type_name(line, "pub enum ");
+            let (variants, next) = parse_enum_body(&lines, i + 1);
+            enums.push(CEnum { name, docs: docs.clone(), variants });
+            i = next;
+        } else if is_repr_c && line.starts_with("pub struct ") && !line.contains('<') {
+            let name = type_name(line, "pub struct ");
+            let (fields, next) = parse_struct_body(&lines, i + 1);
+            structs.push(CStruct { name, docs: docs.clone(), fields });
+            i = next;
+        } else if is_no_mangle && line.starts_with("pub ") && line.contains("extern \"C\" fn ") {
+            // 签名可能跨多行，一直读到函数体开始的 '{'
+            let mut signature = String::new();
+            while i < lines.len() {
+                signature.push_str(lines[i].trim());
+                signature.push(' ');
+                if lines[i].trim_end().ends_with('{') {
+                    break;
+                }
+                i += 1;
+            }
+            functions.push(parse_function(&signature, &docs, file));
+            i += 1;
+        } else {
+            i += 1;
+        }
+
+        if !line.is_empty() {
+            docs.clear();
+            attrs.clear();
+        }
+    }
+}
+
+/// 从 `pub struct Name {` 这样的行中取出类型名
+fn type_name(line: &str, prefix: &str) -> String {
+    line[prefix.len()..]
+        .trim_end_matches('{')
+        .trim()
+        .to_string()
+}
+
+/// 解析枚举体，返回变体列表和结束后的行号
+fn parse_enum_body(lines: &[&str], mut i: usize) -> (Vec<(String, i64, String)>, usize) {
+    let mut variants = Vec::new();
+    let mut doc = String::new();
+    let mut next_value = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+        i += 1;
+
+        if line == "}" {
+            break;
+        }
+        if let Some(text) = line.strip_prefix("///") {
+            if doc.is_empty() {
+                doc = text.trim().to_string();
+            }
+            continue;
+        }
+        if line.is_empty() || line.starts_with("#[") {
+            continue;
+        }
+
+        let line = line.trim_end_matches(',');
+        let (name, value) = match line.split_once('=') {
+            Some((name, value)) => {
+                let value: i64 = value.trim().parse().expect("枚举值必须是整数字面量");
+                (name.trim().to_string(), value)
+            }
+            None => (line.trim().to_string(), next_value),
+        };
+        next_value = value + 1;
+        variants.push((name, value, std::mem::take(&mut doc)));
+    }
+
+    (variants, i)
+}
+
+/// 解析结构体，返回字段列表和结束后的行号
+fn parse_struct_body(lines: &[&str], mut i: usize) -> (Vec<(String, String, String)>, usize) {
+    let mut fields = Vec::new();
+    let mut doc = String::new();
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+        i += 1;
+
+        if line == "}" {
+            break;
+        }
+        if let Some(text) = line.strip_prefix("///") {
+            if doc.is_empty() {
+                doc = text.trim().to_string();
+            }
+            continue;
+        }
+        if let Some(field) = line.strip_prefix("pub ") {
+            let (name, ty) = field
+                .trim_end_matches(',')
+                .split_once(':')
+                .expect("结构体字段格式应为 `pub name: Type,`");
+            fields.push((name.trim().to_string(), ty.trim().to_string(), std::mem::take(&mut doc)));
+        }
+    }
+
+    (fields, i)
+}
+
+/// 解析导出函数签名
+fn parse_function(signature: &str, docs: &[String], file: &str) -> ExportFn {
+    let after_fn = signature
+        .split_once("fn ")
+        .map(|(_, rest)| rest)
+        .expect("缺少 fn 关键字");
+    let open = after_fn.find('(').expect("函数签名缺少 '('");
+    let close = after_fn.rfind(')').expect("函数签名缺少 ')'");
+    let name = after_fn[..open].trim().to_string();
+
+    let params = after_fn[open + 1..close]
+        .split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (name, ty) = param.split_once(':').expect("参数格式应为 `name: Type`");
+            (name.trim().to_string(), ty.trim().to_string())
+        })
+        .collect();
+
+    let ret = after_fn[close + 1..]
+        .trim()
+        .trim_end_matches('{')
+        .trim()
+        .strip_prefix("->")
+        .map(|ret| ret.trim().to_string())
+        .unwrap_or_else(|| "()".to_string());
+
+    let php = docs.iter().find_map(|doc| {
+        if let Some(sig) = doc.strip_prefix("@php-manual ") {
+            Some(parse_php_signature(sig, true))
+        } else {
+            doc.strip_prefix("@php ").map(|sig| parse_php_signature(sig, false))
+        }
+    });
+
+    ExportFn {
+        name,
+        docs: docs.to_vec(),
+        params,
+        ret,
+        php,
+        file: file.to_string(),
+    }
+}
+
+/// 解析 `name(type $a, type $b = 1): ret` 形式的 PHP 签名
+fn parse_php_signature(signature: &str, manual: bool) -> PhpSignature {
+    let open = signature.find('(').expect("PHP 签名缺少 '('");
+    let close = signature.rfind(')').expect("PHP 签名缺少 ')'");
+    let name = signature[..open].trim().to_string();
+    let ret = signature[close + 1..]
+        .trim()
+        .strip_prefix(':')
+        .expect("PHP 签名缺少返回类型")
+        .trim()
+        .to_string();
+
+    let params = signature[open + 1..close]
+        .split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (decl, default) = match param.split_once('=') {
+                Some((decl, default)) => (decl.trim(), Some(default.trim().to_string())),
+                None => (param, None),
+            };
+            let (ty, name) = decl.rsplit_once(' ').expect("PHP 参数格式应为 `type $name`");
+            PhpParam {
+                name: name.trim().trim_start_matches('$').to_string(),
+                ty: ty.trim().to_string(),
+                default,
+            }
+        })
+        .collect();
+
+    PhpSignature { name, params, ret, manual }
+}
+
+// ============================================================================
+// 类型映射
+// ============================================================================
+
+/// Rust 类型到 C 类型的映射上下文
+struct TypeContext {
+    /// `#[repr(C)]` 类型名
+    repr_c: HashSet<String>,
+}
+
+impl TypeContext {
+    fn new(enums: &[CEnum], structs: &[CStruct]) -> Self {
+        let repr_c = enums
+            .iter()
+            .map(|e| e.name.clone())
+            .chain(structs.iter().map(|s| s.name.clone()))
+            .collect();
+        Self { repr_c }
+    }
+
+    /// 把 Rust 类型转换为 C 类型
+    fn c_type(&self, ty: &str) -> String {
+        let ty = ty.trim();
+        if let Some(inner) = ty.strip_prefix("*mut ") {
+            return format!("{}*", self.c_type(inner));
+        }
+        if let Some(inner) = ty.strip_prefix("*const ") {
+            return format!("const {}*", self.c_type(inner));
+        }
+
+        match ty {
+            "()" => "void".to_string(),
+            "bool" => "bool".to_string(),
+            "c_char" => "char".to_string(),
+            "c_int" => "int".to_string(),
+            "c_long" => "long".to_string(),
+            "c_double" => "double".to_string(),
+            _ if self.repr_c.contains(ty) => snake_case(ty),
+            _ if ty.chars().all(|c| c.is_ascii_alphanumeric()) => opaque_name(ty),
+            _ => panic!("不支持在 C 接口中使用类型 `{}`", ty),
+        }
+    }
+
+    /// 收集函数签名中通过指针使用的不透明类型
+    fn opaque_types(&self, functions: &[ExportFn]) -> Vec<String> {
+        let mut names = Vec::new();
+        let types = functions
+            .iter()
+            .flat_map(|f| f.params.iter().map(|(_, ty)| ty.as_str()).chain([f.ret.as_str()]));
+
+        for ty in types {
+            let inner = ty.trim_start_matches("*mut ").trim_start_matches("*const ");
+            let inner = inner.trim_start_matches("*mut ").trim_start_matches("*const ");
+            if inner != ty
+                && !self.repr_c.contains(inner)
+                && !["bool", "c_char", "c_int", "c_long", "c_double"].contains(&inner)
+            {
+                let name = opaque_name(inner);
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+
+        names
+    }
+}
+
+/// CamelCase 转 snake_case，例如 RustFibonacciResult -> rust_fibonacci_result
+fn snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// 不透明类型的 C 名称，例如 BigInt -> rust_bigint
+fn opaque_name(name: &str) -> String {
+    format!("rust_{}", name.to_ascii_lowercase())
+}
+
+/// 把文档注释转换为 C 注释行，去掉 `# Safety` 段落和 `@php` 标记
+fn c_doc_lines(docs: &[String]) -> Vec<String> {
+    let mut lines: Vec<String> = docs
+        .iter()
+        .take_while(|doc| !doc.starts_with("# Safety"))
+        .filter(|doc| !doc.starts_with("@php"))
+        .cloned()
+        .collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+// ============================================================================
+// 生成 rust_math_ffi.h
+// ============================================================================
+
+const GENERATED_NOTICE: &str = "由 build.rs 根据 src/ 中的 Rust 代码自动生成，请勿手动修改";
+
+fn render_ffi_header(ctx: &TypeContext, enums: &[CEnum], structs: &[CStruct], functions: &[ExportFn]) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("/**\n * PHP Rust 数学扩展 - Rust 函数的 C 声明\n *\n * {}\n */\n\n", GENERATED_NOTICE));
+    out.push_str("#ifndef RUST_MATH_FFI_H\n#define RUST_MATH_FFI_H\n\n#include <stdbool.h>\n\n");
+
+    for e in enums {
+        push_block_comment(&mut out, &e.docs, "");
+        out.push_str("typedef enum {\n");
+        let prefix = snake_case(&e.name).to_ascii_uppercase();
+        for (i, (name, value, doc)) in e.variants.iter().enumerate() {
+            let comma = if i + 1 < e.variants.len() { "," } else { "" };
+            let entry = format!("    {}_{} = {}{}", prefix, snake_case(name).to_ascii_uppercase(), value, comma);
+            out.push_str(&format!("{:<44}/* {} */\n", entry, doc));
+        }
+        out.push_str(&format!("}} {};\n\n", snake_case(&e.name)));
+    }
+
+    for s in structs {
+        push_block_comment(&mut out, &s.docs, "");
+        out.push_str("typedef struct {\n");
+        for (name, ty, doc) in &s.fields {
+            let entry = format!("    {} {};", ctx.c_type(ty), name);
+            out.push_str(&format!("{:<44}/* {} */\n", entry, doc));
+        }
+        out.push_str(&format!("}} {};\n\n", snake_case(&s.name)));
+    }
+
+    let opaque = ctx.opaque_types(functions);
+    if !opaque.is_empty() {
+        out.push_str("/* 不透明句柄，内部结构只有 Rust 知道 */\n");
+        for name in opaque {
+            out.push_str(&format!("typedef struct {} {};\n", name, name));
+        }
+        out.push('\n');
+    }
+
+    let mut current_file = "";
+    for f in functions {
+        if f.file != current_file {
+            current_file = &f.file;
+            out.push_str(&format!("/* ========================================================================\n * {}\n * ======================================================================== */\n\n", f.file));
+        }
+        push_block_comment(&mut out, &f.docs, "");
+        out.push_str(&format!("{};\n\n", c_prototype(ctx, f)));
+    }
+
+    out.push_str("#endif /* RUST_MATH_FFI_H */\n");
+    out
+}
+
+/// 生成 C 函数原型
+fn c_prototype(ctx: &TypeContext, f: &ExportFn) -> String {
+    let params = if f.params.is_empty() {
+        "void".to_string()
+    } else {
+        f.params
+            .iter()
+            .map(|(name, ty)| format!("{} {}", ctx.c_type(ty), name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!("{} {}({})", ctx.c_type(&f.ret), f.name, params)
+}
+
+/// 输出 `/** ... */` 形式的注释
+fn push_block_comment(out: &mut String, docs: &[String], indent: &str) {
+    let lines = c_doc_lines(docs);
+    if lines.is_empty() {
+        return;
+    }
+    out.push_str(&format!("{}/**\n", indent));
+    for line in lines {
+        if line.is_empty() {
+            out.push_str(&format!("{} *\n", indent));
+        } else {
+            out.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{} */\n", indent));
+}
+
+// ============================================================================
+// 生成 php_rust_math_arginfo.h
+// ============================================================================
+
+/// PHP 标量类型对应的 Zend 类型码
+fn zend_type_code(ty: &str) -> Option<&'static str> {
+    match ty {
+        "int" => Some("IS_LONG"),
+        "float" => Some("IS_DOUBLE"),
+        "bool" => Some("_IS_BOOL"),
+        "string" => Some("IS_STRING"),
+        "array" => Some("IS_ARRAY"),
+        "void" => Some("IS_VOID"),
+        "mixed" => Some("IS_MIXED"),
+        _ => None,
+    }
+}
+
+/// PHP 标量类型对应的类型掩码
+fn zend_type_mask(ty: &str) -> String {
+    match ty {
+        "int" => "MAY_BE_LONG",
+        "float" => "MAY_BE_DOUBLE",
+        "bool" => "MAY_BE_BOOL",
+        "string" => "MAY_BE_STRING",
+        "array" => "MAY_BE_ARRAY",
+        "null" => "MAY_BE_NULL",
+        _ => panic!("类型掩码中不支持类型 `{}`", ty),
+    }
+    .to_string()
+}
+
+/// 拆分 PHP 类型：返回 (类名, 标量类型列表, 是否可为 null)
+fn split_php_type(ty: &str) -> (Option<String>, Vec<String>, bool) {
+    let (ty, mut nullable) = match ty.strip_prefix('?') {
+        Some(inner) => (inner, true),
+        None => (ty, false),
+    };
+
+    let mut class = None;
+    let mut scalars = Vec::new();
+    for part in ty.split('|') {
+        if part == "null" {
+            nullable = true;
+        } else if zend_type_code(part).is_some() {
+            scalars.push(part.to_string());
+        } else {
+            // 类名在 C 字符串中需要转义反斜杠
+            class = Some(part.trim_start_matches('\\').replace('\\', "\\\\"));
+        }
+    }
+
+    (class, scalars, nullable)
+}
+
+/// 生成参数信息的内容（不含宏名中的变量名），用于比较是否可以复用
+fn render_arginfo_body(sig: &PhpSignature) -> String {
+    let required = sig.params.iter().take_while(|p| p.default.is_none()).count();
+    let (class, scalars, nullable) = split_php_type(&sig.ret);
+    let nullable = nullable as i32;
+
+    let mut out = match (&class, scalars.len()) {
+        (Some(class), 0) => format!(
+            "ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(%NAME%, 0, {}, {}, {})\n",
+            required, class, nullable
+        ),
+        (None, 1) => format!(
+            "ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(%NAME%, 0, {}, {}, {})\n",
+            required,
+            zend_type_code(&scalars[0]).unwrap_or("IS_MIXED"),
+            nullable
+        ),
+        (None, _) => {
+            let mut masks: Vec<String> = scalars.iter().map(|s| zend_type_mask(s)).collect();
+            if nullable == 1 {
+                masks.push("MAY_BE_NULL".to_string());
+            }
+            format!(
+                "ZEND_BEGIN_ARG_WITH_RETURN_TYPE_MASK_EX(%NAME%, 0, {}, {})\n",
+                required,
+                masks.join("|")
+            )
+        }
+        (Some(class), _) => {
+            let masks: Vec<String> = scalars.iter().map(|s| zend_type_mask(s)).collect();
+            format!(
+                "ZEND_BEGIN_ARG_WITH_RETURN_OBJ_TYPE_MASK_EX(%NAME%, 0, {}, {}, {})\n",
+                required,
+                class,
+                masks.join("|")
+            )
+        }
+    };
+
+    for param in &sig.params {
+        let (class, scalars, nullable) = split_php_type(&param.ty);
+        let default = param
+            .default
+            .as_ref()
+            .map(|d| format!("\"{}\"", d.replace('"', "\\\"")));
+        let line = match (&class, scalars.len()) {
+            (Some(class), 0) => match &default {
+                Some(default) => format!(
+                    "ZEND_ARG_OBJ_INFO_WITH_DEFAULT_VALUE(0, {}, {}, {}, {})",
+                    param.name, class, nullable as i32, default
+                ),
+                None => format!("ZEND_ARG_OBJ_INFO(0, {}, {}, {})", param.name, class, nullable as i32),
+            },
+            (None, 1) => {
+                let code = zend_type_code(&scalars[0]).unwrap_or("IS_MIXED");
+                match &default {
+                    Some(default) => format!(
+                        "ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, {}, {}, {}, {})",
+                        param.name, code, nullable as i32, default
+                    ),
+                    None => format!("ZEND_ARG_TYPE_INFO(0, {}, {}, {})", param.name, code, nullable as i32),
+                }
+            }
+            (None, _) => {
+                let mut masks: Vec<String> = scalars.iter().map(|s| zend_type_mask(s)).collect();
+                if nullable {
+                    masks.push("MAY_BE_NULL".to_string());
+                }
+                format!(
+                    "ZEND_ARG_TYPE_MASK(0, {}, {}, {})",
+                    param.name,
+                    masks.join("|"),
+                    default.unwrap_or_else(|| "NULL".to_string())
+                )
+            }
+            (Some(class), _) => {
+                let mut masks: Vec<String> = scalars.iter().map(|s| zend_type_mask(s)).collect();
+                if nullable {
+                    masks.push("MAY_BE_NULL".to_string());
+                }
+                format!(
+                    "ZEND_ARG_OBJ_TYPE_MASK(0, {}, {}, {}, {})",
+                    param.name,
+                    class,
+                    masks.join("|"),
+                    default.unwrap_or_else(|| "NULL".to_string())
+                )
+            }
+        };
+        out.push_str(&format!("    {}\n", line));
+    }
+
+    out.push_str("ZEND_END_ARG_INFO()\n");
+    out
+}
+
+fn render_arginfo(functions: &[ExportFn]) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("/**\n * PHP Rust 数学扩展 - 函数参数信息和函数列表\n *\n * {}\n */\n\n", GENERATED_NOTICE));
+
+    let php_functions: Vec<&PhpSignature> = functions.iter().filter_map(|f| f.php.as_ref()).collect();
+
+    // 内容相同的参数信息只定义一次，其余用 #define 复用（与 PHP 的 gen_stub.php 一致）
+    let mut defined: BTreeMap<String, String> = BTreeMap::new();
+    for sig in &php_functions {
+        let name = format!("arginfo_{}", sig.name);
+        let body = render_arginfo_body(sig);
+        match defined.get(&body) {
+            Some(existing) => out.push_str(&format!("#define {} {}\n\n", name, existing)),
+            None => {
+                out.push_str(&body.replace("%NAME%", &name));
+                out.push('\n');
+                defined.insert(body, name);
+            }
+        }
+    }
+
+    for sig in &php_functions {
+        out.push_str(&format!("PHP_FUNCTION({});\n", sig.name));
+    }
+    out.push('\n');
+
+    out.push_str("static const zend_function_entry ext_functions[] = {\n");
+    for sig in &php_functions {
+        out.push_str(&format!("    PHP_FE({}, arginfo_{})\n", sig.name, sig.name));
+    }
+    out.push_str("    PHP_FE_END\n};\n");
+    out
+}
+
+// ============================================================================
+// 生成 php_rust_math_generated.c
+// ============================================================================
+
+/// Rust 标量类型对应的 (PHP 类型, zpp 宏, C 变量类型, RETURN 宏)
+fn scalar_binding(ty: &str) -> Option<(&'static str, &'static str, &'static str, &'static str)> {
+    match ty {
+        "c_long" | "c_int" => Some(("int", "Z_PARAM_LONG", "zend_long", "RETURN_LONG")),
+        "c_double" => Some(("float", "Z_PARAM_DOUBLE", "double", "RETURN_DOUBLE")),
+        "bool" => Some(("bool", "Z_PARAM_BOOL", "bool", "RETURN_BOOL")),
+        _ => None,
+    }
+}
+
+fn render_wrappers(ctx: &TypeContext, functions: &[ExportFn]) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("/**\n * PHP Rust 数学扩展 - 自动生成的 PHP 函数包装\n *\n * {}\n */\n\n", GENERATED_NOTICE));
+    out.push_str("#include \"php.h\"\n#include \"php_rust_math.h\"\n");
+
+    for f in functions {
+        let sig = match &f.php {
+            Some(sig) if !sig.manual => sig,
+            _ => continue,
+        };
+        out.push('\n');
+        out.push_str(&render_wrapper(ctx, f, sig));
+    }
+
+    out
+}
+
+/// 为一个导出函数生成 PHP_FUNCTION
+///
+/// 支持两种 Rust 签名：
+/// - 直接返回标量：`fn(a: c_long, ...) -> c_long`
+/// - 返回错误码，结果通过最后一个指针参数返回：`fn(a: c_long, ..., result: *mut c_long) -> MathError`
+fn render_wrapper(ctx: &TypeContext, f: &ExportFn, sig: &PhpSignature) -> String {
+    let returns_status = f.ret == "MathError";
+    let (inputs, output) = if returns_status {
+        let (last, rest) = f
+            .params
+            .split_last()
+            .unwrap_or_else(|| panic!("{}: 返回 MathError 的函数必须有结果指针参数", f.name));
+        let inner = last.1.strip_prefix("*mut ").unwrap_or_else(|| {
+            panic!("{}: 返回 MathError 的函数最后一个参数必须是 *mut 指针", f.name)
+        });
+        (rest, inner.to_string())
+    } else {
+        (&f.params[..], f.ret.clone())
+    };
+
+    if inputs.len() != sig.params.len() {
+        panic!("{}: @php 签名的参数个数与 Rust 函数不一致", f.name);
+    }
+
+    let (ret_php, _, _, return_macro) = scalar_binding(&output)
+        .unwrap_or_else(|| panic!("{}: 返回类型 `{}` 需要使用 @php-manual 手写包装", f.name, output));
+    if sig.ret != ret_php {
+        panic!("{}: @php 返回类型应为 {}", f.name, ret_php);
+    }
+
+    let mut out = String::new();
+    let php_params: Vec<String> = sig
+        .params
+        .iter()
+        .map(|p| match &p.default {
+            Some(default) => format!("{} ${} = {}", p.ty, p.name, default),
+            None => format!("{} ${}", p.ty, p.name),
+        })
+        .collect();
+    out.push_str(&format!("/* {}({}): {} */\n", sig.name, php_params.join(", "), sig.ret));
+    out.push_str(&format!("PHP_FUNCTION({})\n{{\n", sig.name));
+
+    // 变量声明
+    for ((name, ty), php) in inputs.iter().zip(&sig.params) {
+        let (php_ty, _, c_ty, _) = scalar_binding(ty)
+            .unwrap_or_else(|| panic!("{}: 参数类型 `{}` 需要使用 @php-manual 手写包装", f.name, ty));
+        if php.ty != php_ty {
+            panic!("{}: 参数 ${} 的 PHP 类型应为 {}", f.name, php.name, php_ty);
+        }
+        match &php.default {
+            Some(default) => out.push_str(&format!("    {} {} = {};\n", c_ty, name, default)),
+            None => out.push_str(&format!("    {} {};\n", c_ty, name)),
+        }
+    }
+    if returns_status {
+        out.push_str(&format!("    {} result;\n", ctx.c_type(&output)));
+    }
+    out.push('\n');
+
+    // 参数解析
+    let required = sig.params.iter().take_while(|p| p.default.is_none()).count();
+    if inputs.is_empty() {
+        out.push_str("    ZEND_PARSE_PARAMETERS_NONE();\n");
+    } else {
+        out.push_str(&format!("    ZEND_PARSE_PARAMETERS_START({}, {})\n", required, inputs.len()));
+        for (i, (name, ty)) in inputs.iter().enumerate() {
+            if i == required {
+                out.push_str("        Z_PARAM_OPTIONAL\n");
+            }
+            let (_, zpp, _, _) = scalar_binding(ty).unwrap_or_default();
+            out.push_str(&format!("        {}({})\n", zpp, name));
+        }
+        out.push_str("    ZEND_PARSE_PARAMETERS_END();\n");
+    }
+    out.push('\n');
+
+    // 调用 Rust 函数
+    let mut args: Vec<String> = inputs
+        .iter()
+        .map(|(name, ty)| match ty.as_str() {
+            "c_int" => format!("(int) {}", name),
+            _ => name.clone(),
+        })
+        .collect();
+    if returns_status {
+        args.push("&result".to_string());
+        out.push_str(&format!("    math_error error = {}({});\n", f.name, args.join(", ")));
+        out.push_str("    if (error != MATH_ERROR_SUCCESS) {\n");
+        out.push_str("        rust_math_throw_error(error);\n");
+        out.push_str("        RETURN_THROWS();\n");
+        out.push_str("    }\n\n");
+        out.push_str(&format!("    {}(result);\n", return_macro));
+    } else {
+        out.push_str(&format!("    {}({}({}));\n", return_macro, f.name, args.join(", ")));
+    }
+
+    out.push_str("}\n");
+    out
+}
@@ -7,12 +7,117 @@
 
 #include "php.h"
 #include "php_ini.h"
+#include "zend_exceptions.h"
 #include "ext/standard/info.h"
 #include "php_rust_math.h"
+#include "php_rust_math_arginfo.h"
 
 /* 扩展版本信息 */
 #define PHP_RUST_MATH_VERSION "0.1.0"
 
+/**
+ * 解析溢出处理模式名称
+ * 
+ * @param name 模式名称：saturate、wrap 或 checked（不区分大小写）
+ * @return 对应的 overflow_mode，名称无效时返回 -1
+ */
+static int rust_math_parse_overflow_mode(const char *name)
+{
+    if (strcasecmp(name, "saturate") == 0) {
+        return OVERFLOW_MODE_SATURATING;
+    }
+    if (strcasecmp(name, "wrap") == 0) {
+        return OVERFLOW_MODE_WRAPPING;
+    }
+    if (strcasecmp(name, "checked") == 0) {
+        return OVERFLOW_MODE_CHECKED;
+    }
+    return -1;
+}
+
+/* INI 设置 rust_math.overflow_mode 的更新处理函数 */
+static ZEND_INI_MH(OnUpdateOverflowMode)
+{
+    int mode = rust_math_parse_overflow_mode(ZSTR_VAL(new_value));
+    
+    if (mode < 0) {
+        php_error_docref(NULL, E_WARNING, "rust_math.overflow_mode must be saturate, wrap or checked");
+        return FAILURE;
+    }
+    
+    rust_set_overflow_mode(mode);
+    return SUCCESS;
+}
+
+/**
+ * 解析浮点数错误处理模式名称
+ * 
+ * @param name 模式名称：strict 或 ieee（不区分大小写）
+ * @return 对应的 float_mode，名称无效时返回 -1
+ */
+static int rust_math_parse_float_mode(const char *name)
+{
+    if (strcasecmp(name, "strict") == 0) {
+        return FLOAT_MODE_STRICT;
+    }
+    if (strcasecmp(name, "ieee") == 0) {
+        return FLOAT_MODE_IEEE;
+    }
+    return -1;
+}
+
+/* INI 设置 rust_math.float_mode 的更新处理函数 */
+static ZEND_INI_MH(OnUpdateFloatMode)
+{
+    int mode = rust_math_parse_float_mode(ZSTR_VAL(new_value));
+    
+    if (mode < 0) {
+        php_error_docref(NULL, E_WARNING, "rust_math.float_mode must be strict or ieee");
+        return FAILURE;
+    }
+    
+    rust_set_float_mode(mode);
+    return SUCCESS;
+}
+
+/**
+ * 解析错误信息语言名称
+ * 
+ * @param name 语言名称：en 或 zh（不区分大小写）
+ * @return 对应的 message_language，名称无效时返回 -1
+ */
+static int rust_math_parse_language(const char *name)
+{
+    if (strcasecmp(name, "en") == 0) {
+        return MESSAGE_LANGUAGE_ENGLISH;
+    }
+    if (strcasecmp(name, "zh") == 0) {
+        return MESSAGE_LANGUAGE_CHINESE;
+    }
+    return -1;
+}
+
+/* INI 设置 rust_math.language 的更新处理函数 */
+static ZEND_INI_MH(OnUpdateLanguage)
+{
+    int language = rust_math_parse_language(ZSTR_VAL(new_value));
+    
+    if (language < 0) {
+        php_error_docref(NULL, E_WARNING, "rust_math.language must be en or zh");
+        return FAILURE;
+    }
+    
+    rust_set_language(language);
+    return SUCCESS;
+}
+
+/* INI 设置 */
+PHP_INI_BEGIN()
+    PHP_INI_ENTRY("rust_math.overflow_mode", "saturate", PHP_INI_ALL, OnUpdateOverflowMode)
+    PHP_INI_ENTRY("rust_math.float_mode", "strict", PHP_INI_ALL, OnUpdateFloatMode)
+    PHP_INI_ENTRY("rust_math.language", "en", PHP_INI_ALL, OnUpdateLanguage)
+PHP_INI_END()
+
 /* 函数声明 */
 PHP_MINIT_FUNCTION(rust_math);
 PHP_MSHUTDOWN_FUNCTION(rust_math);
@@ -20,28 +125,18 @@ PHP_RINIT_FUNCTION(rust_math);
 PHP_RSHUTDOWN_FUNCTION(rust_math);
 PHP_MINFO_FUNCTION(rust_math);
 
-/* 导出函数声明 */
-PHP_FUNCTION(rust_add);
-PHP_FUNCTION(rust_multiply);
-PHP_FUNCTION(rust_factorial);
-PHP_FUNCTION(rust_fibonacci);
-PHP_FUNCTION(rust_is_prime);
-
-/* 函数列表 */
-static const zend_function_entry rust_math_functions[] = {
-    PHP_FE(rust_add, NULL)
-    PHP_FE(rust_multiply, NULL)
-    PHP_FE(rust_factorial, NULL)
-    PHP_FE(rust_fibonacci, NULL)
-    PHP_FE(rust_is_prime, NULL)
-    PHP_FE_END
-};
+/*
+ * PHP 函数的参数信息和函数列表 ext_functions 在 php_rust_math_arginfo.h 中，
+ * 简单函数的包装在 php_rust_math_generated.c 中，它们都由 build.rs 根据
+ * Rust 导出函数上的 @php 标记生成。这里只手写标记为 @php-manual 的函数，
+ * 其中 rust_matrix_* 矩阵函数在 rust_math_matrix.c 中
+ */
 
 /* 扩展模块 ID */
 zend_module_entry rust_math_module_entry = {
     STANDARD_MODULE_HEADER,
     "rust_math",                    /* 扩展名称 */
-    rust_math_functions,            /* 函数列表 */
+    ext_functions,                  /* 函数列表 */
     PHP_MINIT(rust_math),           /* 模块初始化函数 */
     PHP_MSHUTDOWN(rust_math),       /* 模块关闭函数 */
     PHP_RINIT(rust_math),           /* 请求初始化函数 */
@@ -63,6 +158,22 @@ ZEND_GET_MODULE(rust_math)
 PHP_MINIT_FUNCTION(rust_math)
 {
     /* 在这里可以注册 INI 设置、常量等 */
+    REGISTER_INI_ENTRIES();
+    
+    REGISTER_LONG_CONSTANT("RUST_MATH_OVERFLOW_SATURATE", OVERFLOW_MODE_SATURATING, CONST_CS | CONST_PERSISTENT);
+    REGISTER_LONG_CONSTANT("RUST_MATH_OVERFLOW_WRAP", OVERFLOW_MODE_WRAPPING, CONST_CS | CONST_PERSISTENT);
+    REGISTER_LONG_CONSTANT("RUST_MATH_OVERFLOW_CHECKED", OVERFLOW_MODE_CHECKED, CONST_CS | CONST_PERSISTENT);
+    REGISTER_LONG_CONSTANT("RUST_MATH_FLOAT_STRICT", FLOAT_MODE_STRICT, CONST_CS | CONST_PERSISTENT);
+    REGISTER_LONG_CONSTANT("RUST_MATH_FLOAT_IEEE", FLOAT_MODE_IEEE, CONST_CS | CONST_PERSISTENT);
+    
+    rust_math_register_exception_classes();
+    rust_math_register_bigint_class();
+    rust_math_register_sequence_class();
+    rust_math_register_enumeration_class();
+    rust_math_register_rational_class();
+    rust_math_register_decimal_class();
+    rust_math_register_complex_class();
+    
     return SUCCESS;
 }
 
@@ -70,6 +181,8 @@ PHP_MINIT_FUNCTION(rust_math)
 PHP_MSHUTDOWN_FUNCTION(rust_math)
 {
     /* 清理资源 */
+    UNREGISTER_INI_ENTRIES();
+    
     return SUCCESS;
 }
 
@@ -79,6 +192,18 @@ PHP_RINIT_FUNCTION(rust_math)
 #if defined(COMPILE_DL_RUST_MATH) && defined(ZTS)
     ZEND_TSRMLS_CACHE_UPDATE();
 #endif
+    
+    /* 溢出模式保存在 Rust 的线程局部变量中，每个请求开始时按 INI 设置同步一次 */
+    int mode = rust_math_parse_overflow_mode(INI_STR("rust_math.overflow_mode"));
+    rust_set_overflow_mode(mode < 0 ? OVERFLOW_MODE_SATURATING : mode);
+    
+    /* 浮点模式和错误信息语言同理 */
+    int float_mode = rust_math_parse_float_mode(INI_STR("rust_math.float_mode"));
+    rust_set_float_mode(float_mode < 0 ? FLOAT_MODE_STRICT : float_mode);
+    
+    int language = rust_math_parse_language(INI_STR("rust_math.language"));
+    rust_set_language(language < 0 ? MESSAGE_LANGUAGE_ENGLISH : language);
+    
     return SUCCESS;
 }
 
@@ -97,126 +222,387 @@ PHP_MINFO_FUNCTION(rust_math)
     php_info_print_table_row(2, "作者", "Rust 学习者");
     php_info_print_table_row(2, "描述", "使用 Rust 编写的 PHP 数学扩展");
     php_info_print_table_end();
+    
+    DISPLAY_INI_ENTRIES();
 }
 
-/* PHP 函数：rust_add - 计算两个整数的和 */
-PHP_FUNCTION(rust_add)
+/* PHP 函数：rust_math_error_message - 获取错误码的描述，language 为 null 时使用 rust_math.language */
+PHP_FUNCTION(rust_math_error_message)
 {
-    long a, b;
+    zend_long code;
+    zend_string *name = NULL;
+    int language;
     
-    /* 解析参数 */
-    if (zend_parse_parameters(ZEND_NUM_ARGS(), "ll", &a, &b) == FAILURE) {
-        RETURN_NULL();
+    ZEND_PARSE_PARAMETERS_START(1, 2)
+        Z_PARAM_LONG(code)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_STR_OR_NULL(name)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    if (name == NULL) {
+        language = rust_get_language();
+    } else {
+        language = rust_math_parse_language(ZSTR_VAL(name));
+        if (language < 0) {
+            zend_argument_value_error(2, "must be \"en\" or \"zh\"");
+            RETURN_THROWS();
+        }
     }
     
-    /* 调用 Rust 函数 */
-    long result = rust_add_impl(a, b);
+    /* 返回的是 Rust 中的静态字符串，不需要释放 */
+    RETURN_STRING(rust_math_error_message_in((int) code, language));
+}
+
+/* PHP 函数：rust_math_error_name - 获取错误码的符号名称，例如 OVERFLOW */
+PHP_FUNCTION(rust_math_error_name)
+{
+    zend_long code;
     
-    /* 返回结果 */
-    RETURN_LONG(result);
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(code)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    RETURN_STRING(rust_math_error_name((int) code));
 }
 
-/* PHP 函数：rust_multiply - 计算两个数的乘积 */
-PHP_FUNCTION(rust_multiply)
+/* PHP 函数：rust_factorial_big - 计算任意精度阶乘，返回十进制字符串 */
+PHP_FUNCTION(rust_factorial_big)
 {
-    double a, b;
+    zend_long n;
+    rust_string_result result;
     
     /* 解析参数 */
-    if (zend_parse_parameters(ZEND_NUM_ARGS(), "dd", &a, &b) == FAILURE) {
-        RETURN_NULL();
-    }
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
     
     /* 调用 Rust 函数 */
-    double result = rust_multiply_impl(a, b);
+    math_error error = rust_factorial_big_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
     
-    /* 返回结果 */
-    RETURN_DOUBLE(result);
+    /* 复制为 PHP 字符串后释放 Rust 分配的内存 */
+    RETVAL_STRINGL(result.data, result.length);
+    rust_free_string_result(result);
 }
 
-/* PHP 函数：rust_factorial - 计算阶乘 */
-PHP_FUNCTION(rust_factorial)
+/* PHP 函数：rust_fibonacci - 生成斐波那契数列 */
+PHP_FUNCTION(rust_fibonacci)
 {
-    long n;
+    zend_long n;
+    rust_fibonacci_result result;
     
     /* 解析参数 */
-    if (zend_parse_parameters(ZEND_NUM_ARGS(), "l", &n) == FAILURE) {
-        RETURN_NULL();
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    /* 调用 Rust 函数获取数列，长度不在 0 到 100 之间或超过 93 项（结果溢出）时返回错误码 */
+    math_error error = rust_fibonacci_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
     }
     
-    /* 参数验证 */
-    if (n < 0) {
-        php_error_docref(NULL, E_WARNING, "阶乘不能计算负数");
-        RETURN_NULL();
+    /* 创建 PHP 数组 */
+    array_init_size(return_value, result.length);
+    
+    /* 将结果添加到数组中 */
+    for (int i = 0; i < result.length; i++) {
+        add_next_index_long(return_value, result.numbers[i]);
     }
     
-    if (n > 20) {
-        php_error_docref(NULL, E_WARNING, "阶乘数值过大，可能导致溢出");
-        RETURN_NULL();
+    /* 释放 Rust 分配的内存 */
+    rust_free_fibonacci_result(result);
+}
+
+/* 把 Rust 返回的整数数组复制为 PHP 数组，然后释放 Rust 分配的内存 */
+void rust_math_return_integer_array(zval *return_value, rust_integer_array_result result)
+{
+    array_init_size(return_value, (uint32_t) result.length);
+    
+    for (long i = 0; i < result.length; i++) {
+        add_next_index_long(return_value, result.numbers[i]);
     }
     
-    /* 调用 Rust 函数 */
-    long result = rust_factorial_impl(n);
+    rust_free_integer_array_result(result);
+}
+
+/* PHP 函数：rust_factorize - 质因数分解，返回 [质数 => 指数] 数组 */
+PHP_FUNCTION(rust_factorize)
+{
+    zend_long n;
+    rust_factorization_result result;
     
-    /* 返回结果 */
-    RETURN_LONG(result);
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    /* n 为负数或 0 时返回错误码 */
+    math_error error = rust_factorize_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+    
+    array_init_size(return_value, result.length);
+    
+    for (int i = 0; i < result.length; i++) {
+        add_index_long(return_value, result.primes[i], result.exponents[i]);
+    }
+    
+    rust_free_factorization_result(result);
 }
 
-/* PHP 函数：rust_fibonacci - 生成斐波那契数列 */
-PHP_FUNCTION(rust_fibonacci)
+/* PHP 函数：rust_divisors - 按从小到大的顺序返回全部正因数 */
+PHP_FUNCTION(rust_divisors)
 {
-    long n;
+    zend_long n;
+    rust_integer_array_result result;
     
-    /* 解析参数 */
-    if (zend_parse_parameters(ZEND_NUM_ARGS(), "l", &n) == FAILURE) {
-        RETURN_NULL();
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    math_error error = rust_divisors_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
     }
     
-    /* 参数验证 */
-    if (n < 0) {
-        php_error_docref(NULL, E_WARNING, "斐波那契数列长度不能为负数");
-        RETURN_NULL();
+    rust_math_return_integer_array(return_value, result);
+}
+
+/* PHP 函数：rust_primes_up_to - 不超过 n 的全部质数 */
+PHP_FUNCTION(rust_primes_up_to)
+{
+    zend_long n;
+    rust_integer_array_result result;
+    
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    /* n 超过 1 亿时返回错误码 */
+    math_error error = rust_primes_up_to_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
     }
     
-    if (n > 100) {
-        php_error_docref(NULL, E_WARNING, "斐波那契数列长度不能超过 100");
-        RETURN_NULL();
+    rust_math_return_integer_array(return_value, result);
+}
+
+/* PHP 函数：rust_primes_between - 区间 [a, b] 内的全部质数 */
+PHP_FUNCTION(rust_primes_between)
+{
+    zend_long a, b;
+    rust_integer_array_result result;
+    
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    /* 区间长度超过 1 亿时返回错误码 */
+    math_error error = rust_primes_between_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
     }
     
-    /* 调用 Rust 函数获取数列 */
-    rust_fibonacci_result result = rust_fibonacci_impl(n);
+    rust_math_return_integer_array(return_value, result);
+}
+
+/* PHP 函数：rust_extended_gcd - 扩展欧几里得算法，返回 ['gcd' => g, 'x' => x, 'y' => y]，满足 a*x + b*y = g */
+PHP_FUNCTION(rust_extended_gcd)
+{
+    zend_long a, b;
+    rust_extended_gcd_result result;
     
-    /* 创建 PHP 数组 */
-    array_init(return_value);
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
     
-    /* 将结果添加到数组中 */
-    for (int i = 0; i < result.length; i++) {
-        add_next_index_long(return_value, result.numbers[i]);
+    math_error error = rust_extended_gcd_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
     }
     
-    /* 释放 Rust 分配的内存 */
-    rust_free_fibonacci_result(result);
+    array_init_size(return_value, 3);
+    add_assoc_long(return_value, "gcd", result.gcd);
+    add_assoc_long(return_value, "x", result.x);
+    add_assoc_long(return_value, "y", result.y);
 }
 
-/* PHP 函数：rust_is_prime - 检查是否为质数 */
-PHP_FUNCTION(rust_is_prime)
+/* 把只包含整数的 PHP 数组复制到 C 数组中，出错时抛出 TypeError 并返回 false */
+bool rust_math_copy_long_array(HashTable *array, long *numbers, uint32_t arg_num)
 {
-    long n;
+    zval *value;
+    uint32_t i = 0;
     
-    /* 解析参数 */
-    if (zend_parse_parameters(ZEND_NUM_ARGS(), "l", &n) == FAILURE) {
-        RETURN_NULL();
+    ZEND_HASH_FOREACH_VAL(array, value) {
+        if (Z_TYPE_P(value) != IS_LONG) {
+            zend_argument_type_error(arg_num, "must contain only int, %s given", zend_zval_type_name(value));
+            return false;
+        }
+        numbers[i++] = Z_LVAL_P(value);
+    } ZEND_HASH_FOREACH_END();
+    
+    return true;
+}
+
+/* PHP 函数：rust_crt - 中国剩余定理，返回 ['remainder' => x, 'modulus' => m]，全部解为 x + k*m */
+PHP_FUNCTION(rust_crt)
+{
+    HashTable *remainders_ht, *moduli_ht;
+    rust_crt_result result;
+    
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_ARRAY_HT(remainders_ht)
+        Z_PARAM_ARRAY_HT(moduli_ht)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    uint32_t length = zend_hash_num_elements(remainders_ht);
+    if (zend_hash_num_elements(moduli_ht) != length) {
+        zend_argument_value_error(2, "must have the same number of elements as $remainders");
+        RETURN_THROWS();
     }
     
-    /* 参数验证 */
-    if (n < 2) {
-        RETURN_BOOL(0);
+    long *remainders = safe_emalloc(length, sizeof(long), 0);
+    long *moduli = safe_emalloc(length, sizeof(long), 0);
+    
+    math_error error = MATH_ERROR_SUCCESS;
+    if (rust_math_copy_long_array(remainders_ht, remainders, 1) && rust_math_copy_long_array(moduli_ht, moduli, 2)) {
+        /* 模数不是正数、方程组无解或模数的最小公倍数溢出时返回错误码 */
+        error = rust_crt_impl(remainders, moduli, length, &result);
+        if (error != MATH_ERROR_SUCCESS) {
+            rust_math_throw_error(error);
+        }
     }
     
-    /* 调用 Rust 函数 */
-    bool result = rust_is_prime_impl(n);
+    efree(remainders);
+    efree(moduli);
     
-    /* 返回结果 */
-    RETURN_BOOL(result);
+    if (EG(exception)) {
+        RETURN_THROWS();
+    }
+    
+    array_init_size(return_value, 2);
+    add_assoc_long(return_value, "remainder", result.remainder);
+    add_assoc_long(return_value, "modulus", result.modulus);
+}
+
+/* PHP 函数：rust_power_fraction - 分数的整数次幂，返回最简分数 ['numerator' => p, 'denominator' => q]，q > 0 */
+PHP_FUNCTION(rust_power_fraction)
+{
+    zend_long numerator, denominator, exponent;
+    rust_fraction result;
+    
+    ZEND_PARSE_PARAMETERS_START(3, 3)
+        Z_PARAM_LONG(numerator)
+        Z_PARAM_LONG(denominator)
+        Z_PARAM_LONG(exponent)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    /* 分母为 0、0 的负数次幂或结果溢出时返回错误码 */
+    math_error error = rust_power_fraction_impl(numerator, denominator, exponent, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+    
+    array_init_size(return_value, 2);
+    add_assoc_long(return_value, "numerator", result.numerator);
+    add_assoc_long(return_value, "denominator", result.denominator);
+}
+
+/* 对整数数组调用 rust_gcd_many_impl 等把数组归约为一个整数的函数 */
+static void rust_math_reduce_long_array(INTERNAL_FUNCTION_PARAMETERS,
+                                        math_error (*reduce)(const long *, long, long *))
+{
+    HashTable *numbers_ht;
+    long result = 0;
+    
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ARRAY_HT(numbers_ht)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    uint32_t length = zend_hash_num_elements(numbers_ht);
+    long *numbers = safe_emalloc(length, sizeof(long), 0);
+    
+    if (rust_math_copy_long_array(numbers_ht, numbers, 1)) {
+        /* 元素无效或结果超出 int 范围时返回错误码 */
+        math_error error = reduce(numbers, length, &result);
+        if (error != MATH_ERROR_SUCCESS) {
+            rust_math_throw_error(error);
+        }
+    }
+    
+    efree(numbers);
+    
+    if (EG(exception)) {
+        RETURN_THROWS();
+    }
+    
+    RETURN_LONG(result);
+}
+
+/* PHP 函数：rust_gcd_many - 数组中所有整数的最大公约数，空数组返回 0 */
+PHP_FUNCTION(rust_gcd_many)
+{
+    rust_math_reduce_long_array(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_gcd_many_impl);
+}
+
+/* PHP 函数：rust_lcm_many - 数组中所有整数的最小公倍数，空数组返回 1 */
+PHP_FUNCTION(rust_lcm_many)
+{
+    rust_math_reduce_long_array(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_lcm_many_impl);
+}
+
+/* PHP 函数：rust_multinomial - 多项式系数 (k1 + ... + km)! / (k1! · ... · km!)，空数组返回 1 */
+PHP_FUNCTION(rust_multinomial)
+{
+    rust_math_reduce_long_array(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_multinomial_impl);
+}
+
+/* 调用返回十进制字符串的大整数函数，例如 rust_fibonacci_big_impl */
+static void rust_math_return_big_decimal(INTERNAL_FUNCTION_PARAMETERS,
+                                         math_error (*compute)(long, rust_string_result *))
+{
+    zend_long n;
+    rust_string_result result;
+    
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+    
+    /* 下标为负数或过大时返回错误码 */
+    math_error error = compute(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+    
+    /* 复制为 PHP 字符串后释放 Rust 分配的内存 */
+    RETVAL_STRINGL(result.data, result.length);
+    rust_free_string_result(result);
+}
+
+/* PHP 函数：rust_fibonacci_big - 任意精度的第 n 个斐波那契数，返回十进制字符串 */
+PHP_FUNCTION(rust_fibonacci_big)
+{
+    rust_math_return_big_decimal(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_fibonacci_big_impl);
+}
+
+/* PHP 函数：rust_lucas_big - 任意精度的第 n 个卢卡斯数，返回十进制字符串 */
+PHP_FUNCTION(rust_lucas_big)
+{
+    rust_math_return_big_decimal(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_lucas_big_impl);
 }
 
 #ifndef ZEND_DLEXPORT
@@ -1,8 +1,8 @@
 /**
  * PHP Rust 数学扩展 - 头文件
  * 
- * 这个文件定义了 Rust 库的 C 接口
- * 它允许 C 代码调用 Rust 函数
+ * 这个文件声明扩展内部共享的 C 接口
+ * Rust 函数的声明在 build.rs 生成的 rust_math_ffi.h 中
  */
 
 #ifndef PHP_RUST_MATH_H
@@ -14,61 +14,70 @@
 extern zend_module_entry rust_math_module_entry;
 #define phpext_rust_math_ptr &rust_math_module_entry
 
-/* 斐波那契数列结果结构体 */
-typedef struct {
-    long* numbers;  /* 数列数组 */
-    int length;     /* 数组长度 */
-} rust_fibonacci_result;
+/* Rust 函数和类型的声明，由 build.rs 生成 */
+#include "rust_math_ffi.h"
 
-/* Rust 函数声明 - 这些函数在 Rust 库中实现 */
+/* ========================================================================
+ * PHP 类（各自的 .c 文件中实现，在 MINIT 中注册）
+ * ======================================================================== */
 
-/**
- * 计算两个整数的和
- * 
- * @param a 第一个整数
- * @param b 第二个整数
- * @return 两数之和
- */
-long rust_add_impl(long a, long b);
+/* RustMath\MathException 及其实现类（rust_math_exceptions.c） */
+extern zend_class_entry *rust_math_exception_ce;
+extern zend_class_entry *rust_math_overflow_error_ce;
+extern zend_class_entry *rust_math_negative_number_error_ce;
+extern zend_class_entry *rust_math_invalid_parameter_error_ce;
+extern zend_class_entry *rust_math_division_by_zero_error_ce;
+extern zend_class_entry *rust_math_domain_error_ce;
+extern zend_class_entry *rust_math_not_a_number_error_ce;
+void rust_math_register_exception_classes(void);
 
 /**
- * 计算两个数的乘积
+ * 把 Rust 返回的错误码转换为 PHP 异常
  * 
- * @param a 第一个数
- * @param b 第二个数
- * @return 两数之积
+ * @param error Rust 返回的错误码（不能是 MATH_ERROR_SUCCESS）
  */
-double rust_multiply_impl(double a, double b);
+void rust_math_throw_error(math_error error);
 
 /**
- * 计算阶乘
+ * 把 Rust 返回的整数数组转换为 PHP 数组，并释放 Rust 分配的内存
  * 
- * @param n 要计算阶乘的非负整数
- * @return n 的阶乘
+ * @param return_value 输出的 PHP 数组
+ * @param result Rust 返回的整数数组
  */
-long rust_factorial_impl(long n);
+void rust_math_return_integer_array(zval *return_value, rust_integer_array_result result);
 
 /**
- * 生成斐波那契数列
+ * 把只包含整数的 PHP 数组复制到 C 数组中
  * 
- * @param n 要生成的数列长度
- * @return 包含前 n 个斐波那契数的结构体
+ * @param array PHP 数组
+ * @param numbers 输出的 C 数组，长度至少为数组的元素个数
+ * @param arg_num 参数序号，用于异常信息
+ * @return 元素不是 int 时抛出 TypeError 并返回 false
  */
-rust_fibonacci_result rust_fibonacci_impl(long n);
+bool rust_math_copy_long_array(HashTable *array, long *numbers, uint32_t arg_num);
 
-/**
- * 释放斐波那契数列结果的内存
- * 
- * @param result 要释放的结果结构体
- */
-void rust_free_fibonacci_result(rust_fibonacci_result result);
+/* RustMath\BigInt（rust_math_bigint.c） */
+extern zend_class_entry *rust_bigint_ce;
+void rust_math_register_bigint_class(void);
 
-/**
- * 检查一个数是否为质数
- * 
- * @param n 要检查的数
- * @return 如果是质数返回 true，否则返回 false
- */
-bool rust_is_prime_impl(long n);
+/* RustMath\Sequence（rust_math_sequence.c） */
+extern zend_class_entry *rust_sequence_ce;
+void rust_math_register_sequence_class(void);
+
+/* RustMath\Enumeration（rust_math_enumeration.c） */
+extern zend_class_entry *rust_enumeration_ce;
+void rust_math_register_enumeration_class(void);
+
+/* RustMath\Rational（rust_math_rational.c） */
+extern zend_class_entry *rust_rational_ce;
+void rust_math_register_rational_class(void);
+
+/* RustMath\Decimal（rust_math_decimal.c） */
+extern zend_class_entry *rust_decimal_ce;
+void rust_math_register_decimal_class(void);
+
+/* RustMath\Complex（rust_math_complex.c） */
+extern zend_class_entry *rust_complex_ce;
+void rust_math_register_complex_class(void);
 
 #endif /* PHP_RUST_MATH_H */ 
\ No newline at end of file
@@ -0,0 +1,499 @@
+/**
+ * PHP Rust 数学扩展 - 函数参数信息和函数列表
+ *
+ * 由 build.rs 根据 src/ 中的 Rust 代码自动生成，请勿手动修改
+ */
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_math_error_message, 0, 1, IS_STRING, 0)
+    ZEND_ARG_TYPE_INFO(0, code, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, language, IS_STRING, 1, "null")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_math_error_name, 0, 1, IS_STRING, 0)
+    ZEND_ARG_TYPE_INFO(0, code, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_add, 0, 2, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, a, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, b, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_multiply, 0, 2, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, a, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, b, IS_DOUBLE, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_factorial, 0, 1, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, n, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_factorial_big, 0, 1, IS_STRING, 0)
+    ZEND_ARG_TYPE_INFO(0, n, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_fibonacci, 0, 1, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, n, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_is_prime, 0, 1, _IS_BOOL, 0)
+    ZEND_ARG_TYPE_INFO(0, n, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_gcd arginfo_rust_add
+
+#define arginfo_rust_lcm arginfo_rust_add
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_power, 0, 2, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, base, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, exponent, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_sqrt, 0, 1, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, x, IS_DOUBLE, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_cbrt arginfo_rust_sqrt
+
+#define arginfo_rust_ln arginfo_rust_sqrt
+
+#define arginfo_rust_log10 arginfo_rust_sqrt
+
+#define arginfo_rust_sin arginfo_rust_sqrt
+
+#define arginfo_rust_cos arginfo_rust_sqrt
+
+#define arginfo_rust_tan arginfo_rust_sqrt
+
+#define arginfo_rust_asin arginfo_rust_sqrt
+
+#define arginfo_rust_acos arginfo_rust_sqrt
+
+#define arginfo_rust_atan arginfo_rust_sqrt
+
+#define arginfo_rust_sinh arginfo_rust_sqrt
+
+#define arginfo_rust_cosh arginfo_rust_sqrt
+
+#define arginfo_rust_tanh arginfo_rust_sqrt
+
+#define arginfo_rust_subtract_int arginfo_rust_add
+
+#define arginfo_rust_multiply_int arginfo_rust_add
+
+#define arginfo_rust_divide_int arginfo_rust_add
+
+#define arginfo_rust_modulo_int arginfo_rust_add
+
+#define arginfo_rust_abs_int arginfo_rust_factorial
+
+#define arginfo_rust_max_int arginfo_rust_add
+
+#define arginfo_rust_min_int arginfo_rust_add
+
+#define arginfo_rust_add_float arginfo_rust_multiply
+
+#define arginfo_rust_subtract_float arginfo_rust_multiply
+
+#define arginfo_rust_divide_float arginfo_rust_multiply
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_abs_float, 0, 1, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, n, IS_DOUBLE, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_max_float arginfo_rust_multiply
+
+#define arginfo_rust_min_float arginfo_rust_multiply
+
+#define arginfo_rust_bessel_j0 arginfo_rust_sqrt
+
+#define arginfo_rust_bessel_j1 arginfo_rust_sqrt
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_bessel_jn, 0, 2, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, n, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, x, IS_DOUBLE, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_bessel_y0 arginfo_rust_sqrt
+
+#define arginfo_rust_bessel_y1 arginfo_rust_sqrt
+
+#define arginfo_rust_bessel_yn arginfo_rust_bessel_jn
+
+#define arginfo_rust_bessel_i0 arginfo_rust_sqrt
+
+#define arginfo_rust_bessel_i1 arginfo_rust_sqrt
+
+#define arginfo_rust_bessel_in arginfo_rust_bessel_jn
+
+#define arginfo_rust_bessel_k0 arginfo_rust_sqrt
+
+#define arginfo_rust_bessel_k1 arginfo_rust_sqrt
+
+#define arginfo_rust_bessel_kn arginfo_rust_bessel_jn
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_binomial, 0, 2, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, n, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, k, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_permutations arginfo_rust_binomial
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_multinomial, 0, 1, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, counts, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_catalan arginfo_rust_factorial
+
+#define arginfo_rust_stirling_first arginfo_rust_binomial
+
+#define arginfo_rust_stirling_second arginfo_rust_binomial
+
+#define arginfo_rust_bell arginfo_rust_factorial
+
+#define arginfo_rust_partitions arginfo_rust_factorial
+
+#define arginfo_rust_erf arginfo_rust_sqrt
+
+#define arginfo_rust_erfc arginfo_rust_sqrt
+
+#define arginfo_rust_erfcx arginfo_rust_sqrt
+
+#define arginfo_rust_erfinv arginfo_rust_sqrt
+
+#define arginfo_rust_erfcinv arginfo_rust_sqrt
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_normal_pdf, 0, 1, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, x, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, mu, IS_DOUBLE, 0, "0.0")
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, sigma, IS_DOUBLE, 0, "1.0")
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_normal_cdf arginfo_rust_normal_pdf
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_normal_quantile, 0, 1, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, p, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, mu, IS_DOUBLE, 0, "0.0")
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, sigma, IS_DOUBLE, 0, "1.0")
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_fibonacci_nth arginfo_rust_factorial
+
+#define arginfo_rust_lucas_nth arginfo_rust_factorial
+
+#define arginfo_rust_fibonacci_big arginfo_rust_factorial_big
+
+#define arginfo_rust_lucas_big arginfo_rust_factorial_big
+
+#define arginfo_rust_gamma arginfo_rust_sqrt
+
+#define arginfo_rust_lgamma arginfo_rust_sqrt
+
+#define arginfo_rust_digamma arginfo_rust_sqrt
+
+#define arginfo_rust_beta arginfo_rust_multiply
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_matrix_multiply, 0, 2, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, a, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, b, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_matrix_transpose, 0, 1, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, matrix, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_matrix_determinant, 0, 1, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, matrix, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_matrix_inverse arginfo_rust_matrix_transpose
+
+#define arginfo_rust_matrix_lu arginfo_rust_matrix_transpose
+
+#define arginfo_rust_matrix_qr arginfo_rust_matrix_transpose
+
+#define arginfo_rust_matrix_cholesky arginfo_rust_matrix_transpose
+
+#define arginfo_rust_matrix_solve arginfo_rust_matrix_multiply
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_mod_pow, 0, 3, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, base, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, exp, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, m, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_mod_inverse, 0, 2, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, a, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, m, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_extended_gcd, 0, 2, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, a, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, b, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_crt, 0, 2, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, remainders, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, moduli, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_jacobi, 0, 2, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, a, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, n, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_legendre, 0, 2, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, a, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, p, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_factorize arginfo_rust_fibonacci
+
+#define arginfo_rust_divisors arginfo_rust_fibonacci
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_sigma, 0, 1, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, n, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, k, IS_LONG, 0, "1")
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_euler_phi arginfo_rust_factorial
+
+#define arginfo_rust_mobius arginfo_rust_factorial
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_gcd_many, 0, 1, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, numbers, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_lcm_many arginfo_rust_gcd_many
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_pow_float, 0, 2, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, base, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, exponent, IS_DOUBLE, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_powi, 0, 2, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, base, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, exponent, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_power_fraction, 0, 3, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, numerator, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, denominator, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, exponent, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_rust_primes_up_to arginfo_rust_fibonacci
+
+#define arginfo_rust_primes_between arginfo_rust_extended_gcd
+
+#define arginfo_rust_prime_count arginfo_rust_factorial
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_rust_nth_prime, 0, 1, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, k, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+PHP_FUNCTION(rust_math_error_message);
+PHP_FUNCTION(rust_math_error_name);
+PHP_FUNCTION(rust_add);
+PHP_FUNCTION(rust_multiply);
+PHP_FUNCTION(rust_factorial);
+PHP_FUNCTION(rust_factorial_big);
+PHP_FUNCTION(rust_fibonacci);
+PHP_FUNCTION(rust_is_prime);
+PHP_FUNCTION(rust_gcd);
+PHP_FUNCTION(rust_lcm);
+PHP_FUNCTION(rust_power);
+PHP_FUNCTION(rust_sqrt);
+PHP_FUNCTION(rust_cbrt);
+PHP_FUNCTION(rust_ln);
+PHP_FUNCTION(rust_log10);
+PHP_FUNCTION(rust_sin);
+PHP_FUNCTION(rust_cos);
+PHP_FUNCTION(rust_tan);
+PHP_FUNCTION(rust_asin);
+PHP_FUNCTION(rust_acos);
+PHP_FUNCTION(rust_atan);
+PHP_FUNCTION(rust_sinh);
+PHP_FUNCTION(rust_cosh);
+PHP_FUNCTION(rust_tanh);
+PHP_FUNCTION(rust_subtract_int);
+PHP_FUNCTION(rust_multiply_int);
+PHP_FUNCTION(rust_divide_int);
+PHP_FUNCTION(rust_modulo_int);
+PHP_FUNCTION(rust_abs_int);
+PHP_FUNCTION(rust_max_int);
+PHP_FUNCTION(rust_min_int);
+PHP_FUNCTION(rust_add_float);
+PHP_FUNCTION(rust_subtract_float);
+PHP_FUNCTION(rust_divide_float);
+PHP_FUNCTION(rust_abs_float);
+PHP_FUNCTION(rust_max_float);
+PHP_FUNCTION(rust_min_float);
+PHP_FUNCTION(rust_bessel_j0);
+PHP_FUNCTION(rust_bessel_j1);
+PHP_FUNCTION(rust_bessel_jn);
+PHP_FUNCTION(rust_bessel_y0);
+PHP_FUNCTION(rust_bessel_y1);
+PHP_FUNCTION(rust_bessel_yn);
+PHP_FUNCTION(rust_bessel_i0);
+PHP_FUNCTION(rust_bessel_i1);
+PHP_FUNCTION(rust_bessel_in);
+PHP_FUNCTION(rust_bessel_k0);
+PHP_FUNCTION(rust_bessel_k1);
+PHP_FUNCTION(rust_bessel_kn);
+PHP_FUNCTION(rust_binomial);
+PHP_FUNCTION(rust_permutations);
+PHP_FUNCTION(rust_multinomial);
+PHP_FUNCTION(rust_catalan);
+PHP_FUNCTION(rust_stirling_first);
+PHP_FUNCTION(rust_stirling_second);
+PHP_FUNCTION(rust_bell);
+PHP_FUNCTION(rust_partitions);
+PHP_FUNCTION(rust_erf);
+PHP_FUNCTION(rust_erfc);
+PHP_FUNCTION(rust_erfcx);
+PHP_FUNCTION(rust_erfinv);
+PHP_FUNCTION(rust_erfcinv);
+PHP_FUNCTION(rust_normal_pdf);
+PHP_FUNCTION(rust_normal_cdf);
+PHP_FUNCTION(rust_normal_quantile);
+PHP_FUNCTION(rust_fibonacci_nth);
+PHP_FUNCTION(rust_lucas_nth);
+PHP_FUNCTION(rust_fibonacci_big);
+PHP_FUNCTION(rust_lucas_big);
+PHP_FUNCTION(rust_gamma);
+PHP_FUNCTION(rust_lgamma);
+PHP_FUNCTION(rust_digamma);
+PHP_FUNCTION(rust_beta);
+PHP_FUNCTION(rust_matrix_multiply);
+PHP_FUNCTION(rust_matrix_transpose);
+PHP_FUNCTION(rust_matrix_determinant);
+PHP_FUNCTION(rust_matrix_inverse);
+PHP_FUNCTION(rust_matrix_lu);
+PHP_FUNCTION(rust_matrix_qr);
+PHP_FUNCTION(rust_matrix_cholesky);
+PHP_FUNCTION(rust_matrix_solve);
+PHP_FUNCTION(rust_mod_pow);
+PHP_FUNCTION(rust_mod_inverse);
+PHP_FUNCTION(rust_extended_gcd);
+PHP_FUNCTION(rust_crt);
+PHP_FUNCTION(rust_jacobi);
+PHP_FUNCTION(rust_legendre);
+PHP_FUNCTION(rust_factorize);
+PHP_FUNCTION(rust_divisors);
+PHP_FUNCTION(rust_sigma);
+PHP_FUNCTION(rust_euler_phi);
+PHP_FUNCTION(rust_mobius);
+PHP_FUNCTION(rust_gcd_many);
+PHP_FUNCTION(rust_lcm_many);
+PHP_FUNCTION(rust_pow_float);
+PHP_FUNCTION(rust_powi);
+PHP_FUNCTION(rust_power_fraction);
+PHP_FUNCTION(rust_primes_up_to);
+PHP_FUNCTION(rust_primes_between);
+PHP_FUNCTION(rust_prime_count);
+PHP_FUNCTION(rust_nth_prime);
+
+static const zend_function_entry ext_functions[] = {
+    PHP_FE(rust_math_error_message, arginfo_rust_math_error_message)
+    PHP_FE(rust_math_error_name, arginfo_rust_math_error_name)
+    PHP_FE(rust_add, arginfo_rust_add)
+    PHP_FE(rust_multiply, arginfo_rust_multiply)
+    PHP_FE(rust_factorial, arginfo_rust_factorial)
+    PHP_FE(rust_factorial_big, arginfo_rust_factorial_big)
+    PHP_FE(rust_fibonacci, arginfo_rust_fibonacci)
+    PHP_FE(rust_is_prime, arginfo_rust_is_prime)
+    PHP_FE(rust_gcd, arginfo_rust_gcd)
+    PHP_FE(rust_lcm, arginfo_rust_lcm)
+    PHP_FE(rust_power, arginfo_rust_power)
+    PHP_FE(rust_sqrt, arginfo_rust_sqrt)
+    PHP_FE(rust_cbrt, arginfo_rust_cbrt)
+    PHP_FE(rust_ln, arginfo_rust_ln)
+    PHP_FE(rust_log10, arginfo_rust_log10)
+    PHP_FE(rust_sin, arginfo_rust_sin)
+    PHP_FE(rust_cos, arginfo_rust_cos)
+    PHP_FE(rust_tan, arginfo_rust_tan)
+    PHP_FE(rust_asin, arginfo_rust_asin)
+    PHP_FE(rust_acos, arginfo_rust_acos)
+    PHP_FE(rust_atan, arginfo_rust_atan)
+    PHP_FE(rust_sinh, arginfo_rust_sinh)
+    PHP_FE(rust_cosh, arginfo_rust_cosh)
+    PHP_FE(rust_tanh, arginfo_rust_tanh)
+    PHP_FE(rust_subtract_int, arginfo_rust_subtract_int)
+    PHP_FE(rust_multiply_int, arginfo_rust_multiply_int)
+    PHP_FE(rust_divide_int, arginfo_rust_divide_int)
+    PHP_FE(rust_modulo_int, arginfo_rust_modulo_int)
+    PHP_FE(rust_abs_int, arginfo_rust_abs_int)
+    PHP_FE(rust_max_int, arginfo_rust_max_int)
+    PHP_FE(rust_min_int, arginfo_rust_min_int)
+    PHP_FE(rust_add_float, arginfo_rust_add_float)
+    PHP_FE(rust_subtract_float, arginfo_rust_subtract_float)
+    PHP_FE(rust_divide_float, arginfo_rust_divide_float)
+    PHP_FE(rust_abs_float, arginfo_rust_abs_float)
+    PHP_FE(rust_max_float, arginfo_rust_max_float)
+    PHP_FE(rust_min_float, arginfo_rust_min_float)
+    PHP_FE(rust_bessel_j0, arginfo_rust_bessel_j0)
+    PHP_FE(rust_bessel_j1, arginfo_rust_bessel_j1)
+    PHP_FE(rust_bessel_jn, arginfo_rust_bessel_jn)
+    PHP_FE(rust_bessel_y0, arginfo_rust_bessel_y0)
+    PHP_FE(rust_bessel_y1, arginfo_rust_bessel_y1)
+    PHP_FE(rust_bessel_yn, arginfo_rust_bessel_yn)
+    PHP_FE(rust_bessel_i0, arginfo_rust_bessel_i0)
+    PHP_FE(rust_bessel_i1, arginfo_rust_bessel_i1)
+    PHP_FE(rust_bessel_in, arginfo_rust_bessel_in)
+    PHP_FE(rust_bessel_k0, arginfo_rust_bessel_k0)
+    PHP_FE(rust_bessel_k1, arginfo_rust_bessel_k1)
+    PHP_FE(rust_bessel_kn, arginfo_rust_bessel_kn)
+    PHP_FE(rust_binomial, arginfo_rust_binomial)
+    PHP_FE(rust_permutations, arginfo_rust_permutations)
+    PHP_FE(rust_multinomial, arginfo_rust_multinomial)
+    PHP_FE(rust_catalan, arginfo_rust_catalan)
+    PHP_FE(rust_stirling_first, arginfo_rust_stirling_first)
+    PHP_FE(rust_stirling_second, arginfo_rust_stirling_second)
+    PHP_FE(rust_bell, arginfo_rust_bell)
+    PHP_FE(rust_partitions, arginfo_rust_partitions)
+    PHP_FE(rust_erf, arginfo_rust_erf)
+    PHP_FE(rust_erfc, arginfo_rust_erfc)
+    PHP_FE(rust_erfcx, arginfo_rust_erfcx)
+    PHP_FE(rust_erfinv, arginfo_rust_erfinv)
+    PHP_FE(rust_erfcinv, arginfo_rust_erfcinv)
+    PHP_FE(rust_normal_pdf, arginfo_rust_normal_pdf)
+    PHP_FE(rust_normal_cdf, arginfo_rust_normal_cdf)
+    PHP_FE(rust_normal_quantile, arginfo_rust_normal_quantile)
+    PHP_FE(rust_fibonacci_nth, arginfo_rust_fibonacci_nth)
+    PHP_FE(rust_lucas_nth, arginfo_rust_lucas_nth)
+    PHP_FE(rust_fibonacci_big, arginfo_rust_fibonacci_big)
+    PHP_FE(rust_lucas_big, arginfo_rust_lucas_big)
+    PHP_FE(rust_gamma, arginfo_rust_gamma)
+    PHP_FE(rust_lgamma, arginfo_rust_lgamma)
+    PHP_FE(rust_digamma, arginfo_rust_digamma)
+    PHP_FE(rust_beta, arginfo_rust_beta)
+    PHP_FE(rust_matrix_multiply, arginfo_rust_matrix_multiply)
+    PHP_FE(rust_matrix_transpose, arginfo_rust_matrix_transpose)
+    PHP_FE(rust_matrix_determinant, arginfo_rust_matrix_determinant)
+    PHP_FE(rust_matrix_inverse, arginfo_rust_matrix_inverse)
+    PHP_FE(rust_matrix_lu, arginfo_rust_matrix_lu)
+    PHP_FE(rust_matrix_qr, arginfo_rust_matrix_qr)
+    PHP_FE(rust_matrix_cholesky, arginfo_rust_matrix_cholesky)
+    PHP_FE(rust_matrix_solve, arginfo_rust_matrix_solve)
+    PHP_FE(rust_mod_pow, arginfo_rust_mod_pow)
+    PHP_FE(rust_mod_inverse, arginfo_rust_mod_inverse)
+    PHP_FE(rust_extended_gcd, arginfo_rust_extended_gcd)
+    PHP_FE(rust_crt, arginfo_rust_crt)
+    PHP_FE(rust_jacobi, arginfo_rust_jacobi)
+    PHP_FE(rust_legendre, arginfo_rust_legendre)
+    PHP_FE(rust_factorize, arginfo_rust_factorize)
+    PHP_FE(rust_divisors, arginfo_rust_divisors)
+    PHP_FE(rust_sigma, arginfo_rust_sigma)
+    PHP_FE(rust_euler_phi, arginfo_rust_euler_phi)
+    PHP_FE(rust_mobius, arginfo_rust_mobius)
+    PHP_FE(rust_gcd_many, arginfo_rust_gcd_many)
+    PHP_FE(rust_lcm_many, arginfo_rust_lcm_many)
+    PHP_FE(rust_pow_float, arginfo_rust_pow_float)
+    PHP_FE(rust_powi, arginfo_rust_powi)
+    PHP_FE(rust_power_fraction, arginfo_rust_power_fraction)
+    PHP_FE(rust_primes_up_to, arginfo_rust_primes_up_to)
+    PHP_FE(rust_primes_between, arginfo_rust_primes_between)
+    PHP_FE(rust_prime_count, arginfo_rust_prime_count)
+    PHP_FE(rust_nth_prime, arginfo_rust_nth_prime)
+    PHP_FE_END
+};
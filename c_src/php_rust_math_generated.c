@@ -0,0 +1,1553 @@
+/**
+ * PHP Rust 数学扩展 - 自动生成的 PHP 函数包装
+ *
+ * 由 build.rs 根据 src/ 中的 Rust 代码自动生成，请勿手动修改
+ */
+
+#include "php.h"
+#include "php_rust_math.h"
+
+/* rust_add(int $a, int $b): int */
+PHP_FUNCTION(rust_add)
+{
+    zend_long a;
+    zend_long b;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_add_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_multiply(float $a, float $b): float */
+PHP_FUNCTION(rust_multiply)
+{
+    double a;
+    double b;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(a)
+        Z_PARAM_DOUBLE(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_multiply_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_factorial(int $n): int */
+PHP_FUNCTION(rust_factorial)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_factorial_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_is_prime(int $n): bool */
+PHP_FUNCTION(rust_is_prime)
+{
+    zend_long n;
+    bool result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_is_prime_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_BOOL(result);
+}
+
+/* rust_gcd(int $a, int $b): int */
+PHP_FUNCTION(rust_gcd)
+{
+    zend_long a;
+    zend_long b;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_gcd_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_lcm(int $a, int $b): int */
+PHP_FUNCTION(rust_lcm)
+{
+    zend_long a;
+    zend_long b;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_lcm_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_power(int $base, int $exponent): int */
+PHP_FUNCTION(rust_power)
+{
+    zend_long base;
+    zend_long exponent;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(base)
+        Z_PARAM_LONG(exponent)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_power_impl(base, exponent, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_sqrt(float $x): float */
+PHP_FUNCTION(rust_sqrt)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_sqrt_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_cbrt(float $x): float */
+PHP_FUNCTION(rust_cbrt)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_cbrt_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_ln(float $x): float */
+PHP_FUNCTION(rust_ln)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_ln_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_log10(float $x): float */
+PHP_FUNCTION(rust_log10)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_log10_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_sin(float $x): float */
+PHP_FUNCTION(rust_sin)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_sin_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_cos(float $x): float */
+PHP_FUNCTION(rust_cos)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_cos_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_tan(float $x): float */
+PHP_FUNCTION(rust_tan)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_tan_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_asin(float $x): float */
+PHP_FUNCTION(rust_asin)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_asin_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_acos(float $x): float */
+PHP_FUNCTION(rust_acos)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_acos_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_atan(float $x): float */
+PHP_FUNCTION(rust_atan)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_atan_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_sinh(float $x): float */
+PHP_FUNCTION(rust_sinh)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_sinh_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_cosh(float $x): float */
+PHP_FUNCTION(rust_cosh)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_cosh_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_tanh(float $x): float */
+PHP_FUNCTION(rust_tanh)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_tanh_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_subtract_int(int $a, int $b): int */
+PHP_FUNCTION(rust_subtract_int)
+{
+    zend_long a;
+    zend_long b;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_subtract_int_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_multiply_int(int $a, int $b): int */
+PHP_FUNCTION(rust_multiply_int)
+{
+    zend_long a;
+    zend_long b;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_multiply_int_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_divide_int(int $a, int $b): int */
+PHP_FUNCTION(rust_divide_int)
+{
+    zend_long a;
+    zend_long b;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_divide_int_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_modulo_int(int $a, int $b): int */
+PHP_FUNCTION(rust_modulo_int)
+{
+    zend_long a;
+    zend_long b;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_modulo_int_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_abs_int(int $n): int */
+PHP_FUNCTION(rust_abs_int)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_abs_int_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_max_int(int $a, int $b): int */
+PHP_FUNCTION(rust_max_int)
+{
+    zend_long a;
+    zend_long b;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_max_int_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_min_int(int $a, int $b): int */
+PHP_FUNCTION(rust_min_int)
+{
+    zend_long a;
+    zend_long b;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_min_int_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_add_float(float $a, float $b): float */
+PHP_FUNCTION(rust_add_float)
+{
+    double a;
+    double b;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(a)
+        Z_PARAM_DOUBLE(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_add_float_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_subtract_float(float $a, float $b): float */
+PHP_FUNCTION(rust_subtract_float)
+{
+    double a;
+    double b;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(a)
+        Z_PARAM_DOUBLE(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_subtract_float_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_divide_float(float $a, float $b): float */
+PHP_FUNCTION(rust_divide_float)
+{
+    double a;
+    double b;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(a)
+        Z_PARAM_DOUBLE(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_divide_float_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_abs_float(float $n): float */
+PHP_FUNCTION(rust_abs_float)
+{
+    double n;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_abs_float_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_max_float(float $a, float $b): float */
+PHP_FUNCTION(rust_max_float)
+{
+    double a;
+    double b;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(a)
+        Z_PARAM_DOUBLE(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_max_float_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_min_float(float $a, float $b): float */
+PHP_FUNCTION(rust_min_float)
+{
+    double a;
+    double b;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(a)
+        Z_PARAM_DOUBLE(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_min_float_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_j0(float $x): float */
+PHP_FUNCTION(rust_bessel_j0)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_j0_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_j1(float $x): float */
+PHP_FUNCTION(rust_bessel_j1)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_j1_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_jn(int $n, float $x): float */
+PHP_FUNCTION(rust_bessel_jn)
+{
+    zend_long n;
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(n)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_jn_impl(n, x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_y0(float $x): float */
+PHP_FUNCTION(rust_bessel_y0)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_y0_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_y1(float $x): float */
+PHP_FUNCTION(rust_bessel_y1)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_y1_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_yn(int $n, float $x): float */
+PHP_FUNCTION(rust_bessel_yn)
+{
+    zend_long n;
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(n)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_yn_impl(n, x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_i0(float $x): float */
+PHP_FUNCTION(rust_bessel_i0)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_i0_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_i1(float $x): float */
+PHP_FUNCTION(rust_bessel_i1)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_i1_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_in(int $n, float $x): float */
+PHP_FUNCTION(rust_bessel_in)
+{
+    zend_long n;
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(n)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_in_impl(n, x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_k0(float $x): float */
+PHP_FUNCTION(rust_bessel_k0)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_k0_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_k1(float $x): float */
+PHP_FUNCTION(rust_bessel_k1)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_k1_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_bessel_kn(int $n, float $x): float */
+PHP_FUNCTION(rust_bessel_kn)
+{
+    zend_long n;
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(n)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bessel_kn_impl(n, x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_binomial(int $n, int $k): int */
+PHP_FUNCTION(rust_binomial)
+{
+    zend_long n;
+    zend_long k;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(n)
+        Z_PARAM_LONG(k)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_binomial_impl(n, k, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_permutations(int $n, int $k): int */
+PHP_FUNCTION(rust_permutations)
+{
+    zend_long n;
+    zend_long k;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(n)
+        Z_PARAM_LONG(k)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_permutations_impl(n, k, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_catalan(int $n): int */
+PHP_FUNCTION(rust_catalan)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_catalan_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_stirling_first(int $n, int $k): int */
+PHP_FUNCTION(rust_stirling_first)
+{
+    zend_long n;
+    zend_long k;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(n)
+        Z_PARAM_LONG(k)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_stirling_first_impl(n, k, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_stirling_second(int $n, int $k): int */
+PHP_FUNCTION(rust_stirling_second)
+{
+    zend_long n;
+    zend_long k;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(n)
+        Z_PARAM_LONG(k)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_stirling_second_impl(n, k, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_bell(int $n): int */
+PHP_FUNCTION(rust_bell)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_bell_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_partitions(int $n): int */
+PHP_FUNCTION(rust_partitions)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_partitions_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_erf(float $x): float */
+PHP_FUNCTION(rust_erf)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_erf_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_erfc(float $x): float */
+PHP_FUNCTION(rust_erfc)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_erfc_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_erfcx(float $x): float */
+PHP_FUNCTION(rust_erfcx)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_erfcx_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_erfinv(float $x): float */
+PHP_FUNCTION(rust_erfinv)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_erfinv_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_erfcinv(float $x): float */
+PHP_FUNCTION(rust_erfcinv)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_erfcinv_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_normal_pdf(float $x, float $mu = 0.0, float $sigma = 1.0): float */
+PHP_FUNCTION(rust_normal_pdf)
+{
+    double x;
+    double mu = 0.0;
+    double sigma = 1.0;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 3)
+        Z_PARAM_DOUBLE(x)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_DOUBLE(mu)
+        Z_PARAM_DOUBLE(sigma)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_normal_pdf_impl(x, mu, sigma, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_normal_cdf(float $x, float $mu = 0.0, float $sigma = 1.0): float */
+PHP_FUNCTION(rust_normal_cdf)
+{
+    double x;
+    double mu = 0.0;
+    double sigma = 1.0;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 3)
+        Z_PARAM_DOUBLE(x)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_DOUBLE(mu)
+        Z_PARAM_DOUBLE(sigma)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_normal_cdf_impl(x, mu, sigma, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_normal_quantile(float $p, float $mu = 0.0, float $sigma = 1.0): float */
+PHP_FUNCTION(rust_normal_quantile)
+{
+    double p;
+    double mu = 0.0;
+    double sigma = 1.0;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 3)
+        Z_PARAM_DOUBLE(p)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_DOUBLE(mu)
+        Z_PARAM_DOUBLE(sigma)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_normal_quantile_impl(p, mu, sigma, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_fibonacci_nth(int $n): int */
+PHP_FUNCTION(rust_fibonacci_nth)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_fibonacci_nth_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_lucas_nth(int $n): int */
+PHP_FUNCTION(rust_lucas_nth)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_lucas_nth_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_gamma(float $x): float */
+PHP_FUNCTION(rust_gamma)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_gamma_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_lgamma(float $x): float */
+PHP_FUNCTION(rust_lgamma)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_lgamma_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_digamma(float $x): float */
+PHP_FUNCTION(rust_digamma)
+{
+    double x;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_DOUBLE(x)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_digamma_impl(x, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_beta(float $a, float $b): float */
+PHP_FUNCTION(rust_beta)
+{
+    double a;
+    double b;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(a)
+        Z_PARAM_DOUBLE(b)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_beta_impl(a, b, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_mod_pow(int $base, int $exp, int $m): int */
+PHP_FUNCTION(rust_mod_pow)
+{
+    zend_long base;
+    zend_long exp;
+    zend_long m;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(3, 3)
+        Z_PARAM_LONG(base)
+        Z_PARAM_LONG(exp)
+        Z_PARAM_LONG(m)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_mod_pow_impl(base, exp, m, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_mod_inverse(int $a, int $m): int */
+PHP_FUNCTION(rust_mod_inverse)
+{
+    zend_long a;
+    zend_long m;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(m)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_mod_inverse_impl(a, m, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_jacobi(int $a, int $n): int */
+PHP_FUNCTION(rust_jacobi)
+{
+    zend_long a;
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_jacobi_impl(a, n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_legendre(int $a, int $p): int */
+PHP_FUNCTION(rust_legendre)
+{
+    zend_long a;
+    zend_long p;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_LONG(a)
+        Z_PARAM_LONG(p)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_legendre_impl(a, p, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_sigma(int $n, int $k = 1): int */
+PHP_FUNCTION(rust_sigma)
+{
+    zend_long n;
+    zend_long k = 1;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 2)
+        Z_PARAM_LONG(n)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_LONG(k)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_sigma_impl(n, k, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_euler_phi(int $n): int */
+PHP_FUNCTION(rust_euler_phi)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_euler_phi_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_mobius(int $n): int */
+PHP_FUNCTION(rust_mobius)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_mobius_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_pow_float(float $base, float $exponent): float */
+PHP_FUNCTION(rust_pow_float)
+{
+    double base;
+    double exponent;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(base)
+        Z_PARAM_DOUBLE(exponent)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_pow_float_impl(base, exponent, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_powi(float $base, int $exponent): float */
+PHP_FUNCTION(rust_powi)
+{
+    double base;
+    zend_long exponent;
+    double result;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(base)
+        Z_PARAM_LONG(exponent)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_powi_impl(base, exponent, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* rust_prime_count(int $n): int */
+PHP_FUNCTION(rust_prime_count)
+{
+    zend_long n;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(n)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_prime_count_impl(n, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* rust_nth_prime(int $k): int */
+PHP_FUNCTION(rust_nth_prime)
+{
+    zend_long k;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(k)
+    ZEND_PARSE_PARAMETERS_END();
+
+    math_error error = rust_nth_prime_impl(k, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
@@ -0,0 +1,642 @@
+/**
+ * PHP Rust 数学扩展 - RustMath\BigInt 类
+ *
+ * 这个文件把 Rust 的大整数句柄包装成 PHP 对象
+ * 每个 PHP 对象持有一个 rust_bigint 句柄，对象销毁时释放句柄
+ */
+
+#include "php.h"
+#include "zend_exceptions.h"
+#include "zend_interfaces.h"
+#include "php_rust_math.h"
+
+/* 类入口和对象处理器 */
+zend_class_entry *rust_bigint_ce;
+static zend_object_handlers rust_bigint_handlers;
+
+/* PHP 对象结构：Rust 句柄 + 标准 zend_object（必须放在最后） */
+typedef struct {
+    rust_bigint *handle;
+    zend_object std;
+} php_rust_bigint;
+
+static inline php_rust_bigint *php_rust_bigint_from_obj(zend_object *obj)
+{
+    return (php_rust_bigint *)((char *)obj - XtOffsetOf(php_rust_bigint, std));
+}
+
+#define Z_RUST_BIGINT_P(zv) php_rust_bigint_from_obj(Z_OBJ_P(zv))
+
+/* 创建对象：句柄在构造函数或运算结果中设置 */
+static zend_object *rust_bigint_create_object(zend_class_entry *ce)
+{
+    php_rust_bigint *intern = zend_object_alloc(sizeof(php_rust_bigint), ce);
+
+    zend_object_std_init(&intern->std, ce);
+    object_properties_init(&intern->std, ce);
+    intern->std.handlers = &rust_bigint_handlers;
+    intern->handle = NULL;
+
+    return &intern->std;
+}
+
+/* 销毁对象：释放 Rust 句柄 */
+static void rust_bigint_free_obj(zend_object *object)
+{
+    php_rust_bigint *intern = php_rust_bigint_from_obj(object);
+
+    if (intern->handle != NULL) {
+        rust_bigint_free(intern->handle);
+        intern->handle = NULL;
+    }
+
+    zend_object_std_dtor(&intern->std);
+}
+
+/* 克隆对象：复制 Rust 句柄 */
+static zend_object *rust_bigint_clone_obj(zend_object *old_object)
+{
+    php_rust_bigint *old_intern = php_rust_bigint_from_obj(old_object);
+    zend_object *new_object = rust_bigint_create_object(old_object->ce);
+    php_rust_bigint *new_intern = php_rust_bigint_from_obj(new_object);
+
+    zend_objects_clone_members(new_object, old_object);
+    rust_bigint_clone(old_intern->handle, &new_intern->handle);
+
+    return new_object;
+}
+
+/* 比较对象：让 ==、<、> 等运算符按数值比较 */
+static int rust_bigint_compare_objects(zval *a, zval *b)
+{
+    ZEND_COMPARE_OBJECTS_FALLBACK(a, b);
+
+    if (Z_OBJCE_P(a) != rust_bigint_ce || Z_OBJCE_P(b) != rust_bigint_ce) {
+        return ZEND_UNCOMPARABLE;
+    }
+
+    return rust_bigint_cmp(Z_RUST_BIGINT_P(a)->handle, Z_RUST_BIGINT_P(b)->handle);
+}
+
+/* 获取当前对象的句柄，未初始化时抛出异常 */
+static rust_bigint *rust_bigint_this(zval *object)
+{
+    rust_bigint *handle = Z_RUST_BIGINT_P(object)->handle;
+
+    if (handle == NULL) {
+        zend_throw_error(NULL, "RustMath\\BigInt object is not initialized");
+    }
+
+    return handle;
+}
+
+/* 用句柄创建新的 BigInt 对象作为返回值，句柄的所有权转移给对象 */
+static void rust_bigint_return(zval *return_value, rust_bigint *handle)
+{
+    object_init_ex(return_value, rust_bigint_ce);
+    Z_RUST_BIGINT_P(return_value)->handle = handle;
+}
+
+/* 根据 Rust 返回的错误码返回新对象或抛出异常 */
+static void rust_bigint_return_result(zval *return_value, math_error error, rust_bigint *handle)
+{
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        return;
+    }
+
+    rust_bigint_return(return_value, handle);
+}
+
+/**
+ * 把 BigInt|int|string 参数转换为大整数句柄
+ *
+ * 如果参数本身是 BigInt 对象则直接借用它的句柄，*owned 为 false；
+ * 否则创建新句柄，*owned 为 true，调用者用完后需要释放
+ * 出错时抛出异常并返回 NULL
+ */
+static rust_bigint *rust_bigint_operand(zval *value, uint32_t arg_num, bool *owned)
+{
+    rust_bigint *handle = NULL;
+
+    *owned = false;
+
+    switch (Z_TYPE_P(value)) {
+        case IS_LONG:
+            rust_bigint_from_long(Z_LVAL_P(value), &handle);
+            *owned = true;
+            return handle;
+
+        case IS_STRING: {
+            math_error error = rust_bigint_from_string(Z_STRVAL_P(value), 10, &handle);
+            if (error == MATH_ERROR_INVALID_PARAMETER) {
+                zend_argument_value_error(arg_num, "must be a valid decimal integer string");
+                return NULL;
+            }
+            /* 位数超过上限时抛出 OverflowError */
+            if (error != MATH_ERROR_SUCCESS) {
+                rust_math_throw_error(error);
+                return NULL;
+            }
+            *owned = true;
+            return handle;
+        }
+
+        case IS_OBJECT:
+            if (instanceof_function(Z_OBJCE_P(value), rust_bigint_ce)) {
+                return rust_bigint_this(value);
+            }
+            break;
+
+        default:
+            break;
+    }
+
+    zend_argument_type_error(arg_num, "must be of type RustMath\\BigInt|string|int, %s given",
+        zend_zval_type_name(value));
+    return NULL;
+}
+
+/* 释放 rust_bigint_operand 创建的临时句柄 */
+static inline void rust_bigint_release(rust_bigint *handle, bool owned)
+{
+    if (owned && handle != NULL) {
+        rust_bigint_free(handle);
+    }
+}
+
+/* 二元运算的公共实现：解析参数、调用 Rust 函数、返回新对象 */
+static void rust_bigint_binary_op(INTERNAL_FUNCTION_PARAMETERS,
+    math_error (*op)(const rust_bigint *, const rust_bigint *, rust_bigint **))
+{
+    zval *other;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint *operand = rust_bigint_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint *result = NULL;
+    math_error error = op(self, operand, &result);
+    rust_bigint_release(operand, owned);
+
+    rust_bigint_return_result(return_value, error, result);
+}
+
+/* 除法的公共实现：want_quotient 为 true 返回商，否则返回余数 */
+static void rust_bigint_division(INTERNAL_FUNCTION_PARAMETERS, bool want_quotient, bool want_both)
+{
+    zval *other;
+    bool owned;
+    rust_bigint *quotient = NULL;
+    rust_bigint *remainder = NULL;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint *divisor = rust_bigint_operand(other, 1, &owned);
+    if (divisor == NULL) {
+        RETURN_THROWS();
+    }
+
+    /* 除数为零时 Rust 返回 MATH_ERROR_DIVISION_BY_ZERO，抛出 RustMath\DivisionByZeroError */
+    math_error error = rust_bigint_divmod(self, divisor, &quotient, &remainder);
+    rust_bigint_release(divisor, owned);
+
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    if (want_both) {
+        zval q, r;
+        rust_bigint_return(&q, quotient);
+        rust_bigint_return(&r, remainder);
+        array_init_size(return_value, 2);
+        add_next_index_zval(return_value, &q);
+        add_next_index_zval(return_value, &r);
+        return;
+    }
+
+    if (want_quotient) {
+        rust_bigint_free(remainder);
+        rust_bigint_return(return_value, quotient);
+    } else {
+        rust_bigint_free(quotient);
+        rust_bigint_return(return_value, remainder);
+    }
+}
+
+/* 把大整数按进制转换为 PHP 字符串 */
+static void rust_bigint_return_string(zval *return_value, rust_bigint *handle, zend_long base)
+{
+    rust_string_result result;
+
+    if (rust_bigint_to_string(handle, (int) base, &result) != MATH_ERROR_SUCCESS) {
+        zend_argument_value_error(1, "must be between 2 and 36");
+        RETURN_THROWS();
+    }
+
+    RETVAL_STRINGL(result.data, result.length);
+    rust_free_string_result(result);
+}
+
+/* RustMath\BigInt::__construct(int|string $value = 0, int $base = 10) */
+PHP_METHOD(RustMath_BigInt, __construct)
+{
+    zend_string *str = NULL;
+    zend_long value = 0;
+    zend_long base = 10;
+    php_rust_bigint *intern = Z_RUST_BIGINT_P(ZEND_THIS);
+
+    ZEND_PARSE_PARAMETERS_START(0, 2)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_STR_OR_LONG(str, value)
+        Z_PARAM_LONG(base)
+    ZEND_PARSE_PARAMETERS_END();
+
+    if (base < 2 || base > 36) {
+        zend_argument_value_error(2, "must be between 2 and 36");
+        RETURN_THROWS();
+    }
+
+    rust_bigint *handle = NULL;
+    math_error error = str != NULL
+        ? rust_bigint_from_string(ZSTR_VAL(str), (int) base, &handle)
+        : rust_bigint_from_long(value, &handle);
+
+    if (error == MATH_ERROR_INVALID_PARAMETER) {
+        zend_argument_value_error(1, "must be a valid base " ZEND_LONG_FMT " integer", base);
+        RETURN_THROWS();
+    }
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    if (intern->handle != NULL) {
+        rust_bigint_free(intern->handle);
+    }
+    intern->handle = handle;
+}
+
+/* RustMath\BigInt::add(BigInt|int|string $other): BigInt */
+PHP_METHOD(RustMath_BigInt, add)
+{
+    rust_bigint_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_bigint_add);
+}
+
+/* RustMath\BigInt::sub(BigInt|int|string $other): BigInt */
+PHP_METHOD(RustMath_BigInt, sub)
+{
+    rust_bigint_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_bigint_sub);
+}
+
+/* RustMath\BigInt::mul(BigInt|int|string $other): BigInt */
+PHP_METHOD(RustMath_BigInt, mul)
+{
+    rust_bigint_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_bigint_mul);
+}
+
+/* RustMath\BigInt::div(BigInt|int|string $divisor): BigInt - 商向零取整 */
+PHP_METHOD(RustMath_BigInt, div)
+{
+    rust_bigint_division(INTERNAL_FUNCTION_PARAM_PASSTHRU, true, false);
+}
+
+/* RustMath\BigInt::mod(BigInt|int|string $divisor): BigInt - 余数与被除数同号 */
+PHP_METHOD(RustMath_BigInt, mod)
+{
+    rust_bigint_division(INTERNAL_FUNCTION_PARAM_PASSTHRU, false, false);
+}
+
+/* RustMath\BigInt::divmod(BigInt|int|string $divisor): array - 返回 [商, 余数] */
+PHP_METHOD(RustMath_BigInt, divmod)
+{
+    rust_bigint_division(INTERNAL_FUNCTION_PARAM_PASSTHRU, true, true);
+}
+
+/* RustMath\BigInt::pow(int $exponent): BigInt */
+PHP_METHOD(RustMath_BigInt, pow)
+{
+    zend_long exponent;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(exponent)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (exponent < 0) {
+        zend_argument_value_error(1, "must be greater than or equal to 0");
+        RETURN_THROWS();
+    }
+
+    rust_bigint *result = NULL;
+    math_error error = rust_bigint_pow(self, exponent, &result);
+    rust_bigint_return_result(return_value, error, result);
+}
+
+/* RustMath\BigInt::modPow(BigInt|int|string $exponent, BigInt|int|string $modulus): BigInt */
+PHP_METHOD(RustMath_BigInt, modPow)
+{
+    zval *exponent_zv, *modulus_zv;
+    bool exponent_owned, modulus_owned;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_ZVAL(exponent_zv)
+        Z_PARAM_ZVAL(modulus_zv)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint *exponent = rust_bigint_operand(exponent_zv, 1, &exponent_owned);
+    if (exponent == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint *modulus = rust_bigint_operand(modulus_zv, 2, &modulus_owned);
+    if (modulus == NULL) {
+        rust_bigint_release(exponent, exponent_owned);
+        RETURN_THROWS();
+    }
+
+    if (rust_bigint_sign(exponent) < 0) {
+        zend_argument_value_error(1, "must be greater than or equal to 0");
+    } else if (rust_bigint_sign(modulus) <= 0) {
+        zend_argument_value_error(2, "must be greater than 0");
+    } else {
+        rust_bigint *result = NULL;
+        math_error error = rust_bigint_modpow(self, exponent, modulus, &result);
+        rust_bigint_return_result(return_value, error, result);
+    }
+
+    rust_bigint_release(exponent, exponent_owned);
+    rust_bigint_release(modulus, modulus_owned);
+}
+
+/* RustMath\BigInt::neg(): BigInt */
+PHP_METHOD(RustMath_BigInt, neg)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint *result = NULL;
+    math_error error = rust_bigint_neg(self, &result);
+    rust_bigint_return_result(return_value, error, result);
+}
+
+/* RustMath\BigInt::abs(): BigInt */
+PHP_METHOD(RustMath_BigInt, abs)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint *result = NULL;
+    math_error error = rust_bigint_abs(self, &result);
+    rust_bigint_return_result(return_value, error, result);
+}
+
+/* RustMath\BigInt::compare(BigInt|int|string $other): int - 返回 -1、0 或 1 */
+PHP_METHOD(RustMath_BigInt, compare)
+{
+    zval *other;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint *operand = rust_bigint_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    int result = rust_bigint_cmp(self, operand);
+    rust_bigint_release(operand, owned);
+
+    RETURN_LONG(result);
+}
+
+/* RustMath\BigInt::equals(BigInt|int|string $other): bool */
+PHP_METHOD(RustMath_BigInt, equals)
+{
+    zval *other;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint *operand = rust_bigint_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    int result = rust_bigint_cmp(self, operand);
+    rust_bigint_release(operand, owned);
+
+    RETURN_BOOL(result == 0);
+}
+
+/* RustMath\BigInt::sign(): int - 返回 -1、0 或 1 */
+PHP_METHOD(RustMath_BigInt, sign)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_bigint_sign(self));
+}
+
+/* RustMath\BigInt::toInt(): int - 超出 int 范围时抛出异常 */
+PHP_METHOD(RustMath_BigInt, toInt)
+{
+    long result;
+
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    math_error error = rust_bigint_to_long(self, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* RustMath\BigInt::toString(int $base = 10): string */
+PHP_METHOD(RustMath_BigInt, toString)
+{
+    zend_long base = 10;
+
+    ZEND_PARSE_PARAMETERS_START(0, 1)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_LONG(base)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint_return_string(return_value, self, base);
+}
+
+/* RustMath\BigInt::__toString(): string */
+PHP_METHOD(RustMath_BigInt, __toString)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_bigint *self = rust_bigint_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_bigint_return_string(return_value, self, 10);
+}
+
+/* 参数信息 */
+ZEND_BEGIN_ARG_INFO_EX(arginfo_class_RustMath_BigInt___construct, 0, 0, 0)
+    ZEND_ARG_TYPE_MASK(0, value, MAY_BE_LONG|MAY_BE_STRING, "0")
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, base, IS_LONG, 0, "10")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_BigInt_add, 0, 1, RustMath\\BigInt, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\BigInt, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_BigInt_sub arginfo_class_RustMath_BigInt_add
+#define arginfo_class_RustMath_BigInt_mul arginfo_class_RustMath_BigInt_add
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_BigInt_div, 0, 1, RustMath\\BigInt, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, divisor, RustMath\\BigInt, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_BigInt_mod arginfo_class_RustMath_BigInt_div
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_BigInt_divmod, 0, 1, IS_ARRAY, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, divisor, RustMath\\BigInt, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_BigInt_pow, 0, 1, RustMath\\BigInt, 0)
+    ZEND_ARG_TYPE_INFO(0, exponent, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_BigInt_modPow, 0, 2, RustMath\\BigInt, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, exponent, RustMath\\BigInt, MAY_BE_LONG|MAY_BE_STRING, NULL)
+    ZEND_ARG_OBJ_TYPE_MASK(0, modulus, RustMath\\BigInt, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_BigInt_neg, 0, 0, RustMath\\BigInt, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_BigInt_abs arginfo_class_RustMath_BigInt_neg
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_BigInt_compare, 0, 1, IS_LONG, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\BigInt, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_BigInt_equals, 0, 1, _IS_BOOL, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\BigInt, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_BigInt_sign, 0, 0, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_BigInt_toInt arginfo_class_RustMath_BigInt_sign
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_BigInt_toString, 0, 0, IS_STRING, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, base, IS_LONG, 0, "10")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_BigInt___toString, 0, 0, IS_STRING, 0)
+ZEND_END_ARG_INFO()
+
+/* 方法列表 */
+static const zend_function_entry rust_bigint_methods[] = {
+    PHP_ME(RustMath_BigInt, __construct, arginfo_class_RustMath_BigInt___construct, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, add, arginfo_class_RustMath_BigInt_add, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, sub, arginfo_class_RustMath_BigInt_sub, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, mul, arginfo_class_RustMath_BigInt_mul, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, div, arginfo_class_RustMath_BigInt_div, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, mod, arginfo_class_RustMath_BigInt_mod, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, divmod, arginfo_class_RustMath_BigInt_divmod, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, pow, arginfo_class_RustMath_BigInt_pow, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, modPow, arginfo_class_RustMath_BigInt_modPow, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, neg, arginfo_class_RustMath_BigInt_neg, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, abs, arginfo_class_RustMath_BigInt_abs, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, compare, arginfo_class_RustMath_BigInt_compare, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, equals, arginfo_class_RustMath_BigInt_equals, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, sign, arginfo_class_RustMath_BigInt_sign, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, toInt, arginfo_class_RustMath_BigInt_toInt, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, toString, arginfo_class_RustMath_BigInt_toString, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_BigInt, __toString, arginfo_class_RustMath_BigInt___toString, ZEND_ACC_PUBLIC)
+    PHP_FE_END
+};
+
+/* 注册 RustMath\BigInt 类，在 MINIT 中调用 */
+void rust_math_register_bigint_class(void)
+{
+    zend_class_entry ce;
+
+    INIT_NS_CLASS_ENTRY(ce, "RustMath", "BigInt", rust_bigint_methods);
+    rust_bigint_ce = zend_register_internal_class(&ce);
+    rust_bigint_ce->ce_flags |= ZEND_ACC_FINAL;
+    rust_bigint_ce->create_object = rust_bigint_create_object;
+    zend_class_implements(rust_bigint_ce, 1, zend_ce_stringable);
+
+    memcpy(&rust_bigint_handlers, &std_object_handlers, sizeof(zend_object_handlers));
+    rust_bigint_handlers.offset = XtOffsetOf(php_rust_bigint, std);
+    rust_bigint_handlers.free_obj = rust_bigint_free_obj;
+    rust_bigint_handlers.clone_obj = rust_bigint_clone_obj;
+    rust_bigint_handlers.compare = rust_bigint_compare_objects;
+}
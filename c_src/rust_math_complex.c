@@ -0,0 +1,616 @@
+/**
+ * PHP Rust 数学扩展 - RustMath\Complex 类
+ *
+ * 这个文件把 Rust 的复数运算包装成不可变的 PHP 对象：所有方法都返回新对象，不修改自身
+ * 复数只有实部和虚部两个 double，直接以 rust_complex 值保存在对象中，不需要 Rust 句柄
+ * 通过 do_operation 处理器重载 +、-、*、/、** 运算符，int 和 float 操作数按实数处理；
+ * 复数没有大小顺序，== 和 != 比较实部和虚部是否相等，<、> 等比较结果总是 false
+ * 对象设置值后不能再次调用构造函数，否则共享同一对象的所有引用都会被修改
+ */
+
+#include "php.h"
+#include "zend_exceptions.h"
+#include "zend_interfaces.h"
+#include "php_rust_math.h"
+
+/* 类入口和对象处理器 */
+zend_class_entry *rust_complex_ce;
+static zend_object_handlers rust_complex_handlers;
+
+/* PHP 对象结构：复数值 + 是否已经设置值 + 标准 zend_object（必须放在最后） */
+typedef struct {
+    rust_complex value;
+    bool initialized;
+    zend_object std;
+} php_rust_complex;
+
+static inline php_rust_complex *php_rust_complex_from_obj(zend_object *obj)
+{
+    return (php_rust_complex *)((char *)obj - XtOffsetOf(php_rust_complex, std));
+}
+
+#define Z_RUST_COMPLEX_P(zv) php_rust_complex_from_obj(Z_OBJ_P(zv))
+
+/* 创建对象：值初始化为 0，在构造函数或运算结果中设置 */
+static zend_object *rust_complex_create_object(zend_class_entry *ce)
+{
+    php_rust_complex *intern = zend_object_alloc(sizeof(php_rust_complex), ce);
+
+    zend_object_std_init(&intern->std, ce);
+    object_properties_init(&intern->std, ce);
+    intern->std.handlers = &rust_complex_handlers;
+    intern->value.re = 0.0;
+    intern->value.im = 0.0;
+    intern->initialized = false;
+
+    return &intern->std;
+}
+
+/* 克隆对象：直接复制值 */
+static zend_object *rust_complex_clone_obj(zend_object *old_object)
+{
+    php_rust_complex *old_intern = php_rust_complex_from_obj(old_object);
+    zend_object *new_object = rust_complex_create_object(old_object->ce);
+
+    zend_objects_clone_members(new_object, old_object);
+    php_rust_complex_from_obj(new_object)->value = old_intern->value;
+    php_rust_complex_from_obj(new_object)->initialized = true;
+
+    return new_object;
+}
+
+/* 获取当前对象的值 */
+static inline rust_complex rust_complex_this(zval *object)
+{
+    return Z_RUST_COMPLEX_P(object)->value;
+}
+
+/* 用复数值创建新的 Complex 对象 */
+static void rust_complex_return(zval *return_value, rust_complex value)
+{
+    object_init_ex(return_value, rust_complex_ce);
+    Z_RUST_COMPLEX_P(return_value)->value = value;
+    Z_RUST_COMPLEX_P(return_value)->initialized = true;
+}
+
+/* 根据 Rust 返回的错误码返回新对象或抛出异常 */
+static void rust_complex_return_result(zval *return_value, math_error error, rust_complex value)
+{
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        return;
+    }
+
+    rust_complex_return(return_value, value);
+}
+
+/**
+ * 把 Complex|int|float 转换为复数值，不抛出异常
+ *
+ * int 和 float 转换为虚部为 0 的复数；类型不支持时返回 false
+ */
+static bool rust_complex_try_operand(zval *value, rust_complex *out)
+{
+    switch (Z_TYPE_P(value)) {
+        case IS_LONG:
+            out->re = (double) Z_LVAL_P(value);
+            out->im = 0.0;
+            return true;
+
+        case IS_DOUBLE:
+            out->re = Z_DVAL_P(value);
+            out->im = 0.0;
+            return true;
+
+        case IS_OBJECT:
+            if (instanceof_function(Z_OBJCE_P(value), rust_complex_ce)) {
+                *out = Z_RUST_COMPLEX_P(value)->value;
+                return true;
+            }
+            break;
+
+        default:
+            break;
+    }
+
+    return false;
+}
+
+/* 把方法参数转换为复数值，类型不支持时抛出 TypeError 并返回 false */
+static bool rust_complex_operand(zval *value, uint32_t arg_num, rust_complex *out)
+{
+    if (rust_complex_try_operand(value, out)) {
+        return true;
+    }
+
+    zend_argument_type_error(arg_num, "must be of type RustMath\\Complex|int|float, %s given",
+        zend_zval_type_name(value));
+
+    return false;
+}
+
+/* 二元运算的公共实现：解析参数、调用 Rust 函数、返回新对象 */
+static void rust_complex_binary_op(INTERNAL_FUNCTION_PARAMETERS,
+    math_error (*op)(rust_complex, rust_complex, rust_complex *))
+{
+    zval *other;
+    rust_complex operand;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    if (!rust_complex_operand(other, 1, &operand)) {
+        RETURN_THROWS();
+    }
+
+    rust_complex result;
+    math_error error = op(rust_complex_this(ZEND_THIS), operand, &result);
+    rust_complex_return_result(return_value, error, result);
+}
+
+/* 一元运算的公共实现 */
+static void rust_complex_unary_op(INTERNAL_FUNCTION_PARAMETERS,
+    math_error (*op)(rust_complex, rust_complex *))
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_complex result;
+    math_error error = op(rust_complex_this(ZEND_THIS), &result);
+    rust_complex_return_result(return_value, error, result);
+}
+
+/* 幂运算：int 指数使用更精确的二进制快速幂，其他指数计算主值 */
+static math_error rust_complex_power(rust_complex base, zval *exponent, rust_complex operand, rust_complex *result)
+{
+    if (Z_TYPE_P(exponent) == IS_LONG) {
+        return rust_complex_powi(base, Z_LVAL_P(exponent), result);
+    }
+
+    return rust_complex_pow(base, operand, result);
+}
+
+/* 运算符重载：$a + $b、$a - $b、$a * $b、$a / $b 和 $a ** $b */
+static zend_result rust_complex_do_operation(uint8_t opcode, zval *result, zval *op1, zval *op2)
+{
+    rust_complex a, b, value;
+    math_error error;
+
+    /* 不支持的运算符或操作数类型返回 FAILURE，由 Zend 引擎抛出 "Unsupported operand types" */
+    if (!rust_complex_try_operand(op1, &a) || !rust_complex_try_operand(op2, &b)) {
+        return FAILURE;
+    }
+
+    switch (opcode) {
+        case ZEND_ADD:
+            error = rust_complex_add(a, b, &value);
+            break;
+        case ZEND_SUB:
+            error = rust_complex_sub(a, b, &value);
+            break;
+        case ZEND_MUL:
+            error = rust_complex_mul(a, b, &value);
+            break;
+        case ZEND_DIV:
+            error = rust_complex_div(a, b, &value);
+            break;
+        case ZEND_POW:
+            error = rust_complex_power(a, op2, b, &value);
+            break;
+        default:
+            return FAILURE;
+    }
+
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        return FAILURE;
+    }
+
+    /* $a += $b 时 result 和 op1 是同一个 zval，需要先释放旧值 */
+    if (result == op1) {
+        zval_ptr_dtor(result);
+    }
+    rust_complex_return(result, value);
+
+    return SUCCESS;
+}
+
+/* 比较对象：实部和虚部都相等时返回 0，否则不可比较，另一个操作数可以是 int 或 float */
+static int rust_complex_compare_objects(zval *op1, zval *op2)
+{
+    rust_complex a, b;
+
+    if (!rust_complex_try_operand(op1, &a) || !rust_complex_try_operand(op2, &b)) {
+        return zend_std_compare_objects(op1, op2);
+    }
+
+    return (a.re == b.re && a.im == b.im) ? 0 : ZEND_UNCOMPARABLE;
+}
+
+/* RustMath\Complex::__construct(float $real = 0.0, float $imag = 0.0) */
+PHP_METHOD(RustMath_Complex, __construct)
+{
+    double re = 0.0;
+    double im = 0.0;
+
+    ZEND_PARSE_PARAMETERS_START(0, 2)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_DOUBLE(re)
+        Z_PARAM_DOUBLE(im)
+    ZEND_PARSE_PARAMETERS_END();
+
+    php_rust_complex *intern = Z_RUST_COMPLEX_P(ZEND_THIS);
+    if (intern->initialized) {
+        zend_throw_error(NULL, "Cannot call RustMath\\Complex::__construct() on an already constructed immutable object");
+        RETURN_THROWS();
+    }
+
+    intern->value.re = re;
+    intern->value.im = im;
+    intern->initialized = true;
+}
+
+/* RustMath\Complex::fromPolar(float $modulus, float $argument): Complex - 由模和辐角（弧度）创建 */
+PHP_METHOD(RustMath_Complex, fromPolar)
+{
+    double modulus, argument;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_DOUBLE(modulus)
+        Z_PARAM_DOUBLE(argument)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_complex result;
+    math_error error = rust_complex_from_polar(modulus, argument, &result);
+    rust_complex_return_result(return_value, error, result);
+}
+
+/* RustMath\Complex::real(): float */
+PHP_METHOD(RustMath_Complex, real)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    RETURN_DOUBLE(rust_complex_this(ZEND_THIS).re);
+}
+
+/* RustMath\Complex::imag(): float */
+PHP_METHOD(RustMath_Complex, imag)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    RETURN_DOUBLE(rust_complex_this(ZEND_THIS).im);
+}
+
+/* RustMath\Complex::abs(): float - 模 |z| */
+PHP_METHOD(RustMath_Complex, abs)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    RETURN_DOUBLE(rust_complex_abs(rust_complex_this(ZEND_THIS)));
+}
+
+/* RustMath\Complex::arg(): float - 辐角，范围为 [-π, π] */
+PHP_METHOD(RustMath_Complex, arg)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    RETURN_DOUBLE(rust_complex_arg(rust_complex_this(ZEND_THIS)));
+}
+
+/* RustMath\Complex::toPolar(): array - 返回 [模, 辐角] */
+PHP_METHOD(RustMath_Complex, toPolar)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_complex self = rust_complex_this(ZEND_THIS);
+
+    array_init_size(return_value, 2);
+    add_next_index_double(return_value, rust_complex_abs(self));
+    add_next_index_double(return_value, rust_complex_arg(self));
+}
+
+/* RustMath\Complex::conj(): Complex - 共轭复数 */
+PHP_METHOD(RustMath_Complex, conj)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_complex self = rust_complex_this(ZEND_THIS);
+    self.im = -self.im;
+
+    rust_complex_return(return_value, self);
+}
+
+/* RustMath\Complex::neg(): Complex */
+PHP_METHOD(RustMath_Complex, neg)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_complex self = rust_complex_this(ZEND_THIS);
+    self.re = -self.re;
+    self.im = -self.im;
+
+    rust_complex_return(return_value, self);
+}
+
+/* RustMath\Complex::reciprocal(): Complex - 值为 0 时抛出 DivisionByZeroError */
+PHP_METHOD(RustMath_Complex, reciprocal)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_reciprocal);
+}
+
+/* RustMath\Complex::add(Complex|int|float $other): Complex */
+PHP_METHOD(RustMath_Complex, add)
+{
+    rust_complex_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_add);
+}
+
+/* RustMath\Complex::sub(Complex|int|float $other): Complex */
+PHP_METHOD(RustMath_Complex, sub)
+{
+    rust_complex_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_sub);
+}
+
+/* RustMath\Complex::mul(Complex|int|float $other): Complex */
+PHP_METHOD(RustMath_Complex, mul)
+{
+    rust_complex_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_mul);
+}
+
+/* RustMath\Complex::div(Complex|int|float $divisor): Complex - 除数为 0 时抛出 DivisionByZeroError */
+PHP_METHOD(RustMath_Complex, div)
+{
+    rust_complex_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_div);
+}
+
+/* RustMath\Complex::pow(Complex|int|float $exponent): Complex - 幂的主值，int 指数使用快速幂 */
+PHP_METHOD(RustMath_Complex, pow)
+{
+    zval *exponent;
+    rust_complex operand;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(exponent)
+    ZEND_PARSE_PARAMETERS_END();
+
+    if (!rust_complex_operand(exponent, 1, &operand)) {
+        RETURN_THROWS();
+    }
+
+    rust_complex result;
+    math_error error = rust_complex_power(rust_complex_this(ZEND_THIS), exponent, operand, &result);
+    rust_complex_return_result(return_value, error, result);
+}
+
+/* RustMath\Complex::exp(): Complex */
+PHP_METHOD(RustMath_Complex, exp)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_exp);
+}
+
+/* RustMath\Complex::ln(): Complex - 自然对数的主值，值为 0 时抛出异常 */
+PHP_METHOD(RustMath_Complex, ln)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_ln);
+}
+
+/* RustMath\Complex::log10(): Complex - 常用对数的主值，值为 0 时抛出异常 */
+PHP_METHOD(RustMath_Complex, log10)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_log10);
+}
+
+/* RustMath\Complex::sqrt(): Complex - 实部非负的平方根 */
+PHP_METHOD(RustMath_Complex, sqrt)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_sqrt);
+}
+
+/* RustMath\Complex::sin(): Complex */
+PHP_METHOD(RustMath_Complex, sin)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_sin);
+}
+
+/* RustMath\Complex::cos(): Complex */
+PHP_METHOD(RustMath_Complex, cos)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_cos);
+}
+
+/* RustMath\Complex::tan(): Complex */
+PHP_METHOD(RustMath_Complex, tan)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_tan);
+}
+
+/* RustMath\Complex::asin(): Complex */
+PHP_METHOD(RustMath_Complex, asin)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_asin);
+}
+
+/* RustMath\Complex::acos(): Complex */
+PHP_METHOD(RustMath_Complex, acos)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_acos);
+}
+
+/* RustMath\Complex::atan(): Complex - 值为 ±i 时抛出异常 */
+PHP_METHOD(RustMath_Complex, atan)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_atan);
+}
+
+/* RustMath\Complex::sinh(): Complex */
+PHP_METHOD(RustMath_Complex, sinh)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_sinh);
+}
+
+/* RustMath\Complex::cosh(): Complex */
+PHP_METHOD(RustMath_Complex, cosh)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_cosh);
+}
+
+/* RustMath\Complex::tanh(): Complex */
+PHP_METHOD(RustMath_Complex, tanh)
+{
+    rust_complex_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_complex_tanh);
+}
+
+/* RustMath\Complex::equals(Complex|int|float $other, float $epsilon = 0.0): bool - |a - b| 不超过 epsilon 时相等 */
+PHP_METHOD(RustMath_Complex, equals)
+{
+    zval *other;
+    double epsilon = 0.0;
+    rust_complex operand;
+
+    ZEND_PARSE_PARAMETERS_START(1, 2)
+        Z_PARAM_ZVAL(other)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_DOUBLE(epsilon)
+    ZEND_PARSE_PARAMETERS_END();
+
+    if (!rust_complex_operand(other, 1, &operand)) {
+        RETURN_THROWS();
+    }
+
+    if (!(epsilon >= 0.0)) {
+        zend_argument_value_error(2, "must be greater than or equal to 0");
+        RETURN_THROWS();
+    }
+
+    rust_complex self = rust_complex_this(ZEND_THIS);
+    rust_complex difference = { self.re - operand.re, self.im - operand.im };
+
+    RETURN_BOOL(rust_complex_abs(difference) <= epsilon);
+}
+
+/* RustMath\Complex::__toString(): string - 例如 "3+4i"、"3-4i"、"4i" 或 "3" */
+PHP_METHOD(RustMath_Complex, __toString)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_string_result result;
+
+    rust_complex_to_string(rust_complex_this(ZEND_THIS), &result);
+    RETVAL_STRINGL(result.data, result.length);
+    rust_free_string_result(result);
+}
+
+/* 参数信息 */
+ZEND_BEGIN_ARG_INFO_EX(arginfo_class_RustMath_Complex___construct, 0, 0, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, real, IS_DOUBLE, 0, "0.0")
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, imag, IS_DOUBLE, 0, "0.0")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Complex_fromPolar, 0, 2, RustMath\\Complex, 0)
+    ZEND_ARG_TYPE_INFO(0, modulus, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, argument, IS_DOUBLE, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Complex_real, 0, 0, IS_DOUBLE, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Complex_imag arginfo_class_RustMath_Complex_real
+#define arginfo_class_RustMath_Complex_abs arginfo_class_RustMath_Complex_real
+#define arginfo_class_RustMath_Complex_arg arginfo_class_RustMath_Complex_real
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Complex_toPolar, 0, 0, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Complex_conj, 0, 0, RustMath\\Complex, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Complex_neg arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_reciprocal arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_exp arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_ln arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_log10 arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_sqrt arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_sin arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_cos arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_tan arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_asin arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_acos arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_atan arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_sinh arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_cosh arginfo_class_RustMath_Complex_conj
+#define arginfo_class_RustMath_Complex_tanh arginfo_class_RustMath_Complex_conj
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Complex_add, 0, 1, RustMath\\Complex, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\Complex, MAY_BE_LONG|MAY_BE_DOUBLE, NULL)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Complex_sub arginfo_class_RustMath_Complex_add
+#define arginfo_class_RustMath_Complex_mul arginfo_class_RustMath_Complex_add
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Complex_div, 0, 1, RustMath\\Complex, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, divisor, RustMath\\Complex, MAY_BE_LONG|MAY_BE_DOUBLE, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Complex_pow, 0, 1, RustMath\\Complex, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, exponent, RustMath\\Complex, MAY_BE_LONG|MAY_BE_DOUBLE, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Complex_equals, 0, 1, _IS_BOOL, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\Complex, MAY_BE_LONG|MAY_BE_DOUBLE, NULL)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, epsilon, IS_DOUBLE, 0, "0.0")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Complex___toString, 0, 0, IS_STRING, 0)
+ZEND_END_ARG_INFO()
+
+/* 方法列表 */
+static const zend_function_entry rust_complex_methods[] = {
+    PHP_ME(RustMath_Complex, __construct, arginfo_class_RustMath_Complex___construct, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, fromPolar, arginfo_class_RustMath_Complex_fromPolar, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Complex, real, arginfo_class_RustMath_Complex_real, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, imag, arginfo_class_RustMath_Complex_imag, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, abs, arginfo_class_RustMath_Complex_abs, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, arg, arginfo_class_RustMath_Complex_arg, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, toPolar, arginfo_class_RustMath_Complex_toPolar, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, conj, arginfo_class_RustMath_Complex_conj, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, neg, arginfo_class_RustMath_Complex_neg, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, reciprocal, arginfo_class_RustMath_Complex_reciprocal, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, add, arginfo_class_RustMath_Complex_add, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, sub, arginfo_class_RustMath_Complex_sub, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, mul, arginfo_class_RustMath_Complex_mul, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, div, arginfo_class_RustMath_Complex_div, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, pow, arginfo_class_RustMath_Complex_pow, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, exp, arginfo_class_RustMath_Complex_exp, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, ln, arginfo_class_RustMath_Complex_ln, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, log10, arginfo_class_RustMath_Complex_log10, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, sqrt, arginfo_class_RustMath_Complex_sqrt, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, sin, arginfo_class_RustMath_Complex_sin, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, cos, arginfo_class_RustMath_Complex_cos, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, tan, arginfo_class_RustMath_Complex_tan, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, asin, arginfo_class_RustMath_Complex_asin, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, acos, arginfo_class_RustMath_Complex_acos, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, atan, arginfo_class_RustMath_Complex_atan, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, sinh, arginfo_class_RustMath_Complex_sinh, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, cosh, arginfo_class_RustMath_Complex_cosh, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, tanh, arginfo_class_RustMath_Complex_tanh, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, equals, arginfo_class_RustMath_Complex_equals, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Complex, __toString, arginfo_class_RustMath_Complex___toString, ZEND_ACC_PUBLIC)
+    PHP_FE_END
+};
+
+/* 注册 RustMath\Complex 类，在 MINIT 中调用 */
+void rust_math_register_complex_class(void)
+{
+    zend_class_entry ce;
+
+    INIT_NS_CLASS_ENTRY(ce, "RustMath", "Complex", rust_complex_methods);
+    rust_complex_ce = zend_register_internal_class(&ce);
+    rust_complex_ce->ce_flags |= ZEND_ACC_FINAL;
+    rust_complex_ce->create_object = rust_complex_create_object;
+    zend_class_implements(rust_complex_ce, 1, zend_ce_stringable);
+
+    memcpy(&rust_complex_handlers, &std_object_handlers, sizeof(zend_object_handlers));
+    rust_complex_handlers.offset = XtOffsetOf(php_rust_complex, std);
+    rust_complex_handlers.clone_obj = rust_complex_clone_obj;
+    rust_complex_handlers.do_operation = rust_complex_do_operation;
+    rust_complex_handlers.compare = rust_complex_compare_objects;
+}
@@ -0,0 +1,827 @@
+/**
+ * PHP Rust 数学扩展 - RustMath\Decimal 类
+ *
+ * 这个文件把 Rust 的定点小数句柄包装成 PHP 对象，用于金额等需要精确十进制运算的场景
+ * 通过 do_operation 处理器重载 +、-、* 运算符，通过 compare 处理器重载比较运算符；
+ * 除法的结果需要指定小数位数和舍入模式，只能通过 div() 方法计算
+ * 舍入模式是类常量 RustMath\Decimal::ROUND_*，取值与 rust_math_ffi.h 中的 rounding_mode 相同
+ */
+
+#include "php.h"
+#include "zend_exceptions.h"
+#include "zend_interfaces.h"
+#include "php_rust_math.h"
+
+/* 允许的最大小数位数，与 math::decimal::MAX_SCALE 一致 */
+#define RUST_DECIMAL_MAX_SCALE 28
+
+/* 类入口和对象处理器 */
+zend_class_entry *rust_decimal_ce;
+static zend_object_handlers rust_decimal_handlers;
+
+/* PHP 对象结构：Rust 句柄 + 标准 zend_object（必须放在最后） */
+typedef struct {
+    rust_decimal *handle;
+    zend_object std;
+} php_rust_decimal;
+
+static inline php_rust_decimal *php_rust_decimal_from_obj(zend_object *obj)
+{
+    return (php_rust_decimal *)((char *)obj - XtOffsetOf(php_rust_decimal, std));
+}
+
+#define Z_RUST_DECIMAL_P(zv) php_rust_decimal_from_obj(Z_OBJ_P(zv))
+
+/* 创建对象：句柄在构造函数或运算结果中设置 */
+static zend_object *rust_decimal_create_object(zend_class_entry *ce)
+{
+    php_rust_decimal *intern = zend_object_alloc(sizeof(php_rust_decimal), ce);
+
+    zend_object_std_init(&intern->std, ce);
+    object_properties_init(&intern->std, ce);
+    intern->std.handlers = &rust_decimal_handlers;
+    intern->handle = NULL;
+
+    return &intern->std;
+}
+
+/* 销毁对象：释放 Rust 句柄 */
+static void rust_decimal_free_obj(zend_object *object)
+{
+    php_rust_decimal *intern = php_rust_decimal_from_obj(object);
+
+    if (intern->handle != NULL) {
+        rust_decimal_free(intern->handle);
+        intern->handle = NULL;
+    }
+
+    zend_object_std_dtor(&intern->std);
+}
+
+/* 克隆对象：复制 Rust 句柄 */
+static zend_object *rust_decimal_clone_obj(zend_object *old_object)
+{
+    php_rust_decimal *old_intern = php_rust_decimal_from_obj(old_object);
+    zend_object *new_object = rust_decimal_create_object(old_object->ce);
+    php_rust_decimal *new_intern = php_rust_decimal_from_obj(new_object);
+
+    zend_objects_clone_members(new_object, old_object);
+    rust_decimal_clone(old_intern->handle, &new_intern->handle);
+
+    return new_object;
+}
+
+/* 获取当前对象的句柄，未初始化时抛出异常 */
+static rust_decimal *rust_decimal_this(zval *object)
+{
+    rust_decimal *handle = Z_RUST_DECIMAL_P(object)->handle;
+
+    if (handle == NULL) {
+        zend_throw_error(NULL, "RustMath\\Decimal object is not initialized");
+    }
+
+    return handle;
+}
+
+/* 用句柄创建新的 Decimal 对象，句柄的所有权转移给对象 */
+static void rust_decimal_return(zval *return_value, rust_decimal *handle)
+{
+    object_init_ex(return_value, rust_decimal_ce);
+    Z_RUST_DECIMAL_P(return_value)->handle = handle;
+}
+
+/* 根据 Rust 返回的错误码返回新对象或抛出异常 */
+static void rust_decimal_return_result(zval *return_value, math_error error, rust_decimal *handle)
+{
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        return;
+    }
+
+    rust_decimal_return(return_value, handle);
+}
+
+/* 检查小数位数和舍入模式参数，无效时抛出 ValueError 并返回 false */
+static bool rust_decimal_check_rounding(zend_long scale, uint32_t scale_arg, zend_long mode, uint32_t mode_arg)
+{
+    if (scale < 0 || scale > RUST_DECIMAL_MAX_SCALE) {
+        zend_argument_value_error(scale_arg, "must be between 0 and %d", RUST_DECIMAL_MAX_SCALE);
+        return false;
+    }
+
+    if (mode < ROUNDING_MODE_UP || mode > ROUNDING_MODE_HALF_EVEN) {
+        zend_argument_value_error(mode_arg, "must be one of the RustMath\\Decimal::ROUND_* constants");
+        return false;
+    }
+
+    return true;
+}
+
+/**
+ * 把 Decimal|int|string 转换为定点小数句柄，不抛出异常
+ *
+ * 如果参数本身是 Decimal 对象则直接借用它的句柄，*owned 为 false；
+ * 否则创建新句柄，*owned 为 true，调用者用完后需要释放
+ * 类型不支持时返回 NULL，字符串无效时返回 NULL 并通过 *error 返回错误码
+ */
+static rust_decimal *rust_decimal_try_operand(zval *value, bool *owned, math_error *error)
+{
+    rust_decimal *handle = NULL;
+
+    *owned = false;
+    *error = MATH_ERROR_SUCCESS;
+
+    switch (Z_TYPE_P(value)) {
+        case IS_LONG:
+            rust_decimal_from_long(Z_LVAL_P(value), &handle);
+            *owned = true;
+            return handle;
+
+        case IS_STRING:
+            *error = rust_decimal_from_string(Z_STRVAL_P(value), &handle);
+            *owned = handle != NULL;
+            return handle;
+
+        case IS_OBJECT:
+            if (instanceof_function(Z_OBJCE_P(value), rust_decimal_ce)) {
+                return Z_RUST_DECIMAL_P(value)->handle;
+            }
+            break;
+
+        default:
+            break;
+    }
+
+    return NULL;
+}
+
+/**
+ * 把方法参数转换为定点小数句柄，出错时抛出异常并返回 NULL
+ *
+ * 借用和释放规则与 rust_decimal_try_operand 相同
+ */
+static rust_decimal *rust_decimal_operand(zval *value, uint32_t arg_num, bool *owned)
+{
+    math_error error;
+    rust_decimal *handle = rust_decimal_try_operand(value, owned, &error);
+
+    if (handle != NULL) {
+        return handle;
+    }
+
+    if (Z_TYPE_P(value) == IS_OBJECT && instanceof_function(Z_OBJCE_P(value), rust_decimal_ce)) {
+        zend_throw_error(NULL, "RustMath\\Decimal object is not initialized");
+    } else if (error == MATH_ERROR_INVALID_PARAMETER) {
+        zend_argument_value_error(arg_num, "must be a valid decimal number string with at most %d decimal places",
+            RUST_DECIMAL_MAX_SCALE);
+    } else if (error != MATH_ERROR_SUCCESS) {
+        /* 超出 128 位整数范围 */
+        rust_math_throw_error(error);
+    } else {
+        zend_argument_type_error(arg_num, "must be of type RustMath\\Decimal|string|int, %s given",
+            zend_zval_type_name(value));
+    }
+
+    return NULL;
+}
+
+/* 释放 rust_decimal_operand 创建的临时句柄 */
+static inline void rust_decimal_release(rust_decimal *handle, bool owned)
+{
+    if (owned && handle != NULL) {
+        rust_decimal_free(handle);
+    }
+}
+
+/* 精确的二元运算的公共实现：解析参数、调用 Rust 函数、返回新对象 */
+static void rust_decimal_binary_op(INTERNAL_FUNCTION_PARAMETERS,
+    math_error (*op)(const rust_decimal *, const rust_decimal *, rust_decimal **))
+{
+    zval *other;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_decimal *operand = rust_decimal_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    /* 结果超出范围或乘积的小数位数过多时返回 MATH_ERROR_OVERFLOW */
+    rust_decimal *result = NULL;
+    math_error error = op(self, operand, &result);
+    rust_decimal_release(operand, owned);
+
+    rust_decimal_return_result(return_value, error, result);
+}
+
+/* 一元运算的公共实现 */
+static void rust_decimal_unary_op(INTERNAL_FUNCTION_PARAMETERS,
+    math_error (*op)(const rust_decimal *, rust_decimal **))
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_decimal *result = NULL;
+    math_error error = op(self, &result);
+    rust_decimal_return_result(return_value, error, result);
+}
+
+/* 运算符重载：$a + $b、$a - $b 和 $a * $b，除法需要指定小数位数，不重载 / */
+static zend_result rust_decimal_do_operation(uint8_t opcode, zval *result, zval *op1, zval *op2)
+{
+    bool owned1, owned2;
+    math_error error;
+    rust_decimal *value = NULL;
+
+    if (opcode != ZEND_ADD && opcode != ZEND_SUB && opcode != ZEND_MUL) {
+        return FAILURE;
+    }
+
+    /* 不支持的操作数类型返回 FAILURE，由 Zend 引擎抛出 "Unsupported operand types" */
+    rust_decimal *a = rust_decimal_try_operand(op1, &owned1, &error);
+    if (a == NULL) {
+        if (error != MATH_ERROR_SUCCESS) {
+            rust_math_throw_error(error);
+        }
+        return FAILURE;
+    }
+
+    rust_decimal *b = rust_decimal_try_operand(op2, &owned2, &error);
+    if (b == NULL) {
+        rust_decimal_release(a, owned1);
+        if (error != MATH_ERROR_SUCCESS) {
+            rust_math_throw_error(error);
+        }
+        return FAILURE;
+    }
+
+    switch (opcode) {
+        case ZEND_ADD:
+            error = rust_decimal_add(a, b, &value);
+            break;
+        case ZEND_SUB:
+            error = rust_decimal_sub(a, b, &value);
+            break;
+        default:
+            error = rust_decimal_mul(a, b, &value);
+            break;
+    }
+
+    rust_decimal_release(a, owned1);
+    rust_decimal_release(b, owned2);
+
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        return FAILURE;
+    }
+
+    /* $a += $b 时 result 和 op1 是同一个 zval，需要先释放旧值 */
+    if (result == op1) {
+        zval_ptr_dtor(result);
+    }
+    rust_decimal_return(result, value);
+
+    return SUCCESS;
+}
+
+/* 比较对象：让 ==、<、> 等运算符按数值比较（1.50 == 1.5），另一个操作数可以是 int 或字符串 */
+static int rust_decimal_compare_objects(zval *op1, zval *op2)
+{
+    bool owned1, owned2;
+    math_error error;
+
+    rust_decimal *a = rust_decimal_try_operand(op1, &owned1, &error);
+    rust_decimal *b = rust_decimal_try_operand(op2, &owned2, &error);
+
+    if (a == NULL || b == NULL) {
+        rust_decimal_release(a, owned1);
+        rust_decimal_release(b, owned2);
+        return zend_std_compare_objects(op1, op2);
+    }
+
+    int result = rust_decimal_cmp(a, b);
+    rust_decimal_release(a, owned1);
+    rust_decimal_release(b, owned2);
+
+    return result;
+}
+
+/* 类型转换：支持 (float) 和 (string)，(int) 和 (bool) 按默认规则处理 */
+static zend_result rust_decimal_cast_object(zend_object *object, zval *result, int type)
+{
+    rust_decimal *handle = php_rust_decimal_from_obj(object)->handle;
+
+    if (type == IS_DOUBLE && handle != NULL) {
+        ZVAL_DOUBLE(result, rust_decimal_to_double(handle));
+        return SUCCESS;
+    }
+
+    return zend_std_cast_object_tostring(object, result, type);
+}
+
+/* 按比例分配金额，返回 Decimal 对象数组 */
+static void rust_decimal_return_allocation(zval *return_value, rust_decimal *self, long *ratios, uint32_t length)
+{
+    rust_decimal **parts = safe_emalloc(length, sizeof(rust_decimal *), 0);
+
+    /* 比例全为 0 时返回 MATH_ERROR_INVALID_PARAMETER，有负数时返回 MATH_ERROR_NEGATIVE_NUMBER */
+    math_error error = rust_decimal_allocate(self, ratios, length, parts);
+
+    if (error != MATH_ERROR_SUCCESS) {
+        if (error == MATH_ERROR_INVALID_PARAMETER) {
+            zend_argument_value_error(1, "must contain at least one ratio greater than 0");
+        } else {
+            rust_math_throw_error(error);
+        }
+        efree(parts);
+        return;
+    }
+
+    array_init_size(return_value, length);
+    for (uint32_t i = 0; i < length; i++) {
+        zval part;
+        rust_decimal_return(&part, parts[i]);
+        add_next_index_zval(return_value, &part);
+    }
+
+    efree(parts);
+}
+
+/* 把定点小数转换为 PHP 字符串 */
+static void rust_decimal_return_string(zval *return_value, rust_decimal *handle)
+{
+    rust_string_result result;
+
+    rust_decimal_to_string(handle, &result);
+    RETVAL_STRINGL(result.data, result.length);
+    rust_free_string_result(result);
+}
+
+/* RustMath\Decimal::__construct(int|string $value = 0, ?int $scale = null, int $roundingMode = Decimal::ROUND_HALF_EVEN) */
+PHP_METHOD(RustMath_Decimal, __construct)
+{
+    zend_string *str = NULL;
+    zend_long value = 0;
+    zend_long scale = 0;
+    bool scale_is_null = true;
+    zend_long mode = ROUNDING_MODE_HALF_EVEN;
+    php_rust_decimal *intern = Z_RUST_DECIMAL_P(ZEND_THIS);
+
+    ZEND_PARSE_PARAMETERS_START(0, 3)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_STR_OR_LONG(str, value)
+        Z_PARAM_LONG_OR_NULL(scale, scale_is_null)
+        Z_PARAM_LONG(mode)
+    ZEND_PARSE_PARAMETERS_END();
+
+    if (!scale_is_null && !rust_decimal_check_rounding(scale, 2, mode, 3)) {
+        RETURN_THROWS();
+    }
+
+    rust_decimal *handle = NULL;
+    math_error error;
+
+    if (str != NULL && scale_is_null) {
+        /* 保留字符串的全部小数位数 */
+        error = rust_decimal_from_string(ZSTR_VAL(str), &handle);
+    } else if (str != NULL) {
+        error = rust_decimal_from_string_rounded(ZSTR_VAL(str), scale, (int) mode, &handle);
+    } else {
+        error = rust_decimal_from_long(value, &handle);
+        if (error == MATH_ERROR_SUCCESS && !scale_is_null) {
+            rust_decimal *rounded = NULL;
+            error = rust_decimal_round(handle, scale, (int) mode, &rounded);
+            rust_decimal_free(handle);
+            handle = rounded;
+        }
+    }
+
+    if (error == MATH_ERROR_INVALID_PARAMETER && scale_is_null) {
+        zend_argument_value_error(1, "must be a valid decimal number string with at most %d decimal places", RUST_DECIMAL_MAX_SCALE);
+        RETURN_THROWS();
+    }
+    if (error == MATH_ERROR_INVALID_PARAMETER) {
+        zend_argument_value_error(1, "must be a valid decimal number string");
+        RETURN_THROWS();
+    }
+
+    /* 超出 128 位整数范围 */
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    if (intern->handle != NULL) {
+        rust_decimal_free(intern->handle);
+    }
+    intern->handle = handle;
+}
+
+/* RustMath\Decimal::fromFloat(float $value, int $scale, int $roundingMode = Decimal::ROUND_HALF_EVEN): Decimal */
+PHP_METHOD(RustMath_Decimal, fromFloat)
+{
+    double value;
+    zend_long scale;
+    zend_long mode = ROUNDING_MODE_HALF_EVEN;
+
+    ZEND_PARSE_PARAMETERS_START(2, 3)
+        Z_PARAM_DOUBLE(value)
+        Z_PARAM_LONG(scale)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_LONG(mode)
+    ZEND_PARSE_PARAMETERS_END();
+
+    if (!rust_decimal_check_rounding(scale, 2, mode, 3)) {
+        RETURN_THROWS();
+    }
+
+    /* 使用能还原出同一个浮点数的最短十进制表示；NaN、无穷大或超出范围时返回错误码 */
+    rust_decimal *handle = NULL;
+    math_error error = rust_decimal_from_double(value, scale, (int) mode, &handle);
+    rust_decimal_return_result(return_value, error, handle);
+}
+
+/* RustMath\Decimal::add(Decimal|int|string $other): Decimal */
+PHP_METHOD(RustMath_Decimal, add)
+{
+    rust_decimal_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_decimal_add);
+}
+
+/* RustMath\Decimal::sub(Decimal|int|string $other): Decimal */
+PHP_METHOD(RustMath_Decimal, sub)
+{
+    rust_decimal_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_decimal_sub);
+}
+
+/* RustMath\Decimal::mul(Decimal|int|string $other): Decimal - 结果的小数位数为两者之和 */
+PHP_METHOD(RustMath_Decimal, mul)
+{
+    rust_decimal_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_decimal_mul);
+}
+
+/* RustMath\Decimal::div(Decimal|int|string $divisor, int $scale, int $roundingMode = Decimal::ROUND_HALF_EVEN): Decimal */
+PHP_METHOD(RustMath_Decimal, div)
+{
+    zval *other;
+    zend_long scale;
+    zend_long mode = ROUNDING_MODE_HALF_EVEN;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(2, 3)
+        Z_PARAM_ZVAL(other)
+        Z_PARAM_LONG(scale)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_LONG(mode)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (!rust_decimal_check_rounding(scale, 2, mode, 3)) {
+        RETURN_THROWS();
+    }
+
+    rust_decimal *operand = rust_decimal_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    /* 除数为 0 时返回 MATH_ERROR_DIVISION_BY_ZERO，结果超出范围时返回 MATH_ERROR_OVERFLOW */
+    rust_decimal *result = NULL;
+    math_error error = rust_decimal_div(self, operand, scale, (int) mode, &result);
+    rust_decimal_release(operand, owned);
+
+    rust_decimal_return_result(return_value, error, result);
+}
+
+/* RustMath\Decimal::round(int $scale, int $roundingMode = Decimal::ROUND_HALF_EVEN): Decimal - 位数比当前多时只补 0 */
+PHP_METHOD(RustMath_Decimal, round)
+{
+    zend_long scale;
+    zend_long mode = ROUNDING_MODE_HALF_EVEN;
+
+    ZEND_PARSE_PARAMETERS_START(1, 2)
+        Z_PARAM_LONG(scale)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_LONG(mode)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (!rust_decimal_check_rounding(scale, 1, mode, 2)) {
+        RETURN_THROWS();
+    }
+
+    rust_decimal *result = NULL;
+    math_error error = rust_decimal_round(self, scale, (int) mode, &result);
+    rust_decimal_return_result(return_value, error, result);
+}
+
+/* RustMath\Decimal::neg(): Decimal */
+PHP_METHOD(RustMath_Decimal, neg)
+{
+    rust_decimal_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_decimal_neg);
+}
+
+/* RustMath\Decimal::abs(): Decimal */
+PHP_METHOD(RustMath_Decimal, abs)
+{
+    rust_decimal_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_decimal_abs);
+}
+
+/* RustMath\Decimal::allocate(array $ratios): array - 按比例分配，各部分之和恰好等于原值 */
+PHP_METHOD(RustMath_Decimal, allocate)
+{
+    HashTable *ratios_ht;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ARRAY_HT(ratios_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    uint32_t length = zend_hash_num_elements(ratios_ht);
+    if (length == 0) {
+        zend_argument_value_error(1, "must not be empty");
+        RETURN_THROWS();
+    }
+
+    long *ratios = safe_emalloc(length, sizeof(long), 0);
+
+    if (rust_math_copy_long_array(ratios_ht, ratios, 1)) {
+        rust_decimal_return_allocation(return_value, self, ratios, length);
+    }
+
+    efree(ratios);
+}
+
+/* RustMath\Decimal::split(int $parts): array - 平均分成 $parts 份，余下的最小单位分给靠前的几份 */
+PHP_METHOD(RustMath_Decimal, split)
+{
+    zend_long parts;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(parts)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (parts < 1 || parts > HT_MAX_SIZE) {
+        zend_argument_value_error(1, "must be greater than 0");
+        RETURN_THROWS();
+    }
+
+    long *ratios = safe_emalloc(parts, sizeof(long), 0);
+    for (zend_long i = 0; i < parts; i++) {
+        ratios[i] = 1;
+    }
+
+    rust_decimal_return_allocation(return_value, self, ratios, (uint32_t) parts);
+    efree(ratios);
+}
+
+/* RustMath\Decimal::compare(Decimal|int|string $other): int - 按数值比较，返回 -1、0 或 1 */
+PHP_METHOD(RustMath_Decimal, compare)
+{
+    zval *other;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_decimal *operand = rust_decimal_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    int result = rust_decimal_cmp(self, operand);
+    rust_decimal_release(operand, owned);
+
+    RETURN_LONG(result);
+}
+
+/* RustMath\Decimal::equals(Decimal|int|string $other): bool - 按数值比较，不考虑小数位数 */
+PHP_METHOD(RustMath_Decimal, equals)
+{
+    zval *other;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_decimal *operand = rust_decimal_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    int result = rust_decimal_cmp(self, operand);
+    rust_decimal_release(operand, owned);
+
+    RETURN_BOOL(result == 0);
+}
+
+/* RustMath\Decimal::sign(): int - 返回 -1、0 或 1 */
+PHP_METHOD(RustMath_Decimal, sign)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_decimal_sign(self));
+}
+
+/* RustMath\Decimal::scale(): int - 小数位数 */
+PHP_METHOD(RustMath_Decimal, scale)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_decimal_scale(self));
+}
+
+/* RustMath\Decimal::toFloat(): float */
+PHP_METHOD(RustMath_Decimal, toFloat)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(rust_decimal_to_double(self));
+}
+
+/* RustMath\Decimal::__toString(): string - 保留全部小数位数，例如 "-0.050" */
+PHP_METHOD(RustMath_Decimal, __toString)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_decimal *self = rust_decimal_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_decimal_return_string(return_value, self);
+}
+
+/* 参数信息 */
+ZEND_BEGIN_ARG_INFO_EX(arginfo_class_RustMath_Decimal___construct, 0, 0, 0)
+    ZEND_ARG_TYPE_MASK(0, value, MAY_BE_LONG|MAY_BE_STRING, "0")
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, scale, IS_LONG, 1, "null")
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, roundingMode, IS_LONG, 0, "RustMath\\Decimal::ROUND_HALF_EVEN")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Decimal_fromFloat, 0, 2, RustMath\\Decimal, 0)
+    ZEND_ARG_TYPE_INFO(0, value, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO(0, scale, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, roundingMode, IS_LONG, 0, "RustMath\\Decimal::ROUND_HALF_EVEN")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Decimal_add, 0, 1, RustMath\\Decimal, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\Decimal, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Decimal_sub arginfo_class_RustMath_Decimal_add
+#define arginfo_class_RustMath_Decimal_mul arginfo_class_RustMath_Decimal_add
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Decimal_div, 0, 2, RustMath\\Decimal, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, divisor, RustMath\\Decimal, MAY_BE_LONG|MAY_BE_STRING, NULL)
+    ZEND_ARG_TYPE_INFO(0, scale, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, roundingMode, IS_LONG, 0, "RustMath\\Decimal::ROUND_HALF_EVEN")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Decimal_round, 0, 1, RustMath\\Decimal, 0)
+    ZEND_ARG_TYPE_INFO(0, scale, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, roundingMode, IS_LONG, 0, "RustMath\\Decimal::ROUND_HALF_EVEN")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Decimal_neg, 0, 0, RustMath\\Decimal, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Decimal_abs arginfo_class_RustMath_Decimal_neg
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Decimal_allocate, 0, 1, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, ratios, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Decimal_split, 0, 1, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, parts, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Decimal_compare, 0, 1, IS_LONG, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\Decimal, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Decimal_equals, 0, 1, _IS_BOOL, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\Decimal, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Decimal_sign, 0, 0, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Decimal_scale arginfo_class_RustMath_Decimal_sign
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Decimal_toFloat, 0, 0, IS_DOUBLE, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Decimal___toString, 0, 0, IS_STRING, 0)
+ZEND_END_ARG_INFO()
+
+/* 方法列表 */
+static const zend_function_entry rust_decimal_methods[] = {
+    PHP_ME(RustMath_Decimal, __construct, arginfo_class_RustMath_Decimal___construct, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, fromFloat, arginfo_class_RustMath_Decimal_fromFloat, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Decimal, add, arginfo_class_RustMath_Decimal_add, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, sub, arginfo_class_RustMath_Decimal_sub, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, mul, arginfo_class_RustMath_Decimal_mul, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, div, arginfo_class_RustMath_Decimal_div, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, round, arginfo_class_RustMath_Decimal_round, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, neg, arginfo_class_RustMath_Decimal_neg, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, abs, arginfo_class_RustMath_Decimal_abs, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, allocate, arginfo_class_RustMath_Decimal_allocate, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, split, arginfo_class_RustMath_Decimal_split, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, compare, arginfo_class_RustMath_Decimal_compare, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, equals, arginfo_class_RustMath_Decimal_equals, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, sign, arginfo_class_RustMath_Decimal_sign, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, scale, arginfo_class_RustMath_Decimal_scale, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, toFloat, arginfo_class_RustMath_Decimal_toFloat, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Decimal, __toString, arginfo_class_RustMath_Decimal___toString, ZEND_ACC_PUBLIC)
+    PHP_FE_END
+};
+
+/* 注册舍入模式类常量 */
+static void rust_decimal_register_constant(const char *name, rounding_mode mode)
+{
+    zend_declare_class_constant_long(rust_decimal_ce, name, strlen(name), (zend_long) mode);
+}
+
+/* 注册 RustMath\Decimal 类，在 MINIT 中调用 */
+void rust_math_register_decimal_class(void)
+{
+    zend_class_entry ce;
+
+    INIT_NS_CLASS_ENTRY(ce, "RustMath", "Decimal", rust_decimal_methods);
+    rust_decimal_ce = zend_register_internal_class(&ce);
+    rust_decimal_ce->ce_flags |= ZEND_ACC_FINAL;
+    rust_decimal_ce->create_object = rust_decimal_create_object;
+    zend_class_implements(rust_decimal_ce, 1, zend_ce_stringable);
+
+    rust_decimal_register_constant("ROUND_UP", ROUNDING_MODE_UP);
+    rust_decimal_register_constant("ROUND_DOWN", ROUNDING_MODE_DOWN);
+    rust_decimal_register_constant("ROUND_CEILING", ROUNDING_MODE_CEILING);
+    rust_decimal_register_constant("ROUND_FLOOR", ROUNDING_MODE_FLOOR);
+    rust_decimal_register_constant("ROUND_HALF_UP", ROUNDING_MODE_HALF_UP);
+    rust_decimal_register_constant("ROUND_HALF_DOWN", ROUNDING_MODE_HALF_DOWN);
+    rust_decimal_register_constant("ROUND_HALF_EVEN", ROUNDING_MODE_HALF_EVEN);
+
+    memcpy(&rust_decimal_handlers, &std_object_handlers, sizeof(zend_object_handlers));
+    rust_decimal_handlers.offset = XtOffsetOf(php_rust_decimal, std);
+    rust_decimal_handlers.free_obj = rust_decimal_free_obj;
+    rust_decimal_handlers.clone_obj = rust_decimal_clone_obj;
+    rust_decimal_handlers.do_operation = rust_decimal_do_operation;
+    rust_decimal_handlers.compare = rust_decimal_compare_objects;
+    rust_decimal_handlers.cast_object = rust_decimal_cast_object;
+}
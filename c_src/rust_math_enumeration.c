@@ -0,0 +1,541 @@
+/**
+ * PHP Rust 数学扩展 - RustMath\Enumeration 类
+ *
+ * 这个文件把 Rust 的组合枚举句柄包装成实现 Iterator 和 Countable 的 PHP 对象
+ * Rust 只按字典序生成元素的位置，这里再用位置从 PHP 数组中取出实际的元素；
+ * 输入数组的键会被忽略，元素按数组中的顺序编号为 0, 1, 2, ...
+ */
+
+#include "php.h"
+#include "zend_exceptions.h"
+#include "zend_interfaces.h"
+#include "php_rust_math.h"
+
+/* 类入口和对象处理器 */
+zend_class_entry *rust_enumeration_ce;
+static zend_object_handlers rust_enumeration_handlers;
+
+/* PHP 对象结构：Rust 句柄 + 元素数组 + 标准 zend_object（必须放在最后） */
+typedef struct {
+    rust_enumeration *handle;
+    /* 重新编号后的元素；笛卡尔积时是由每个集合组成的数组 */
+    zval items;
+    bool is_product;
+    zend_object std;
+} php_rust_enumeration;
+
+static inline php_rust_enumeration *php_rust_enumeration_from_obj(zend_object *obj)
+{
+    return (php_rust_enumeration *)((char *)obj - XtOffsetOf(php_rust_enumeration, std));
+}
+
+#define Z_RUST_ENUMERATION_P(zv) php_rust_enumeration_from_obj(Z_OBJ_P(zv))
+
+/* 创建对象：句柄和元素在静态工厂方法中设置 */
+static zend_object *rust_enumeration_create_object(zend_class_entry *ce)
+{
+    php_rust_enumeration *intern = zend_object_alloc(sizeof(php_rust_enumeration), ce);
+
+    zend_object_std_init(&intern->std, ce);
+    object_properties_init(&intern->std, ce);
+    intern->std.handlers = &rust_enumeration_handlers;
+    intern->handle = NULL;
+    ZVAL_UNDEF(&intern->items);
+    intern->is_product = false;
+
+    return &intern->std;
+}
+
+/* 销毁对象：释放 Rust 句柄和元素数组 */
+static void rust_enumeration_free_obj(zend_object *object)
+{
+    php_rust_enumeration *intern = php_rust_enumeration_from_obj(object);
+
+    if (intern->handle != NULL) {
+        rust_enumeration_free(intern->handle);
+        intern->handle = NULL;
+    }
+    zval_ptr_dtor(&intern->items);
+
+    zend_object_std_dtor(&intern->std);
+}
+
+/* 克隆对象：复制 Rust 句柄，副本从同一个排列开始独立前进，元素数组共享 */
+static zend_object *rust_enumeration_clone_obj(zend_object *old_object)
+{
+    php_rust_enumeration *old_intern = php_rust_enumeration_from_obj(old_object);
+    zend_object *new_object = rust_enumeration_create_object(old_object->ce);
+    php_rust_enumeration *new_intern = php_rust_enumeration_from_obj(new_object);
+
+    zend_objects_clone_members(new_object, old_object);
+    rust_enumeration_clone(old_intern->handle, &new_intern->handle);
+    ZVAL_COPY(&new_intern->items, &old_intern->items);
+    new_intern->is_product = old_intern->is_product;
+
+    return new_object;
+}
+
+/* 垃圾回收：元素中可能含有引用了这个对象的值 */
+static HashTable *rust_enumeration_get_gc(zend_object *object, zval **table, int *n)
+{
+    php_rust_enumeration *intern = php_rust_enumeration_from_obj(object);
+
+    *table = &intern->items;
+    *n = Z_ISUNDEF(intern->items) ? 0 : 1;
+
+    return zend_std_get_properties(object);
+}
+
+/* 获取当前对象，未初始化时抛出异常 */
+static php_rust_enumeration *rust_enumeration_this(zval *object)
+{
+    php_rust_enumeration *intern = Z_RUST_ENUMERATION_P(object);
+
+    if (intern->handle == NULL) {
+        zend_throw_error(NULL, "RustMath\\Enumeration object is not initialized");
+        return NULL;
+    }
+
+    return intern;
+}
+
+/* 把 PHP 数组的值按顺序复制为从 0 开始编号的新数组 */
+static void rust_enumeration_copy_values(zval *target, HashTable *source)
+{
+    zval *value;
+
+    array_init_size(target, zend_hash_num_elements(source));
+    ZEND_HASH_FOREACH_VAL(source, value) {
+        Z_TRY_ADDREF_P(value);
+        add_next_index_zval(target, value);
+    } ZEND_HASH_FOREACH_END();
+}
+
+/* 根据 Rust 返回的错误码返回新的 Enumeration 对象或抛出异常，句柄和元素的所有权转移给对象 */
+static void rust_enumeration_return_result(zval *return_value, math_error error, rust_enumeration *handle, zval *items, bool is_product)
+{
+    if (error != MATH_ERROR_SUCCESS) {
+        zval_ptr_dtor(items);
+        rust_math_throw_error(error);
+        return;
+    }
+
+    object_init_ex(return_value, rust_enumeration_ce);
+    php_rust_enumeration *intern = Z_RUST_ENUMERATION_P(return_value);
+    intern->handle = handle;
+    ZVAL_COPY_VALUE(&intern->items, items);
+    intern->is_product = is_product;
+}
+
+/* 把 Rust 返回的位置数组映射为元素数组，然后释放 Rust 分配的内存 */
+static void rust_enumeration_return_items(zval *return_value, php_rust_enumeration *intern, rust_integer_array_result result)
+{
+    array_init_size(return_value, (uint32_t) result.length);
+
+    for (long i = 0; i < result.length; i++) {
+        HashTable *set = Z_ARRVAL(intern->items);
+        if (intern->is_product) {
+            set = Z_ARRVAL_P(zend_hash_index_find(set, i));
+        }
+
+        zval *value = zend_hash_index_find(set, result.numbers[i]);
+        Z_TRY_ADDREF_P(value);
+        add_next_index_zval(return_value, value);
+    }
+
+    rust_free_integer_array_result(result);
+}
+
+/* 从元素中选出 k 个的工厂方法的公共实现 */
+static void rust_enumeration_factory(INTERNAL_FUNCTION_PARAMETERS, math_error (*create)(long, long, rust_enumeration **))
+{
+    HashTable *items_ht;
+    zend_long k;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_ARRAY_HT(items_ht)
+        Z_PARAM_LONG(k)
+    ZEND_PARSE_PARAMETERS_END();
+
+    if (k < 0) {
+        zend_argument_value_error(2, "must be greater than or equal to 0");
+        RETURN_THROWS();
+    }
+
+    zval items;
+    rust_enumeration_copy_values(&items, items_ht);
+
+    /* k 过大时返回错误码 */
+    rust_enumeration *handle = NULL;
+    math_error error = create(zend_hash_num_elements(items_ht), k, &handle);
+    rust_enumeration_return_result(return_value, error, handle, &items, false);
+}
+
+/* RustMath\Enumeration::__construct() - 私有，只能通过静态工厂方法创建 */
+PHP_METHOD(RustMath_Enumeration, __construct)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+}
+
+/* RustMath\Enumeration::combinations(array $items, int $k): Enumeration - 不重复的 k 元子集 */
+PHP_METHOD(RustMath_Enumeration, combinations)
+{
+    rust_enumeration_factory(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_enumeration_combinations);
+}
+
+/* RustMath\Enumeration::combinationsWithReplacement(array $items, int $k): Enumeration */
+PHP_METHOD(RustMath_Enumeration, combinationsWithReplacement)
+{
+    rust_enumeration_factory(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_enumeration_combinations_with_replacement);
+}
+
+/* RustMath\Enumeration::permutations(array $items, ?int $k = null): Enumeration - $k 为 null 时是全排列 */
+PHP_METHOD(RustMath_Enumeration, permutations)
+{
+    HashTable *items_ht;
+    zend_long k = 0;
+    bool k_is_null = true;
+
+    ZEND_PARSE_PARAMETERS_START(1, 2)
+        Z_PARAM_ARRAY_HT(items_ht)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_LONG_OR_NULL(k, k_is_null)
+    ZEND_PARSE_PARAMETERS_END();
+
+    long n = zend_hash_num_elements(items_ht);
+    if (k_is_null) {
+        k = n;
+    } else if (k < 0) {
+        zend_argument_value_error(2, "must be greater than or equal to 0");
+        RETURN_THROWS();
+    }
+
+    zval items;
+    rust_enumeration_copy_values(&items, items_ht);
+
+    /* 元素或 k 过多时返回错误码 */
+    rust_enumeration *handle = NULL;
+    math_error error = rust_enumeration_permutations(n, k, &handle);
+    rust_enumeration_return_result(return_value, error, handle, &items, false);
+}
+
+/* RustMath\Enumeration::permutationsWithReplacement(array $items, int $k): Enumeration - 元素的 k 元组 */
+PHP_METHOD(RustMath_Enumeration, permutationsWithReplacement)
+{
+    rust_enumeration_factory(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_enumeration_permutations_with_replacement);
+}
+
+/* RustMath\Enumeration::product(array ...$sets): Enumeration - 笛卡尔积，最后一个集合变化最快 */
+PHP_METHOD(RustMath_Enumeration, product)
+{
+    zval *sets = NULL;
+    uint32_t count = 0;
+
+    ZEND_PARSE_PARAMETERS_START(0, -1)
+        Z_PARAM_VARIADIC('*', sets, count)
+    ZEND_PARSE_PARAMETERS_END();
+
+    for (uint32_t i = 0; i < count; i++) {
+        if (Z_TYPE(sets[i]) != IS_ARRAY) {
+            zend_argument_type_error(i + 1, "must be of type array, %s given", zend_zval_type_name(&sets[i]));
+            RETURN_THROWS();
+        }
+    }
+
+    zval items;
+    long *sizes = safe_emalloc(count, sizeof(long), 0);
+
+    array_init_size(&items, count);
+    for (uint32_t i = 0; i < count; i++) {
+        zval set;
+        rust_enumeration_copy_values(&set, Z_ARRVAL(sets[i]));
+        add_next_index_zval(&items, &set);
+        sizes[i] = zend_hash_num_elements(Z_ARRVAL(sets[i]));
+    }
+
+    /* 集合过多时返回错误码 */
+    rust_enumeration *handle = NULL;
+    math_error error = rust_enumeration_product(sizes, count, &handle);
+    rust_enumeration_return_result(return_value, error, handle, &items, true);
+
+    efree(sizes);
+}
+
+/* RustMath\Enumeration::current(): ?array - 当前的组合或排列，全部生成完后返回 null */
+PHP_METHOD(RustMath_Enumeration, current)
+{
+    rust_integer_array_result result;
+
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (rust_enumeration_current(self->handle, &result) != MATH_ERROR_SUCCESS) {
+        RETURN_NULL();
+    }
+
+    rust_enumeration_return_items(return_value, self, result);
+}
+
+/* RustMath\Enumeration::positions(): ?array - 当前排列中元素的位置，全部生成完后返回 null */
+PHP_METHOD(RustMath_Enumeration, positions)
+{
+    rust_integer_array_result result;
+
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (rust_enumeration_current(self->handle, &result) != MATH_ERROR_SUCCESS) {
+        RETURN_NULL();
+    }
+
+    rust_math_return_integer_array(return_value, result);
+}
+
+/* RustMath\Enumeration::key(): int - 当前排列的下标，从 0 开始 */
+PHP_METHOD(RustMath_Enumeration, key)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_enumeration_key(self->handle));
+}
+
+/* RustMath\Enumeration::next(): void */
+PHP_METHOD(RustMath_Enumeration, next)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_enumeration_next(self->handle);
+}
+
+/* RustMath\Enumeration::rewind(): void - 回到第 0 个排列 */
+PHP_METHOD(RustMath_Enumeration, rewind)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_enumeration_rewind(self->handle);
+}
+
+/* RustMath\Enumeration::valid(): bool - 全部生成完后返回 false */
+PHP_METHOD(RustMath_Enumeration, valid)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_BOOL(rust_enumeration_valid(self->handle));
+}
+
+/* RustMath\Enumeration::count(): int - 排列总数，超出 int 范围时抛出异常 */
+PHP_METHOD(RustMath_Enumeration, count)
+{
+    long result;
+
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    math_error error = rust_enumeration_count(self->handle, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* RustMath\Enumeration::rank(array $positions): int - 由元素位置组成的排列在字典序中的下标 */
+PHP_METHOD(RustMath_Enumeration, rank)
+{
+    HashTable *positions_ht;
+    long result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ARRAY_HT(positions_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    uint32_t length = zend_hash_num_elements(positions_ht);
+    long *positions = safe_emalloc(length, sizeof(long), 0);
+
+    if (rust_math_copy_long_array(positions_ht, positions, 1)) {
+        /* 位置不是这个枚举会生成的排列时返回错误码 */
+        math_error error = rust_enumeration_rank(self->handle, positions, length, &result);
+        if (error != MATH_ERROR_SUCCESS) {
+            rust_math_throw_error(error);
+        } else {
+            RETVAL_LONG(result);
+        }
+    }
+
+    efree(positions);
+}
+
+/* RustMath\Enumeration::unrank(int $index): array - 字典序中第 $index 个排列，不移动当前排列 */
+PHP_METHOD(RustMath_Enumeration, unrank)
+{
+    zend_long index;
+    rust_integer_array_result result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(index)
+    ZEND_PARSE_PARAMETERS_END();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    /* index 超出范围时返回错误码 */
+    math_error error = rust_enumeration_unrank(self->handle, index, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    rust_enumeration_return_items(return_value, self, result);
+}
+
+/* RustMath\Enumeration::seek(int $index): void - 移动到字典序中的第 $index 个排列 */
+PHP_METHOD(RustMath_Enumeration, seek)
+{
+    zend_long index;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(index)
+    ZEND_PARSE_PARAMETERS_END();
+
+    php_rust_enumeration *self = rust_enumeration_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    /* 出错时当前排列不变 */
+    math_error error = rust_enumeration_seek(self->handle, index);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+}
+
+/* 参数信息 */
+ZEND_BEGIN_ARG_INFO_EX(arginfo_class_RustMath_Enumeration___construct, 0, 0, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Enumeration_combinations, 0, 2, RustMath\\Enumeration, 0)
+    ZEND_ARG_TYPE_INFO(0, items, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, k, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Enumeration_combinationsWithReplacement arginfo_class_RustMath_Enumeration_combinations
+#define arginfo_class_RustMath_Enumeration_permutationsWithReplacement arginfo_class_RustMath_Enumeration_combinations
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Enumeration_permutations, 0, 1, RustMath\\Enumeration, 0)
+    ZEND_ARG_TYPE_INFO(0, items, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, k, IS_LONG, 1, "null")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Enumeration_product, 0, 0, RustMath\\Enumeration, 0)
+    ZEND_ARG_VARIADIC_TYPE_INFO(0, sets, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Enumeration_current, 0, 0, IS_ARRAY, 1)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Enumeration_positions arginfo_class_RustMath_Enumeration_current
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Enumeration_key, 0, 0, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Enumeration_count arginfo_class_RustMath_Enumeration_key
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Enumeration_next, 0, 0, IS_VOID, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Enumeration_rewind arginfo_class_RustMath_Enumeration_next
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Enumeration_valid, 0, 0, _IS_BOOL, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Enumeration_rank, 0, 1, IS_LONG, 0)
+    ZEND_ARG_TYPE_INFO(0, positions, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Enumeration_unrank, 0, 1, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, index, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Enumeration_seek, 0, 1, IS_VOID, 0)
+    ZEND_ARG_TYPE_INFO(0, index, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+/* 方法列表 */
+static const zend_function_entry rust_enumeration_methods[] = {
+    PHP_ME(RustMath_Enumeration, __construct, arginfo_class_RustMath_Enumeration___construct, ZEND_ACC_PRIVATE)
+    PHP_ME(RustMath_Enumeration, combinations, arginfo_class_RustMath_Enumeration_combinations, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Enumeration, combinationsWithReplacement, arginfo_class_RustMath_Enumeration_combinationsWithReplacement, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Enumeration, permutations, arginfo_class_RustMath_Enumeration_permutations, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Enumeration, permutationsWithReplacement, arginfo_class_RustMath_Enumeration_permutationsWithReplacement, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Enumeration, product, arginfo_class_RustMath_Enumeration_product, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Enumeration, current, arginfo_class_RustMath_Enumeration_current, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Enumeration, positions, arginfo_class_RustMath_Enumeration_positions, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Enumeration, key, arginfo_class_RustMath_Enumeration_key, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Enumeration, next, arginfo_class_RustMath_Enumeration_next, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Enumeration, rewind, arginfo_class_RustMath_Enumeration_rewind, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Enumeration, valid, arginfo_class_RustMath_Enumeration_valid, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Enumeration, count, arginfo_class_RustMath_Enumeration_count, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Enumeration, rank, arginfo_class_RustMath_Enumeration_rank, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Enumeration, unrank, arginfo_class_RustMath_Enumeration_unrank, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Enumeration, seek, arginfo_class_RustMath_Enumeration_seek, ZEND_ACC_PUBLIC)
+    PHP_FE_END
+};
+
+/* 注册 RustMath\Enumeration 类，在 MINIT 中调用 */
+void rust_math_register_enumeration_class(void)
+{
+    zend_class_entry ce;
+
+    INIT_NS_CLASS_ENTRY(ce, "RustMath", "Enumeration", rust_enumeration_methods);
+    rust_enumeration_ce = zend_register_internal_class(&ce);
+    rust_enumeration_ce->ce_flags |= ZEND_ACC_FINAL;
+    rust_enumeration_ce->create_object = rust_enumeration_create_object;
+    zend_class_implements(rust_enumeration_ce, 2, zend_ce_iterator, zend_ce_countable);
+
+    memcpy(&rust_enumeration_handlers, &std_object_handlers, sizeof(zend_object_handlers));
+    rust_enumeration_handlers.offset = XtOffsetOf(php_rust_enumeration, std);
+    rust_enumeration_handlers.free_obj = rust_enumeration_free_obj;
+    rust_enumeration_handlers.clone_obj = rust_enumeration_clone_obj;
+    rust_enumeration_handlers.get_gc = rust_enumeration_get_gc;
+}
@@ -0,0 +1,111 @@
+/**
+ * PHP Rust 数学扩展 - 异常类
+ *
+ * Rust 函数返回的错误码在这里统一转换为 PHP 异常：
+ *
+ * - RustMath\MathException          接口，扩展抛出的所有异常都实现它
+ * - RustMath\OverflowError          继承 ArithmeticError，整数运算溢出
+ * - RustMath\NegativeNumberError    继承 ValueError，参数不能为负数
+ * - RustMath\InvalidParameterError  继承 ValueError，参数无效
+ * - RustMath\DivisionByZeroError    继承 DivisionByZeroError，除数为零
+ * - RustMath\DomainError            继承 ValueError，参数超出函数的定义域
+ * - RustMath\NotANumberError        继承 ArithmeticError，参数或结果为 NaN
+ *
+ * 异常的 code 是 Rust 中 MathError 的取值，MathException 上的常量（例如
+ * MathException::OVERFLOW）给出了每个取值的符号名称；message 使用
+ * rust_math.language 设置的语言
+ * 继承 PHP 内置的异常类，所以原来捕获 ArithmeticError / ValueError 的代码仍然有效
+ */
+
+#include "php.h"
+#include "zend_exceptions.h"
+#include "php_rust_math.h"
+
+/* 类入口 */
+zend_class_entry *rust_math_exception_ce;
+zend_class_entry *rust_math_overflow_error_ce;
+zend_class_entry *rust_math_negative_number_error_ce;
+zend_class_entry *rust_math_invalid_parameter_error_ce;
+zend_class_entry *rust_math_division_by_zero_error_ce;
+zend_class_entry *rust_math_domain_error_ce;
+zend_class_entry *rust_math_not_a_number_error_ce;
+
+/* 在 MathException 接口上声明错误码常量，名称与 rust_math_error_name 一致 */
+static void rust_math_declare_error_constant(math_error error)
+{
+    const char *name = rust_math_error_name(error);
+
+    zend_declare_class_constant_long(rust_math_exception_ce, name, strlen(name), (zend_long) error);
+}
+
+/* 注册一个实现 RustMath\MathException 的异常类 */
+static zend_class_entry *rust_math_register_error_class(const char *name, zend_class_entry *parent)
+{
+    zend_class_entry ce;
+    zend_class_entry *class_entry;
+
+    INIT_CLASS_ENTRY_EX(ce, name, strlen(name), NULL);
+    class_entry = zend_register_internal_class_ex(&ce, parent);
+    zend_class_implements(class_entry, 1, rust_math_exception_ce);
+
+    return class_entry;
+}
+
+/* 注册异常类，在 MINIT 中调用 */
+void rust_math_register_exception_classes(void)
+{
+    zend_class_entry ce;
+
+    INIT_NS_CLASS_ENTRY(ce, "RustMath", "MathException", NULL);
+    rust_math_exception_ce = zend_register_internal_interface(&ce);
+    zend_class_implements(rust_math_exception_ce, 1, zend_ce_throwable);
+
+    rust_math_declare_error_constant(MATH_ERROR_NEGATIVE_NUMBER);
+    rust_math_declare_error_constant(MATH_ERROR_OVERFLOW);
+    rust_math_declare_error_constant(MATH_ERROR_INVALID_PARAMETER);
+    rust_math_declare_error_constant(MATH_ERROR_DIVISION_BY_ZERO);
+    rust_math_declare_error_constant(MATH_ERROR_DOMAIN_ERROR);
+    rust_math_declare_error_constant(MATH_ERROR_NOT_A_NUMBER);
+
+    rust_math_overflow_error_ce = rust_math_register_error_class(
+        "RustMath\\OverflowError", zend_ce_arithmetic_error);
+    rust_math_negative_number_error_ce = rust_math_register_error_class(
+        "RustMath\\NegativeNumberError", zend_ce_value_error);
+    rust_math_invalid_parameter_error_ce = rust_math_register_error_class(
+        "RustMath\\InvalidParameterError", zend_ce_value_error);
+    rust_math_division_by_zero_error_ce = rust_math_register_error_class(
+        "RustMath\\DivisionByZeroError", zend_ce_division_by_zero_error);
+    rust_math_domain_error_ce = rust_math_register_error_class(
+        "RustMath\\DomainError", zend_ce_value_error);
+    rust_math_not_a_number_error_ce = rust_math_register_error_class(
+        "RustMath\\NotANumberError", zend_ce_arithmetic_error);
+}
+
+/* 把 Rust 返回的错误码转换为 PHP 异常 */
+void rust_math_throw_error(math_error error)
+{
+    zend_class_entry *ce;
+
+    switch (error) {
+        case MATH_ERROR_OVERFLOW:
+            ce = rust_math_overflow_error_ce;
+            break;
+        case MATH_ERROR_NEGATIVE_NUMBER:
+            ce = rust_math_negative_number_error_ce;
+            break;
+        case MATH_ERROR_DIVISION_BY_ZERO:
+            ce = rust_math_division_by_zero_error_ce;
+            break;
+        case MATH_ERROR_DOMAIN_ERROR:
+            ce = rust_math_domain_error_ce;
+            break;
+        case MATH_ERROR_NOT_A_NUMBER:
+            ce = rust_math_not_a_number_error_ce;
+            break;
+        default:
+            ce = rust_math_invalid_parameter_error_ce;
+            break;
+    }
+
+    zend_throw_exception(ce, rust_math_error_message(error), (zend_long) error);
+}
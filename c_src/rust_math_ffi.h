@@ -0,0 +1,1647 @@
+/**
+ * PHP Rust 数学扩展 - Rust 函数的 C 声明
+ *
+ * 由 build.rs 根据 src/ 中的 Rust 代码自动生成，请勿手动修改
+ */
+
+#ifndef RUST_MATH_FFI_H
+#define RUST_MATH_FFI_H
+
+#include <stdbool.h>
+
+/**
+ * 数学运算错误码
+ *
+ * 用于表示数学运算中的各种错误情况
+ * 每个错误码都有一个稳定的符号名称（见 `name`），可以在日志和文档中引用
+ */
+typedef enum {
+    MATH_ERROR_SUCCESS = 0,                 /* 成功 */
+    MATH_ERROR_NEGATIVE_NUMBER = -1,        /* 负数错误（如负数阶乘） */
+    MATH_ERROR_OVERFLOW = -2,               /* 溢出错误（如阶乘结果过大） */
+    MATH_ERROR_INVALID_PARAMETER = -3,      /* 无效参数错误 */
+    MATH_ERROR_DIVISION_BY_ZERO = -4,       /* 除数为零 */
+    MATH_ERROR_DOMAIN_ERROR = -5,           /* 参数超出函数的定义域（如 asin(2.0)） */
+    MATH_ERROR_NOT_A_NUMBER = -6            /* 参数或结果不是数字（NaN） */
+} math_error;
+
+/**
+ * 浮点数运算的错误处理模式
+ *
+ * 决定浮点函数遇到除数为零、参数超出定义域或 NaN 时的行为
+ */
+typedef enum {
+    FLOAT_MODE_STRICT = 0,                  /* 严格：返回 DivisionByZero、DomainError 或 NotANumber 错误码（默认） */
+    FLOAT_MODE_IEEE = 1                     /* IEEE 754：按标准返回 inf、-inf 或 NaN，不报告错误 */
+} float_mode;
+
+/**
+ * 错误描述使用的语言
+ */
+typedef enum {
+    MESSAGE_LANGUAGE_ENGLISH = 0,           /* 英文（默认） */
+    MESSAGE_LANGUAGE_CHINESE = 1            /* 中文 */
+} message_language;
+
+/**
+ * 整数溢出处理模式
+ *
+ * 决定整数运算结果超出 c_long 范围时的行为
+ */
+typedef enum {
+    OVERFLOW_MODE_SATURATING = 0,           /* 饱和：结果截断到 c_long::MAX 或 c_long::MIN（默认，与旧版本行为一致） */
+    OVERFLOW_MODE_WRAPPING = 1,             /* 回绕：按二进制补码回绕，与 C 语言的无符号运算类似 */
+    OVERFLOW_MODE_CHECKED = 2               /* 检查：溢出时返回 MathError::Overflow */
+} overflow_mode;
+
+/**
+ * 定点小数的舍入模式
+ *
+ * 决定 `Decimal` 减少小数位数时如何处理被舍去的部分
+ */
+typedef enum {
+    ROUNDING_MODE_UP = 0,                   /* 远离零：只要舍去的部分不为 0 就进位 */
+    ROUNDING_MODE_DOWN = 1,                 /* 趋向零：直接截断 */
+    ROUNDING_MODE_CEILING = 2,              /* 向正无穷 */
+    ROUNDING_MODE_FLOOR = 3,                /* 向负无穷 */
+    ROUNDING_MODE_HALF_UP = 4,              /* 四舍五入：恰好一半时远离零 */
+    ROUNDING_MODE_HALF_DOWN = 5,            /* 五舍六入：恰好一半时趋向零 */
+    ROUNDING_MODE_HALF_EVEN = 6             /* 银行家舍入：恰好一半时舍入到偶数（默认） */
+} rounding_mode;
+
+/**
+ * 斐波那契数列结果结构体
+ *
+ * 这个结构体用于在 Rust 和 C 之间传递斐波那契数列结果
+ * #[repr(C)] 确保内存布局与 C 代码兼容
+ */
+typedef struct {
+    long* numbers;                          /* 斐波那契数列数组的指针 */
+    int length;                             /* 数组的长度 */
+} rust_fibonacci_result;
+
+/**
+ * 字符串结果结构体
+ *
+ * 用于把 Rust 生成的字符串（例如大整数的十进制表示）传递给 C 代码
+ * 字符串以 NUL 结尾，length 不包含结尾的 NUL
+ */
+typedef struct {
+    char* data;                             /* 字符串数据的指针，出错时为空指针 */
+    long length;                            /* 字符串的长度（字节数） */
+} rust_string_result;
+
+/**
+ * 整数数组结果结构体
+ *
+ * 用于把 Rust 生成的整数列表（例如一个数的全部因数）传递给 C 代码
+ */
+typedef struct {
+    long* numbers;                          /* 数组的指针，出错或数组为空时为空指针 */
+    long length;                            /* 数组的长度 */
+} rust_integer_array_result;
+
+/**
+ * 质因数分解结果结构体
+ *
+ * primes 和 exponents 是长度相同的两个数组，primes[i] 的指数为 exponents[i]
+ */
+typedef struct {
+    long* primes;                           /* 质因数数组的指针，按从小到大排列 */
+    int* exponents;                         /* 指数数组的指针 */
+    int length;                             /* 两个数组的长度 */
+} rust_factorization_result;
+
+/**
+ * 扩展欧几里得算法结果结构体
+ *
+ * 满足 a · x + b · y = gcd
+ */
+typedef struct {
+    long gcd;                               /* 非负的最大公约数 */
+    long x;                                 /* a 的 Bézout 系数 */
+    long y;                                 /* b 的 Bézout 系数 */
+} rust_extended_gcd_result;
+
+/**
+ * 中国剩余定理结果结构体
+ *
+ * 同余方程组的全部解为 remainder + k · modulus
+ */
+typedef struct {
+    long remainder;                         /* 最小的非负解 */
+    long modulus;                           /* 所有模数的最小公倍数 */
+} rust_crt_result;
+
+/**
+ * 分数结果结构体
+ *
+ * 表示 numerator / denominator，总是约分到最简形式，分母大于 0
+ */
+typedef struct {
+    long numerator;                         /* 分子，带有分数的符号 */
+    long denominator;                       /* 分母，大于 0 */
+} rust_fraction;
+
+/**
+ * 复数结构体
+ *
+ * 表示 re + im·i，按值在 Rust 和 C 之间传递；运算和初等函数见 `math::complex` 和 `math::advanced`
+ */
+typedef struct {
+    double re;                              /* 实部 */
+    double im;                              /* 虚部 */
+} rust_complex;
+
+/* 不透明句柄，内部结构只有 Rust 知道 */
+typedef struct rust_bigint rust_bigint;
+typedef struct rust_decimal rust_decimal;
+typedef struct rust_enumeration rust_enumeration;
+typedef struct rust_rational rust_rational;
+typedef struct rust_sequence rust_sequence;
+
+/* ========================================================================
+ * src/lib.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：设置当前线程的整数溢出处理模式
+ *
+ * # 参数
+ * * `mode` - 0 饱和，1 回绕，2 检查
+ *
+ * # 返回值
+ * 模式代码有效返回 true，否则返回 false 并保持原设置
+ */
+bool rust_set_overflow_mode(int mode);
+
+/**
+ * 导出函数：获取当前线程的整数溢出处理模式
+ */
+int rust_get_overflow_mode(void);
+
+/**
+ * 导出函数：设置当前线程的浮点数错误处理模式
+ *
+ * # 参数
+ * * `mode` - 0 严格，1 IEEE 754
+ *
+ * # 返回值
+ * 模式代码有效返回 true，否则返回 false 并保持原设置
+ */
+bool rust_set_float_mode(int mode);
+
+/**
+ * 导出函数：获取当前线程的浮点数错误处理模式
+ */
+int rust_get_float_mode(void);
+
+/**
+ * 导出函数：设置当前线程的错误描述语言
+ *
+ * # 参数
+ * * `language` - 0 英文，1 中文
+ *
+ * # 返回值
+ * 语言代码有效返回 true，否则返回 false 并保持原设置
+ */
+bool rust_set_language(int language);
+
+/**
+ * 导出函数：获取当前线程的错误描述语言
+ */
+int rust_get_language(void);
+
+/**
+ * 导出函数：获取错误码对应的错误信息（当前语言）
+ *
+ * # 参数
+ * * `code` - MathError 的取值
+ *
+ * # 返回值
+ * 以 NUL 结尾的静态字符串，不需要释放；错误码无效时返回 "Unknown error"
+ */
+const char* rust_math_error_message(int code);
+
+/**
+ * 导出函数：获取错误码对应的指定语言的错误信息
+ *
+ * # 参数
+ * * `code` - MathError 的取值
+ * * `language` - 0 英文，1 中文，无效时使用英文
+ *
+ * # 返回值
+ * 以 NUL 结尾的静态字符串，不需要释放
+ */
+const char* rust_math_error_message_in(int code, int language);
+
+/**
+ * 导出函数：获取错误码的符号名称
+ *
+ * # 参数
+ * * `code` - MathError 的取值
+ *
+ * # 返回值
+ * 以 NUL 结尾的静态字符串（如 "OVERFLOW"），不需要释放；错误码无效时返回 "UNKNOWN"
+ */
+const char* rust_math_error_name(int code);
+
+/**
+ * 导出函数：计算两个整数的和
+ *
+ * 这个函数可以从 C 代码调用
+ * #[no_mangle] 告诉 Rust 不要修改函数名
+ * extern "C" 指定使用 C 调用约定
+ *
+ * 结果通过 result 指针返回，返回值是错误码：
+ * 在检查模式下溢出时返回 MathError::Overflow
+ */
+math_error rust_add_impl(long a, long b, long* result);
+
+/**
+ * 导出函数：计算两个数的乘积
+ */
+math_error rust_multiply_impl(double a, double b, double* result);
+
+/**
+ * 导出函数：计算阶乘
+ *
+ * 负数返回 MathError::NegativeNumber，结果超出 long 范围（n > 20）时返回 MathError::Overflow
+ */
+math_error rust_factorial_impl(long n, long* result);
+
+/**
+ * 导出函数：计算任意精度阶乘
+ *
+ * 通过 result 返回阶乘结果的十进制字符串，出错时 data 为空指针
+ * 返回的内存需要调用 rust_free_string_result 释放
+ *
+ * 负数返回 MathError::NegativeNumber，n 超过 FACTORIAL_BIG_LIMIT 时返回 MathError::InvalidParameter
+ */
+math_error rust_factorial_big_impl(long n, rust_string_result* result);
+
+/**
+ * 导出函数：释放字符串结果的内存
+ */
+void rust_free_string_result(rust_string_result result);
+
+/**
+ * 导出函数：生成斐波那契数列
+ *
+ * 通过 result 返回数列，出错时 numbers 为空指针
+ * 返回的内存需要调用 rust_free_fibonacci_result 释放
+ *
+ * 长度为负数返回 MathError::NegativeNumber，超过 100 返回 MathError::InvalidParameter，
+ * 超过 93（第 94 项超出 int 范围）时返回 MathError::Overflow，与溢出模式无关
+ */
+math_error rust_fibonacci_impl(long n, rust_fibonacci_result* result);
+
+/**
+ * 导出函数：释放斐波那契数列结果的内存
+ */
+void rust_free_fibonacci_result(rust_fibonacci_result result);
+
+/**
+ * 导出函数：检查一个数是否为质数
+ */
+math_error rust_is_prime_impl(long n, bool* result);
+
+/**
+ * 导出函数：计算最大公约数
+ *
+ * 结果为 2^63 时（例如 gcd(PHP_INT_MIN, 0)）返回 MathError::Overflow
+ */
+math_error rust_gcd_impl(long a, long b, long* result);
+
+/**
+ * 导出函数：计算最小公倍数
+ *
+ * 先除以最大公约数再相乘，结果超出 int 范围时返回 MathError::Overflow
+ */
+math_error rust_lcm_impl(long a, long b, long* result);
+
+/**
+ * 导出函数：计算幂运算
+ *
+ * 结果通过 result 指针返回，返回值是错误码：
+ * 指数为负数且结果不是整数时返回 MathError::InvalidParameter（0 的负数次幂返回 MathError::DivisionByZero），
+ * 溢出时除回绕模式外都返回 MathError::Overflow。浮点结果和分数结果见 `ffi::power`
+ */
+math_error rust_power_impl(long base, long exponent, long* result);
+
+/* ========================================================================
+ * src/ffi/advanced.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：平方根
+ */
+math_error rust_sqrt_impl(double x, double* result);
+
+/**
+ * 导出函数：立方根
+ */
+math_error rust_cbrt_impl(double x, double* result);
+
+/**
+ * 导出函数：自然对数
+ */
+math_error rust_ln_impl(double x, double* result);
+
+/**
+ * 导出函数：常用对数
+ */
+math_error rust_log10_impl(double x, double* result);
+
+/**
+ * 导出函数：正弦
+ */
+math_error rust_sin_impl(double x, double* result);
+
+/**
+ * 导出函数：余弦
+ */
+math_error rust_cos_impl(double x, double* result);
+
+/**
+ * 导出函数：正切
+ */
+math_error rust_tan_impl(double x, double* result);
+
+/**
+ * 导出函数：反正弦
+ */
+math_error rust_asin_impl(double x, double* result);
+
+/**
+ * 导出函数：反余弦
+ */
+math_error rust_acos_impl(double x, double* result);
+
+/**
+ * 导出函数：反正切
+ */
+math_error rust_atan_impl(double x, double* result);
+
+/**
+ * 导出函数：双曲正弦
+ */
+math_error rust_sinh_impl(double x, double* result);
+
+/**
+ * 导出函数：双曲余弦
+ */
+math_error rust_cosh_impl(double x, double* result);
+
+/**
+ * 导出函数：双曲正切
+ */
+math_error rust_tanh_impl(double x, double* result);
+
+/* ========================================================================
+ * src/ffi/basic.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：整数减法
+ */
+math_error rust_subtract_int_impl(long a, long b, long* result);
+
+/**
+ * 导出函数：整数乘法
+ */
+math_error rust_multiply_int_impl(long a, long b, long* result);
+
+/**
+ * 导出函数：整数除法（商向零取整）
+ */
+math_error rust_divide_int_impl(long a, long b, long* result);
+
+/**
+ * 导出函数：整数取模
+ */
+math_error rust_modulo_int_impl(long a, long b, long* result);
+
+/**
+ * 导出函数：整数绝对值
+ */
+math_error rust_abs_int_impl(long n, long* result);
+
+/**
+ * 导出函数：两个整数的最大值
+ */
+math_error rust_max_int_impl(long a, long b, long* result);
+
+/**
+ * 导出函数：两个整数的最小值
+ */
+math_error rust_min_int_impl(long a, long b, long* result);
+
+/**
+ * 导出函数：浮点数加法
+ */
+math_error rust_add_float_impl(double a, double b, double* result);
+
+/**
+ * 导出函数：浮点数减法
+ */
+math_error rust_subtract_float_impl(double a, double b, double* result);
+
+/**
+ * 导出函数：浮点数除法
+ */
+math_error rust_divide_float_impl(double a, double b, double* result);
+
+/**
+ * 导出函数：浮点数绝对值
+ */
+math_error rust_abs_float_impl(double n, double* result);
+
+/**
+ * 导出函数：两个浮点数的最大值
+ */
+math_error rust_max_float_impl(double a, double b, double* result);
+
+/**
+ * 导出函数：两个浮点数的最小值
+ */
+math_error rust_min_float_impl(double a, double b, double* result);
+
+/* ========================================================================
+ * src/ffi/bessel.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：零阶第一类贝塞尔函数 J0
+ */
+math_error rust_bessel_j0_impl(double x, double* result);
+
+/**
+ * 导出函数：一阶第一类贝塞尔函数 J1
+ */
+math_error rust_bessel_j1_impl(double x, double* result);
+
+/**
+ * 导出函数：n 阶第一类贝塞尔函数 Jn
+ */
+math_error rust_bessel_jn_impl(long n, double x, double* result);
+
+/**
+ * 导出函数：零阶第二类贝塞尔函数 Y0
+ */
+math_error rust_bessel_y0_impl(double x, double* result);
+
+/**
+ * 导出函数：一阶第二类贝塞尔函数 Y1
+ */
+math_error rust_bessel_y1_impl(double x, double* result);
+
+/**
+ * 导出函数：n 阶第二类贝塞尔函数 Yn
+ */
+math_error rust_bessel_yn_impl(long n, double x, double* result);
+
+/**
+ * 导出函数：零阶修正的第一类贝塞尔函数 I0
+ */
+math_error rust_bessel_i0_impl(double x, double* result);
+
+/**
+ * 导出函数：一阶修正的第一类贝塞尔函数 I1
+ */
+math_error rust_bessel_i1_impl(double x, double* result);
+
+/**
+ * 导出函数：n 阶修正的第一类贝塞尔函数 In
+ */
+math_error rust_bessel_in_impl(long n, double x, double* result);
+
+/**
+ * 导出函数：零阶修正的第二类贝塞尔函数 K0
+ */
+math_error rust_bessel_k0_impl(double x, double* result);
+
+/**
+ * 导出函数：一阶修正的第二类贝塞尔函数 K1
+ */
+math_error rust_bessel_k1_impl(double x, double* result);
+
+/**
+ * 导出函数：n 阶修正的第二类贝塞尔函数 Kn
+ */
+math_error rust_bessel_kn_impl(long n, double x, double* result);
+
+/* ========================================================================
+ * src/ffi/bigint.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：由整数创建大整数
+ */
+math_error rust_bigint_from_long(long n, rust_bigint** result);
+
+/**
+ * 导出函数：按指定进制解析字符串创建大整数
+ *
+ * 字符串格式无效或进制不在 2 到 36 之间时返回 MathError::InvalidParameter，
+ * 结果超过 BIGINT_MAX_BITS 位时返回 MathError::Overflow
+ */
+math_error rust_bigint_from_string(const char* s, int base, rust_bigint** result);
+
+/**
+ * 导出函数：复制大整数
+ */
+math_error rust_bigint_clone(const rust_bigint* a, rust_bigint** result);
+
+/**
+ * 导出函数：释放大整数
+ */
+void rust_bigint_free(rust_bigint* a);
+
+/**
+ * 导出函数：大整数加法
+ *
+ * 结果超过 BIGINT_MAX_BITS 位时返回 MathError::Overflow
+ */
+math_error rust_bigint_add(const rust_bigint* a, const rust_bigint* b, rust_bigint** result);
+
+/**
+ * 导出函数：大整数减法
+ *
+ * 结果超过 BIGINT_MAX_BITS 位时返回 MathError::Overflow
+ */
+math_error rust_bigint_sub(const rust_bigint* a, const rust_bigint* b, rust_bigint** result);
+
+/**
+ * 导出函数：大整数乘法
+ *
+ * 结果超过 BIGINT_MAX_BITS 位时返回 MathError::Overflow
+ */
+math_error rust_bigint_mul(const rust_bigint* a, const rust_bigint* b, rust_bigint** result);
+
+/**
+ * 导出函数：大整数带余除法
+ *
+ * 商向零取整，余数与被除数同号
+ * 成功时通过 quotient 和 remainder 返回两个新句柄，除数为零时返回 MathError::DivisionByZero
+ */
+math_error rust_bigint_divmod(const rust_bigint* a, const rust_bigint* b, rust_bigint** quotient, rust_bigint** remainder);
+
+/**
+ * 导出函数：大整数幂运算
+ *
+ * 指数为负数时返回 MathError::NegativeNumber，结果过大时返回 MathError::Overflow
+ */
+math_error rust_bigint_pow(const rust_bigint* a, long exponent, rust_bigint** result);
+
+/**
+ * 导出函数：大整数模幂运算
+ *
+ * 指数为负数时返回 MathError::NegativeNumber，模数为零时返回 MathError::DivisionByZero，
+ * 模数为负数时返回 MathError::InvalidParameter
+ */
+math_error rust_bigint_modpow(const rust_bigint* base, const rust_bigint* exponent, const rust_bigint* modulus, rust_bigint** result);
+
+/**
+ * 导出函数：大整数取反
+ */
+math_error rust_bigint_neg(const rust_bigint* a, rust_bigint** result);
+
+/**
+ * 导出函数：大整数绝对值
+ */
+math_error rust_bigint_abs(const rust_bigint* a, rust_bigint** result);
+
+/**
+ * 导出函数：比较两个大整数
+ *
+ * # 返回值
+ * a < b 返回 -1，a == b 返回 0，a > b 返回 1
+ */
+int rust_bigint_cmp(const rust_bigint* a, const rust_bigint* b);
+
+/**
+ * 导出函数：获取大整数的符号
+ *
+ * # 返回值
+ * 负数返回 -1，零返回 0，正数返回 1
+ */
+int rust_bigint_sign(const rust_bigint* a);
+
+/**
+ * 导出函数：把大整数转换为整数
+ *
+ * 超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_bigint_to_long(const rust_bigint* a, long* result);
+
+/**
+ * 导出函数：把大整数按指定进制转换为字符串
+ *
+ * 进制不在 2 到 36 之间时返回 MathError::InvalidParameter，此时 data 为空指针
+ * 返回的内存需要调用 rust_free_string_result 释放
+ */
+math_error rust_bigint_to_string(const rust_bigint* a, int base, rust_string_result* result);
+
+/* ========================================================================
+ * src/ffi/combinatorics.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：组合数 C(n, k)，k > n 时为 0
+ */
+math_error rust_binomial_impl(long n, long k, long* result);
+
+/**
+ * 导出函数：排列数 P(n, k) = n! / (n - k)!，k > n 时为 0
+ */
+math_error rust_permutations_impl(long n, long k, long* result);
+
+/**
+ * 导出函数：多项式系数 (k1 + ... + km)! / (k1! · ... · km!)
+ *
+ * 数组为空时结果为 1
+ */
+math_error rust_multinomial_impl(const long* counts, long length, long* result);
+
+/**
+ * 导出函数：第 n 个卡塔兰数
+ */
+math_error rust_catalan_impl(long n, long* result);
+
+/**
+ * 导出函数：无符号第一类斯特林数 c(n, k)
+ */
+math_error rust_stirling_first_impl(long n, long k, long* result);
+
+/**
+ * 导出函数：第二类斯特林数 S(n, k)
+ */
+math_error rust_stirling_second_impl(long n, long k, long* result);
+
+/**
+ * 导出函数：第 n 个贝尔数
+ */
+math_error rust_bell_impl(long n, long* result);
+
+/**
+ * 导出函数：整数分拆数 p(n)
+ */
+math_error rust_partitions_impl(long n, long* result);
+
+/* ========================================================================
+ * src/ffi/complex.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：由模和辐角创建复数
+ */
+math_error rust_complex_from_polar(double modulus, double argument, rust_complex* result);
+
+/**
+ * 导出函数：复数加法
+ */
+math_error rust_complex_add(rust_complex a, rust_complex b, rust_complex* result);
+
+/**
+ * 导出函数：复数减法
+ */
+math_error rust_complex_sub(rust_complex a, rust_complex b, rust_complex* result);
+
+/**
+ * 导出函数：复数乘法
+ */
+math_error rust_complex_mul(rust_complex a, rust_complex b, rust_complex* result);
+
+/**
+ * 导出函数：复数除法
+ *
+ * 除数为 0 时，严格模式返回 MathError::DivisionByZero
+ */
+math_error rust_complex_div(rust_complex a, rust_complex b, rust_complex* result);
+
+/**
+ * 导出函数：倒数
+ *
+ * z 为 0 时，严格模式返回 MathError::DivisionByZero
+ */
+math_error rust_complex_reciprocal(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：复数幂的主值 a^b
+ *
+ * 严格模式下 0 的负整数次幂返回 MathError::DivisionByZero，
+ * 0 的其他实部不大于 0 的次幂返回 MathError::DomainError
+ */
+math_error rust_complex_pow(rust_complex a, rust_complex b, rust_complex* result);
+
+/**
+ * 导出函数：整数次幂，使用二进制快速幂
+ *
+ * 0 的负数次幂在严格模式下返回 MathError::DivisionByZero
+ */
+math_error rust_complex_powi(rust_complex a, long exponent, rust_complex* result);
+
+/**
+ * 导出函数：模 |z|
+ */
+double rust_complex_abs(rust_complex a);
+
+/**
+ * 导出函数：辐角，范围为 [-π, π]
+ */
+double rust_complex_arg(rust_complex a);
+
+/**
+ * 导出函数：e^z
+ */
+math_error rust_complex_exp(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：自然对数的主值
+ *
+ * z 为 0 时，严格模式返回 MathError::DomainError
+ */
+math_error rust_complex_ln(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：常用对数的主值
+ *
+ * z 为 0 时，严格模式返回 MathError::DomainError
+ */
+math_error rust_complex_log10(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：平方根的主值，实部非负
+ */
+math_error rust_complex_sqrt(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：正弦
+ */
+math_error rust_complex_sin(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：余弦
+ */
+math_error rust_complex_cos(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：正切
+ */
+math_error rust_complex_tan(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：反正弦的主值
+ */
+math_error rust_complex_asin(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：反余弦的主值
+ */
+math_error rust_complex_acos(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：反正切的主值
+ *
+ * z 为 ±i 时，严格模式返回 MathError::DomainError
+ */
+math_error rust_complex_atan(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：双曲正弦
+ */
+math_error rust_complex_sinh(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：双曲余弦
+ */
+math_error rust_complex_cosh(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：双曲正切
+ */
+math_error rust_complex_tanh(rust_complex a, rust_complex* result);
+
+/**
+ * 导出函数：转换为 "3+4i"、"3-4i"、"4i" 或 "3" 形式的字符串
+ *
+ * 返回的内存需要调用 rust_free_string_result 释放
+ */
+math_error rust_complex_to_string(rust_complex a, rust_string_result* result);
+
+/* ========================================================================
+ * src/ffi/decimal.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：由整数创建定点小数，小数位数为 0
+ */
+math_error rust_decimal_from_long(long n, rust_decimal** result);
+
+/**
+ * 导出函数：解析 "-12.340" 形式的字符串，保留全部小数位数
+ *
+ * 格式无效或小数位数超过 MAX_SCALE 时返回 MathError::InvalidParameter，超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_from_string(const char* s, rust_decimal** result);
+
+/**
+ * 导出函数：解析字符串并舍入到指定的小数位数
+ *
+ * 格式无效时返回 MathError::InvalidParameter，超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_from_string_rounded(const char* s, long scale, int mode, rust_decimal** result);
+
+/**
+ * 导出函数：按舍入模式把浮点数转换为定点小数
+ *
+ * 使用能还原出同一个浮点数的最短十进制表示，x 为 NaN 时返回 MathError::NotANumber，
+ * 无穷大或超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_from_double(double x, long scale, int mode, rust_decimal** result);
+
+/**
+ * 导出函数：复制定点小数
+ */
+math_error rust_decimal_clone(const rust_decimal* a, rust_decimal** result);
+
+/**
+ * 导出函数：释放定点小数
+ */
+void rust_decimal_free(rust_decimal* a);
+
+/**
+ * 导出函数：精确加法，结果的小数位数为两者的较大值
+ *
+ * 结果超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_add(const rust_decimal* a, const rust_decimal* b, rust_decimal** result);
+
+/**
+ * 导出函数：精确减法，结果的小数位数为两者的较大值
+ *
+ * 结果超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_sub(const rust_decimal* a, const rust_decimal* b, rust_decimal** result);
+
+/**
+ * 导出函数：精确乘法，结果的小数位数为两者之和
+ *
+ * 结果超出范围或小数位数超过 MAX_SCALE 时返回 MathError::Overflow
+ */
+math_error rust_decimal_mul(const rust_decimal* a, const rust_decimal* b, rust_decimal** result);
+
+/**
+ * 导出函数：除法，结果舍入到指定的小数位数
+ *
+ * 除数为 0 时返回 MathError::DivisionByZero，结果超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_div(const rust_decimal* a, const rust_decimal* b, long scale, int mode, rust_decimal** result);
+
+/**
+ * 导出函数：舍入到指定的小数位数，位数比当前多时只补 0
+ *
+ * 结果超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_round(const rust_decimal* a, long scale, int mode, rust_decimal** result);
+
+/**
+ * 导出函数：取反
+ *
+ * 结果超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_neg(const rust_decimal* a, rust_decimal** result);
+
+/**
+ * 导出函数：绝对值
+ *
+ * 结果超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_abs(const rust_decimal* a, rust_decimal** result);
+
+/**
+ * 导出函数：按比例分配金额，各部分之和恰好等于原值
+ *
+ * 通过 results 返回 length 个句柄，出错时全部写入空指针
+ * ratios 为空或全为 0 时返回 MathError::InvalidParameter，有负数时返回 MathError::NegativeNumber，
+ * 计算超出范围时返回 MathError::Overflow
+ */
+math_error rust_decimal_allocate(const rust_decimal* a, const long* ratios, long length, rust_decimal** results);
+
+/**
+ * 导出函数：比较两个定点小数的数值，不考虑小数位数
+ *
+ * # 返回值
+ * a < b 返回 -1，a == b 返回 0，a > b 返回 1
+ */
+int rust_decimal_cmp(const rust_decimal* a, const rust_decimal* b);
+
+/**
+ * 导出函数：获取定点小数的符号
+ *
+ * # 返回值
+ * 负数返回 -1，零返回 0，正数返回 1
+ */
+int rust_decimal_sign(const rust_decimal* a);
+
+/**
+ * 导出函数：小数位数
+ */
+long rust_decimal_scale(const rust_decimal* a);
+
+/**
+ * 导出函数：转换为最接近的浮点数
+ */
+double rust_decimal_to_double(const rust_decimal* a);
+
+/**
+ * 导出函数：转换为保留全部小数位数的十进制字符串，例如 "-0.050"
+ *
+ * 返回的内存需要调用 rust_free_string_result 释放
+ */
+math_error rust_decimal_to_string(const rust_decimal* a, rust_string_result* result);
+
+/* ========================================================================
+ * src/ffi/enumeration.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：从 n 个元素中选出 k 个的组合
+ */
+math_error rust_enumeration_combinations(long n, long k, rust_enumeration** result);
+
+/**
+ * 导出函数：从 n 个元素中可重复地选出 k 个的组合
+ */
+math_error rust_enumeration_combinations_with_replacement(long n, long k, rust_enumeration** result);
+
+/**
+ * 导出函数：从 n 个元素中选出 k 个的排列
+ */
+math_error rust_enumeration_permutations(long n, long k, rust_enumeration** result);
+
+/**
+ * 导出函数：n 个元素的 k 元组（可重复排列）
+ */
+math_error rust_enumeration_permutations_with_replacement(long n, long k, rust_enumeration** result);
+
+/**
+ * 导出函数：多个集合的笛卡尔积
+ */
+math_error rust_enumeration_product(const long* sizes, long length, rust_enumeration** result);
+
+/**
+ * 导出函数：复制枚举，副本从同一个排列开始独立前进
+ */
+math_error rust_enumeration_clone(const rust_enumeration* e, rust_enumeration** result);
+
+/**
+ * 导出函数：释放枚举
+ */
+void rust_enumeration_free(rust_enumeration* e);
+
+/**
+ * 导出函数：是否还有当前排列
+ */
+bool rust_enumeration_valid(const rust_enumeration* e);
+
+/**
+ * 导出函数：当前排列的下标，从 0 开始
+ */
+long rust_enumeration_key(const rust_enumeration* e);
+
+/**
+ * 导出函数：当前排列中元素的位置
+ *
+ * 通过 result 返回位置数组，全部生成完后返回 MathError::InvalidParameter
+ * 返回的内存需要调用 rust_free_integer_array_result 释放
+ */
+math_error rust_enumeration_current(const rust_enumeration* e, rust_integer_array_result* result);
+
+/**
+ * 导出函数：移动到下一个排列
+ */
+void rust_enumeration_next(rust_enumeration* e);
+
+/**
+ * 导出函数：回到第 0 个排列
+ */
+void rust_enumeration_rewind(rust_enumeration* e);
+
+/**
+ * 导出函数：排列总数
+ *
+ * 总数超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_enumeration_count(const rust_enumeration* e, long* result);
+
+/**
+ * 导出函数：排列在字典序中的下标
+ *
+ * 位置不是这个枚举会生成的排列时返回 MathError::InvalidParameter，
+ * 排列总数超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_enumeration_rank(const rust_enumeration* e, const long* positions, long length, long* result);
+
+/**
+ * 导出函数：字典序中第 index 个排列的位置，不移动当前排列
+ *
+ * index 为负数时返回 MathError::NegativeNumber，不小于排列总数时返回 MathError::InvalidParameter，
+ * 排列总数超出 long 范围时返回 MathError::Overflow
+ * 返回的内存需要调用 rust_free_integer_array_result 释放
+ */
+math_error rust_enumeration_unrank(const rust_enumeration* e, long index, rust_integer_array_result* result);
+
+/**
+ * 导出函数：移动到字典序中的第 index 个排列
+ *
+ * 错误与 `rust_enumeration_unrank` 相同，出错时当前排列不变
+ */
+math_error rust_enumeration_seek(rust_enumeration* e, long index);
+
+/* ========================================================================
+ * src/ffi/erf.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：误差函数
+ */
+math_error rust_erf_impl(double x, double* result);
+
+/**
+ * 导出函数：补误差函数
+ */
+math_error rust_erfc_impl(double x, double* result);
+
+/**
+ * 导出函数：缩放的补误差函数 exp(x²) · erfc(x)
+ */
+math_error rust_erfcx_impl(double x, double* result);
+
+/**
+ * 导出函数：反误差函数
+ */
+math_error rust_erfinv_impl(double x, double* result);
+
+/**
+ * 导出函数：反补误差函数
+ */
+math_error rust_erfcinv_impl(double x, double* result);
+
+/**
+ * 导出函数：正态分布的概率密度函数
+ *
+ * sigma 不是正数时返回 MathError::InvalidParameter
+ */
+math_error rust_normal_pdf_impl(double x, double mu, double sigma, double* result);
+
+/**
+ * 导出函数：正态分布的累积分布函数
+ *
+ * sigma 不是正数时返回 MathError::InvalidParameter
+ */
+math_error rust_normal_cdf_impl(double x, double mu, double sigma, double* result);
+
+/**
+ * 导出函数：正态分布的分位数函数
+ *
+ * sigma 不是正数时返回 MathError::InvalidParameter
+ */
+math_error rust_normal_quantile_impl(double p, double mu, double sigma, double* result);
+
+/* ========================================================================
+ * src/ffi/fibonacci.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：第 n 个斐波那契数
+ */
+math_error rust_fibonacci_nth_impl(long n, long* result);
+
+/**
+ * 导出函数：第 n 个卢卡斯数
+ */
+math_error rust_lucas_nth_impl(long n, long* result);
+
+/**
+ * 导出函数：任意精度的第 n 个斐波那契数
+ *
+ * 通过 result 返回十进制字符串，返回的内存需要调用 rust_free_string_result 释放
+ */
+math_error rust_fibonacci_big_impl(long n, rust_string_result* result);
+
+/**
+ * 导出函数：任意精度的第 n 个卢卡斯数
+ *
+ * 通过 result 返回十进制字符串，返回的内存需要调用 rust_free_string_result 释放
+ */
+math_error rust_lucas_big_impl(long n, rust_string_result* result);
+
+/* ========================================================================
+ * src/ffi/gamma.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：伽马函数
+ */
+math_error rust_gamma_impl(double x, double* result);
+
+/**
+ * 导出函数：伽马函数绝对值的自然对数
+ */
+math_error rust_lgamma_impl(double x, double* result);
+
+/**
+ * 导出函数：双伽马函数
+ */
+math_error rust_digamma_impl(double x, double* result);
+
+/**
+ * 导出函数：贝塔函数
+ */
+math_error rust_beta_impl(double a, double b, double* result);
+
+/* ========================================================================
+ * src/ffi/matrix.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：矩阵乘法 A·B
+ *
+ * A 的列数不等于 B 的行数时返回 MathError::InvalidParameter
+ */
+math_error rust_matrix_multiply_impl(const double* a, long a_rows, long a_cols, const double* b, long b_rows, long b_cols, double* result);
+
+/**
+ * 导出函数：转置矩阵
+ */
+math_error rust_matrix_transpose_impl(const double* matrix, long rows, long cols, double* result);
+
+/**
+ * 导出函数：行列式
+ *
+ * 不是方阵时返回 MathError::InvalidParameter
+ */
+math_error rust_matrix_determinant_impl(const double* matrix, long rows, long cols, double* result);
+
+/**
+ * 导出函数：逆矩阵
+ *
+ * 不是方阵时返回 MathError::InvalidParameter，奇异矩阵返回 MathError::DivisionByZero
+ */
+math_error rust_matrix_inverse_impl(const double* matrix, long rows, long cols, double* result);
+
+/**
+ * 导出函数：LU 分解 P·A = L·U，使用部分选主元
+ *
+ * P·A 的第 i 行是 A 的第 permutation[i] 行；奇异矩阵也可以分解，不是方阵时返回 MathError::InvalidParameter
+ */
+math_error rust_matrix_lu_impl(const double* matrix, long rows, long cols, double* lower, double* upper, long* permutation);
+
+/**
+ * 导出函数：QR 分解 A = Q·R，使用 Householder 变换
+ *
+ * Q 是 rows × rows 的正交矩阵，R 是 rows × cols 的上三角矩阵，R 的对角线元素非负
+ */
+math_error rust_matrix_qr_impl(const double* matrix, long rows, long cols, double* q, double* r);
+
+/**
+ * 导出函数：Cholesky 分解 A = L·Lᵀ
+ *
+ * 不是方阵时返回 MathError::InvalidParameter，不是对称正定矩阵时返回 MathError::DomainError
+ */
+math_error rust_matrix_cholesky_impl(const double* matrix, long rows, long cols, double* result);
+
+/**
+ * 导出函数：解线性方程组 A·X = B
+ *
+ * B 有 rows 行、b_cols 列，每一列是一个右端向量；方阵求精确解，行数多于列数时求最小二乘解
+ * A 的行数少于列数时返回 MathError::InvalidParameter，A 奇异或列不满秩时返回 MathError::DivisionByZero
+ */
+math_error rust_matrix_solve_impl(const double* a, long rows, long cols, const double* b, long b_cols, double* result);
+
+/* ========================================================================
+ * src/ffi/mod.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：释放整数数组结果的内存
+ */
+void rust_free_integer_array_result(rust_integer_array_result result);
+
+/* ========================================================================
+ * src/ffi/modular.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：模幂 base^exp mod m
+ *
+ * 指数为负数时使用 base 的模逆元，逆元不存在时返回 MathError::DomainError
+ */
+math_error rust_mod_pow_impl(long base, long exp, long m, long* result);
+
+/**
+ * 导出函数：模逆元
+ *
+ * a 与 m 不互质时返回 MathError::DomainError
+ */
+math_error rust_mod_inverse_impl(long a, long m, long* result);
+
+/**
+ * 导出函数：扩展欧几里得算法
+ *
+ * 最大公约数超出 int 范围时返回 MathError::Overflow
+ */
+math_error rust_extended_gcd_impl(long a, long b, rust_extended_gcd_result* result);
+
+/**
+ * 导出函数：中国剩余定理
+ *
+ * 方程组无解时返回 MathError::DomainError，模数的最小公倍数超出 int 范围时返回 MathError::Overflow
+ */
+math_error rust_crt_impl(const long* remainders, const long* moduli, long length, rust_crt_result* result);
+
+/**
+ * 导出函数：雅可比符号 (a/n)
+ *
+ * n 不是正奇数时返回 MathError::InvalidParameter
+ */
+math_error rust_jacobi_impl(long a, long n, long* result);
+
+/**
+ * 导出函数：勒让德符号 (a/p)
+ *
+ * p 不是奇质数时返回 MathError::InvalidParameter
+ */
+math_error rust_legendre_impl(long a, long p, long* result);
+
+/* ========================================================================
+ * src/ffi/number_theory.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：质因数分解
+ *
+ * 通过 result 返回按从小到大排列的质因数和对应的指数
+ * 返回的内存需要调用 rust_free_factorization_result 释放
+ */
+math_error rust_factorize_impl(long n, rust_factorization_result* result);
+
+/**
+ * 导出函数：释放质因数分解结果的内存
+ */
+void rust_free_factorization_result(rust_factorization_result result);
+
+/**
+ * 导出函数：正整数的全部正因数
+ *
+ * 通过 result 返回从小到大排列的因数，出错时 numbers 为空指针
+ * 返回的内存需要调用 rust_free_integer_array_result 释放
+ */
+math_error rust_divisors_impl(long n, rust_integer_array_result* result);
+
+/**
+ * 导出函数：因数幂和函数 σ_k(n)
+ *
+ * 结果超出 int 范围时返回 MathError::Overflow
+ */
+math_error rust_sigma_impl(long n, long k, long* result);
+
+/**
+ * 导出函数：欧拉函数 φ(n)
+ */
+math_error rust_euler_phi_impl(long n, long* result);
+
+/**
+ * 导出函数：莫比乌斯函数 μ(n)
+ */
+math_error rust_mobius_impl(long n, long* result);
+
+/**
+ * 导出函数：多个数的最大公约数
+ *
+ * 数组为空时结果为 0，结果为 2^63 时返回 MathError::Overflow
+ */
+math_error rust_gcd_many_impl(const long* numbers, long length, long* result);
+
+/**
+ * 导出函数：多个数的最小公倍数
+ *
+ * 数组为空时结果为 1，结果超出 int 范围时返回 MathError::Overflow
+ */
+math_error rust_lcm_many_impl(const long* numbers, long length, long* result);
+
+/* ========================================================================
+ * src/ffi/power.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：浮点数的浮点次幂
+ *
+ * 负数的非整数次幂返回 MathError::DomainError，0 的负数次幂返回 MathError::DivisionByZero
+ */
+math_error rust_pow_float_impl(double base, double exponent, double* result);
+
+/**
+ * 导出函数：浮点数的整数次幂，指数可以为负数
+ */
+math_error rust_powi_impl(double base, long exponent, double* result);
+
+/**
+ * 导出函数：分数的整数次幂
+ *
+ * 通过 result 返回最简分数，分母大于 0
+ */
+math_error rust_power_fraction_impl(long numerator, long denominator, long exponent, rust_fraction* result);
+
+/* ========================================================================
+ * src/ffi/rational.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：由分子和分母创建有理数，结果约分到最简形式
+ *
+ * 分母为 0 时返回 MathError::DivisionByZero，约分后超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_rational_new(long numerator, long denominator, rust_rational** result);
+
+/**
+ * 导出函数：解析 "-7"、"3/4" 或 "-1.25" 形式的字符串创建有理数
+ *
+ * 格式无效时返回 MathError::InvalidParameter，分母为 0 时返回 MathError::DivisionByZero，
+ * 超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_rational_from_string(const char* s, rust_rational** result);
+
+/**
+ * 导出函数：分母不超过 max_denominator 的浮点数的最佳近似
+ *
+ * x 为 NaN 时返回 MathError::NotANumber，无穷大或超出 long 范围时返回 MathError::Overflow，
+ * max_denominator 小于 1 时返回 MathError::InvalidParameter
+ */
+math_error rust_rational_from_double(double x, long max_denominator, rust_rational** result);
+
+/**
+ * 导出函数：由连分数 [a0; a1, a2, ...] 创建有理数
+ *
+ * terms 为空或 a0 以外的项不大于 0 时返回 MathError::InvalidParameter，
+ * 中间结果超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_rational_from_continued_fraction(const long* terms, long length, rust_rational** result);
+
+/**
+ * 导出函数：复制有理数
+ */
+math_error rust_rational_clone(const rust_rational* a, rust_rational** result);
+
+/**
+ * 导出函数：释放有理数
+ */
+void rust_rational_free(rust_rational* a);
+
+/**
+ * 导出函数：有理数加法
+ *
+ * 结果超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_rational_add(const rust_rational* a, const rust_rational* b, rust_rational** result);
+
+/**
+ * 导出函数：有理数减法
+ *
+ * 结果超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_rational_sub(const rust_rational* a, const rust_rational* b, rust_rational** result);
+
+/**
+ * 导出函数：有理数乘法
+ *
+ * 结果超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_rational_mul(const rust_rational* a, const rust_rational* b, rust_rational** result);
+
+/**
+ * 导出函数：有理数除法
+ *
+ * 除数为 0 时返回 MathError::DivisionByZero，结果超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_rational_div(const rust_rational* a, const rust_rational* b, rust_rational** result);
+
+/**
+ * 导出函数：有理数的整数次幂，指数可以为负数
+ *
+ * 0 的负数次幂返回 MathError::DivisionByZero，结果超出 long 范围时返回 MathError::Overflow
+ */
+math_error rust_rational_pow(const rust_rational* a, long exponent, rust_rational** result);
+
+/**
+ * 导出函数：有理数取反
+ *
+ * 分子为 long 最小值时返回 MathError::Overflow
+ */
+math_error rust_rational_neg(const rust_rational* a, rust_rational** result);
+
+/**
+ * 导出函数：有理数绝对值
+ *
+ * 分子为 long 最小值时返回 MathError::Overflow
+ */
+math_error rust_rational_abs(const rust_rational* a, rust_rational** result);
+
+/**
+ * 导出函数：有理数的倒数
+ *
+ * 值为 0 时返回 MathError::DivisionByZero，分子为 long 最小值时返回 MathError::Overflow
+ */
+math_error rust_rational_reciprocal(const rust_rational* a, rust_rational** result);
+
+/**
+ * 导出函数：分母不超过 max_denominator 的最佳近似
+ *
+ * max_denominator 小于 1 时返回 MathError::InvalidParameter
+ */
+math_error rust_rational_limit_denominator(const rust_rational* a, long max_denominator, rust_rational** result);
+
+/**
+ * 导出函数：比较两个有理数
+ *
+ * # 返回值
+ * a < b 返回 -1，a == b 返回 0，a > b 返回 1
+ */
+int rust_rational_cmp(const rust_rational* a, const rust_rational* b);
+
+/**
+ * 导出函数：获取有理数的符号
+ *
+ * # 返回值
+ * 负数返回 -1，零返回 0，正数返回 1
+ */
+int rust_rational_sign(const rust_rational* a);
+
+/**
+ * 导出函数：最简形式的分子
+ */
+long rust_rational_numerator(const rust_rational* a);
+
+/**
+ * 导出函数：最简形式的分母，总是大于 0
+ */
+long rust_rational_denominator(const rust_rational* a);
+
+/**
+ * 导出函数：向下取整
+ */
+long rust_rational_floor(const rust_rational* a);
+
+/**
+ * 导出函数：向上取整
+ */
+long rust_rational_ceil(const rust_rational* a);
+
+/**
+ * 导出函数：转换为最接近的浮点数
+ */
+double rust_rational_to_double(const rust_rational* a);
+
+/**
+ * 导出函数：转换为 "n/d" 形式的字符串，整数转换为 "n"
+ *
+ * 返回的内存需要调用 rust_free_string_result 释放
+ */
+math_error rust_rational_to_string(const rust_rational* a, rust_string_result* result);
+
+/**
+ * 导出函数：连分数展开 [a0; a1, a2, ...]
+ *
+ * 返回的内存需要调用 rust_free_integer_array_result 释放
+ */
+math_error rust_rational_continued_fraction(const rust_rational* a, rust_integer_array_result* result);
+
+/* ========================================================================
+ * src/ffi/sequence.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：创建斐波那契数列
+ */
+math_error rust_sequence_fibonacci(rust_sequence** result);
+
+/**
+ * 导出函数：创建卢卡斯数列
+ */
+math_error rust_sequence_lucas(rust_sequence** result);
+
+/**
+ * 导出函数：创建卡塔兰数列
+ */
+math_error rust_sequence_catalan(rust_sequence** result);
+
+/**
+ * 导出函数：创建三角形数列
+ */
+math_error rust_sequence_triangular(rust_sequence** result);
+
+/**
+ * 导出函数：创建质数序列
+ */
+math_error rust_sequence_primes(rust_sequence** result);
+
+/**
+ * 导出函数：创建考拉兹序列
+ *
+ * start 为负数时返回 MathError::NegativeNumber，为 0 时返回 MathError::InvalidParameter
+ */
+math_error rust_sequence_collatz(long start, rust_sequence** result);
+
+/**
+ * 导出函数：创建常系数线性递推序列
+ *
+ * 系数为空时返回 MathError::InvalidParameter
+ */
+math_error rust_sequence_linear(const long* coefficients, const long* initial, long length, rust_sequence** result);
+
+/**
+ * 导出函数：复制序列，副本从同一项开始独立前进
+ */
+math_error rust_sequence_clone(const rust_sequence* s, rust_sequence** result);
+
+/**
+ * 导出函数：释放序列
+ */
+void rust_sequence_free(rust_sequence* s);
+
+/**
+ * 导出函数：序列是否还有当前项
+ *
+ * # 返回值
+ * 有限序列结束后返回 false；当前项超出范围时仍然返回 true，读取它时报告溢出
+ */
+bool rust_sequence_valid(const rust_sequence* s);
+
+/**
+ * 导出函数：当前项的下标，从 0 开始
+ */
+long rust_sequence_key(const rust_sequence* s);
+
+/**
+ * 导出函数：获取当前项
+ *
+ * 当前项超出 long 范围时返回 MathError::Overflow，序列已经结束时返回 MathError::InvalidParameter
+ */
+math_error rust_sequence_current(const rust_sequence* s, long* result);
+
+/**
+ * 导出函数：移动到下一项
+ *
+ * 下一项超出 long 范围时仍然会移动，之后读取当前项时返回 MathError::Overflow
+ */
+void rust_sequence_next(rust_sequence* s);
+
+/**
+ * 导出函数：回到第 0 项
+ */
+void rust_sequence_rewind(rust_sequence* s);
+
+/**
+ * 导出函数：从当前项开始取出最多 count 项
+ *
+ * count 超过 SEQUENCE_TAKE_LIMIT 时返回 MathError::InvalidParameter；
+ * 通过 result 返回取出的项，出错时 numbers 为空指针
+ * 返回的内存需要调用 rust_free_integer_array_result 释放
+ */
+math_error rust_sequence_take(rust_sequence* s, long count, rust_integer_array_result* result);
+
+/* ========================================================================
+ * src/ffi/sieve.rs
+ * ======================================================================== */
+
+/**
+ * 导出函数：不超过 n 的全部质数
+ *
+ * n 超过 1 亿时返回 MathError::InvalidParameter
+ */
+math_error rust_primes_up_to_impl(long n, rust_integer_array_result* result);
+
+/**
+ * 导出函数：区间 [a, b] 内的全部质数
+ *
+ * 区间长度超过 1 亿时返回 MathError::InvalidParameter
+ */
+math_error rust_primes_between_impl(long a, long b, rust_integer_array_result* result);
+
+/**
+ * 导出函数：不超过 n 的质数个数
+ *
+ * n 超过 10 亿时返回 MathError::InvalidParameter
+ */
+math_error rust_prime_count_impl(long n, long* result);
+
+/**
+ * 导出函数：第 k 个质数
+ *
+ * k 小于 1 或超过 1 亿时返回 MathError::InvalidParameter
+ */
+math_error rust_nth_prime_impl(long k, long* result);
+
+#endif /* RUST_MATH_FFI_H */
@@ -0,0 +1,426 @@
+/**
+ * PHP Rust 数学扩展 - 矩阵函数
+ *
+ * 这个文件实现 rust_matrix_* 系列 PHP 函数（参数信息由 build.rs 根据 @php-manual 标记生成）
+ * PHP 中的矩阵是由行组成的嵌套数组，例如 [[1, 2], [3, 4]]，元素可以是 int 或 float；
+ * 这里把它展开成按行优先顺序排列的 double 数组交给 Rust，再把结果转换回嵌套数组
+ * 维数在调用 Rust 之前检查，这样可以抛出指明参数的 ValueError
+ */
+
+#include "php.h"
+#include "zend_exceptions.h"
+#include "php_rust_math.h"
+
+/* 把只包含 int 和 float 的一行复制到 out 中，出错时抛出 TypeError 并返回 false */
+static bool rust_matrix_copy_row(HashTable *row, double *out, uint32_t arg_num)
+{
+    zval *value;
+    uint32_t i = 0;
+
+    ZEND_HASH_FOREACH_VAL(row, value) {
+        ZVAL_DEREF(value);
+        if (Z_TYPE_P(value) == IS_LONG) {
+            out[i++] = (double) Z_LVAL_P(value);
+        } else if (Z_TYPE_P(value) == IS_DOUBLE) {
+            out[i++] = Z_DVAL_P(value);
+        } else {
+            zend_argument_type_error(arg_num, "must contain only int or float, %s given", zend_zval_type_name(value));
+            return false;
+        }
+    } ZEND_HASH_FOREACH_END();
+
+    return true;
+}
+
+/**
+ * 把 PHP 嵌套数组展开为按行优先顺序排列的 double 数组
+ *
+ * 矩阵至少有一行一列，各行长度相同；成功时通过 rows 和 cols 返回行数和列数，
+ * 返回的数组由 emalloc 分配，调用者用完后需要 efree；出错时抛出异常并返回 NULL
+ */
+static double *rust_matrix_from_array(HashTable *matrix, uint32_t arg_num, long *rows, long *cols)
+{
+    uint32_t row_count = zend_hash_num_elements(matrix);
+    uint32_t col_count = 0;
+    double *data = NULL;
+    uint32_t i = 0;
+    zval *row;
+
+    if (row_count == 0) {
+        zend_argument_value_error(arg_num, "must not be empty");
+        return NULL;
+    }
+
+    ZEND_HASH_FOREACH_VAL(matrix, row) {
+        ZVAL_DEREF(row);
+        if (Z_TYPE_P(row) != IS_ARRAY) {
+            zend_argument_type_error(arg_num, "must contain only arrays as rows, %s given", zend_zval_type_name(row));
+            goto failure;
+        }
+
+        if (data == NULL) {
+            col_count = zend_hash_num_elements(Z_ARRVAL_P(row));
+            if (col_count == 0) {
+                zend_argument_value_error(arg_num, "must not contain empty rows");
+                return NULL;
+            }
+            data = safe_emalloc(row_count, col_count * sizeof(double), 0);
+        } else if (zend_hash_num_elements(Z_ARRVAL_P(row)) != col_count) {
+            zend_argument_value_error(arg_num, "must have rows of equal length");
+            goto failure;
+        }
+
+        if (!rust_matrix_copy_row(Z_ARRVAL_P(row), data + (size_t) i * col_count, arg_num)) {
+            goto failure;
+        }
+        i++;
+    } ZEND_HASH_FOREACH_END();
+
+    *rows = row_count;
+    *cols = col_count;
+    return data;
+
+failure:
+    if (data != NULL) {
+        efree(data);
+    }
+    return NULL;
+}
+
+/* 第一个元素不是数组时按向量（只有一列的矩阵）处理 */
+static bool rust_matrix_is_vector(HashTable *array)
+{
+    zval *first;
+
+    ZEND_HASH_FOREACH_VAL(array, first) {
+        ZVAL_DEREF(first);
+        return Z_TYPE_P(first) != IS_ARRAY;
+    } ZEND_HASH_FOREACH_END();
+
+    return false;
+}
+
+/* 把按行优先顺序排列的 rows × cols 个元素转换为 PHP 嵌套数组 */
+static void rust_matrix_to_array(zval *return_value, const double *data, long rows, long cols)
+{
+    array_init_size(return_value, (uint32_t) rows);
+
+    for (long i = 0; i < rows; i++) {
+        zval row;
+        array_init_size(&row, (uint32_t) cols);
+        for (long j = 0; j < cols; j++) {
+            add_next_index_double(&row, data[i * cols + j]);
+        }
+        add_next_index_zval(return_value, &row);
+    }
+}
+
+/* 检查矩阵是否为方阵，不是时抛出 ValueError 并返回 false */
+static bool rust_matrix_check_square(long rows, long cols, uint32_t arg_num)
+{
+    if (rows != cols) {
+        zend_argument_value_error(arg_num, "must be a square matrix, %ld × %ld given", rows, cols);
+        return false;
+    }
+
+    return true;
+}
+
+/* 对方阵调用结果大小与输入相同的 Rust 函数，例如 rust_matrix_inverse_impl */
+static void rust_matrix_square_op(INTERNAL_FUNCTION_PARAMETERS,
+                                  math_error (*op)(const double *, long, long, double *))
+{
+    HashTable *matrix_ht;
+    long rows, cols;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ARRAY_HT(matrix_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    double *matrix = rust_matrix_from_array(matrix_ht, 1, &rows, &cols);
+    if (matrix == NULL) {
+        RETURN_THROWS();
+    }
+    if (!rust_matrix_check_square(rows, cols, 1)) {
+        efree(matrix);
+        RETURN_THROWS();
+    }
+
+    /* 奇异矩阵或不是对称正定矩阵时返回错误码 */
+    double *result = safe_emalloc(rows, cols * sizeof(double), 0);
+    math_error error = op(matrix, rows, cols, result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+    } else {
+        rust_matrix_to_array(return_value, result, rows, cols);
+    }
+
+    efree(matrix);
+    efree(result);
+}
+
+/* PHP 函数：rust_matrix_multiply - 矩阵乘法 A·B */
+PHP_FUNCTION(rust_matrix_multiply)
+{
+    HashTable *a_ht, *b_ht;
+    long a_rows, a_cols, b_rows, b_cols;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_ARRAY_HT(a_ht)
+        Z_PARAM_ARRAY_HT(b_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    double *a = rust_matrix_from_array(a_ht, 1, &a_rows, &a_cols);
+    if (a == NULL) {
+        RETURN_THROWS();
+    }
+    double *b = rust_matrix_from_array(b_ht, 2, &b_rows, &b_cols);
+    if (b == NULL) {
+        efree(a);
+        RETURN_THROWS();
+    }
+    if (b_rows != a_cols) {
+        zend_argument_value_error(2, "must have as many rows as $a has columns (%ld), %ld given", a_cols, b_rows);
+        efree(a);
+        efree(b);
+        RETURN_THROWS();
+    }
+
+    double *result = safe_emalloc(a_rows, b_cols * sizeof(double), 0);
+    math_error error = rust_matrix_multiply_impl(a, a_rows, a_cols, b, b_rows, b_cols, result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+    } else {
+        rust_matrix_to_array(return_value, result, a_rows, b_cols);
+    }
+
+    efree(a);
+    efree(b);
+    efree(result);
+}
+
+/* PHP 函数：rust_matrix_transpose - 转置矩阵 */
+PHP_FUNCTION(rust_matrix_transpose)
+{
+    HashTable *matrix_ht;
+    long rows, cols;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ARRAY_HT(matrix_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    double *matrix = rust_matrix_from_array(matrix_ht, 1, &rows, &cols);
+    if (matrix == NULL) {
+        RETURN_THROWS();
+    }
+
+    double *result = safe_emalloc(rows, cols * sizeof(double), 0);
+    math_error error = rust_matrix_transpose_impl(matrix, rows, cols, result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+    } else {
+        rust_matrix_to_array(return_value, result, cols, rows);
+    }
+
+    efree(matrix);
+    efree(result);
+}
+
+/* PHP 函数：rust_matrix_determinant - 方阵的行列式 */
+PHP_FUNCTION(rust_matrix_determinant)
+{
+    HashTable *matrix_ht;
+    long rows, cols;
+    double result = 0.0;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ARRAY_HT(matrix_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    double *matrix = rust_matrix_from_array(matrix_ht, 1, &rows, &cols);
+    if (matrix == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (rust_matrix_check_square(rows, cols, 1)) {
+        /* 严格模式下结果为 NaN 时返回错误码 */
+        math_error error = rust_matrix_determinant_impl(matrix, rows, cols, &result);
+        if (error != MATH_ERROR_SUCCESS) {
+            rust_math_throw_error(error);
+        }
+    }
+
+    efree(matrix);
+
+    if (EG(exception)) {
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(result);
+}
+
+/* PHP 函数：rust_matrix_inverse - 逆矩阵，奇异矩阵抛出 DivisionByZeroError */
+PHP_FUNCTION(rust_matrix_inverse)
+{
+    rust_matrix_square_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_matrix_inverse_impl);
+}
+
+/* PHP 函数：rust_matrix_cholesky - Cholesky 分解，返回满足 A = L·Lᵀ 的下三角矩阵 L */
+PHP_FUNCTION(rust_matrix_cholesky)
+{
+    rust_matrix_square_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_matrix_cholesky_impl);
+}
+
+/* PHP 函数：rust_matrix_lu - LU 分解，返回 ['l' => L, 'u' => U, 'p' => 行置换]，P·A 的第 i 行是 A 的第 p[i] 行 */
+PHP_FUNCTION(rust_matrix_lu)
+{
+    HashTable *matrix_ht;
+    long rows, cols;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ARRAY_HT(matrix_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    double *matrix = rust_matrix_from_array(matrix_ht, 1, &rows, &cols);
+    if (matrix == NULL) {
+        RETURN_THROWS();
+    }
+    if (!rust_matrix_check_square(rows, cols, 1)) {
+        efree(matrix);
+        RETURN_THROWS();
+    }
+
+    double *lower = safe_emalloc(rows, cols * sizeof(double), 0);
+    double *upper = safe_emalloc(rows, cols * sizeof(double), 0);
+    long *permutation = safe_emalloc(rows, sizeof(long), 0);
+
+    math_error error = rust_matrix_lu_impl(matrix, rows, cols, lower, upper, permutation);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+    } else {
+        zval l, u, p;
+
+        rust_matrix_to_array(&l, lower, rows, cols);
+        rust_matrix_to_array(&u, upper, rows, cols);
+        array_init_size(&p, (uint32_t) rows);
+        for (long i = 0; i < rows; i++) {
+            add_next_index_long(&p, permutation[i]);
+        }
+
+        array_init_size(return_value, 3);
+        add_assoc_zval(return_value, "l", &l);
+        add_assoc_zval(return_value, "u", &u);
+        add_assoc_zval(return_value, "p", &p);
+    }
+
+    efree(matrix);
+    efree(lower);
+    efree(upper);
+    efree(permutation);
+}
+
+/* PHP 函数：rust_matrix_qr - QR 分解，返回 ['q' => Q, 'r' => R]，Q 是正交方阵，R 的对角线元素非负 */
+PHP_FUNCTION(rust_matrix_qr)
+{
+    HashTable *matrix_ht;
+    long rows, cols;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ARRAY_HT(matrix_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    double *matrix = rust_matrix_from_array(matrix_ht, 1, &rows, &cols);
+    if (matrix == NULL) {
+        RETURN_THROWS();
+    }
+
+    double *q = safe_emalloc(rows, rows * sizeof(double), 0);
+    double *r = safe_emalloc(rows, cols * sizeof(double), 0);
+
+    math_error error = rust_matrix_qr_impl(matrix, rows, cols, q, r);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+    } else {
+        zval q_array, r_array;
+
+        rust_matrix_to_array(&q_array, q, rows, rows);
+        rust_matrix_to_array(&r_array, r, rows, cols);
+
+        array_init_size(return_value, 2);
+        add_assoc_zval(return_value, "q", &q_array);
+        add_assoc_zval(return_value, "r", &r_array);
+    }
+
+    efree(matrix);
+    efree(q);
+    efree(r);
+}
+
+/**
+ * PHP 函数：rust_matrix_solve - 解线性方程组 A·X = B
+ *
+ * $b 可以是向量 [b1, b2, ...]，这时返回向量；也可以是每一列为一个右端向量的矩阵，这时返回矩阵
+ * A 是方阵时求精确解，行数多于列数时求最小二乘解；A 奇异或列不满秩时抛出 DivisionByZeroError
+ */
+PHP_FUNCTION(rust_matrix_solve)
+{
+    HashTable *a_ht, *b_ht;
+    long rows, cols, b_rows, b_cols;
+    double *b;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_ARRAY_HT(a_ht)
+        Z_PARAM_ARRAY_HT(b_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    double *a = rust_matrix_from_array(a_ht, 1, &rows, &cols);
+    if (a == NULL) {
+        RETURN_THROWS();
+    }
+    if (rows < cols) {
+        zend_argument_value_error(1, "must have at least as many rows as columns, %ld × %ld given", rows, cols);
+        efree(a);
+        RETURN_THROWS();
+    }
+
+    bool vector = rust_matrix_is_vector(b_ht);
+    if (vector) {
+        b_rows = zend_hash_num_elements(b_ht);
+        b_cols = 1;
+        b = safe_emalloc(b_rows, sizeof(double), 0);
+        if (!rust_matrix_copy_row(b_ht, b, 2)) {
+            efree(a);
+            efree(b);
+            RETURN_THROWS();
+        }
+    } else {
+        b = rust_matrix_from_array(b_ht, 2, &b_rows, &b_cols);
+        if (b == NULL) {
+            efree(a);
+            RETURN_THROWS();
+        }
+    }
+    if (b_rows != rows) {
+        zend_argument_value_error(2, "must have the same number of rows as $a (%ld), %ld given", rows, b_rows);
+        efree(a);
+        efree(b);
+        RETURN_THROWS();
+    }
+
+    double *result = safe_emalloc(cols, b_cols * sizeof(double), 0);
+    math_error error = rust_matrix_solve_impl(a, rows, cols, b, b_cols, result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+    } else if (vector) {
+        array_init_size(return_value, (uint32_t) cols);
+        for (long i = 0; i < cols; i++) {
+            add_next_index_double(return_value, result[i]);
+        }
+    } else {
+        rust_matrix_to_array(return_value, result, cols, b_cols);
+    }
+
+    efree(a);
+    efree(b);
+    efree(result);
+}
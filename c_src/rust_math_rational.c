@@ -0,0 +1,805 @@
+/**
+ * PHP Rust 数学扩展 - RustMath\Rational 类
+ *
+ * 这个文件把 Rust 的有理数句柄包装成 PHP 对象
+ * 通过 do_operation 处理器重载 +、-、*、/ 和 ** 运算符，通过 compare 处理器重载比较运算符，
+ * 另一个操作数可以是 Rational、int 或 "3/4"、"-1.25" 形式的字符串
+ */
+
+#include "php.h"
+#include "zend_exceptions.h"
+#include "zend_interfaces.h"
+#include "php_rust_math.h"
+
+/* 类入口和对象处理器 */
+zend_class_entry *rust_rational_ce;
+static zend_object_handlers rust_rational_handlers;
+
+/* PHP 对象结构：Rust 句柄 + 标准 zend_object（必须放在最后） */
+typedef struct {
+    rust_rational *handle;
+    zend_object std;
+} php_rust_rational;
+
+static inline php_rust_rational *php_rust_rational_from_obj(zend_object *obj)
+{
+    return (php_rust_rational *)((char *)obj - XtOffsetOf(php_rust_rational, std));
+}
+
+#define Z_RUST_RATIONAL_P(zv) php_rust_rational_from_obj(Z_OBJ_P(zv))
+
+/* 创建对象：句柄在构造函数或运算结果中设置 */
+static zend_object *rust_rational_create_object(zend_class_entry *ce)
+{
+    php_rust_rational *intern = zend_object_alloc(sizeof(php_rust_rational), ce);
+
+    zend_object_std_init(&intern->std, ce);
+    object_properties_init(&intern->std, ce);
+    intern->std.handlers = &rust_rational_handlers;
+    intern->handle = NULL;
+
+    return &intern->std;
+}
+
+/* 销毁对象：释放 Rust 句柄 */
+static void rust_rational_free_obj(zend_object *object)
+{
+    php_rust_rational *intern = php_rust_rational_from_obj(object);
+
+    if (intern->handle != NULL) {
+        rust_rational_free(intern->handle);
+        intern->handle = NULL;
+    }
+
+    zend_object_std_dtor(&intern->std);
+}
+
+/* 克隆对象：复制 Rust 句柄 */
+static zend_object *rust_rational_clone_obj(zend_object *old_object)
+{
+    php_rust_rational *old_intern = php_rust_rational_from_obj(old_object);
+    zend_object *new_object = rust_rational_create_object(old_object->ce);
+    php_rust_rational *new_intern = php_rust_rational_from_obj(new_object);
+
+    zend_objects_clone_members(new_object, old_object);
+    rust_rational_clone(old_intern->handle, &new_intern->handle);
+
+    return new_object;
+}
+
+/* 获取当前对象的句柄，未初始化时抛出异常 */
+static rust_rational *rust_rational_this(zval *object)
+{
+    rust_rational *handle = Z_RUST_RATIONAL_P(object)->handle;
+
+    if (handle == NULL) {
+        zend_throw_error(NULL, "RustMath\\Rational object is not initialized");
+    }
+
+    return handle;
+}
+
+/* 用句柄创建新的 Rational 对象作为返回值，句柄的所有权转移给对象 */
+static void rust_rational_return(zval *return_value, rust_rational *handle)
+{
+    object_init_ex(return_value, rust_rational_ce);
+    Z_RUST_RATIONAL_P(return_value)->handle = handle;
+}
+
+/* 根据 Rust 返回的错误码返回新对象或抛出异常 */
+static void rust_rational_return_result(zval *return_value, math_error error, rust_rational *handle)
+{
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        return;
+    }
+
+    rust_rational_return(return_value, handle);
+}
+
+/**
+ * 把 Rational|int|string 转换为有理数句柄，不抛出异常
+ *
+ * 如果参数本身是 Rational 对象则直接借用它的句柄，*owned 为 false；
+ * 否则创建新句柄，*owned 为 true，调用者用完后需要释放
+ * 类型不支持时返回 NULL，字符串无效时返回 NULL 并通过 *error 返回错误码
+ */
+static rust_rational *rust_rational_try_operand(zval *value, bool *owned, math_error *error)
+{
+    rust_rational *handle = NULL;
+
+    *owned = false;
+    *error = MATH_ERROR_SUCCESS;
+
+    switch (Z_TYPE_P(value)) {
+        case IS_LONG:
+            rust_rational_new(Z_LVAL_P(value), 1, &handle);
+            *owned = true;
+            return handle;
+
+        case IS_STRING:
+            *error = rust_rational_from_string(Z_STRVAL_P(value), &handle);
+            *owned = handle != NULL;
+            return handle;
+
+        case IS_OBJECT:
+            if (instanceof_function(Z_OBJCE_P(value), rust_rational_ce)) {
+                return Z_RUST_RATIONAL_P(value)->handle;
+            }
+            break;
+
+        default:
+            break;
+    }
+
+    return NULL;
+}
+
+/**
+ * 把方法参数转换为有理数句柄，出错时抛出异常并返回 NULL
+ *
+ * 借用和释放规则与 rust_rational_try_operand 相同
+ */
+static rust_rational *rust_rational_operand(zval *value, uint32_t arg_num, bool *owned)
+{
+    math_error error;
+    rust_rational *handle = rust_rational_try_operand(value, owned, &error);
+
+    if (handle != NULL) {
+        return handle;
+    }
+
+    if (Z_TYPE_P(value) == IS_OBJECT && instanceof_function(Z_OBJCE_P(value), rust_rational_ce)) {
+        zend_throw_error(NULL, "RustMath\\Rational object is not initialized");
+    } else if (error == MATH_ERROR_INVALID_PARAMETER) {
+        zend_argument_value_error(arg_num, "must be a valid integer, fraction or decimal string");
+    } else if (error != MATH_ERROR_SUCCESS) {
+        /* 分母为 0 或超出 int 范围 */
+        rust_math_throw_error(error);
+    } else {
+        zend_argument_type_error(arg_num, "must be of type RustMath\\Rational|string|int, %s given",
+            zend_zval_type_name(value));
+    }
+
+    return NULL;
+}
+
+/* 释放 rust_rational_operand 创建的临时句柄 */
+static inline void rust_rational_release(rust_rational *handle, bool owned)
+{
+    if (owned && handle != NULL) {
+        rust_rational_free(handle);
+    }
+}
+
+/* 二元运算的公共实现：解析参数、调用 Rust 函数、返回新对象 */
+static void rust_rational_binary_op(INTERNAL_FUNCTION_PARAMETERS,
+    math_error (*op)(const rust_rational *, const rust_rational *, rust_rational **))
+{
+    zval *other;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_rational *operand = rust_rational_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    /* 除数为 0 时返回 MATH_ERROR_DIVISION_BY_ZERO，结果超出 int 范围时返回 MATH_ERROR_OVERFLOW */
+    rust_rational *result = NULL;
+    math_error error = op(self, operand, &result);
+    rust_rational_release(operand, owned);
+
+    rust_rational_return_result(return_value, error, result);
+}
+
+/* 一元运算的公共实现 */
+static void rust_rational_unary_op(INTERNAL_FUNCTION_PARAMETERS,
+    math_error (*op)(const rust_rational *, rust_rational **))
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_rational *result = NULL;
+    math_error error = op(self, &result);
+    rust_rational_return_result(return_value, error, result);
+}
+
+/* 运算符重载：$a + $b、$a - $b、$a * $b、$a / $b 和 $a ** $n（$n 必须是 int） */
+static zend_result rust_rational_do_operation(uint8_t opcode, zval *result, zval *op1, zval *op2)
+{
+    bool owned1 = false, owned2 = false;
+    math_error error;
+    rust_rational *a, *b = NULL;
+    rust_rational *value = NULL;
+
+    switch (opcode) {
+        case ZEND_ADD:
+        case ZEND_SUB:
+        case ZEND_MUL:
+        case ZEND_DIV:
+        case ZEND_POW:
+            break;
+        default:
+            return FAILURE;
+    }
+
+    /* 不支持的操作数类型返回 FAILURE，由 Zend 引擎抛出 "Unsupported operand types" */
+    a = rust_rational_try_operand(op1, &owned1, &error);
+    if (a == NULL) {
+        if (error != MATH_ERROR_SUCCESS) {
+            rust_math_throw_error(error);
+        }
+        return FAILURE;
+    }
+
+    if (opcode == ZEND_POW) {
+        if (Z_TYPE_P(op2) != IS_LONG) {
+            rust_rational_release(a, owned1);
+            return FAILURE;
+        }
+        error = rust_rational_pow(a, Z_LVAL_P(op2), &value);
+    } else {
+        b = rust_rational_try_operand(op2, &owned2, &error);
+        if (b == NULL) {
+            rust_rational_release(a, owned1);
+            if (error != MATH_ERROR_SUCCESS) {
+                rust_math_throw_error(error);
+            }
+            return FAILURE;
+        }
+
+        switch (opcode) {
+            case ZEND_ADD:
+                error = rust_rational_add(a, b, &value);
+                break;
+            case ZEND_SUB:
+                error = rust_rational_sub(a, b, &value);
+                break;
+            case ZEND_MUL:
+                error = rust_rational_mul(a, b, &value);
+                break;
+            default:
+                error = rust_rational_div(a, b, &value);
+                break;
+        }
+    }
+
+    rust_rational_release(a, owned1);
+    rust_rational_release(b, owned2);
+
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        return FAILURE;
+    }
+
+    /* $a += $b 时 result 和 op1 是同一个 zval，需要先释放旧值 */
+    if (result == op1) {
+        zval_ptr_dtor(result);
+    }
+    rust_rational_return(result, value);
+
+    return SUCCESS;
+}
+
+/* 比较对象：让 ==、<、> 等运算符按数值比较，另一个操作数可以是 int 或字符串 */
+static int rust_rational_compare_objects(zval *op1, zval *op2)
+{
+    bool owned1, owned2;
+    math_error error;
+
+    rust_rational *a = rust_rational_try_operand(op1, &owned1, &error);
+    rust_rational *b = rust_rational_try_operand(op2, &owned2, &error);
+
+    if (a == NULL || b == NULL) {
+        rust_rational_release(a, owned1);
+        rust_rational_release(b, owned2);
+        return zend_std_compare_objects(op1, op2);
+    }
+
+    int result = rust_rational_cmp(a, b);
+    rust_rational_release(a, owned1);
+    rust_rational_release(b, owned2);
+
+    return result;
+}
+
+/* 类型转换：支持 (float) 和 (string)，(int) 和 (bool) 按默认规则处理 */
+static zend_result rust_rational_cast_object(zend_object *object, zval *result, int type)
+{
+    rust_rational *handle = php_rust_rational_from_obj(object)->handle;
+
+    if (type == IS_DOUBLE && handle != NULL) {
+        ZVAL_DOUBLE(result, rust_rational_to_double(handle));
+        return SUCCESS;
+    }
+
+    return zend_std_cast_object_tostring(object, result, type);
+}
+
+/* 把有理数转换为 PHP 字符串 */
+static void rust_rational_return_string(zval *return_value, rust_rational *handle)
+{
+    rust_string_result result;
+
+    rust_rational_to_string(handle, &result);
+    RETVAL_STRINGL(result.data, result.length);
+    rust_free_string_result(result);
+}
+
+/* RustMath\Rational::__construct(int|string $value = 0, int $denominator = 1) */
+PHP_METHOD(RustMath_Rational, __construct)
+{
+    zend_string *str = NULL;
+    zend_long value = 0;
+    zend_long denominator = 1;
+    php_rust_rational *intern = Z_RUST_RATIONAL_P(ZEND_THIS);
+
+    ZEND_PARSE_PARAMETERS_START(0, 2)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_STR_OR_LONG(str, value)
+        Z_PARAM_LONG(denominator)
+    ZEND_PARSE_PARAMETERS_END();
+
+    if (denominator == 0) {
+        zend_argument_value_error(2, "must not be 0");
+        RETURN_THROWS();
+    }
+
+    rust_rational *handle = NULL;
+    math_error error;
+
+    if (str != NULL) {
+        error = rust_rational_from_string(ZSTR_VAL(str), &handle);
+        if (error == MATH_ERROR_INVALID_PARAMETER) {
+            zend_argument_value_error(1, "must be a valid integer, fraction or decimal string");
+            RETURN_THROWS();
+        }
+
+        /* 字符串再除以 $denominator */
+        if (error == MATH_ERROR_SUCCESS && denominator != 1) {
+            rust_rational *divisor = NULL;
+            rust_rational *quotient = NULL;
+            rust_rational_new(denominator, 1, &divisor);
+            error = rust_rational_div(handle, divisor, &quotient);
+            rust_rational_free(divisor);
+            rust_rational_free(handle);
+            handle = quotient;
+        }
+    } else {
+        error = rust_rational_new(value, denominator, &handle);
+    }
+
+    /* 分母为 0 或约分后超出 int 范围 */
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    if (intern->handle != NULL) {
+        rust_rational_free(intern->handle);
+    }
+    intern->handle = handle;
+}
+
+/* RustMath\Rational::fromFloat(float $value, int $maxDenominator = 1000000): Rational - 分母不超过上限的最佳近似 */
+PHP_METHOD(RustMath_Rational, fromFloat)
+{
+    double value;
+    zend_long max_denominator = 1000000;
+
+    ZEND_PARSE_PARAMETERS_START(1, 2)
+        Z_PARAM_DOUBLE(value)
+        Z_PARAM_OPTIONAL
+        Z_PARAM_LONG(max_denominator)
+    ZEND_PARSE_PARAMETERS_END();
+
+    if (max_denominator < 1) {
+        zend_argument_value_error(2, "must be greater than 0");
+        RETURN_THROWS();
+    }
+
+    /* NaN、无穷大或超出 int 范围时返回错误码 */
+    rust_rational *handle = NULL;
+    math_error error = rust_rational_from_double(value, max_denominator, &handle);
+    rust_rational_return_result(return_value, error, handle);
+}
+
+/* RustMath\Rational::fromContinuedFraction(array $terms): Rational - 由 [a0; a1, a2, ...] 计算 */
+PHP_METHOD(RustMath_Rational, fromContinuedFraction)
+{
+    HashTable *terms_ht;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ARRAY_HT(terms_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    uint32_t length = zend_hash_num_elements(terms_ht);
+    if (length == 0) {
+        zend_argument_value_error(1, "must not be empty");
+        RETURN_THROWS();
+    }
+
+    long *terms = safe_emalloc(length, sizeof(long), 0);
+
+    if (rust_math_copy_long_array(terms_ht, terms, 1)) {
+        /* a0 以外的项不大于 0 时返回 MATH_ERROR_INVALID_PARAMETER */
+        rust_rational *handle = NULL;
+        math_error error = rust_rational_from_continued_fraction(terms, length, &handle);
+        if (error == MATH_ERROR_INVALID_PARAMETER) {
+            zend_argument_value_error(1, "must contain only values greater than 0 after the first element");
+        } else {
+            rust_rational_return_result(return_value, error, handle);
+        }
+    }
+
+    efree(terms);
+}
+
+/* RustMath\Rational::add(Rational|int|string $other): Rational */
+PHP_METHOD(RustMath_Rational, add)
+{
+    rust_rational_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_rational_add);
+}
+
+/* RustMath\Rational::sub(Rational|int|string $other): Rational */
+PHP_METHOD(RustMath_Rational, sub)
+{
+    rust_rational_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_rational_sub);
+}
+
+/* RustMath\Rational::mul(Rational|int|string $other): Rational */
+PHP_METHOD(RustMath_Rational, mul)
+{
+    rust_rational_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_rational_mul);
+}
+
+/* RustMath\Rational::div(Rational|int|string $divisor): Rational */
+PHP_METHOD(RustMath_Rational, div)
+{
+    rust_rational_binary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_rational_div);
+}
+
+/* RustMath\Rational::pow(int $exponent): Rational - 指数可以为负数 */
+PHP_METHOD(RustMath_Rational, pow)
+{
+    zend_long exponent;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(exponent)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_rational *result = NULL;
+    math_error error = rust_rational_pow(self, exponent, &result);
+    rust_rational_return_result(return_value, error, result);
+}
+
+/* RustMath\Rational::neg(): Rational */
+PHP_METHOD(RustMath_Rational, neg)
+{
+    rust_rational_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_rational_neg);
+}
+
+/* RustMath\Rational::abs(): Rational */
+PHP_METHOD(RustMath_Rational, abs)
+{
+    rust_rational_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_rational_abs);
+}
+
+/* RustMath\Rational::reciprocal(): Rational - 值为 0 时抛出 DivisionByZeroError */
+PHP_METHOD(RustMath_Rational, reciprocal)
+{
+    rust_rational_unary_op(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_rational_reciprocal);
+}
+
+/* RustMath\Rational::limitDenominator(int $maxDenominator): Rational - 分母不超过上限的最佳近似 */
+PHP_METHOD(RustMath_Rational, limitDenominator)
+{
+    zend_long max_denominator;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(max_denominator)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (max_denominator < 1) {
+        zend_argument_value_error(1, "must be greater than 0");
+        RETURN_THROWS();
+    }
+
+    rust_rational *result = NULL;
+    math_error error = rust_rational_limit_denominator(self, max_denominator, &result);
+    rust_rational_return_result(return_value, error, result);
+}
+
+/* RustMath\Rational::compare(Rational|int|string $other): int - 返回 -1、0 或 1 */
+PHP_METHOD(RustMath_Rational, compare)
+{
+    zval *other;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_rational *operand = rust_rational_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    int result = rust_rational_cmp(self, operand);
+    rust_rational_release(operand, owned);
+
+    RETURN_LONG(result);
+}
+
+/* RustMath\Rational::equals(Rational|int|string $other): bool */
+PHP_METHOD(RustMath_Rational, equals)
+{
+    zval *other;
+    bool owned;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_ZVAL(other)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_rational *operand = rust_rational_operand(other, 1, &owned);
+    if (operand == NULL) {
+        RETURN_THROWS();
+    }
+
+    int result = rust_rational_cmp(self, operand);
+    rust_rational_release(operand, owned);
+
+    RETURN_BOOL(result == 0);
+}
+
+/* RustMath\Rational::sign(): int - 返回 -1、0 或 1 */
+PHP_METHOD(RustMath_Rational, sign)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_rational_sign(self));
+}
+
+/* RustMath\Rational::numerator(): int - 最简形式的分子，带有符号 */
+PHP_METHOD(RustMath_Rational, numerator)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_rational_numerator(self));
+}
+
+/* RustMath\Rational::denominator(): int - 最简形式的分母，总是大于 0 */
+PHP_METHOD(RustMath_Rational, denominator)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_rational_denominator(self));
+}
+
+/* RustMath\Rational::floor(): int */
+PHP_METHOD(RustMath_Rational, floor)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_rational_floor(self));
+}
+
+/* RustMath\Rational::ceil(): int */
+PHP_METHOD(RustMath_Rational, ceil)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_rational_ceil(self));
+}
+
+/* RustMath\Rational::toFloat(): float */
+PHP_METHOD(RustMath_Rational, toFloat)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_DOUBLE(rust_rational_to_double(self));
+}
+
+/* RustMath\Rational::continuedFraction(): array - 连分数展开 [a0, a1, a2, ...] */
+PHP_METHOD(RustMath_Rational, continuedFraction)
+{
+    rust_integer_array_result result;
+
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_rational_continued_fraction(self, &result);
+    rust_math_return_integer_array(return_value, result);
+}
+
+/* RustMath\Rational::__toString(): string - "n/d"，整数为 "n" */
+PHP_METHOD(RustMath_Rational, __toString)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_rational *self = rust_rational_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_rational_return_string(return_value, self);
+}
+
+/* 参数信息 */
+ZEND_BEGIN_ARG_INFO_EX(arginfo_class_RustMath_Rational___construct, 0, 0, 0)
+    ZEND_ARG_TYPE_MASK(0, value, MAY_BE_LONG|MAY_BE_STRING, "0")
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, denominator, IS_LONG, 0, "1")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Rational_fromFloat, 0, 1, RustMath\\Rational, 0)
+    ZEND_ARG_TYPE_INFO(0, value, IS_DOUBLE, 0)
+    ZEND_ARG_TYPE_INFO_WITH_DEFAULT_VALUE(0, maxDenominator, IS_LONG, 0, "1000000")
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Rational_fromContinuedFraction, 0, 1, RustMath\\Rational, 0)
+    ZEND_ARG_TYPE_INFO(0, terms, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Rational_add, 0, 1, RustMath\\Rational, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\Rational, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Rational_sub arginfo_class_RustMath_Rational_add
+#define arginfo_class_RustMath_Rational_mul arginfo_class_RustMath_Rational_add
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Rational_div, 0, 1, RustMath\\Rational, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, divisor, RustMath\\Rational, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Rational_pow, 0, 1, RustMath\\Rational, 0)
+    ZEND_ARG_TYPE_INFO(0, exponent, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Rational_neg, 0, 0, RustMath\\Rational, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Rational_abs arginfo_class_RustMath_Rational_neg
+#define arginfo_class_RustMath_Rational_reciprocal arginfo_class_RustMath_Rational_neg
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Rational_limitDenominator, 0, 1, RustMath\\Rational, 0)
+    ZEND_ARG_TYPE_INFO(0, maxDenominator, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Rational_compare, 0, 1, IS_LONG, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\Rational, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Rational_equals, 0, 1, _IS_BOOL, 0)
+    ZEND_ARG_OBJ_TYPE_MASK(0, other, RustMath\\Rational, MAY_BE_LONG|MAY_BE_STRING, NULL)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Rational_sign, 0, 0, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Rational_numerator arginfo_class_RustMath_Rational_sign
+#define arginfo_class_RustMath_Rational_denominator arginfo_class_RustMath_Rational_sign
+#define arginfo_class_RustMath_Rational_floor arginfo_class_RustMath_Rational_sign
+#define arginfo_class_RustMath_Rational_ceil arginfo_class_RustMath_Rational_sign
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Rational_toFloat, 0, 0, IS_DOUBLE, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Rational_continuedFraction, 0, 0, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Rational___toString, 0, 0, IS_STRING, 0)
+ZEND_END_ARG_INFO()
+
+/* 方法列表 */
+static const zend_function_entry rust_rational_methods[] = {
+    PHP_ME(RustMath_Rational, __construct, arginfo_class_RustMath_Rational___construct, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, fromFloat, arginfo_class_RustMath_Rational_fromFloat, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Rational, fromContinuedFraction, arginfo_class_RustMath_Rational_fromContinuedFraction, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Rational, add, arginfo_class_RustMath_Rational_add, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, sub, arginfo_class_RustMath_Rational_sub, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, mul, arginfo_class_RustMath_Rational_mul, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, div, arginfo_class_RustMath_Rational_div, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, pow, arginfo_class_RustMath_Rational_pow, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, neg, arginfo_class_RustMath_Rational_neg, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, abs, arginfo_class_RustMath_Rational_abs, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, reciprocal, arginfo_class_RustMath_Rational_reciprocal, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, limitDenominator, arginfo_class_RustMath_Rational_limitDenominator, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, compare, arginfo_class_RustMath_Rational_compare, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, equals, arginfo_class_RustMath_Rational_equals, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, sign, arginfo_class_RustMath_Rational_sign, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, numerator, arginfo_class_RustMath_Rational_numerator, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, denominator, arginfo_class_RustMath_Rational_denominator, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, floor, arginfo_class_RustMath_Rational_floor, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, ceil, arginfo_class_RustMath_Rational_ceil, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, toFloat, arginfo_class_RustMath_Rational_toFloat, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, continuedFraction, arginfo_class_RustMath_Rational_continuedFraction, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Rational, __toString, arginfo_class_RustMath_Rational___toString, ZEND_ACC_PUBLIC)
+    PHP_FE_END
+};
+
+/* 注册 RustMath\Rational 类，在 MINIT 中调用 */
+void rust_math_register_rational_class(void)
+{
+    zend_class_entry ce;
+
+    INIT_NS_CLASS_ENTRY(ce, "RustMath", "Rational", rust_rational_methods);
+    rust_rational_ce = zend_register_internal_class(&ce);
+    rust_rational_ce->ce_flags |= ZEND_ACC_FINAL;
+    rust_rational_ce->create_object = rust_rational_create_object;
+    zend_class_implements(rust_rational_ce, 1, zend_ce_stringable);
+
+    memcpy(&rust_rational_handlers, &std_object_handlers, sizeof(zend_object_handlers));
+    rust_rational_handlers.offset = XtOffsetOf(php_rust_rational, std);
+    rust_rational_handlers.free_obj = rust_rational_free_obj;
+    rust_rational_handlers.clone_obj = rust_rational_clone_obj;
+    rust_rational_handlers.do_operation = rust_rational_do_operation;
+    rust_rational_handlers.compare = rust_rational_compare_objects;
+    rust_rational_handlers.cast_object = rust_rational_cast_object;
+}
@@ -0,0 +1,375 @@
+/**
+ * PHP Rust 数学扩展 - RustMath\Sequence 类
+ *
+ * 这个文件把 Rust 的整数序列句柄包装成实现 Iterator 的 PHP 对象
+ * 序列按需逐项生成，可以直接用于 foreach；无限序列需要调用者自己决定何时停止
+ * 当前项超出 int 范围时 current() 抛出 RustMath\OverflowError，不会静默结束
+ */
+
+#include "php.h"
+#include "zend_exceptions.h"
+#include "zend_interfaces.h"
+#include "php_rust_math.h"
+
+/* take() 一次允许取出的最大项数，与 math::sequence::SEQUENCE_TAKE_LIMIT 一致 */
+#define RUST_SEQUENCE_TAKE_LIMIT (1 << 20)
+
+/* 类入口和对象处理器 */
+zend_class_entry *rust_sequence_ce;
+static zend_object_handlers rust_sequence_handlers;
+
+/* PHP 对象结构：Rust 句柄 + 标准 zend_object（必须放在最后） */
+typedef struct {
+    rust_sequence *handle;
+    zend_object std;
+} php_rust_sequence;
+
+static inline php_rust_sequence *php_rust_sequence_from_obj(zend_object *obj)
+{
+    return (php_rust_sequence *)((char *)obj - XtOffsetOf(php_rust_sequence, std));
+}
+
+#define Z_RUST_SEQUENCE_P(zv) php_rust_sequence_from_obj(Z_OBJ_P(zv))
+
+/* 创建对象：句柄在静态工厂方法中设置 */
+static zend_object *rust_sequence_create_object(zend_class_entry *ce)
+{
+    php_rust_sequence *intern = zend_object_alloc(sizeof(php_rust_sequence), ce);
+
+    zend_object_std_init(&intern->std, ce);
+    object_properties_init(&intern->std, ce);
+    intern->std.handlers = &rust_sequence_handlers;
+    intern->handle = NULL;
+
+    return &intern->std;
+}
+
+/* 销毁对象：释放 Rust 句柄 */
+static void rust_sequence_free_obj(zend_object *object)
+{
+    php_rust_sequence *intern = php_rust_sequence_from_obj(object);
+
+    if (intern->handle != NULL) {
+        rust_sequence_free(intern->handle);
+        intern->handle = NULL;
+    }
+
+    zend_object_std_dtor(&intern->std);
+}
+
+/* 克隆对象：复制 Rust 句柄，副本从同一项开始独立前进 */
+static zend_object *rust_sequence_clone_obj(zend_object *old_object)
+{
+    php_rust_sequence *old_intern = php_rust_sequence_from_obj(old_object);
+    zend_object *new_object = rust_sequence_create_object(old_object->ce);
+    php_rust_sequence *new_intern = php_rust_sequence_from_obj(new_object);
+
+    zend_objects_clone_members(new_object, old_object);
+    rust_sequence_clone(old_intern->handle, &new_intern->handle);
+
+    return new_object;
+}
+
+/* 获取当前对象的句柄，未初始化时抛出异常 */
+static rust_sequence *rust_sequence_this(zval *object)
+{
+    rust_sequence *handle = Z_RUST_SEQUENCE_P(object)->handle;
+
+    if (handle == NULL) {
+        zend_throw_error(NULL, "RustMath\\Sequence object is not initialized");
+    }
+
+    return handle;
+}
+
+/* 根据 Rust 返回的错误码返回新的 Sequence 对象或抛出异常，句柄的所有权转移给对象 */
+static void rust_sequence_return_result(zval *return_value, math_error error, rust_sequence *handle)
+{
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        return;
+    }
+
+    object_init_ex(return_value, rust_sequence_ce);
+    Z_RUST_SEQUENCE_P(return_value)->handle = handle;
+}
+
+/* 无参数工厂方法的公共实现 */
+static void rust_sequence_factory(INTERNAL_FUNCTION_PARAMETERS, math_error (*create)(rust_sequence **))
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_sequence *handle = NULL;
+    math_error error = create(&handle);
+    rust_sequence_return_result(return_value, error, handle);
+}
+
+/* RustMath\Sequence::__construct() - 私有，只能通过静态工厂方法创建 */
+PHP_METHOD(RustMath_Sequence, __construct)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+}
+
+/* RustMath\Sequence::fibonacci(): Sequence - 0, 1, 1, 2, 3, 5, ... */
+PHP_METHOD(RustMath_Sequence, fibonacci)
+{
+    rust_sequence_factory(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_sequence_fibonacci);
+}
+
+/* RustMath\Sequence::lucas(): Sequence - 2, 1, 3, 4, 7, 11, ... */
+PHP_METHOD(RustMath_Sequence, lucas)
+{
+    rust_sequence_factory(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_sequence_lucas);
+}
+
+/* RustMath\Sequence::catalan(): Sequence - 1, 1, 2, 5, 14, 42, ... */
+PHP_METHOD(RustMath_Sequence, catalan)
+{
+    rust_sequence_factory(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_sequence_catalan);
+}
+
+/* RustMath\Sequence::triangular(): Sequence - 1, 3, 6, 10, 15, ... */
+PHP_METHOD(RustMath_Sequence, triangular)
+{
+    rust_sequence_factory(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_sequence_triangular);
+}
+
+/* RustMath\Sequence::primes(): Sequence - 2, 3, 5, 7, 11, ... */
+PHP_METHOD(RustMath_Sequence, primes)
+{
+    rust_sequence_factory(INTERNAL_FUNCTION_PARAM_PASSTHRU, rust_sequence_primes);
+}
+
+/* RustMath\Sequence::collatz(int $start): Sequence - 第一次到达 1 后结束 */
+PHP_METHOD(RustMath_Sequence, collatz)
+{
+    zend_long start;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(start)
+    ZEND_PARSE_PARAMETERS_END();
+
+    /* start 为负数或 0 时返回错误码 */
+    rust_sequence *handle = NULL;
+    math_error error = rust_sequence_collatz(start, &handle);
+    rust_sequence_return_result(return_value, error, handle);
+}
+
+/* RustMath\Sequence::linearRecurrence(array $coefficients, array $initial): Sequence */
+PHP_METHOD(RustMath_Sequence, linearRecurrence)
+{
+    HashTable *coefficients_ht, *initial_ht;
+
+    ZEND_PARSE_PARAMETERS_START(2, 2)
+        Z_PARAM_ARRAY_HT(coefficients_ht)
+        Z_PARAM_ARRAY_HT(initial_ht)
+    ZEND_PARSE_PARAMETERS_END();
+
+    uint32_t length = zend_hash_num_elements(coefficients_ht);
+    if (length == 0) {
+        zend_argument_value_error(1, "must not be empty");
+        RETURN_THROWS();
+    }
+    if (zend_hash_num_elements(initial_ht) != length) {
+        zend_argument_value_error(2, "must have the same number of elements as $coefficients");
+        RETURN_THROWS();
+    }
+
+    long *coefficients = safe_emalloc(length, sizeof(long), 0);
+    long *initial = safe_emalloc(length, sizeof(long), 0);
+
+    if (rust_math_copy_long_array(coefficients_ht, coefficients, 1) && rust_math_copy_long_array(initial_ht, initial, 2)) {
+        rust_sequence *handle = NULL;
+        math_error error = rust_sequence_linear(coefficients, initial, length, &handle);
+        rust_sequence_return_result(return_value, error, handle);
+    }
+
+    efree(coefficients);
+    efree(initial);
+}
+
+/* RustMath\Sequence::current(): ?int - 序列结束后返回 null，当前项超出 int 范围时抛出异常 */
+PHP_METHOD(RustMath_Sequence, current)
+{
+    long result;
+
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_sequence *self = rust_sequence_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (!rust_sequence_valid(self)) {
+        RETURN_NULL();
+    }
+
+    math_error error = rust_sequence_current(self, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(result);
+}
+
+/* RustMath\Sequence::key(): int - 当前项的下标，从 0 开始 */
+PHP_METHOD(RustMath_Sequence, key)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_sequence *self = rust_sequence_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_LONG(rust_sequence_key(self));
+}
+
+/* RustMath\Sequence::next(): void */
+PHP_METHOD(RustMath_Sequence, next)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_sequence *self = rust_sequence_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_sequence_next(self);
+}
+
+/* RustMath\Sequence::rewind(): void - 回到第 0 项 */
+PHP_METHOD(RustMath_Sequence, rewind)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_sequence *self = rust_sequence_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    rust_sequence_rewind(self);
+}
+
+/* RustMath\Sequence::valid(): bool - 有限序列结束后返回 false */
+PHP_METHOD(RustMath_Sequence, valid)
+{
+    ZEND_PARSE_PARAMETERS_NONE();
+
+    rust_sequence *self = rust_sequence_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    RETURN_BOOL(rust_sequence_valid(self));
+}
+
+/* RustMath\Sequence::take(int $count): array - 从当前项开始取出最多 $count 项并前进 */
+PHP_METHOD(RustMath_Sequence, take)
+{
+    zend_long count;
+    rust_integer_array_result result;
+
+    ZEND_PARSE_PARAMETERS_START(1, 1)
+        Z_PARAM_LONG(count)
+    ZEND_PARSE_PARAMETERS_END();
+
+    rust_sequence *self = rust_sequence_this(ZEND_THIS);
+    if (self == NULL) {
+        RETURN_THROWS();
+    }
+
+    if (count < 0) {
+        zend_argument_value_error(1, "must be greater than or equal to 0");
+        RETURN_THROWS();
+    }
+    if (count > RUST_SEQUENCE_TAKE_LIMIT) {
+        zend_argument_value_error(1, "must be less than or equal to %d", RUST_SEQUENCE_TAKE_LIMIT);
+        RETURN_THROWS();
+    }
+
+    /* 需要取出的某一项超出 int 范围时返回错误码 */
+    math_error error = rust_sequence_take(self, count, &result);
+    if (error != MATH_ERROR_SUCCESS) {
+        rust_math_throw_error(error);
+        RETURN_THROWS();
+    }
+
+    rust_math_return_integer_array(return_value, result);
+}
+
+/* 参数信息 */
+ZEND_BEGIN_ARG_INFO_EX(arginfo_class_RustMath_Sequence___construct, 0, 0, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Sequence_fibonacci, 0, 0, RustMath\\Sequence, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Sequence_lucas arginfo_class_RustMath_Sequence_fibonacci
+#define arginfo_class_RustMath_Sequence_catalan arginfo_class_RustMath_Sequence_fibonacci
+#define arginfo_class_RustMath_Sequence_triangular arginfo_class_RustMath_Sequence_fibonacci
+#define arginfo_class_RustMath_Sequence_primes arginfo_class_RustMath_Sequence_fibonacci
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Sequence_collatz, 0, 1, RustMath\\Sequence, 0)
+    ZEND_ARG_TYPE_INFO(0, start, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_OBJ_INFO_EX(arginfo_class_RustMath_Sequence_linearRecurrence, 0, 2, RustMath\\Sequence, 0)
+    ZEND_ARG_TYPE_INFO(0, coefficients, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, initial, IS_ARRAY, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Sequence_current, 0, 0, IS_LONG, 1)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Sequence_key, 0, 0, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Sequence_next, 0, 0, IS_VOID, 0)
+ZEND_END_ARG_INFO()
+
+#define arginfo_class_RustMath_Sequence_rewind arginfo_class_RustMath_Sequence_next
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Sequence_valid, 0, 0, _IS_BOOL, 0)
+ZEND_END_ARG_INFO()
+
+ZEND_BEGIN_ARG_WITH_RETURN_TYPE_INFO_EX(arginfo_class_RustMath_Sequence_take, 0, 1, IS_ARRAY, 0)
+    ZEND_ARG_TYPE_INFO(0, count, IS_LONG, 0)
+ZEND_END_ARG_INFO()
+
+/* 方法列表 */
+static const zend_function_entry rust_sequence_methods[] = {
+    PHP_ME(RustMath_Sequence, __construct, arginfo_class_RustMath_Sequence___construct, ZEND_ACC_PRIVATE)
+    PHP_ME(RustMath_Sequence, fibonacci, arginfo_class_RustMath_Sequence_fibonacci, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Sequence, lucas, arginfo_class_RustMath_Sequence_lucas, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Sequence, catalan, arginfo_class_RustMath_Sequence_catalan, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Sequence, triangular, arginfo_class_RustMath_Sequence_triangular, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Sequence, primes, arginfo_class_RustMath_Sequence_primes, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Sequence, collatz, arginfo_class_RustMath_Sequence_collatz, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Sequence, linearRecurrence, arginfo_class_RustMath_Sequence_linearRecurrence, ZEND_ACC_PUBLIC|ZEND_ACC_STATIC)
+    PHP_ME(RustMath_Sequence, current, arginfo_class_RustMath_Sequence_current, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Sequence, key, arginfo_class_RustMath_Sequence_key, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Sequence, next, arginfo_class_RustMath_Sequence_next, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Sequence, rewind, arginfo_class_RustMath_Sequence_rewind, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Sequence, valid, arginfo_class_RustMath_Sequence_valid, ZEND_ACC_PUBLIC)
+    PHP_ME(RustMath_Sequence, take, arginfo_class_RustMath_Sequence_take, ZEND_ACC_PUBLIC)
+    PHP_FE_END
+};
+
+/* 注册 RustMath\Sequence 类，在 MINIT 中调用 */
+void rust_math_register_sequence_class(void)
+{
+    zend_class_entry ce;
+
+    INIT_NS_CLASS_ENTRY(ce, "RustMath", "Sequence", rust_sequence_methods);
+    rust_sequence_ce = zend_register_internal_class(&ce);
+    rust_sequence_ce->ce_flags |= ZEND_ACC_FINAL;
+    rust_sequence_ce->create_object = rust_sequence_create_object;
+    zend_class_implements(rust_sequence_ce, 1, zend_ce_iterator);
+
+    memcpy(&rust_sequence_handlers, &std_object_handlers, sizeof(zend_object_handlers));
+    rust_sequence_handlers.offset = XtOffsetOf(php_rust_sequence, std);
+    rust_sequence_handlers.free_obj = rust_sequence_free_obj;
+    rust_sequence_handlers.clone_obj = rust_sequence_clone_obj;
+}
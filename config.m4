@@ -16,7 +16,7 @@ if test "$PHP_RUST_MATH" != "no"; then
     dnl 检查 C++ 编译器（用于链接）
     AC_PROG_CXX
     
-    dnl 构建 Rust 静态库
+    dnl 构建 Rust 静态库（build.rs 同时会在 c_src/ 中生成头文件和函数包装）
     echo "构建 Rust 静态库..."
     cargo build --release
     if test $? -ne 0; then
@@ -48,7 +48,7 @@ if test "$PHP_RUST_MATH" != "no"; then
     PHP_ADD_INCLUDE([$ext_srcdir/c_src])
     
     dnl 编译 C 源文件
-    PHP_NEW_EXTENSION(rust_math, c_src/php_rust_math.c, $ext_shared)
+    PHP_NEW_EXTENSION(rust_math, c_src/php_rust_math.c c_src/php_rust_math_generated.c c_src/rust_math_exceptions.c c_src/rust_math_bigint.c c_src/rust_math_sequence.c c_src/rust_math_enumeration.c c_src/rust_math_rational.c c_src/rust_math_decimal.c c_src/rust_math_complex.c c_src/rust_math_matrix.c, $ext_shared)
     
     dnl 链接 Rust 静态库
     RUST_MATH_SHARED_LIBADD="${RUST_MATH_SHARED_LIBADD} ${RUST_LIB_FILE}"
@@ -75,19 +75,19 @@ echo "   - 性能提升: " . round($php_time / $rust_time, 1) . "x\n\n";
 // 示例 6：错误处理
 echo "6. 错误处理示例:\n";
 
-// 测试无效输入
+// 测试无效输入：出错时抛出实现了 RustMath\MathException 的异常，code 是 Rust 的错误码
 try {
     $result = rust_factorial(-1);
     echo "   负数阶乘结果: {$result}\n";
-} catch (Exception $e) {
-    echo "   负数阶乘错误: " . $e->getMessage() . "\n";
+} catch (RustMath\MathException $e) {
+    echo "   负数阶乘错误: " . get_class($e) . " (" . $e->getCode() . "): " . $e->getMessage() . "\n";
 }
 
 try {
     $result = rust_fibonacci(-5);
-    echo "   负数斐波那契结果: " . (is_array($result) ? "[" . implode(", ", $result) . "]" : $result) . "\n";
-} catch (Exception $e) {
-    echo "   负数斐波那契错误: " . $e->getMessage() . "\n";
+    echo "   负数斐波那契结果: [" . implode(", ", $result) . "]\n";
+} catch (RustMath\MathException $e) {
+    echo "   负数斐波那契错误: " . get_class($e) . " (" . $e->getCode() . "): " . $e->getMessage() . "\n";
 }
 
 echo "\n=== 示例完成 ===\n";
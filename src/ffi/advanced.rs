@@ -0,0 +1,153 @@
+//! 高级数学运算接口
+//! 
+//! 导出 `math::advanced` 中的函数，所有函数都接收 c_double，结果通过 result 指针返回
+//! 结果为 NaN 时返回 MathError::NotANumber（IEEE 浮点模式下直接返回 NaN）
+
+use std::os::raw::c_double;
+use crate::ffi::write_result;
+use crate::math::advanced;
+use crate::types::MathError;
+use crate::utils::check_float;
+
+/// 导出函数：平方根
+/// 
+/// @php rust_sqrt(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sqrt_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(advanced::sqrt(x), result)
+}
+
+/// 导出函数：立方根
+/// 
+/// @php rust_cbrt(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_cbrt_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(advanced::cbrt(x)), result)
+}
+
+/// 导出函数：自然对数
+/// 
+/// @php rust_ln(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_ln_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(advanced::ln(x), result)
+}
+
+/// 导出函数：常用对数
+/// 
+/// @php rust_log10(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_log10_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(advanced::log10(x), result)
+}
+
+/// 导出函数：正弦
+/// 
+/// @php rust_sin(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sin_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(advanced::sin(x)), result)
+}
+
+/// 导出函数：余弦
+/// 
+/// @php rust_cos(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_cos_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(advanced::cos(x)), result)
+}
+
+/// 导出函数：正切
+/// 
+/// @php rust_tan(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_tan_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(advanced::tan(x)), result)
+}
+
+/// 导出函数：反正弦
+/// 
+/// @php rust_asin(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_asin_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(advanced::asin(x), result)
+}
+
+/// 导出函数：反余弦
+/// 
+/// @php rust_acos(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_acos_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(advanced::acos(x), result)
+}
+
+/// 导出函数：反正切
+/// 
+/// @php rust_atan(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_atan_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(advanced::atan(x)), result)
+}
+
+/// 导出函数：双曲正弦
+/// 
+/// @php rust_sinh(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sinh_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(advanced::sinh(x)), result)
+}
+
+/// 导出函数：双曲余弦
+/// 
+/// @php rust_cosh(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_cosh_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(advanced::cosh(x)), result)
+}
+
+/// 导出函数：双曲正切
+/// 
+/// @php rust_tanh(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_tanh_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(advanced::tanh(x)), result)
+}
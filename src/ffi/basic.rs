@@ -0,0 +1,155 @@
+//! 基本数学运算接口
+//! 
+//! 导出 `math::basic` 中的函数
+//! 
+//! 所有函数都通过 result 指针返回结果，返回值是错误码
+//! 浮点函数的结果为 NaN 时返回 MathError::NotANumber（IEEE 浮点模式下直接返回 NaN）
+
+use std::os::raw::{c_double, c_long};
+use crate::ffi::{write_result, write_value};
+use crate::math::basic;
+use crate::types::MathError;
+use crate::utils::check_float;
+
+/// 导出函数：整数减法
+/// 
+/// @php rust_subtract_int(int $a, int $b): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_subtract_int_impl(a: c_long, b: c_long, result: *mut c_long) -> MathError {
+    write_result(basic::subtract_integers(a, b), result)
+}
+
+/// 导出函数：整数乘法
+/// 
+/// @php rust_multiply_int(int $a, int $b): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_multiply_int_impl(a: c_long, b: c_long, result: *mut c_long) -> MathError {
+    write_result(basic::multiply_integers(a, b), result)
+}
+
+/// 导出函数：整数除法（商向零取整）
+/// 
+/// @php rust_divide_int(int $a, int $b): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_divide_int_impl(a: c_long, b: c_long, result: *mut c_long) -> MathError {
+    write_result(basic::divide_integers(a, b), result)
+}
+
+/// 导出函数：整数取模
+/// 
+/// @php rust_modulo_int(int $a, int $b): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_modulo_int_impl(a: c_long, b: c_long, result: *mut c_long) -> MathError {
+    write_result(basic::modulo_integers(a, b), result)
+}
+
+/// 导出函数：整数绝对值
+/// 
+/// @php rust_abs_int(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_abs_int_impl(n: c_long, result: *mut c_long) -> MathError {
+    write_result(basic::abs_integer(n), result)
+}
+
+/// 导出函数：两个整数的最大值
+/// 
+/// @php rust_max_int(int $a, int $b): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_max_int_impl(a: c_long, b: c_long, result: *mut c_long) -> MathError {
+    write_value(basic::max_integers(a, b), result)
+}
+
+/// 导出函数：两个整数的最小值
+/// 
+/// @php rust_min_int(int $a, int $b): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_min_int_impl(a: c_long, b: c_long, result: *mut c_long) -> MathError {
+    write_value(basic::min_integers(a, b), result)
+}
+
+/// 导出函数：浮点数加法
+/// 
+/// @php rust_add_float(float $a, float $b): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_add_float_impl(a: c_double, b: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(basic::add_floats(a, b)), result)
+}
+
+/// 导出函数：浮点数减法
+/// 
+/// @php rust_subtract_float(float $a, float $b): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_subtract_float_impl(a: c_double, b: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(basic::subtract_floats(a, b)), result)
+}
+
+/// 导出函数：浮点数除法
+/// 
+/// @php rust_divide_float(float $a, float $b): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_divide_float_impl(a: c_double, b: c_double, result: *mut c_double) -> MathError {
+    write_result(basic::divide_floats(a, b), result)
+}
+
+/// 导出函数：浮点数绝对值
+/// 
+/// @php rust_abs_float(float $n): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_abs_float_impl(n: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(basic::abs_float(n)), result)
+}
+
+/// 导出函数：两个浮点数的最大值
+/// 
+/// @php rust_max_float(float $a, float $b): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_max_float_impl(a: c_double, b: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(basic::max_floats(a, b)), result)
+}
+
+/// 导出函数：两个浮点数的最小值
+/// 
+/// @php rust_min_float(float $a, float $b): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_min_float_impl(a: c_double, b: c_double, result: *mut c_double) -> MathError {
+    write_result(check_float(basic::min_floats(a, b)), result)
+}
@@ -0,0 +1,142 @@
+//! 贝塞尔函数接口
+//! 
+//! 导出 `math::bessel` 中的函数，结果通过 result 指针返回
+//! 阶数的绝对值超过 10000 时返回 MathError::InvalidParameter；
+//! Y 和 K 的参数必须大于 0，否则返回 MathError::DomainError
+
+use std::os::raw::{c_double, c_long};
+use crate::ffi::write_result;
+use crate::math::bessel;
+use crate::types::MathError;
+
+/// 导出函数：零阶第一类贝塞尔函数 J0
+/// 
+/// @php rust_bessel_j0(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_j0_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_j0(x), result)
+}
+
+/// 导出函数：一阶第一类贝塞尔函数 J1
+/// 
+/// @php rust_bessel_j1(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_j1_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_j1(x), result)
+}
+
+/// 导出函数：n 阶第一类贝塞尔函数 Jn
+/// 
+/// @php rust_bessel_jn(int $n, float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_jn_impl(n: c_long, x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_jn(n, x), result)
+}
+
+/// 导出函数：零阶第二类贝塞尔函数 Y0
+/// 
+/// @php rust_bessel_y0(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_y0_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_y0(x), result)
+}
+
+/// 导出函数：一阶第二类贝塞尔函数 Y1
+/// 
+/// @php rust_bessel_y1(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_y1_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_y1(x), result)
+}
+
+/// 导出函数：n 阶第二类贝塞尔函数 Yn
+/// 
+/// @php rust_bessel_yn(int $n, float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_yn_impl(n: c_long, x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_yn(n, x), result)
+}
+
+/// 导出函数：零阶修正的第一类贝塞尔函数 I0
+/// 
+/// @php rust_bessel_i0(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_i0_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_i0(x), result)
+}
+
+/// 导出函数：一阶修正的第一类贝塞尔函数 I1
+/// 
+/// @php rust_bessel_i1(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_i1_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_i1(x), result)
+}
+
+/// 导出函数：n 阶修正的第一类贝塞尔函数 In
+/// 
+/// @php rust_bessel_in(int $n, float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_in_impl(n: c_long, x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_in(n, x), result)
+}
+
+/// 导出函数：零阶修正的第二类贝塞尔函数 K0
+/// 
+/// @php rust_bessel_k0(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_k0_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_k0(x), result)
+}
+
+/// 导出函数：一阶修正的第二类贝塞尔函数 K1
+/// 
+/// @php rust_bessel_k1(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_k1_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_k1(x), result)
+}
+
+/// 导出函数：n 阶修正的第二类贝塞尔函数 Kn
+/// 
+/// @php rust_bessel_kn(int $n, float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bessel_kn_impl(n: c_long, x: c_double, result: *mut c_double) -> MathError {
+    write_result(bessel::bessel_kn(n, x), result)
+}
@@ -0,0 +1,300 @@
+//! 大整数对象接口
+//! 
+//! 为 C 代码提供 `BigInt` 的句柄操作，PHP 层用它实现 `RustMath\BigInt` 类
+//! 
+//! 创建新句柄的函数返回错误码，句柄通过最后的 result 指针返回，出错时写入空指针；
+//! 返回的非空句柄都需要调用 `rust_bigint_free` 释放
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_long};
+use std::ptr;
+use crate::ffi::{empty_string_result, string_result, write_value};
+use crate::math::bigint::BigInt;
+use crate::types::{MathError, MathResult, RustStringResult};
+
+/// 把运算结果放到堆上并通过 out 返回句柄，出错时写入空指针
+/// 
+/// # Safety
+/// `out` 必须是可写的指针或空指针，为空指针时返回 MathError::InvalidParameter
+unsafe fn write_handle(result: MathResult<BigInt>, out: *mut *mut BigInt) -> MathError {
+    if out.is_null() {
+        return MathError::InvalidParameter;
+    }
+
+    *out = if result.is_error() {
+        ptr::null_mut()
+    } else {
+        Box::into_raw(Box::new(result.value))
+    };
+    result.error
+}
+
+/// 导出函数：由整数创建大整数
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_from_long(n: c_long, result: *mut *mut BigInt) -> MathError {
+    write_handle(MathResult::success(BigInt::from_i64(n)), result)
+}
+
+/// 导出函数：按指定进制解析字符串创建大整数
+/// 
+/// 字符串格式无效或进制不在 2 到 36 之间时返回 MathError::InvalidParameter，
+/// 结果超过 BIGINT_MAX_BITS 位时返回 MathError::Overflow
+/// 
+/// # Safety
+/// `s` 必须是有效的、以 NUL 结尾的 C 字符串，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_from_string(
+    s: *const c_char,
+    base: c_int,
+    result: *mut *mut BigInt,
+) -> MathError {
+    if s.is_null() || !(2..=36).contains(&base) {
+        return write_handle(MathResult::error(MathError::InvalidParameter, BigInt::zero()), result);
+    }
+
+    let parsed = match CStr::from_ptr(s).to_str() {
+        Ok(text) => BigInt::from_str_radix(text, base as u32),
+        Err(_) => MathResult::error(MathError::InvalidParameter, BigInt::zero()),
+    };
+    write_handle(parsed, result)
+}
+
+/// 导出函数：复制大整数
+/// 
+/// # Safety
+/// `a` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_clone(a: *const BigInt, result: *mut *mut BigInt) -> MathError {
+    unary_op(a, result, |a| MathResult::success(a.clone()))
+}
+
+/// 导出函数：释放大整数
+/// 
+/// # Safety
+/// `a` 必须是由本模块返回的句柄或空指针，并且只能释放一次
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_free(a: *mut BigInt) {
+    if !a.is_null() {
+        // 重新取得所有权，离开作用域时自动释放
+        let _value = Box::from_raw(a);
+    }
+}
+
+/// 对一个句柄执行运算，句柄为空指针时返回 MathError::InvalidParameter
+/// 
+/// # Safety
+/// `a` 必须是有效的大整数句柄或空指针，`result` 必须是可写的指针
+unsafe fn unary_op(
+    a: *const BigInt,
+    result: *mut *mut BigInt,
+    op: impl FnOnce(&BigInt) -> MathResult<BigInt>,
+) -> MathError {
+    match a.as_ref() {
+        Some(a) => write_handle(op(a), result),
+        None => write_handle(MathResult::error(MathError::InvalidParameter, BigInt::zero()), result),
+    }
+}
+
+/// 对两个句柄执行运算，任一句柄为空指针时返回 MathError::InvalidParameter
+/// 
+/// # Safety
+/// `a` 和 `b` 必须是有效的大整数句柄或空指针，`result` 必须是可写的指针
+unsafe fn binary_op(
+    a: *const BigInt,
+    b: *const BigInt,
+    result: *mut *mut BigInt,
+    op: impl FnOnce(&BigInt, &BigInt) -> MathResult<BigInt>,
+) -> MathError {
+    match (a.as_ref(), b.as_ref()) {
+        (Some(a), Some(b)) => write_handle(op(a, b), result),
+        _ => write_handle(MathResult::error(MathError::InvalidParameter, BigInt::zero()), result),
+    }
+}
+
+/// 导出函数：大整数加法
+/// 
+/// 结果超过 BIGINT_MAX_BITS 位时返回 MathError::Overflow
+/// 
+/// # Safety
+/// `a` 和 `b` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_add(a: *const BigInt, b: *const BigInt, result: *mut *mut BigInt) -> MathError {
+    binary_op(a, b, result, |a, b| a.checked_add(b))
+}
+
+/// 导出函数：大整数减法
+/// 
+/// 结果超过 BIGINT_MAX_BITS 位时返回 MathError::Overflow
+/// 
+/// # Safety
+/// `a` 和 `b` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_sub(a: *const BigInt, b: *const BigInt, result: *mut *mut BigInt) -> MathError {
+    binary_op(a, b, result, |a, b| a.checked_sub(b))
+}
+
+/// 导出函数：大整数乘法
+/// 
+/// 结果超过 BIGINT_MAX_BITS 位时返回 MathError::Overflow
+/// 
+/// # Safety
+/// `a` 和 `b` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_mul(a: *const BigInt, b: *const BigInt, result: *mut *mut BigInt) -> MathError {
+    binary_op(a, b, result, |a, b| a.checked_mul(b))
+}
+
+/// 导出函数：大整数带余除法
+/// 
+/// 商向零取整，余数与被除数同号
+/// 成功时通过 quotient 和 remainder 返回两个新句柄，除数为零时返回 MathError::DivisionByZero
+/// 
+/// # Safety
+/// `a` 和 `b` 必须是有效的大整数句柄，`quotient` 和 `remainder` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_divmod(
+    a: *const BigInt,
+    b: *const BigInt,
+    quotient: *mut *mut BigInt,
+    remainder: *mut *mut BigInt,
+) -> MathError {
+    let (a, b) = match (a.as_ref(), b.as_ref()) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return MathError::InvalidParameter,
+    };
+
+    if quotient.is_null() || remainder.is_null() {
+        return MathError::InvalidParameter;
+    }
+
+    let result = a.div_rem(b);
+    if result.is_error() {
+        *quotient = ptr::null_mut();
+        *remainder = ptr::null_mut();
+        return result.error;
+    }
+
+    let (q, r) = result.value;
+    write_handle(MathResult::success(q), quotient);
+    write_handle(MathResult::success(r), remainder)
+}
+
+/// 导出函数：大整数幂运算
+/// 
+/// 指数为负数时返回 MathError::NegativeNumber，结果过大时返回 MathError::Overflow
+/// 
+/// # Safety
+/// `a` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_pow(a: *const BigInt, exponent: c_long, result: *mut *mut BigInt) -> MathError {
+    unary_op(a, result, |a| a.pow(exponent))
+}
+
+/// 导出函数：大整数模幂运算
+/// 
+/// 指数为负数时返回 MathError::NegativeNumber，模数为零时返回 MathError::DivisionByZero，
+/// 模数为负数时返回 MathError::InvalidParameter
+/// 
+/// # Safety
+/// `base`、`exponent` 和 `modulus` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_modpow(
+    base: *const BigInt,
+    exponent: *const BigInt,
+    modulus: *const BigInt,
+    result: *mut *mut BigInt,
+) -> MathError {
+    match modulus.as_ref() {
+        Some(modulus) => binary_op(base, exponent, result, |base, exponent| base.mod_pow(exponent, modulus)),
+        None => write_handle(MathResult::error(MathError::InvalidParameter, BigInt::zero()), result),
+    }
+}
+
+/// 导出函数：大整数取反
+/// 
+/// # Safety
+/// `a` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_neg(a: *const BigInt, result: *mut *mut BigInt) -> MathError {
+    unary_op(a, result, |a| MathResult::success(-a))
+}
+
+/// 导出函数：大整数绝对值
+/// 
+/// # Safety
+/// `a` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_abs(a: *const BigInt, result: *mut *mut BigInt) -> MathError {
+    unary_op(a, result, |a| MathResult::success(a.abs()))
+}
+
+/// 导出函数：比较两个大整数
+/// 
+/// # 返回值
+/// a < b 返回 -1，a == b 返回 0，a > b 返回 1
+/// 
+/// # Safety
+/// `a` 和 `b` 必须是有效的大整数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_cmp(a: *const BigInt, b: *const BigInt) -> c_int {
+    match (a.as_ref(), b.as_ref()) {
+        (Some(a), Some(b)) => a.cmp(b) as c_int,
+        _ => 0,
+    }
+}
+
+/// 导出函数：获取大整数的符号
+/// 
+/// # 返回值
+/// 负数返回 -1，零返回 0，正数返回 1
+/// 
+/// # Safety
+/// `a` 必须是有效的大整数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_sign(a: *const BigInt) -> c_int {
+    a.as_ref().map_or(0, |a| a.signum() as c_int)
+}
+
+/// 导出函数：把大整数转换为整数
+/// 
+/// 超出 long 范围时返回 MathError::Overflow
+/// 
+/// # Safety
+/// `a` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_to_long(a: *const BigInt, result: *mut c_long) -> MathError {
+    let a = match a.as_ref() {
+        Some(a) => a,
+        None => return MathError::InvalidParameter,
+    };
+
+    match a.to_i64().and_then(|value| c_long::try_from(value).ok()) {
+        Some(value) => write_value(value, result),
+        None => MathError::Overflow,
+    }
+}
+
+/// 导出函数：把大整数按指定进制转换为字符串
+/// 
+/// 进制不在 2 到 36 之间时返回 MathError::InvalidParameter，此时 data 为空指针
+/// 返回的内存需要调用 rust_free_string_result 释放
+/// 
+/// # Safety
+/// `a` 必须是有效的大整数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bigint_to_string(a: *const BigInt, base: c_int, result: *mut RustStringResult) -> MathError {
+    if result.is_null() {
+        return MathError::InvalidParameter;
+    }
+
+    match a.as_ref() {
+        Some(a) if (2..=36).contains(&base) => write_value(string_result(a.to_str_radix(base as u32)), result),
+        _ => {
+            *result = empty_string_result();
+            MathError::InvalidParameter
+        }
+    }
+}
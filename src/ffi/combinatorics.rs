@@ -0,0 +1,103 @@
+//! 组合数学接口
+//! 
+//! 导出 `math::combinatorics` 中的函数
+//! 参数为负数时返回 MathError::NegativeNumber，结果超出 int 范围时返回 MathError::Overflow（与溢出模式无关）
+
+use std::os::raw::c_long;
+use crate::ffi::{numbers_slice, write_result};
+use crate::math::combinatorics;
+use crate::types::MathError;
+
+/// 导出函数：组合数 C(n, k)，k > n 时为 0
+/// 
+/// @php rust_binomial(int $n, int $k): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_binomial_impl(n: c_long, k: c_long, result: *mut c_long) -> MathError {
+    write_result(combinatorics::binomial(n, k), result)
+}
+
+/// 导出函数：排列数 P(n, k) = n! / (n - k)!，k > n 时为 0
+/// 
+/// @php rust_permutations(int $n, int $k): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_permutations_impl(n: c_long, k: c_long, result: *mut c_long) -> MathError {
+    write_result(combinatorics::permutations(n, k), result)
+}
+
+/// 导出函数：多项式系数 (k1 + ... + km)! / (k1! · ... · km!)
+/// 
+/// 数组为空时结果为 1
+/// 
+/// @php-manual rust_multinomial(array $counts): int
+/// 
+/// # Safety
+/// `counts` 必须指向至少 `length` 个元素的数组（length 为 0 时可以是空指针），
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_multinomial_impl(counts: *const c_long, length: c_long, result: *mut c_long) -> MathError {
+    match numbers_slice(counts, length) {
+        Some(counts) => write_result(combinatorics::multinomial(counts), result),
+        None => MathError::InvalidParameter,
+    }
+}
+
+/// 导出函数：第 n 个卡塔兰数
+/// 
+/// @php rust_catalan(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_catalan_impl(n: c_long, result: *mut c_long) -> MathError {
+    write_result(combinatorics::catalan(n), result)
+}
+
+/// 导出函数：无符号第一类斯特林数 c(n, k)
+/// 
+/// @php rust_stirling_first(int $n, int $k): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_stirling_first_impl(n: c_long, k: c_long, result: *mut c_long) -> MathError {
+    write_result(combinatorics::stirling_first(n, k), result)
+}
+
+/// 导出函数：第二类斯特林数 S(n, k)
+/// 
+/// @php rust_stirling_second(int $n, int $k): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_stirling_second_impl(n: c_long, k: c_long, result: *mut c_long) -> MathError {
+    write_result(combinatorics::stirling_second(n, k), result)
+}
+
+/// 导出函数：第 n 个贝尔数
+/// 
+/// @php rust_bell(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_bell_impl(n: c_long, result: *mut c_long) -> MathError {
+    write_result(combinatorics::bell(n), result)
+}
+
+/// 导出函数：整数分拆数 p(n)
+/// 
+/// @php rust_partitions(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_partitions_impl(n: c_long, result: *mut c_long) -> MathError {
+    write_result(combinatorics::partitions(n), result)
+}
@@ -0,0 +1,240 @@
+//! 复数接口
+//!
+//! 复数只有实部和虚部两个浮点数，按值以 `RustComplex` 结构体传递，不需要句柄和释放函数；
+//! PHP 层用这些函数实现不可变的 `RustMath\Complex` 类
+//!
+//! 结果通过 result 指针返回；与实数浮点函数一样，严格模式下结果为 NaN 时返回 MathError::NotANumber，
+//! 除以 0 和对数的参数为 0 分别返回 MathError::DivisionByZero 和 MathError::DomainError
+
+use std::os::raw::{c_double, c_long};
+use crate::ffi::{string_result, write_result, write_value};
+use crate::math::advanced;
+use crate::math::complex::{check_complex, Complex};
+use crate::types::{MathError, RustComplex, RustStringResult};
+
+/// 导出函数：由模和辐角创建复数
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_from_polar(modulus: c_double, argument: c_double, result: *mut RustComplex) -> MathError {
+    write_result(check_complex(Complex::from_polar(modulus, argument)), result)
+}
+
+/// 导出函数：复数加法
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_add(a: RustComplex, b: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(check_complex(a.add(&b)), result)
+}
+
+/// 导出函数：复数减法
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_sub(a: RustComplex, b: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(check_complex(a.sub(&b)), result)
+}
+
+/// 导出函数：复数乘法
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_mul(a: RustComplex, b: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(check_complex(a.mul(&b)), result)
+}
+
+/// 导出函数：复数除法
+///
+/// 除数为 0 时，严格模式返回 MathError::DivisionByZero
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_div(a: RustComplex, b: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(a.div(&b), result)
+}
+
+/// 导出函数：倒数
+///
+/// z 为 0 时，严格模式返回 MathError::DivisionByZero
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_reciprocal(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(a.reciprocal(), result)
+}
+
+/// 导出函数：复数幂的主值 a^b
+///
+/// 严格模式下 0 的负整数次幂返回 MathError::DivisionByZero，
+/// 0 的其他实部不大于 0 的次幂返回 MathError::DomainError
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_pow(a: RustComplex, b: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_pow(a, b), result)
+}
+
+/// 导出函数：整数次幂，使用二进制快速幂
+///
+/// 0 的负数次幂在严格模式下返回 MathError::DivisionByZero
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_powi(a: RustComplex, exponent: c_long, result: *mut RustComplex) -> MathError {
+    write_result(a.powi(exponent), result)
+}
+
+/// 导出函数：模 |z|
+#[no_mangle]
+pub extern "C" fn rust_complex_abs(a: RustComplex) -> c_double {
+    a.abs()
+}
+
+/// 导出函数：辐角，范围为 [-π, π]
+#[no_mangle]
+pub extern "C" fn rust_complex_arg(a: RustComplex) -> c_double {
+    a.arg()
+}
+
+/// 导出函数：e^z
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_exp(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_exp(a), result)
+}
+
+/// 导出函数：自然对数的主值
+///
+/// z 为 0 时，严格模式返回 MathError::DomainError
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_ln(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_ln(a), result)
+}
+
+/// 导出函数：常用对数的主值
+///
+/// z 为 0 时，严格模式返回 MathError::DomainError
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_log10(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_log10(a), result)
+}
+
+/// 导出函数：平方根的主值，实部非负
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_sqrt(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_sqrt(a), result)
+}
+
+/// 导出函数：正弦
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_sin(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_sin(a), result)
+}
+
+/// 导出函数：余弦
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_cos(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_cos(a), result)
+}
+
+/// 导出函数：正切
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_tan(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_tan(a), result)
+}
+
+/// 导出函数：反正弦的主值
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_asin(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_asin(a), result)
+}
+
+/// 导出函数：反余弦的主值
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_acos(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_acos(a), result)
+}
+
+/// 导出函数：反正切的主值
+///
+/// z 为 ±i 时，严格模式返回 MathError::DomainError
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_atan(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_atan(a), result)
+}
+
+/// 导出函数：双曲正弦
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_sinh(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_sinh(a), result)
+}
+
+/// 导出函数：双曲余弦
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_cosh(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_cosh(a), result)
+}
+
+/// 导出函数：双曲正切
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_tanh(a: RustComplex, result: *mut RustComplex) -> MathError {
+    write_result(advanced::complex_tanh(a), result)
+}
+
+/// 导出函数：转换为 "3+4i"、"3-4i"、"4i" 或 "3" 形式的字符串
+///
+/// 返回的内存需要调用 rust_free_string_result 释放
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_complex_to_string(a: RustComplex, result: *mut RustStringResult) -> MathError {
+    write_value(string_result(a.to_string()), result)
+}
@@ -0,0 +1,354 @@
+//! 定点小数对象接口
+//!
+//! 为 C 代码提供 `Decimal` 的句柄操作，PHP 层用它实现 `RustMath\Decimal` 类
+//!
+//! 舍入模式以整数代码传入（见 `RoundingMode`），代码无效或小数位数不在 0..=MAX_SCALE 内时
+//! 返回 MathError::InvalidParameter
+//! 创建新句柄的函数返回错误码，句柄通过最后的 result 指针返回，出错时写入空指针；
+//! 返回的非空句柄都需要调用 `rust_decimal_free` 释放
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_double, c_int, c_long};
+use std::ptr;
+use crate::ffi::{numbers_slice, string_result, write_value};
+use crate::math::decimal::{Decimal, MAX_SCALE};
+use crate::types::{MathError, MathResult, RoundingMode, RustStringResult};
+
+/// 把运算结果放到堆上并通过 out 返回句柄，出错时写入空指针
+///
+/// # Safety
+/// `out` 必须是可写的指针或空指针，为空指针时返回 MathError::InvalidParameter
+unsafe fn write_handle(result: MathResult<Decimal>, out: *mut *mut Decimal) -> MathError {
+    if out.is_null() {
+        return MathError::InvalidParameter;
+    }
+
+    *out = if result.is_error() {
+        ptr::null_mut()
+    } else {
+        Box::into_raw(Box::new(result.value))
+    };
+    result.error
+}
+
+/// 参数无效时使用的错误结果
+fn invalid() -> MathResult<Decimal> {
+    MathResult::error(MathError::InvalidParameter, Decimal::zero())
+}
+
+/// 转换小数位数和舍入模式代码，无效时返回 None
+fn rounding(scale: c_long, mode: c_int) -> Option<(u32, RoundingMode)> {
+    let scale = u32::try_from(scale).ok().filter(|&scale| scale <= MAX_SCALE)?;
+    Some((scale, RoundingMode::from_code(mode)?))
+}
+
+/// 把 C 字符串转换为 &str，空指针或不是 UTF-8 时返回 None
+///
+/// # Safety
+/// `s` 必须是有效的、以 NUL 结尾的 C 字符串或空指针
+unsafe fn text<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    CStr::from_ptr(s).to_str().ok()
+}
+
+/// 导出函数：由整数创建定点小数，小数位数为 0
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_from_long(n: c_long, result: *mut *mut Decimal) -> MathError {
+    write_handle(MathResult::success(Decimal::from_integer(n)), result)
+}
+
+/// 导出函数：解析 "-12.340" 形式的字符串，保留全部小数位数
+///
+/// 格式无效或小数位数超过 MAX_SCALE 时返回 MathError::InvalidParameter，超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `s` 必须是有效的、以 NUL 结尾的 C 字符串，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_from_string(s: *const c_char, result: *mut *mut Decimal) -> MathError {
+    match text(s) {
+        Some(text) => write_handle(Decimal::parse(text), result),
+        None => write_handle(invalid(), result),
+    }
+}
+
+/// 导出函数：解析字符串并舍入到指定的小数位数
+///
+/// 格式无效时返回 MathError::InvalidParameter，超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `s` 必须是有效的、以 NUL 结尾的 C 字符串，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_from_string_rounded(
+    s: *const c_char,
+    scale: c_long,
+    mode: c_int,
+    result: *mut *mut Decimal,
+) -> MathError {
+    match (text(s), rounding(scale, mode)) {
+        (Some(text), Some((scale, mode))) => write_handle(Decimal::parse_rounded(text, scale, mode), result),
+        _ => write_handle(invalid(), result),
+    }
+}
+
+/// 导出函数：按舍入模式把浮点数转换为定点小数
+///
+/// 使用能还原出同一个浮点数的最短十进制表示，x 为 NaN 时返回 MathError::NotANumber，
+/// 无穷大或超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_from_double(
+    x: c_double,
+    scale: c_long,
+    mode: c_int,
+    result: *mut *mut Decimal,
+) -> MathError {
+    match rounding(scale, mode) {
+        Some((scale, mode)) => write_handle(Decimal::from_f64(x, scale, mode), result),
+        None => write_handle(invalid(), result),
+    }
+}
+
+/// 导出函数：复制定点小数
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_clone(a: *const Decimal, result: *mut *mut Decimal) -> MathError {
+    unary_op(a, result, |a| MathResult::success(*a))
+}
+
+/// 导出函数：释放定点小数
+///
+/// # Safety
+/// `a` 必须是由本模块返回的句柄或空指针，并且只能释放一次
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_free(a: *mut Decimal) {
+    if !a.is_null() {
+        // 重新取得所有权，离开作用域时自动释放
+        let _value = Box::from_raw(a);
+    }
+}
+
+/// 对一个句柄执行运算，句柄为空指针时返回 MathError::InvalidParameter
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄或空指针，`result` 必须是可写的指针
+unsafe fn unary_op(
+    a: *const Decimal,
+    result: *mut *mut Decimal,
+    op: impl FnOnce(&Decimal) -> MathResult<Decimal>,
+) -> MathError {
+    match a.as_ref() {
+        Some(a) => write_handle(op(a), result),
+        None => write_handle(invalid(), result),
+    }
+}
+
+/// 对两个句柄执行运算，任一句柄为空指针时返回 MathError::InvalidParameter
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的定点小数句柄或空指针，`result` 必须是可写的指针
+unsafe fn binary_op(
+    a: *const Decimal,
+    b: *const Decimal,
+    result: *mut *mut Decimal,
+    op: impl FnOnce(&Decimal, &Decimal) -> MathResult<Decimal>,
+) -> MathError {
+    match (a.as_ref(), b.as_ref()) {
+        (Some(a), Some(b)) => write_handle(op(a, b), result),
+        _ => write_handle(invalid(), result),
+    }
+}
+
+/// 导出函数：精确加法，结果的小数位数为两者的较大值
+///
+/// 结果超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的定点小数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_add(a: *const Decimal, b: *const Decimal, result: *mut *mut Decimal) -> MathError {
+    binary_op(a, b, result, Decimal::add)
+}
+
+/// 导出函数：精确减法，结果的小数位数为两者的较大值
+///
+/// 结果超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的定点小数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_sub(a: *const Decimal, b: *const Decimal, result: *mut *mut Decimal) -> MathError {
+    binary_op(a, b, result, Decimal::sub)
+}
+
+/// 导出函数：精确乘法，结果的小数位数为两者之和
+///
+/// 结果超出范围或小数位数超过 MAX_SCALE 时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的定点小数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_mul(a: *const Decimal, b: *const Decimal, result: *mut *mut Decimal) -> MathError {
+    binary_op(a, b, result, Decimal::mul)
+}
+
+/// 导出函数：除法，结果舍入到指定的小数位数
+///
+/// 除数为 0 时返回 MathError::DivisionByZero，结果超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的定点小数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_div(
+    a: *const Decimal,
+    b: *const Decimal,
+    scale: c_long,
+    mode: c_int,
+    result: *mut *mut Decimal,
+) -> MathError {
+    match rounding(scale, mode) {
+        Some((scale, mode)) => binary_op(a, b, result, |a, b| a.div(b, scale, mode)),
+        None => write_handle(invalid(), result),
+    }
+}
+
+/// 导出函数：舍入到指定的小数位数，位数比当前多时只补 0
+///
+/// 结果超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_round(
+    a: *const Decimal,
+    scale: c_long,
+    mode: c_int,
+    result: *mut *mut Decimal,
+) -> MathError {
+    match rounding(scale, mode) {
+        Some((scale, mode)) => unary_op(a, result, |a| a.round(scale, mode)),
+        None => write_handle(invalid(), result),
+    }
+}
+
+/// 导出函数：取反
+///
+/// 结果超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_neg(a: *const Decimal, result: *mut *mut Decimal) -> MathError {
+    unary_op(a, result, Decimal::neg)
+}
+
+/// 导出函数：绝对值
+///
+/// 结果超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_abs(a: *const Decimal, result: *mut *mut Decimal) -> MathError {
+    unary_op(a, result, Decimal::abs)
+}
+
+/// 导出函数：按比例分配金额，各部分之和恰好等于原值
+///
+/// 通过 results 返回 length 个句柄，出错时全部写入空指针
+/// ratios 为空或全为 0 时返回 MathError::InvalidParameter，有负数时返回 MathError::NegativeNumber，
+/// 计算超出范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄，`ratios` 必须指向至少 `length` 个元素的数组，
+/// `results` 必须指向至少 `length` 个可写的句柄指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_allocate(
+    a: *const Decimal,
+    ratios: *const c_long,
+    length: c_long,
+    results: *mut *mut Decimal,
+) -> MathError {
+    let (Some(a), Some(ratios)) = (a.as_ref(), numbers_slice(ratios, length)) else {
+        return MathError::InvalidParameter;
+    };
+    if results.is_null() && !ratios.is_empty() {
+        return MathError::InvalidParameter;
+    }
+
+    let parts = a.allocate(ratios);
+    for i in 0..ratios.len() {
+        *results.add(i) = match parts.value.get(i) {
+            Some(part) => Box::into_raw(Box::new(*part)),
+            None => ptr::null_mut(),
+        };
+    }
+    parts.error
+}
+
+/// 导出函数：比较两个定点小数的数值，不考虑小数位数
+///
+/// # 返回值
+/// a < b 返回 -1，a == b 返回 0，a > b 返回 1
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的定点小数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_cmp(a: *const Decimal, b: *const Decimal) -> c_int {
+    match (a.as_ref(), b.as_ref()) {
+        (Some(a), Some(b)) => a.cmp(b) as c_int,
+        _ => 0,
+    }
+}
+
+/// 导出函数：获取定点小数的符号
+///
+/// # 返回值
+/// 负数返回 -1，零返回 0，正数返回 1
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_sign(a: *const Decimal) -> c_int {
+    a.as_ref().map_or(0, |a| a.signum() as c_int)
+}
+
+/// 导出函数：小数位数
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_scale(a: *const Decimal) -> c_long {
+    a.as_ref().map_or(0, |a| a.scale() as c_long)
+}
+
+/// 导出函数：转换为最接近的浮点数
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_to_double(a: *const Decimal) -> c_double {
+    a.as_ref().map_or(0.0, Decimal::to_f64)
+}
+
+/// 导出函数：转换为保留全部小数位数的十进制字符串，例如 "-0.050"
+///
+/// 返回的内存需要调用 rust_free_string_result 释放
+///
+/// # Safety
+/// `a` 必须是有效的定点小数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_decimal_to_string(a: *const Decimal, result: *mut RustStringResult) -> MathError {
+    match a.as_ref() {
+        Some(a) => write_value(string_result(a.to_string()), result),
+        None => MathError::InvalidParameter,
+    }
+}
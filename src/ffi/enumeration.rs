@@ -0,0 +1,240 @@
+//! 组合枚举接口
+//! 
+//! 为 C 代码提供 `Enumeration` 的句柄操作，PHP 层用它实现 `RustMath\Enumeration` 迭代器类
+//! 
+//! 枚举生成的是元素的位置（从 0 开始），由 C 代码映射为 PHP 数组中的元素
+//! 创建句柄的函数返回错误码，句柄通过最后的 result 指针返回，出错时写入空指针；
+//! 返回的非空句柄都需要调用 `rust_enumeration_free` 释放
+
+use std::os::raw::c_long;
+use std::ptr;
+use crate::ffi::{numbers_slice, write_integer_array, write_result};
+use crate::math::enumeration::Enumeration;
+use crate::types::{MathError, MathResult, RustIntegerArrayResult};
+
+/// 把枚举放到堆上并通过 out 返回句柄，出错时写入空指针
+/// 
+/// # Safety
+/// `out` 必须是可写的指针或空指针，为空指针时返回 MathError::InvalidParameter
+unsafe fn write_handle(result: MathResult<Enumeration>, out: *mut *mut Enumeration) -> MathError {
+    if out.is_null() {
+        return MathError::InvalidParameter;
+    }
+
+    *out = if result.is_error() {
+        ptr::null_mut()
+    } else {
+        Box::into_raw(Box::new(result.value))
+    };
+    result.error
+}
+
+/// 导出函数：从 n 个元素中选出 k 个的组合
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_combinations(n: c_long, k: c_long, result: *mut *mut Enumeration) -> MathError {
+    write_handle(Enumeration::combinations(n, k), result)
+}
+
+/// 导出函数：从 n 个元素中可重复地选出 k 个的组合
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_combinations_with_replacement(
+    n: c_long,
+    k: c_long,
+    result: *mut *mut Enumeration,
+) -> MathError {
+    write_handle(Enumeration::combinations_with_replacement(n, k), result)
+}
+
+/// 导出函数：从 n 个元素中选出 k 个的排列
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_permutations(n: c_long, k: c_long, result: *mut *mut Enumeration) -> MathError {
+    write_handle(Enumeration::permutations(n, k), result)
+}
+
+/// 导出函数：n 个元素的 k 元组（可重复排列）
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_permutations_with_replacement(
+    n: c_long,
+    k: c_long,
+    result: *mut *mut Enumeration,
+) -> MathError {
+    write_handle(Enumeration::permutations_with_replacement(n, k), result)
+}
+
+/// 导出函数：多个集合的笛卡尔积
+/// 
+/// # Safety
+/// `sizes` 必须指向至少 `length` 个元素的数组（length 为 0 时可以是空指针），
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_product(
+    sizes: *const c_long,
+    length: c_long,
+    result: *mut *mut Enumeration,
+) -> MathError {
+    match numbers_slice(sizes, length) {
+        Some(sizes) => write_handle(Enumeration::product(sizes), result),
+        None => write_handle(MathResult::error(MathError::InvalidParameter, Enumeration::product(&[]).value), result),
+    }
+}
+
+/// 导出函数：复制枚举，副本从同一个排列开始独立前进
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_clone(e: *const Enumeration, result: *mut *mut Enumeration) -> MathError {
+    match e.as_ref() {
+        Some(e) => write_handle(MathResult::success(e.clone()), result),
+        None => write_handle(MathResult::error(MathError::InvalidParameter, Enumeration::product(&[]).value), result),
+    }
+}
+
+/// 导出函数：释放枚举
+/// 
+/// # Safety
+/// `e` 必须是由本模块返回的句柄或空指针，并且只能释放一次
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_free(e: *mut Enumeration) {
+    if !e.is_null() {
+        // 重新取得所有权，离开作用域时自动释放
+        let _enumeration = Box::from_raw(e);
+    }
+}
+
+/// 导出函数：是否还有当前排列
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_valid(e: *const Enumeration) -> bool {
+    e.as_ref().is_some_and(|e| e.current().is_some())
+}
+
+/// 导出函数：当前排列的下标，从 0 开始
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_key(e: *const Enumeration) -> c_long {
+    e.as_ref().map_or(0, Enumeration::index)
+}
+
+/// 导出函数：当前排列中元素的位置
+/// 
+/// 通过 result 返回位置数组，全部生成完后返回 MathError::InvalidParameter
+/// 返回的内存需要调用 rust_free_integer_array_result 释放
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_current(e: *const Enumeration, result: *mut RustIntegerArrayResult) -> MathError {
+    let current = match e.as_ref().and_then(Enumeration::current) {
+        Some(current) => MathResult::success(current.to_vec()),
+        None => MathResult::error(MathError::InvalidParameter, Vec::new()),
+    };
+    write_integer_array(current, result)
+}
+
+/// 导出函数：移动到下一个排列
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_next(e: *mut Enumeration) {
+    if let Some(e) = e.as_mut() {
+        e.advance();
+    }
+}
+
+/// 导出函数：回到第 0 个排列
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_rewind(e: *mut Enumeration) {
+    if let Some(e) = e.as_mut() {
+        e.rewind();
+    }
+}
+
+/// 导出函数：排列总数
+/// 
+/// 总数超出 long 范围时返回 MathError::Overflow
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_count(e: *const Enumeration, result: *mut c_long) -> MathError {
+    match e.as_ref() {
+        Some(e) => write_result(e.count(), result),
+        None => MathError::InvalidParameter,
+    }
+}
+
+/// 导出函数：排列在字典序中的下标
+/// 
+/// 位置不是这个枚举会生成的排列时返回 MathError::InvalidParameter，
+/// 排列总数超出 long 范围时返回 MathError::Overflow
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄，`positions` 必须指向至少 `length` 个元素的数组
+/// （length 为 0 时可以是空指针），`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_rank(
+    e: *const Enumeration,
+    positions: *const c_long,
+    length: c_long,
+    result: *mut c_long,
+) -> MathError {
+    match (e.as_ref(), numbers_slice(positions, length)) {
+        (Some(e), Some(positions)) => write_result(e.rank(positions), result),
+        _ => MathError::InvalidParameter,
+    }
+}
+
+/// 导出函数：字典序中第 index 个排列的位置，不移动当前排列
+/// 
+/// index 为负数时返回 MathError::NegativeNumber，不小于排列总数时返回 MathError::InvalidParameter，
+/// 排列总数超出 long 范围时返回 MathError::Overflow
+/// 返回的内存需要调用 rust_free_integer_array_result 释放
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_unrank(
+    e: *const Enumeration,
+    index: c_long,
+    result: *mut RustIntegerArrayResult,
+) -> MathError {
+    match e.as_ref() {
+        Some(e) => write_integer_array(e.unrank(index), result),
+        None => write_integer_array(MathResult::error(MathError::InvalidParameter, Vec::new()), result),
+    }
+}
+
+/// 导出函数：移动到字典序中的第 index 个排列
+/// 
+/// 错误与 `rust_enumeration_unrank` 相同，出错时当前排列不变
+/// 
+/// # Safety
+/// `e` 必须是有效的枚举句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_enumeration_seek(e: *mut Enumeration, index: c_long) -> MathError {
+    match e.as_mut() {
+        Some(e) => e.seek(index).error,
+        None => MathError::InvalidParameter,
+    }
+}
@@ -0,0 +1,102 @@
+//! 误差函数接口
+//! 
+//! 导出 `math::erf` 中的函数，结果通过 result 指针返回
+
+use std::os::raw::c_double;
+use crate::ffi::write_result;
+use crate::math::erf;
+use crate::types::MathError;
+
+/// 导出函数：误差函数
+/// 
+/// @php rust_erf(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_erf_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(erf::erf(x), result)
+}
+
+/// 导出函数：补误差函数
+/// 
+/// @php rust_erfc(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_erfc_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(erf::erfc(x), result)
+}
+
+/// 导出函数：缩放的补误差函数 exp(x²) · erfc(x)
+/// 
+/// @php rust_erfcx(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_erfcx_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(erf::erfcx(x), result)
+}
+
+/// 导出函数：反误差函数
+/// 
+/// @php rust_erfinv(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_erfinv_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(erf::erfinv(x), result)
+}
+
+/// 导出函数：反补误差函数
+/// 
+/// @php rust_erfcinv(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_erfcinv_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(erf::erfcinv(x), result)
+}
+
+/// 导出函数：正态分布的概率密度函数
+/// 
+/// sigma 不是正数时返回 MathError::InvalidParameter
+/// 
+/// @php rust_normal_pdf(float $x, float $mu = 0.0, float $sigma = 1.0): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_normal_pdf_impl(x: c_double, mu: c_double, sigma: c_double, result: *mut c_double) -> MathError {
+    write_result(erf::normal_pdf(x, mu, sigma), result)
+}
+
+/// 导出函数：正态分布的累积分布函数
+/// 
+/// sigma 不是正数时返回 MathError::InvalidParameter
+/// 
+/// @php rust_normal_cdf(float $x, float $mu = 0.0, float $sigma = 1.0): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_normal_cdf_impl(x: c_double, mu: c_double, sigma: c_double, result: *mut c_double) -> MathError {
+    write_result(erf::normal_cdf(x, mu, sigma), result)
+}
+
+/// 导出函数：正态分布的分位数函数
+/// 
+/// sigma 不是正数时返回 MathError::InvalidParameter
+/// 
+/// @php rust_normal_quantile(float $p, float $mu = 0.0, float $sigma = 1.0): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_normal_quantile_impl(p: c_double, mu: c_double, sigma: c_double, result: *mut c_double) -> MathError {
+    write_result(erf::normal_quantile(p, mu, sigma), result)
+}
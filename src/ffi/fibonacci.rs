@@ -0,0 +1,76 @@
+//! 斐波那契数和卢卡斯数接口
+//! 
+//! 导出 `math::fibonacci` 中的函数
+//! 下标为负数时返回 MathError::NegativeNumber；整数结果超出 int 范围时返回 MathError::Overflow，
+//! 大整数版本下标超过 FIBONACCI_BIG_LIMIT 时返回 MathError::InvalidParameter
+
+use std::os::raw::c_long;
+use crate::ffi::{empty_string_result, string_result, write_result, write_value};
+use crate::math::bigint::BigUint;
+use crate::math::fibonacci;
+use crate::types::{MathError, MathResult, RustStringResult};
+
+/// 把大整数结果转换为十进制字符串写入输出指针
+/// 
+/// # Safety
+/// `out` 必须是可写的指针或空指针，为空指针时返回 MathError::InvalidParameter
+unsafe fn write_decimal(big: MathResult<BigUint>, out: *mut RustStringResult) -> MathError {
+    if out.is_null() {
+        return MathError::InvalidParameter;
+    }
+
+    if big.is_error() {
+        *out = empty_string_result();
+        return big.error;
+    }
+
+    write_value(string_result(big.value.to_decimal_string()), out)
+}
+
+/// 导出函数：第 n 个斐波那契数
+/// 
+/// @php rust_fibonacci_nth(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_fibonacci_nth_impl(n: c_long, result: *mut c_long) -> MathError {
+    write_result(fibonacci::fibonacci_nth(n), result)
+}
+
+/// 导出函数：第 n 个卢卡斯数
+/// 
+/// @php rust_lucas_nth(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_lucas_nth_impl(n: c_long, result: *mut c_long) -> MathError {
+    write_result(fibonacci::lucas_nth(n), result)
+}
+
+/// 导出函数：任意精度的第 n 个斐波那契数
+/// 
+/// 通过 result 返回十进制字符串，返回的内存需要调用 rust_free_string_result 释放
+/// 
+/// @php-manual rust_fibonacci_big(int $n): string
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_fibonacci_big_impl(n: c_long, result: *mut RustStringResult) -> MathError {
+    write_decimal(fibonacci::fibonacci_big(n), result)
+}
+
+/// 导出函数：任意精度的第 n 个卢卡斯数
+/// 
+/// 通过 result 返回十进制字符串，返回的内存需要调用 rust_free_string_result 释放
+/// 
+/// @php-manual rust_lucas_big(int $n): string
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_lucas_big_impl(n: c_long, result: *mut RustStringResult) -> MathError {
+    write_decimal(fibonacci::lucas_big(n), result)
+}
@@ -0,0 +1,53 @@
+//! 伽马函数接口
+//! 
+//! 导出 `math::gamma` 中的函数，结果通过 result 指针返回
+//! 参数为极点（0 或负整数）时返回 MathError::DomainError（IEEE 浮点模式下返回 NaN 或 inf）
+
+use std::os::raw::c_double;
+use crate::ffi::write_result;
+use crate::math::gamma;
+use crate::types::MathError;
+
+/// 导出函数：伽马函数
+/// 
+/// @php rust_gamma(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_gamma_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(gamma::gamma(x), result)
+}
+
+/// 导出函数：伽马函数绝对值的自然对数
+/// 
+/// @php rust_lgamma(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_lgamma_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(gamma::lgamma(x), result)
+}
+
+/// 导出函数：双伽马函数
+/// 
+/// @php rust_digamma(float $x): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_digamma_impl(x: c_double, result: *mut c_double) -> MathError {
+    write_result(gamma::digamma(x), result)
+}
+
+/// 导出函数：贝塔函数
+/// 
+/// @php rust_beta(float $a, float $b): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_beta_impl(a: c_double, b: c_double, result: *mut c_double) -> MathError {
+    write_result(gamma::beta(a, b), result)
+}
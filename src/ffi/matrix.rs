@@ -0,0 +1,230 @@
+//! 稠密矩阵接口
+//!
+//! 矩阵以按行优先顺序排列的 double 数组加上行数和列数传入，PHP 层把嵌套数组展开成这种形式
+//! 结果的大小都可以由参数确定，所以结果写入调用者分配的数组中，不需要释放函数；
+//! 出错时不写入结果
+//!
+//! 行数或列数不是正数、指针为空或维数不匹配时返回 MathError::InvalidParameter，
+//! 其他错误码见 `math::matrix`
+
+use std::os::raw::{c_double, c_long};
+use std::{ptr, slice};
+use crate::ffi::write_result;
+use crate::math::matrix::Matrix;
+use crate::types::{MathError, MathResult};
+
+/// 把 C 数组转换为矩阵
+///
+/// # Safety
+/// `data` 必须指向至少 `rows * cols` 个元素的数组
+unsafe fn matrix_from_raw(data: *const c_double, rows: c_long, cols: c_long) -> MathResult<Matrix> {
+    if data.is_null() || rows <= 0 || cols <= 0 {
+        return MathResult::error(MathError::InvalidParameter, Matrix::default());
+    }
+
+    let (rows, cols) = (rows as usize, cols as usize);
+    match rows.checked_mul(cols) {
+        Some(length) => Matrix::new(rows, cols, slice::from_raw_parts(data, length).to_vec()),
+        None => MathResult::error(MathError::InvalidParameter, Matrix::default()),
+    }
+}
+
+/// 把矩阵的元素按行优先顺序复制到 out 并返回错误码，出错时不写入
+///
+/// # Safety
+/// `out` 必须指向足够容纳结果的可写数组或为空指针，为空指针时返回 MathError::InvalidParameter
+unsafe fn write_matrix(result: MathResult<Matrix>, out: *mut c_double) -> MathError {
+    if out.is_null() {
+        return MathError::InvalidParameter;
+    }
+    if result.is_error() {
+        return result.error;
+    }
+
+    let data = result.value.data();
+    ptr::copy_nonoverlapping(data.as_ptr(), out, data.len());
+    MathError::Success
+}
+
+/// 导出函数：矩阵乘法 A·B
+///
+/// A 的列数不等于 B 的行数时返回 MathError::InvalidParameter
+///
+/// @php-manual rust_matrix_multiply(array $a, array $b): array
+///
+/// # Safety
+/// `a` 和 `b` 必须分别指向 `a_rows * a_cols` 和 `b_rows * b_cols` 个元素的数组，
+/// `result` 必须指向 `a_rows * b_cols` 个元素的可写数组
+#[no_mangle]
+pub unsafe extern "C" fn rust_matrix_multiply_impl(
+    a: *const c_double,
+    a_rows: c_long,
+    a_cols: c_long,
+    b: *const c_double,
+    b_rows: c_long,
+    b_cols: c_long,
+    result: *mut c_double,
+) -> MathError {
+    let a = matrix_from_raw(a, a_rows, a_cols);
+    let b = matrix_from_raw(b, b_rows, b_cols);
+    if a.is_error() || b.is_error() {
+        return MathError::InvalidParameter;
+    }
+    write_matrix(a.value.mul(&b.value), result)
+}
+
+/// 导出函数：转置矩阵
+///
+/// @php-manual rust_matrix_transpose(array $matrix): array
+///
+/// # Safety
+/// `matrix` 必须指向 `rows * cols` 个元素的数组，`result` 必须指向同样大小的可写数组
+#[no_mangle]
+pub unsafe extern "C" fn rust_matrix_transpose_impl(matrix: *const c_double, rows: c_long, cols: c_long, result: *mut c_double) -> MathError {
+    let matrix = matrix_from_raw(matrix, rows, cols);
+    if matrix.is_error() {
+        return matrix.error;
+    }
+    write_matrix(MathResult::success(matrix.value.transpose()), result)
+}
+
+/// 导出函数：行列式
+///
+/// 不是方阵时返回 MathError::InvalidParameter
+///
+/// @php-manual rust_matrix_determinant(array $matrix): float
+///
+/// # Safety
+/// `matrix` 必须指向 `rows * cols` 个元素的数组，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_matrix_determinant_impl(matrix: *const c_double, rows: c_long, cols: c_long, result: *mut c_double) -> MathError {
+    let matrix = matrix_from_raw(matrix, rows, cols);
+    if matrix.is_error() {
+        return matrix.error;
+    }
+    write_result(matrix.value.determinant(), result)
+}
+
+/// 导出函数：逆矩阵
+///
+/// 不是方阵时返回 MathError::InvalidParameter，奇异矩阵返回 MathError::DivisionByZero
+///
+/// @php-manual rust_matrix_inverse(array $matrix): array
+///
+/// # Safety
+/// `matrix` 必须指向 `rows * cols` 个元素的数组，`result` 必须指向同样大小的可写数组
+#[no_mangle]
+pub unsafe extern "C" fn rust_matrix_inverse_impl(matrix: *const c_double, rows: c_long, cols: c_long, result: *mut c_double) -> MathError {
+    let matrix = matrix_from_raw(matrix, rows, cols);
+    if matrix.is_error() {
+        return matrix.error;
+    }
+    write_matrix(matrix.value.inverse(), result)
+}
+
+/// 导出函数：LU 分解 P·A = L·U，使用部分选主元
+///
+/// P·A 的第 i 行是 A 的第 permutation[i] 行；奇异矩阵也可以分解，不是方阵时返回 MathError::InvalidParameter
+///
+/// @php-manual rust_matrix_lu(array $matrix): array
+///
+/// # Safety
+/// `matrix` 必须指向 `rows * cols` 个元素的数组，`lower` 和 `upper` 必须指向同样大小的可写数组，
+/// `permutation` 必须指向 `rows` 个元素的可写数组
+#[no_mangle]
+pub unsafe extern "C" fn rust_matrix_lu_impl(
+    matrix: *const c_double,
+    rows: c_long,
+    cols: c_long,
+    lower: *mut c_double,
+    upper: *mut c_double,
+    permutation: *mut c_long,
+) -> MathError {
+    if lower.is_null() || upper.is_null() || permutation.is_null() {
+        return MathError::InvalidParameter;
+    }
+    let matrix = matrix_from_raw(matrix, rows, cols);
+    if matrix.is_error() {
+        return matrix.error;
+    }
+    let lu = matrix.value.lu();
+    if lu.is_error() {
+        return lu.error;
+    }
+
+    let lu = lu.value;
+    write_matrix(MathResult::success(lu.lower()), lower);
+    write_matrix(MathResult::success(lu.upper()), upper);
+    for (i, &row) in lu.permutation().iter().enumerate() {
+        *permutation.add(i) = row as c_long;
+    }
+    MathError::Success
+}
+
+/// 导出函数：QR 分解 A = Q·R，使用 Householder 变换
+///
+/// Q 是 rows × rows 的正交矩阵，R 是 rows × cols 的上三角矩阵，R 的对角线元素非负
+///
+/// @php-manual rust_matrix_qr(array $matrix): array
+///
+/// # Safety
+/// `matrix` 必须指向 `rows * cols` 个元素的数组，`q` 必须指向 `rows * rows` 个元素的可写数组，
+/// `r` 必须指向 `rows * cols` 个元素的可写数组
+#[no_mangle]
+pub unsafe extern "C" fn rust_matrix_qr_impl(matrix: *const c_double, rows: c_long, cols: c_long, q: *mut c_double, r: *mut c_double) -> MathError {
+    if q.is_null() || r.is_null() {
+        return MathError::InvalidParameter;
+    }
+    let matrix = matrix_from_raw(matrix, rows, cols);
+    if matrix.is_error() {
+        return matrix.error;
+    }
+
+    let qr = matrix.value.qr();
+    write_matrix(MathResult::success(qr.q().clone()), q);
+    write_matrix(MathResult::success(qr.r().clone()), r)
+}
+
+/// 导出函数：Cholesky 分解 A = L·Lᵀ
+///
+/// 不是方阵时返回 MathError::InvalidParameter，不是对称正定矩阵时返回 MathError::DomainError
+///
+/// @php-manual rust_matrix_cholesky(array $matrix): array
+///
+/// # Safety
+/// `matrix` 必须指向 `rows * cols` 个元素的数组，`result` 必须指向同样大小的可写数组
+#[no_mangle]
+pub unsafe extern "C" fn rust_matrix_cholesky_impl(matrix: *const c_double, rows: c_long, cols: c_long, result: *mut c_double) -> MathError {
+    let matrix = matrix_from_raw(matrix, rows, cols);
+    if matrix.is_error() {
+        return matrix.error;
+    }
+    write_matrix(matrix.value.cholesky(), result)
+}
+
+/// 导出函数：解线性方程组 A·X = B
+///
+/// B 有 rows 行、b_cols 列，每一列是一个右端向量；方阵求精确解，行数多于列数时求最小二乘解
+/// A 的行数少于列数时返回 MathError::InvalidParameter，A 奇异或列不满秩时返回 MathError::DivisionByZero
+///
+/// @php-manual rust_matrix_solve(array $a, array $b): array
+///
+/// # Safety
+/// `a` 必须指向 `rows * cols` 个元素的数组，`b` 必须指向 `rows * b_cols` 个元素的数组，
+/// `result` 必须指向 `cols * b_cols` 个元素的可写数组
+#[no_mangle]
+pub unsafe extern "C" fn rust_matrix_solve_impl(
+    a: *const c_double,
+    rows: c_long,
+    cols: c_long,
+    b: *const c_double,
+    b_cols: c_long,
+    result: *mut c_double,
+) -> MathError {
+    let a = matrix_from_raw(a, rows, cols);
+    let b = matrix_from_raw(b, rows, b_cols);
+    if a.is_error() || b.is_error() {
+        return MathError::InvalidParameter;
+    }
+    write_matrix(a.value.solve(&b.value), result)
+}
@@ -0,0 +1,187 @@
+//! C 接口模块
+//! 
+//! `lib.rs` 中导出的是扩展最早的几个核心函数，这个模块按功能分组导出其余的接口：
+//! 
+//! - `basic`、`advanced`: `math::basic` 和 `math::advanced` 中函数的包装
+//! - `gamma`: 伽马函数、lgamma、digamma 和 beta
+//! - `bessel`: 贝塞尔函数 J、Y、I、K
+//! - `erf`: 误差函数及其反函数、正态分布
+//! - `number_theory`: 质因数分解、因数、欧拉函数、莫比乌斯函数，以及多个数的最大公约数和最小公倍数
+//! - `sieve`: 质数筛，批量生成和计数质数
+//! - `modular`: 模幂、模逆元、扩展欧几里得算法、中国剩余定理和雅可比符号
+//! - `power`: 浮点幂、负整数次幂和精确的分数幂
+//! - `fibonacci`: 快速倍增法计算的单个斐波那契数和卢卡斯数，包括任意精度版本
+//! - `bigint`: 基于不透明句柄（opaque handle）的大整数对象接口
+//! - `sequence`: 基于句柄的整数序列对象接口，逐项生成序列
+//! - `combinatorics`: 组合数、排列数、多项式系数、卡塔兰数、斯特林数、贝尔数和分拆数
+//! - `enumeration`: 基于句柄的组合枚举对象接口，按字典序生成组合、排列和笛卡尔积
+//! - `rational`: 基于句柄的有理数对象接口，精确的分数运算
+//! - `decimal`: 基于句柄的定点小数对象接口，可配置舍入模式的十进制运算
+//! - `complex`: 按值传递的复数运算，以及复数的指数、对数、幂、三角函数和双曲函数
+//! - `matrix`: 按行优先顺序传递的稠密矩阵运算、矩阵分解和线性方程组求解
+//! 
+//! 除了释放函数和只读查询外，所有导出函数都返回 `MathError` 错误码，
+//! 结果通过最后的指针参数返回，这样错误永远不会和合法结果混在一起
+//! 
+//! 对于句柄接口：
+//! 
+//! - 对象由 Rust 在堆上分配，C 代码只持有指针
+//! - C 代码不能直接访问对象内部，只能通过导出函数操作
+//! - 每个句柄使用完毕后都必须调用对应的释放函数
+
+use std::ffi::CString;
+use std::os::raw::c_long;
+use std::{ptr, slice};
+use crate::types::{MathError, MathResult, RustIntegerArrayResult, RustStringResult};
+
+/// 基本数学运算接口
+pub mod basic;
+/// 高级数学运算接口
+pub mod advanced;
+/// 伽马函数接口
+pub mod gamma;
+/// 贝塞尔函数接口
+pub mod bessel;
+/// 误差函数接口
+pub mod erf;
+/// 数论接口
+pub mod number_theory;
+/// 质数筛接口
+pub mod sieve;
+/// 模运算接口
+pub mod modular;
+/// 幂运算接口
+pub mod power;
+/// 斐波那契数和卢卡斯数接口
+pub mod fibonacci;
+/// 大整数对象接口
+pub mod bigint;
+/// 整数序列对象接口
+pub mod sequence;
+/// 组合数学接口
+pub mod combinatorics;
+/// 组合枚举对象接口
+pub mod enumeration;
+/// 有理数对象接口
+pub mod rational;
+/// 定点小数对象接口
+pub mod decimal;
+/// 复数接口
+pub mod complex;
+/// 稠密矩阵接口
+pub mod matrix;
+
+/// 把 C 数组转换为切片，指针或长度无效时返回 None
+/// 
+/// # Safety
+/// `numbers` 必须指向至少 `length` 个元素的数组（length 为 0 时可以是空指针）
+pub(crate) unsafe fn numbers_slice<'a>(numbers: *const c_long, length: c_long) -> Option<&'a [c_long]> {
+    if length < 0 || (length > 0 && numbers.is_null()) {
+        return None;
+    }
+
+    if length == 0 {
+        Some(&[])
+    } else {
+        Some(slice::from_raw_parts(numbers, length as usize))
+    }
+}
+
+/// 把运算结果写入输出指针并返回错误码
+/// 
+/// 即使出错也会写入结果中的值（例如饱和后的值），方便调用者按需使用
+/// 
+/// # Safety
+/// `out` 必须是可写的指针或空指针，为空指针时返回 MathError::InvalidParameter
+pub(crate) unsafe fn write_result<T>(result: MathResult<T>, out: *mut T) -> MathError {
+    if out.is_null() {
+        return MathError::InvalidParameter;
+    }
+    
+    *out = result.value;
+    result.error
+}
+
+/// 把不会出错的运算结果写入输出指针
+/// 
+/// # Safety
+/// `out` 必须是可写的指针或空指针，为空指针时返回 MathError::InvalidParameter
+pub(crate) unsafe fn write_value<T>(value: T, out: *mut T) -> MathError {
+    write_result(MathResult::success(value), out)
+}
+
+/// 把 Rust 字符串转换为交给 C 代码的字符串结果
+/// 
+/// 返回的内存需要调用 rust_free_string_result 释放
+pub(crate) fn string_result(text: String) -> RustStringResult {
+    let length = text.len() as c_long;
+    
+    // 数字字符串中不会出现 NUL 字符，这里的转换不会失败
+    let data = CString::new(text)
+        .map(CString::into_raw)
+        .unwrap_or(ptr::null_mut());
+    
+    RustStringResult { data, length }
+}
+
+/// 出错时使用的空字符串结果
+pub(crate) fn empty_string_result() -> RustStringResult {
+    RustStringResult {
+        data: ptr::null_mut(),
+        length: 0,
+    }
+}
+
+/// 把 Rust 数组转换为交给 C 代码的数组结果
+/// 
+/// 空数组使用空指针表示；返回的内存需要调用 rust_free_integer_array_result 释放
+pub(crate) fn integer_array_result(numbers: Vec<c_long>) -> RustIntegerArrayResult {
+    if numbers.is_empty() {
+        return empty_integer_array_result();
+    }
+    
+    let length = numbers.len() as c_long;
+    
+    // 转换为 Box<[c_long]> 保证容量等于长度，释放时可以按长度重建
+    let numbers = Box::into_raw(numbers.into_boxed_slice()) as *mut c_long;
+    
+    RustIntegerArrayResult { numbers, length }
+}
+
+/// 出错时使用的空数组结果
+pub(crate) fn empty_integer_array_result() -> RustIntegerArrayResult {
+    RustIntegerArrayResult {
+        numbers: ptr::null_mut(),
+        length: 0,
+    }
+}
+
+/// 把返回整数数组的运算结果写入输出指针并返回错误码
+/// 
+/// 出错时写入空数组结果
+/// 
+/// # Safety
+/// `out` 必须是可写的指针或空指针，为空指针时返回 MathError::InvalidParameter
+pub(crate) unsafe fn write_integer_array(result: MathResult<Vec<c_long>>, out: *mut RustIntegerArrayResult) -> MathError {
+    if out.is_null() {
+        return MathError::InvalidParameter;
+    }
+    
+    *out = if result.is_error() {
+        empty_integer_array_result()
+    } else {
+        integer_array_result(result.value)
+    };
+    result.error
+}
+
+/// 导出函数：释放整数数组结果的内存
+#[no_mangle]
+pub extern "C" fn rust_free_integer_array_result(result: RustIntegerArrayResult) {
+    if !result.numbers.is_null() {
+        unsafe {
+            // 重新取得所有权，离开作用域时自动释放
+            let _numbers = Box::from_raw(ptr::slice_from_raw_parts_mut(result.numbers, result.length as usize));
+        }
+    }
+}
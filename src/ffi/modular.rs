@@ -0,0 +1,111 @@
+//! 模运算接口
+//! 
+//! 导出 `math::modular` 中的函数
+//! 模数为 0 时返回 MathError::DivisionByZero，为负数时返回 MathError::InvalidParameter
+
+use std::os::raw::c_long;
+use std::slice;
+use crate::ffi::write_result;
+use crate::math::modular;
+use crate::types::{MathError, MathResult, RustCrtResult, RustExtendedGcdResult};
+
+/// 导出函数：模幂 base^exp mod m
+/// 
+/// 指数为负数时使用 base 的模逆元，逆元不存在时返回 MathError::DomainError
+/// 
+/// @php rust_mod_pow(int $base, int $exp, int $m): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_mod_pow_impl(base: c_long, exp: c_long, m: c_long, result: *mut c_long) -> MathError {
+    write_result(modular::mod_pow(base, exp, m), result)
+}
+
+/// 导出函数：模逆元
+/// 
+/// a 与 m 不互质时返回 MathError::DomainError
+/// 
+/// @php rust_mod_inverse(int $a, int $m): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_mod_inverse_impl(a: c_long, m: c_long, result: *mut c_long) -> MathError {
+    write_result(modular::mod_inverse(a, m), result)
+}
+
+/// 导出函数：扩展欧几里得算法
+/// 
+/// 最大公约数超出 int 范围时返回 MathError::Overflow
+/// 
+/// @php-manual rust_extended_gcd(int $a, int $b): array
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_extended_gcd_impl(a: c_long, b: c_long, result: *mut RustExtendedGcdResult) -> MathError {
+    let egcd = modular::extended_gcd(a, b);
+    let (gcd, x, y) = egcd.value;
+    write_result(MathResult::error(egcd.error, RustExtendedGcdResult { gcd, x, y }), result)
+}
+
+/// 导出函数：中国剩余定理
+/// 
+/// 方程组无解时返回 MathError::DomainError，模数的最小公倍数超出 int 范围时返回 MathError::Overflow
+/// 
+/// @php-manual rust_crt(array $remainders, array $moduli): array
+/// 
+/// # Safety
+/// `remainders` 和 `moduli` 必须指向至少 `length` 个元素的数组（length 为 0 时可以是空指针），
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_crt_impl(
+    remainders: *const c_long,
+    moduli: *const c_long,
+    length: c_long,
+    result: *mut RustCrtResult,
+) -> MathError {
+    if length < 0 || (length > 0 && (remainders.is_null() || moduli.is_null())) {
+        return MathError::InvalidParameter;
+    }
+
+    let (remainders, moduli) = if length == 0 {
+        (&[][..], &[][..])
+    } else {
+        (
+            slice::from_raw_parts(remainders, length as usize),
+            slice::from_raw_parts(moduli, length as usize),
+        )
+    };
+
+    let solution = modular::crt(remainders, moduli);
+    let (remainder, modulus) = solution.value;
+    write_result(MathResult::error(solution.error, RustCrtResult { remainder, modulus }), result)
+}
+
+/// 导出函数：雅可比符号 (a/n)
+/// 
+/// n 不是正奇数时返回 MathError::InvalidParameter
+/// 
+/// @php rust_jacobi(int $a, int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_jacobi_impl(a: c_long, n: c_long, result: *mut c_long) -> MathError {
+    write_result(modular::jacobi(a, n), result)
+}
+
+/// 导出函数：勒让德符号 (a/p)
+/// 
+/// p 不是奇质数时返回 MathError::InvalidParameter
+/// 
+/// @php rust_legendre(int $a, int $p): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_legendre_impl(a: c_long, p: c_long, result: *mut c_long) -> MathError {
+    write_result(modular::legendre(a, p), result)
+}
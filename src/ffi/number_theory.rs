@@ -0,0 +1,149 @@
+//! 数论接口
+//! 
+//! 导出 `math::number_theory` 中的函数，以及多个数的最大公约数和最小公倍数
+//! 参数为负数时返回 MathError::NegativeNumber，为 0 时返回 MathError::InvalidParameter
+
+use std::os::raw::{c_int, c_long};
+use std::ptr;
+use crate::ffi::{numbers_slice, write_integer_array, write_result};
+use crate::math::{self, number_theory};
+use crate::types::{MathError, RustFactorizationResult, RustIntegerArrayResult};
+
+/// 出错或分解结果为空（n = 1）时使用的空分解结果
+fn empty_factorization_result() -> RustFactorizationResult {
+    RustFactorizationResult {
+        primes: ptr::null_mut(),
+        exponents: ptr::null_mut(),
+        length: 0,
+    }
+}
+
+/// 导出函数：质因数分解
+/// 
+/// 通过 result 返回按从小到大排列的质因数和对应的指数
+/// 返回的内存需要调用 rust_free_factorization_result 释放
+/// 
+/// @php-manual rust_factorize(int $n): array
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_factorize_impl(n: c_long, result: *mut RustFactorizationResult) -> MathError {
+    if result.is_null() {
+        return MathError::InvalidParameter;
+    }
+
+    let factors = number_theory::factorize(n);
+    if factors.is_error() || factors.value.is_empty() {
+        *result = empty_factorization_result();
+        return factors.error;
+    }
+
+    let (primes, exponents): (Vec<c_long>, Vec<c_int>) = factors.value.into_iter().unzip();
+    let length = primes.len() as c_int;
+
+    *result = RustFactorizationResult {
+        primes: Box::into_raw(primes.into_boxed_slice()) as *mut c_long,
+        exponents: Box::into_raw(exponents.into_boxed_slice()) as *mut c_int,
+        length,
+    };
+    MathError::Success
+}
+
+/// 导出函数：释放质因数分解结果的内存
+#[no_mangle]
+pub extern "C" fn rust_free_factorization_result(result: RustFactorizationResult) {
+    if result.primes.is_null() {
+        return;
+    }
+
+    let length = result.length as usize;
+    unsafe {
+        // 重新取得所有权，离开作用域时自动释放
+        let _primes = Box::from_raw(ptr::slice_from_raw_parts_mut(result.primes, length));
+        let _exponents = Box::from_raw(ptr::slice_from_raw_parts_mut(result.exponents, length));
+    }
+}
+
+/// 导出函数：正整数的全部正因数
+/// 
+/// 通过 result 返回从小到大排列的因数，出错时 numbers 为空指针
+/// 返回的内存需要调用 rust_free_integer_array_result 释放
+/// 
+/// @php-manual rust_divisors(int $n): array
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_divisors_impl(n: c_long, result: *mut RustIntegerArrayResult) -> MathError {
+    write_integer_array(number_theory::divisors(n), result)
+}
+
+/// 导出函数：因数幂和函数 σ_k(n)
+/// 
+/// 结果超出 int 范围时返回 MathError::Overflow
+/// 
+/// @php rust_sigma(int $n, int $k = 1): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sigma_impl(n: c_long, k: c_long, result: *mut c_long) -> MathError {
+    write_result(number_theory::sigma(n, k), result)
+}
+
+/// 导出函数：欧拉函数 φ(n)
+/// 
+/// @php rust_euler_phi(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_euler_phi_impl(n: c_long, result: *mut c_long) -> MathError {
+    write_result(number_theory::euler_phi(n), result)
+}
+
+/// 导出函数：莫比乌斯函数 μ(n)
+/// 
+/// @php rust_mobius(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_mobius_impl(n: c_long, result: *mut c_long) -> MathError {
+    write_result(number_theory::mobius(n), result)
+}
+
+/// 导出函数：多个数的最大公约数
+/// 
+/// 数组为空时结果为 0，结果为 2^63 时返回 MathError::Overflow
+/// 
+/// @php-manual rust_gcd_many(array $numbers): int
+/// 
+/// # Safety
+/// `numbers` 必须指向至少 `length` 个元素的数组（length 为 0 时可以是空指针），
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_gcd_many_impl(numbers: *const c_long, length: c_long, result: *mut c_long) -> MathError {
+    match numbers_slice(numbers, length) {
+        Some(numbers) => write_result(math::gcd_many(numbers), result),
+        None => MathError::InvalidParameter,
+    }
+}
+
+/// 导出函数：多个数的最小公倍数
+/// 
+/// 数组为空时结果为 1，结果超出 int 范围时返回 MathError::Overflow
+/// 
+/// @php-manual rust_lcm_many(array $numbers): int
+/// 
+/// # Safety
+/// `numbers` 必须指向至少 `length` 个元素的数组（length 为 0 时可以是空指针），
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_lcm_many_impl(numbers: *const c_long, length: c_long, result: *mut c_long) -> MathError {
+    match numbers_slice(numbers, length) {
+        Some(numbers) => write_result(math::lcm_many(numbers), result),
+        None => MathError::InvalidParameter,
+    }
+}
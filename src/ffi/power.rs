@@ -0,0 +1,51 @@
+//! 幂运算接口
+//! 
+//! 导出 `math::power` 中的函数
+//! 浮点函数按当前浮点模式报告错误，分数幂溢出时总是返回 MathError::Overflow
+
+use std::os::raw::{c_double, c_long};
+use crate::ffi::write_result;
+use crate::math::power;
+use crate::types::{MathError, RustFraction};
+
+/// 导出函数：浮点数的浮点次幂
+/// 
+/// 负数的非整数次幂返回 MathError::DomainError，0 的负数次幂返回 MathError::DivisionByZero
+/// 
+/// @php rust_pow_float(float $base, float $exponent): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_pow_float_impl(base: c_double, exponent: c_double, result: *mut c_double) -> MathError {
+    write_result(power::pow_float(base, exponent), result)
+}
+
+/// 导出函数：浮点数的整数次幂，指数可以为负数
+/// 
+/// @php rust_powi(float $base, int $exponent): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_powi_impl(base: c_double, exponent: c_long, result: *mut c_double) -> MathError {
+    write_result(power::powi(base, exponent), result)
+}
+
+/// 导出函数：分数的整数次幂
+/// 
+/// 通过 result 返回最简分数，分母大于 0
+/// 
+/// @php-manual rust_power_fraction(int $numerator, int $denominator, int $exponent): array
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_power_fraction_impl(
+    numerator: c_long,
+    denominator: c_long,
+    exponent: c_long,
+    result: *mut RustFraction,
+) -> MathError {
+    write_result(power::power_fraction(numerator, denominator, exponent), result)
+}
@@ -0,0 +1,357 @@
+//! 有理数对象接口
+//!
+//! 为 C 代码提供 `Rational` 的句柄操作，PHP 层用它实现 `RustMath\Rational` 类
+//!
+//! 创建新句柄的函数返回错误码，句柄通过最后的 result 指针返回，出错时写入空指针；
+//! 返回的非空句柄都需要调用 `rust_rational_free` 释放
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_double, c_int, c_long};
+use std::ptr;
+use crate::ffi::{numbers_slice, string_result, write_integer_array, write_value};
+use crate::math::rational::Rational;
+use crate::types::{MathError, MathResult, RustIntegerArrayResult, RustStringResult};
+
+/// 把运算结果放到堆上并通过 out 返回句柄，出错时写入空指针
+///
+/// # Safety
+/// `out` 必须是可写的指针或空指针，为空指针时返回 MathError::InvalidParameter
+unsafe fn write_handle(result: MathResult<Rational>, out: *mut *mut Rational) -> MathError {
+    if out.is_null() {
+        return MathError::InvalidParameter;
+    }
+
+    *out = if result.is_error() {
+        ptr::null_mut()
+    } else {
+        Box::into_raw(Box::new(result.value))
+    };
+    result.error
+}
+
+/// 句柄为空指针时使用的错误结果
+fn invalid() -> MathResult<Rational> {
+    MathResult::error(MathError::InvalidParameter, Rational::zero())
+}
+
+/// 导出函数：由分子和分母创建有理数，结果约分到最简形式
+///
+/// 分母为 0 时返回 MathError::DivisionByZero，约分后超出 long 范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_new(numerator: c_long, denominator: c_long, result: *mut *mut Rational) -> MathError {
+    write_handle(Rational::new(numerator, denominator), result)
+}
+
+/// 导出函数：解析 "-7"、"3/4" 或 "-1.25" 形式的字符串创建有理数
+///
+/// 格式无效时返回 MathError::InvalidParameter，分母为 0 时返回 MathError::DivisionByZero，
+/// 超出 long 范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `s` 必须是有效的、以 NUL 结尾的 C 字符串，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_from_string(s: *const c_char, result: *mut *mut Rational) -> MathError {
+    if s.is_null() {
+        return write_handle(invalid(), result);
+    }
+
+    let parsed = match CStr::from_ptr(s).to_str() {
+        Ok(text) => Rational::parse(text),
+        Err(_) => invalid(),
+    };
+    write_handle(parsed, result)
+}
+
+/// 导出函数：分母不超过 max_denominator 的浮点数的最佳近似
+///
+/// x 为 NaN 时返回 MathError::NotANumber，无穷大或超出 long 范围时返回 MathError::Overflow，
+/// max_denominator 小于 1 时返回 MathError::InvalidParameter
+///
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_from_double(
+    x: c_double,
+    max_denominator: c_long,
+    result: *mut *mut Rational,
+) -> MathError {
+    write_handle(Rational::from_f64(x, max_denominator), result)
+}
+
+/// 导出函数：由连分数 [a0; a1, a2, ...] 创建有理数
+///
+/// terms 为空或 a0 以外的项不大于 0 时返回 MathError::InvalidParameter，
+/// 中间结果超出 long 范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `terms` 必须指向至少 `length` 个元素的数组，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_from_continued_fraction(
+    terms: *const c_long,
+    length: c_long,
+    result: *mut *mut Rational,
+) -> MathError {
+    match numbers_slice(terms, length) {
+        Some(terms) => write_handle(Rational::from_continued_fraction(terms), result),
+        None => write_handle(invalid(), result),
+    }
+}
+
+/// 导出函数：复制有理数
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_clone(a: *const Rational, result: *mut *mut Rational) -> MathError {
+    unary_op(a, result, |a| MathResult::success(*a))
+}
+
+/// 导出函数：释放有理数
+///
+/// # Safety
+/// `a` 必须是由本模块返回的句柄或空指针，并且只能释放一次
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_free(a: *mut Rational) {
+    if !a.is_null() {
+        // 重新取得所有权，离开作用域时自动释放
+        let _value = Box::from_raw(a);
+    }
+}
+
+/// 对一个句柄执行运算，句柄为空指针时返回 MathError::InvalidParameter
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄或空指针，`result` 必须是可写的指针
+unsafe fn unary_op(
+    a: *const Rational,
+    result: *mut *mut Rational,
+    op: impl FnOnce(&Rational) -> MathResult<Rational>,
+) -> MathError {
+    match a.as_ref() {
+        Some(a) => write_handle(op(a), result),
+        None => write_handle(invalid(), result),
+    }
+}
+
+/// 对两个句柄执行运算，任一句柄为空指针时返回 MathError::InvalidParameter
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的有理数句柄或空指针，`result` 必须是可写的指针
+unsafe fn binary_op(
+    a: *const Rational,
+    b: *const Rational,
+    result: *mut *mut Rational,
+    op: impl FnOnce(&Rational, &Rational) -> MathResult<Rational>,
+) -> MathError {
+    match (a.as_ref(), b.as_ref()) {
+        (Some(a), Some(b)) => write_handle(op(a, b), result),
+        _ => write_handle(invalid(), result),
+    }
+}
+
+/// 导出函数：有理数加法
+///
+/// 结果超出 long 范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_add(a: *const Rational, b: *const Rational, result: *mut *mut Rational) -> MathError {
+    binary_op(a, b, result, Rational::add)
+}
+
+/// 导出函数：有理数减法
+///
+/// 结果超出 long 范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_sub(a: *const Rational, b: *const Rational, result: *mut *mut Rational) -> MathError {
+    binary_op(a, b, result, Rational::sub)
+}
+
+/// 导出函数：有理数乘法
+///
+/// 结果超出 long 范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_mul(a: *const Rational, b: *const Rational, result: *mut *mut Rational) -> MathError {
+    binary_op(a, b, result, Rational::mul)
+}
+
+/// 导出函数：有理数除法
+///
+/// 除数为 0 时返回 MathError::DivisionByZero，结果超出 long 范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_div(a: *const Rational, b: *const Rational, result: *mut *mut Rational) -> MathError {
+    binary_op(a, b, result, Rational::div)
+}
+
+/// 导出函数：有理数的整数次幂，指数可以为负数
+///
+/// 0 的负数次幂返回 MathError::DivisionByZero，结果超出 long 范围时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_pow(a: *const Rational, exponent: c_long, result: *mut *mut Rational) -> MathError {
+    unary_op(a, result, |a| a.pow(exponent))
+}
+
+/// 导出函数：有理数取反
+///
+/// 分子为 long 最小值时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_neg(a: *const Rational, result: *mut *mut Rational) -> MathError {
+    unary_op(a, result, Rational::neg)
+}
+
+/// 导出函数：有理数绝对值
+///
+/// 分子为 long 最小值时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_abs(a: *const Rational, result: *mut *mut Rational) -> MathError {
+    unary_op(a, result, Rational::abs)
+}
+
+/// 导出函数：有理数的倒数
+///
+/// 值为 0 时返回 MathError::DivisionByZero，分子为 long 最小值时返回 MathError::Overflow
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_reciprocal(a: *const Rational, result: *mut *mut Rational) -> MathError {
+    unary_op(a, result, Rational::reciprocal)
+}
+
+/// 导出函数：分母不超过 max_denominator 的最佳近似
+///
+/// max_denominator 小于 1 时返回 MathError::InvalidParameter
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_limit_denominator(
+    a: *const Rational,
+    max_denominator: c_long,
+    result: *mut *mut Rational,
+) -> MathError {
+    unary_op(a, result, |a| a.limit_denominator(max_denominator))
+}
+
+/// 导出函数：比较两个有理数
+///
+/// # 返回值
+/// a < b 返回 -1，a == b 返回 0，a > b 返回 1
+///
+/// # Safety
+/// `a` 和 `b` 必须是有效的有理数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_cmp(a: *const Rational, b: *const Rational) -> c_int {
+    match (a.as_ref(), b.as_ref()) {
+        (Some(a), Some(b)) => a.cmp(b) as c_int,
+        _ => 0,
+    }
+}
+
+/// 导出函数：获取有理数的符号
+///
+/// # 返回值
+/// 负数返回 -1，零返回 0，正数返回 1
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_sign(a: *const Rational) -> c_int {
+    a.as_ref().map_or(0, |a| a.signum() as c_int)
+}
+
+/// 导出函数：最简形式的分子
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_numerator(a: *const Rational) -> c_long {
+    a.as_ref().map_or(0, Rational::numerator)
+}
+
+/// 导出函数：最简形式的分母，总是大于 0
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_denominator(a: *const Rational) -> c_long {
+    a.as_ref().map_or(1, Rational::denominator)
+}
+
+/// 导出函数：向下取整
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_floor(a: *const Rational) -> c_long {
+    a.as_ref().map_or(0, Rational::floor)
+}
+
+/// 导出函数：向上取整
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_ceil(a: *const Rational) -> c_long {
+    a.as_ref().map_or(0, Rational::ceil)
+}
+
+/// 导出函数：转换为最接近的浮点数
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_to_double(a: *const Rational) -> c_double {
+    a.as_ref().map_or(0.0, Rational::to_f64)
+}
+
+/// 导出函数：转换为 "n/d" 形式的字符串，整数转换为 "n"
+///
+/// 返回的内存需要调用 rust_free_string_result 释放
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_to_string(a: *const Rational, result: *mut RustStringResult) -> MathError {
+    match a.as_ref() {
+        Some(a) => write_value(string_result(a.to_string()), result),
+        None => MathError::InvalidParameter,
+    }
+}
+
+/// 导出函数：连分数展开 [a0; a1, a2, ...]
+///
+/// 返回的内存需要调用 rust_free_integer_array_result 释放
+///
+/// # Safety
+/// `a` 必须是有效的有理数句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_rational_continued_fraction(a: *const Rational, result: *mut RustIntegerArrayResult) -> MathError {
+    let terms = match a.as_ref() {
+        Some(a) => MathResult::success(a.continued_fraction()),
+        None => MathResult::error(MathError::InvalidParameter, Vec::new()),
+    };
+    write_integer_array(terms, result)
+}
@@ -0,0 +1,212 @@
+//! 整数序列对象接口
+//! 
+//! 为 C 代码提供 `Sequence` 的句柄操作，PHP 层用它实现 `RustMath\Sequence` 迭代器类
+//! 
+//! 创建句柄的函数返回错误码，句柄通过最后的 result 指针返回，出错时写入空指针；
+//! 返回的非空句柄都需要调用 `rust_sequence_free` 释放
+
+use std::os::raw::c_long;
+use std::{ptr, slice};
+use crate::ffi::{write_integer_array, write_value};
+use crate::math::sequence::Sequence;
+use crate::types::{MathError, MathResult, RustIntegerArrayResult};
+
+/// 把序列放到堆上并通过 out 返回句柄，出错时写入空指针
+/// 
+/// # Safety
+/// `out` 必须是可写的指针或空指针，为空指针时返回 MathError::InvalidParameter
+unsafe fn write_handle(result: MathResult<Sequence>, out: *mut *mut Sequence) -> MathError {
+    if out.is_null() {
+        return MathError::InvalidParameter;
+    }
+
+    *out = if result.is_error() {
+        ptr::null_mut()
+    } else {
+        Box::into_raw(Box::new(result.value))
+    };
+    result.error
+}
+
+/// 导出函数：创建斐波那契数列
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_fibonacci(result: *mut *mut Sequence) -> MathError {
+    write_handle(MathResult::success(Sequence::fibonacci()), result)
+}
+
+/// 导出函数：创建卢卡斯数列
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_lucas(result: *mut *mut Sequence) -> MathError {
+    write_handle(MathResult::success(Sequence::lucas()), result)
+}
+
+/// 导出函数：创建卡塔兰数列
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_catalan(result: *mut *mut Sequence) -> MathError {
+    write_handle(MathResult::success(Sequence::catalan()), result)
+}
+
+/// 导出函数：创建三角形数列
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_triangular(result: *mut *mut Sequence) -> MathError {
+    write_handle(MathResult::success(Sequence::triangular()), result)
+}
+
+/// 导出函数：创建质数序列
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_primes(result: *mut *mut Sequence) -> MathError {
+    write_handle(MathResult::success(Sequence::primes()), result)
+}
+
+/// 导出函数：创建考拉兹序列
+/// 
+/// start 为负数时返回 MathError::NegativeNumber，为 0 时返回 MathError::InvalidParameter
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_collatz(start: c_long, result: *mut *mut Sequence) -> MathError {
+    write_handle(Sequence::collatz(start), result)
+}
+
+/// 导出函数：创建常系数线性递推序列
+/// 
+/// 系数为空时返回 MathError::InvalidParameter
+/// 
+/// # Safety
+/// `coefficients` 和 `initial` 必须指向至少 `length` 个元素的数组，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_linear(
+    coefficients: *const c_long,
+    initial: *const c_long,
+    length: c_long,
+    result: *mut *mut Sequence,
+) -> MathError {
+    if length <= 0 || coefficients.is_null() || initial.is_null() {
+        return write_handle(MathResult::error(MathError::InvalidParameter, Sequence::fibonacci()), result);
+    }
+
+    let coefficients = slice::from_raw_parts(coefficients, length as usize);
+    let initial = slice::from_raw_parts(initial, length as usize);
+    write_handle(Sequence::linear_recurrence(coefficients, initial), result)
+}
+
+/// 导出函数：复制序列，副本从同一项开始独立前进
+/// 
+/// # Safety
+/// `s` 必须是有效的序列句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_clone(s: *const Sequence, result: *mut *mut Sequence) -> MathError {
+    match s.as_ref() {
+        Some(s) => write_handle(MathResult::success(s.clone()), result),
+        None => write_handle(MathResult::error(MathError::InvalidParameter, Sequence::fibonacci()), result),
+    }
+}
+
+/// 导出函数：释放序列
+/// 
+/// # Safety
+/// `s` 必须是由本模块返回的句柄或空指针，并且只能释放一次
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_free(s: *mut Sequence) {
+    if !s.is_null() {
+        // 重新取得所有权，离开作用域时自动释放
+        let _sequence = Box::from_raw(s);
+    }
+}
+
+/// 导出函数：序列是否还有当前项
+/// 
+/// # 返回值
+/// 有限序列结束后返回 false；当前项超出范围时仍然返回 true，读取它时报告溢出
+/// 
+/// # Safety
+/// `s` 必须是有效的序列句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_valid(s: *const Sequence) -> bool {
+    s.as_ref().is_some_and(|s| !s.is_finished())
+}
+
+/// 导出函数：当前项的下标，从 0 开始
+/// 
+/// # Safety
+/// `s` 必须是有效的序列句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_key(s: *const Sequence) -> c_long {
+    s.as_ref().map_or(0, Sequence::index)
+}
+
+/// 导出函数：获取当前项
+/// 
+/// 当前项超出 long 范围时返回 MathError::Overflow，序列已经结束时返回 MathError::InvalidParameter
+/// 
+/// # Safety
+/// `s` 必须是有效的序列句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_current(s: *const Sequence, result: *mut c_long) -> MathError {
+    let current = match s.as_ref() {
+        Some(s) => s.current(),
+        None => return MathError::InvalidParameter,
+    };
+
+    match current.value {
+        _ if current.is_error() => current.error,
+        Some(term) => write_value(term, result),
+        None => MathError::InvalidParameter,
+    }
+}
+
+/// 导出函数：移动到下一项
+/// 
+/// 下一项超出 long 范围时仍然会移动，之后读取当前项时返回 MathError::Overflow
+/// 
+/// # Safety
+/// `s` 必须是有效的序列句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_next(s: *mut Sequence) {
+    if let Some(s) = s.as_mut() {
+        s.advance();
+    }
+}
+
+/// 导出函数：回到第 0 项
+/// 
+/// # Safety
+/// `s` 必须是有效的序列句柄
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_rewind(s: *mut Sequence) {
+    if let Some(s) = s.as_mut() {
+        s.rewind();
+    }
+}
+
+/// 导出函数：从当前项开始取出最多 count 项
+/// 
+/// count 超过 SEQUENCE_TAKE_LIMIT 时返回 MathError::InvalidParameter；
+/// 通过 result 返回取出的项，出错时 numbers 为空指针
+/// 返回的内存需要调用 rust_free_integer_array_result 释放
+/// 
+/// # Safety
+/// `s` 必须是有效的序列句柄，`result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_sequence_take(s: *mut Sequence, count: c_long, result: *mut RustIntegerArrayResult) -> MathError {
+    match s.as_mut() {
+        Some(s) => write_integer_array(s.take(count), result),
+        None => write_integer_array(MathResult::error(MathError::InvalidParameter, Vec::new()), result),
+    }
+}
@@ -0,0 +1,62 @@
+//! 质数筛接口
+//! 
+//! 导出 `math::sieve` 中的函数
+//! 返回质数列表的函数通过 RustIntegerArrayResult 返回数组，
+//! 返回的内存需要调用 rust_free_integer_array_result 释放
+
+use std::os::raw::c_long;
+use crate::ffi::{write_integer_array, write_result};
+use crate::math::sieve;
+use crate::types::{MathError, RustIntegerArrayResult};
+
+/// 导出函数：不超过 n 的全部质数
+/// 
+/// n 超过 1 亿时返回 MathError::InvalidParameter
+/// 
+/// @php-manual rust_primes_up_to(int $n): array
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_primes_up_to_impl(n: c_long, result: *mut RustIntegerArrayResult) -> MathError {
+    write_integer_array(sieve::primes_up_to(n), result)
+}
+
+/// 导出函数：区间 [a, b] 内的全部质数
+/// 
+/// 区间长度超过 1 亿时返回 MathError::InvalidParameter
+/// 
+/// @php-manual rust_primes_between(int $a, int $b): array
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_primes_between_impl(a: c_long, b: c_long, result: *mut RustIntegerArrayResult) -> MathError {
+    write_integer_array(sieve::primes_between(a, b), result)
+}
+
+/// 导出函数：不超过 n 的质数个数
+/// 
+/// n 超过 10 亿时返回 MathError::InvalidParameter
+/// 
+/// @php rust_prime_count(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_prime_count_impl(n: c_long, result: *mut c_long) -> MathError {
+    write_result(sieve::prime_count(n), result)
+}
+
+/// 导出函数：第 k 个质数
+/// 
+/// k 小于 1 或超过 1 亿时返回 MathError::InvalidParameter
+/// 
+/// @php rust_nth_prime(int $k): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_nth_prime_impl(k: c_long, result: *mut c_long) -> MathError {
+    write_result(sieve::nth_prime(k), result)
+}
@@ -9,6 +9,8 @@
 //! - `math`: 数学运算函数
 //!   - `basic`: 基本数学运算（加减乘除等）
 //!   - `advanced`: 高级数学运算（三角函数、对数等）
+//!   - `bigint`: 任意精度整数
+//! - `ffi`: 基于对象句柄的 C 接口
 //! 
 //! ## 作者
 //! 学习 Rust 的新手
@@ -17,64 +19,265 @@
 //! 学习 Rust 和 PHP 扩展开发
 
 // 导入必要的标准库模块
-use std::os::raw::{c_long, c_double, c_int};
+use std::ffi::CString;
+use std::os::raw::{c_char, c_long, c_double, c_int};
 use std::ptr;
 
 // 声明子模块
 pub mod types;
 pub mod utils;
 pub mod math;
+pub mod ffi;
 
 // 重新导出主要类型
-pub use types::{RustFibonacciResult, MathError, MathResult};
+pub use types::{RustFibonacciResult, RustStringResult, RustIntegerArrayResult, RustFactorizationResult, RustExtendedGcdResult, RustCrtResult, RustFraction, RustComplex, MathError, MathResult, OverflowMode, FloatMode, MessageLanguage};
 
 // 重新导出主要数学函数
-pub use math::{add, multiply, factorial, fibonacci, is_prime, gcd, lcm, power};
+pub use math::{add, multiply, factorial, factorial_big, fibonacci, is_prime, gcd, lcm, power};
 
 // ============================================================================
 // C 接口函数 - 这些函数将被导出到 C 代码
 // ============================================================================
 
+/// 导出函数：设置当前线程的整数溢出处理模式
+/// 
+/// # 参数
+/// * `mode` - 0 饱和，1 回绕，2 检查
+/// 
+/// # 返回值
+/// 模式代码有效返回 true，否则返回 false 并保持原设置
+#[no_mangle]
+pub extern "C" fn rust_set_overflow_mode(mode: c_int) -> bool {
+    match OverflowMode::from_code(mode) {
+        Some(mode) => {
+            utils::set_overflow_mode(mode);
+            true
+        }
+        None => false,
+    }
+}
+
+/// 导出函数：获取当前线程的整数溢出处理模式
+#[no_mangle]
+pub extern "C" fn rust_get_overflow_mode() -> c_int {
+    utils::overflow_mode() as c_int
+}
+
+/// 导出函数：设置当前线程的浮点数错误处理模式
+/// 
+/// # 参数
+/// * `mode` - 0 严格，1 IEEE 754
+/// 
+/// # 返回值
+/// 模式代码有效返回 true，否则返回 false 并保持原设置
+#[no_mangle]
+pub extern "C" fn rust_set_float_mode(mode: c_int) -> bool {
+    match FloatMode::from_code(mode) {
+        Some(mode) => {
+            utils::set_float_mode(mode);
+            true
+        }
+        None => false,
+    }
+}
+
+/// 导出函数：获取当前线程的浮点数错误处理模式
+#[no_mangle]
+pub extern "C" fn rust_get_float_mode() -> c_int {
+    utils::float_mode() as c_int
+}
+
+/// 导出函数：设置当前线程的错误描述语言
+/// 
+/// # 参数
+/// * `language` - 0 英文，1 中文
+/// 
+/// # 返回值
+/// 语言代码有效返回 true，否则返回 false 并保持原设置
+#[no_mangle]
+pub extern "C" fn rust_set_language(language: c_int) -> bool {
+    match MessageLanguage::from_code(language) {
+        Some(language) => {
+            utils::set_language(language);
+            true
+        }
+        None => false,
+    }
+}
+
+/// 导出函数：获取当前线程的错误描述语言
+#[no_mangle]
+pub extern "C" fn rust_get_language() -> c_int {
+    utils::language() as c_int
+}
+
+/// 导出函数：获取错误码对应的错误信息（当前语言）
+/// 
+/// # 参数
+/// * `code` - MathError 的取值
+/// 
+/// # 返回值
+/// 以 NUL 结尾的静态字符串，不需要释放；错误码无效时返回 "Unknown error"
+#[no_mangle]
+pub extern "C" fn rust_math_error_message(code: c_int) -> *const c_char {
+    rust_math_error_message_in(code, utils::language() as c_int)
+}
+
+/// 导出函数：获取错误码对应的指定语言的错误信息
+/// 
+/// # 参数
+/// * `code` - MathError 的取值
+/// * `language` - 0 英文，1 中文，无效时使用英文
+/// 
+/// # 返回值
+/// 以 NUL 结尾的静态字符串，不需要释放
+/// 
+/// @php-manual rust_math_error_message(int $code, ?string $language = null): string
+#[no_mangle]
+pub extern "C" fn rust_math_error_message_in(code: c_int, language: c_int) -> *const c_char {
+    let language = MessageLanguage::from_code(language).unwrap_or_default();
+    
+    match (MathError::from_code(code), language) {
+        (Some(error), _) => error.message_in(language).as_ptr(),
+        (None, MessageLanguage::English) => c"Unknown error".as_ptr(),
+        (None, MessageLanguage::Chinese) => c"未知错误".as_ptr(),
+    }
+}
+
+/// 导出函数：获取错误码的符号名称
+/// 
+/// # 参数
+/// * `code` - MathError 的取值
+/// 
+/// # 返回值
+/// 以 NUL 结尾的静态字符串（如 "OVERFLOW"），不需要释放；错误码无效时返回 "UNKNOWN"
+/// 
+/// @php-manual rust_math_error_name(int $code): string
+#[no_mangle]
+pub extern "C" fn rust_math_error_name(code: c_int) -> *const c_char {
+    match MathError::from_code(code) {
+        Some(error) => error.name().as_ptr(),
+        None => c"UNKNOWN".as_ptr(),
+    }
+}
+
 /// 导出函数：计算两个整数的和
 /// 
 /// 这个函数可以从 C 代码调用
 /// #[no_mangle] 告诉 Rust 不要修改函数名
 /// extern "C" 指定使用 C 调用约定
+/// 
+/// 结果通过 result 指针返回，返回值是错误码：
+/// 在检查模式下溢出时返回 MathError::Overflow
+/// 
+/// @php rust_add(int $a, int $b): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
 #[no_mangle]
-pub extern "C" fn rust_add_impl(a: c_long, b: c_long) -> c_long {
-    add(a, b)
+pub unsafe extern "C" fn rust_add_impl(a: c_long, b: c_long, result: *mut c_long) -> MathError {
+    ffi::write_result(add(a, b), result)
 }
 
 /// 导出函数：计算两个数的乘积
+/// 
+/// @php rust_multiply(float $a, float $b): float
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
 #[no_mangle]
-pub extern "C" fn rust_multiply_impl(a: c_double, b: c_double) -> c_double {
-    multiply(a, b)
+pub unsafe extern "C" fn rust_multiply_impl(a: c_double, b: c_double, result: *mut c_double) -> MathError {
+    ffi::write_result(utils::check_float(multiply(a, b)), result)
 }
 
 /// 导出函数：计算阶乘
+/// 
+/// 负数返回 MathError::NegativeNumber，结果超出 long 范围（n > 20）时返回 MathError::Overflow
+/// 
+/// @php rust_factorial(int $n): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn rust_factorial_impl(n: c_long, result: *mut c_long) -> MathError {
+    ffi::write_result(factorial(n), result)
+}
+
+/// 导出函数：计算任意精度阶乘
+/// 
+/// 通过 result 返回阶乘结果的十进制字符串，出错时 data 为空指针
+/// 返回的内存需要调用 rust_free_string_result 释放
+/// 
+/// 负数返回 MathError::NegativeNumber，n 超过 FACTORIAL_BIG_LIMIT 时返回 MathError::InvalidParameter
+/// 
+/// @php-manual rust_factorial_big(int $n): string
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
 #[no_mangle]
-pub extern "C" fn rust_factorial_impl(n: c_long) -> c_long {
-    match factorial(n) {
-        MathResult { value, error: MathError::Success } => value,
-        MathResult { value: _, error: MathError::NegativeNumber } => -1,
-        MathResult { value: _, error: MathError::Overflow } => -2,
-        _ => -3,
+pub unsafe extern "C" fn rust_factorial_big_impl(n: c_long, result: *mut RustStringResult) -> MathError {
+    if result.is_null() {
+        return MathError::InvalidParameter;
+    }
+    
+    let big = factorial_big(n);
+    if big.is_error() {
+        *result = ffi::empty_string_result();
+        return big.error;
+    }
+    
+    ffi::write_value(ffi::string_result(big.value.to_decimal_string()), result)
+}
+
+/// 导出函数：释放字符串结果的内存
+#[no_mangle]
+pub extern "C" fn rust_free_string_result(result: RustStringResult) {
+    if !result.data.is_null() {
+        unsafe {
+            // 重新取得所有权，离开作用域时自动释放
+            let _data = CString::from_raw(result.data);
+        }
     }
 }
 
 /// 导出函数：生成斐波那契数列
+/// 
+/// 通过 result 返回数列，出错时 numbers 为空指针
+/// 返回的内存需要调用 rust_free_fibonacci_result 释放
+/// 
+/// 长度为负数返回 MathError::NegativeNumber，超过 100 返回 MathError::InvalidParameter，
+/// 超过 93（第 94 项超出 int 范围）时返回 MathError::Overflow，与溢出模式无关
+/// 
+/// @php-manual rust_fibonacci(int $n): array
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
 #[no_mangle]
-pub extern "C" fn rust_fibonacci_impl(n: c_long) -> RustFibonacciResult {
+pub unsafe extern "C" fn rust_fibonacci_impl(n: c_long, result: *mut RustFibonacciResult) -> MathError {
+    if result.is_null() {
+        return MathError::InvalidParameter;
+    }
+    
+    *result = RustFibonacciResult {
+        numbers: ptr::null_mut(),
+        length: 0,
+    };
+    
     // 参数验证
-    if n < 0 || n > 100 {
-        return RustFibonacciResult {
-            numbers: ptr::null_mut(),
-            length: 0,
-        };
+    if n < 0 {
+        return MathError::NegativeNumber;
+    }
+    
+    if n > 100 {
+        return MathError::InvalidParameter;
     }
     
     // 生成斐波那契数列
     let sequence = fibonacci(n);
+    if sequence.is_error() {
+        return sequence.error;
+    }
+    let sequence = sequence.value;
     
     // 将 Rust 向量转换为 C 数组
     let length = sequence.len() as c_int;
@@ -90,10 +293,11 @@ pub extern "C" fn rust_fibonacci_impl(n: c_long) -> RustFibonacciResult {
     // 防止 Rust 在函数结束时释放内存
     std::mem::forget(numbers);
     
-    RustFibonacciResult {
+    *result = RustFibonacciResult {
         numbers: numbers_ptr,
         length,
-    }
+    };
+    MathError::Success
 }
 
 /// 导出函数：释放斐波那契数列结果的内存
@@ -113,30 +317,55 @@ pub extern "C" fn rust_free_fibonacci_result(result: RustFibonacciResult) {
 }
 
 /// 导出函数：检查一个数是否为质数
+/// 
+/// @php rust_is_prime(int $n): bool
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
 #[no_mangle]
-pub extern "C" fn rust_is_prime_impl(n: c_long) -> bool {
-    is_prime(n)
+pub unsafe extern "C" fn rust_is_prime_impl(n: c_long, result: *mut bool) -> MathError {
+    ffi::write_value(is_prime(n), result)
 }
 
 /// 导出函数：计算最大公约数
+/// 
+/// 结果为 2^63 时（例如 gcd(PHP_INT_MIN, 0)）返回 MathError::Overflow
+/// 
+/// @php rust_gcd(int $a, int $b): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
 #[no_mangle]
-pub extern "C" fn rust_gcd_impl(a: c_long, b: c_long) -> c_long {
-    gcd(a, b)
+pub unsafe extern "C" fn rust_gcd_impl(a: c_long, b: c_long, result: *mut c_long) -> MathError {
+    ffi::write_result(gcd(a, b), result)
 }
 
 /// 导出函数：计算最小公倍数
+/// 
+/// 先除以最大公约数再相乘，结果超出 int 范围时返回 MathError::Overflow
+/// 
+/// @php rust_lcm(int $a, int $b): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
 #[no_mangle]
-pub extern "C" fn rust_lcm_impl(a: c_long, b: c_long) -> c_long {
-    lcm(a, b)
+pub unsafe extern "C" fn rust_lcm_impl(a: c_long, b: c_long, result: *mut c_long) -> MathError {
+    ffi::write_result(lcm(a, b), result)
 }
 
 /// 导出函数：计算幂运算
+/// 
+/// 结果通过 result 指针返回，返回值是错误码：
+/// 指数为负数且结果不是整数时返回 MathError::InvalidParameter（0 的负数次幂返回 MathError::DivisionByZero），
+/// 溢出时除回绕模式外都返回 MathError::Overflow。浮点结果和分数结果见 `ffi::power`
+/// 
+/// @php rust_power(int $base, int $exponent): int
+/// 
+/// # Safety
+/// `result` 必须是可写的指针
 #[no_mangle]
-pub extern "C" fn rust_power_impl(base: c_long, exponent: c_long) -> c_long {
-    match power(base, exponent) {
-        MathResult { value, error: MathError::Success } => value,
-        _ => -1, // 错误情况
-    }
+pub unsafe extern "C" fn rust_power_impl(base: c_long, exponent: c_long, result: *mut c_long) -> MathError {
+    ffi::write_result(power(base, exponent), result)
 }
 
 // ============================================================================
@@ -147,92 +376,1523 @@ pub extern "C" fn rust_power_impl(base: c_long, exponent: c_long) -> c_long {
 mod tests {
     use super::*;
     
+    /// 调用通过指针返回结果的导出函数，返回 (错误码, 结果)
+    fn call<T: Default>(f: impl FnOnce(*mut T) -> MathError) -> (MathError, T) {
+        let mut result = T::default();
+        let error = f(&mut result);
+        (error, result)
+    }
+    
+    /// 断言浮点数的相对误差不超过 tolerance
+    fn assert_close(actual: c_double, expected: c_double, tolerance: c_double) {
+        let error = ((actual - expected) / expected).abs();
+        assert!(error <= tolerance, "{actual} != {expected}，相对误差 {error:e}");
+    }
+    
+    /// 调用 rust_add_impl 并返回 (错误码, 结果)
+    fn call_rust_add(a: c_long, b: c_long) -> (MathError, c_long) {
+        call(|result| unsafe { rust_add_impl(a, b, result) })
+    }
+    
     #[test]
     fn test_rust_add_impl() {
-        assert_eq!(rust_add_impl(5, 3), 8);
-        assert_eq!(rust_add_impl(-5, 3), -2);
-        assert_eq!(rust_add_impl(0, 0), 0);
-        assert_eq!(rust_add_impl(100, 200), 300);
+        assert_eq!(call_rust_add(5, 3), (MathError::Success, 8));
+        assert_eq!(call_rust_add(-5, 3), (MathError::Success, -2));
+        assert_eq!(call_rust_add(0, 0), (MathError::Success, 0));
+        assert_eq!(call_rust_add(100, 200), (MathError::Success, 300));
+    }
+    
+    #[test]
+    fn test_overflow_modes() {
+        // 默认是饱和模式，与旧版本行为一致
+        assert_eq!(rust_get_overflow_mode(), OverflowMode::Saturating as c_int);
+        assert_eq!(call_rust_add(c_long::MAX, 1), (MathError::Success, c_long::MAX));
+        
+        assert!(rust_set_overflow_mode(OverflowMode::Wrapping as c_int));
+        assert_eq!(call_rust_add(c_long::MAX, 1), (MathError::Success, c_long::MIN));
+        assert_eq!(math::basic::abs_integer(c_long::MIN).value, c_long::MIN);
+        assert_eq!(power(3, 41).value, 3_i64.wrapping_pow(41));
+        
+        assert!(rust_set_overflow_mode(OverflowMode::Checked as c_int));
+        assert_eq!(call_rust_add(c_long::MAX, 1).0, MathError::Overflow);
+        assert_eq!(call_rust_add(c_long::MAX - 1, 1), (MathError::Success, c_long::MAX));
+        assert!(math::basic::subtract_integers(c_long::MIN, 1).is_error());
+        assert!(math::basic::multiply_integers(1 << 32, 1 << 32).is_error());
+        assert!(math::basic::divide_integers(c_long::MIN, -1).is_error());
+        assert_eq!(math::basic::modulo_integers(c_long::MIN, -1).value, 0);
+        assert!(power(2, 63).is_error());
+        assert_eq!(power(-2, 63).value, c_long::MIN);
+        assert!(fibonacci(100).is_error());
+        let mut sequence = RustFibonacciResult { numbers: ptr::null_mut(), length: 0 };
+        assert_eq!(unsafe { rust_fibonacci_impl(100, &mut sequence) }, MathError::Overflow);
+        assert!(sequence.numbers.is_null());
+        
+        // 无效代码不会改变当前设置
+        assert!(!rust_set_overflow_mode(7));
+        assert_eq!(rust_get_overflow_mode(), OverflowMode::Checked as c_int);
+        
+        rust_set_overflow_mode(OverflowMode::Saturating as c_int);
+        // 幂运算在饱和模式下也报告溢出
+        assert_eq!(power(2, 64).error, MathError::Overflow);
+        assert_eq!(power(-2, 65).error, MathError::Overflow);
+        assert_eq!(power(-2, 63).value, c_long::MIN);
+        // 斐波那契数列不再饱和，超出范围时总是报告溢出
+        let sequence = fibonacci(100);
+        assert_eq!(sequence.error, MathError::Overflow);
+        assert_eq!(sequence.value.len(), 93);
+        assert_eq!(fibonacci(93).value[92], 7_540_113_804_746_346_429);
+    }
+    
+    #[test]
+    fn test_float_modes() {
+        use math::{advanced, basic};
+        
+        // 默认是严格模式，无效输入返回错误码而不是 0
+        assert_eq!(rust_get_float_mode(), FloatMode::Strict as c_int);
+        assert_eq!(basic::divide_integers(7, 0).error, MathError::DivisionByZero);
+        assert_eq!(basic::modulo_integers(7, 0).error, MathError::DivisionByZero);
+        assert_eq!(basic::divide_floats(1.0, 0.0).error, MathError::DivisionByZero);
+        assert_eq!(advanced::ln(0.0).error, MathError::DomainError);
+        assert_eq!(advanced::log10(-10.0).error, MathError::DomainError);
+        assert_eq!(advanced::ln(c_double::NAN).error, MathError::NotANumber);
+        
+        assert!(rust_set_float_mode(FloatMode::Ieee as c_int));
+        assert_eq!(basic::divide_floats(-1.0, 0.0).value, c_double::NEG_INFINITY);
+        assert!(basic::divide_floats(0.0, 0.0).value.is_nan());
+        assert_eq!(advanced::ln(0.0).value, c_double::NEG_INFINITY);
+        assert!(advanced::log10(-10.0).is_success());
+        assert!(advanced::sqrt(-1.0).value.is_nan());
+        // 整数除法没有 IEEE 语义，不受浮点模式影响
+        assert!(basic::divide_integers(7, 0).is_error());
+        
+        assert!(!rust_set_float_mode(3));
+        assert_eq!(rust_get_float_mode(), FloatMode::Ieee as c_int);
+        rust_set_float_mode(FloatMode::Strict as c_int);
+    }
+    
+    #[test]
+    fn test_rust_math_error_message() {
+        let message = |code| unsafe { std::ffi::CStr::from_ptr(rust_math_error_message(code)) };
+        
+        assert_eq!(message(MathError::Overflow as c_int).to_str(), Ok(MathError::Overflow.to_string()));
+        assert_eq!(MathError::from_code(MathError::InvalidParameter as c_int), Some(MathError::InvalidParameter));
+        assert_eq!(message(MathError::DivisionByZero as c_int).to_str(), Ok("Division by zero"));
+        assert_eq!(message(42).to_str(), Ok("Unknown error"));
+        
+        assert!(rust_set_language(MessageLanguage::Chinese as c_int));
+        assert_eq!(MathError::Overflow.to_string(), "溢出错误");
+        assert_eq!(message(42).to_str(), Ok("未知错误"));
+        assert!(!rust_set_language(5));
+        assert_eq!(rust_get_language(), MessageLanguage::Chinese as c_int);
+        rust_set_language(MessageLanguage::English as c_int);
+        
+        let message_in = |code, language| unsafe { std::ffi::CStr::from_ptr(rust_math_error_message_in(code, language)) };
+        assert_eq!(message_in(MathError::DomainError as c_int, 1).to_str(), Ok("参数超出定义域"));
+        assert_eq!(message_in(MathError::DomainError as c_int, 9).to_str(), Ok("Argument outside the function domain"));
+        
+        let name = |code| unsafe { std::ffi::CStr::from_ptr(rust_math_error_name(code)) };
+        assert_eq!(name(MathError::DomainError as c_int).to_str(), Ok("DOMAIN"));
+        assert_eq!(name(MathError::NotANumber as c_int).to_str(), Ok("NOT_A_NUMBER"));
+        assert_eq!(name(42).to_str(), Ok("UNKNOWN"));
     }
     
     #[test]
     fn test_rust_multiply_impl() {
-        assert_eq!(rust_multiply_impl(5.0, 3.0), 15.0);
-        assert_eq!(rust_multiply_impl(-2.0, 4.0), -8.0);
-        assert_eq!(rust_multiply_impl(0.0, 5.0), 0.0);
-        assert_eq!(rust_multiply_impl(2.5, 3.5), 8.75);
+        let multiply = |a, b| call(|result| unsafe { rust_multiply_impl(a, b, result) });
+        
+        assert_eq!(multiply(5.0, 3.0), (MathError::Success, 15.0));
+        assert_eq!(multiply(-2.0, 4.0), (MathError::Success, -8.0));
+        assert_eq!(multiply(0.0, 5.0), (MathError::Success, 0.0));
+        assert_eq!(multiply(2.5, 3.5), (MathError::Success, 8.75));
     }
     
     #[test]
     fn test_rust_factorial_impl() {
-        assert_eq!(rust_factorial_impl(0), 1);
-        assert_eq!(rust_factorial_impl(1), 1);
-        assert_eq!(rust_factorial_impl(5), 120);
-        assert_eq!(rust_factorial_impl(10), 3628800);
-        assert_eq!(rust_factorial_impl(-1), -1); // 负数错误
-        assert_eq!(rust_factorial_impl(25), -2); // 溢出错误
+        let factorial = |n| call(|result| unsafe { rust_factorial_impl(n, result) });
+        
+        assert_eq!(factorial(0), (MathError::Success, 1));
+        assert_eq!(factorial(1), (MathError::Success, 1));
+        assert_eq!(factorial(5), (MathError::Success, 120));
+        assert_eq!(factorial(10), (MathError::Success, 3628800));
+        assert_eq!(factorial(-1).0, MathError::NegativeNumber); // 负数错误
+        assert_eq!(factorial(25).0, MathError::Overflow); // 溢出错误
     }
     
     #[test]
     fn test_rust_fibonacci_impl() {
-        let result = rust_fibonacci_impl(0);
-        assert_eq!(result.length, 0);
+        let mut result = RustFibonacciResult { numbers: ptr::null_mut(), length: 0 };
         
-        let result = rust_fibonacci_impl(1);
-        assert_eq!(result.length, 1);
         unsafe {
+            assert_eq!(rust_fibonacci_impl(0, &mut result), MathError::Success);
+            assert_eq!(result.length, 0);
+            
+            assert_eq!(rust_fibonacci_impl(1, &mut result), MathError::Success);
+            assert_eq!(result.length, 1);
             assert_eq!(*result.numbers, 0);
-        }
-        
-        let result = rust_fibonacci_impl(5);
-        assert_eq!(result.length, 5);
-        unsafe {
+            rust_free_fibonacci_result(result);
+            
+            let mut result = RustFibonacciResult { numbers: ptr::null_mut(), length: 0 };
+            assert_eq!(rust_fibonacci_impl(5, &mut result), MathError::Success);
+            assert_eq!(result.length, 5);
             let numbers = std::slice::from_raw_parts(result.numbers, 5);
             assert_eq!(numbers, [0, 1, 1, 2, 3]);
+            
+            // 清理内存
+            rust_free_fibonacci_result(result);
+            
+            let mut result = RustFibonacciResult { numbers: ptr::null_mut(), length: 0 };
+            assert_eq!(rust_fibonacci_impl(-1, &mut result), MathError::NegativeNumber);
+            assert_eq!(rust_fibonacci_impl(101, &mut result), MathError::InvalidParameter);
+            assert!(result.numbers.is_null());
         }
-        
-        // 清理内存
-        rust_free_fibonacci_result(result);
     }
     
     #[test]
     fn test_rust_is_prime_impl() {
-        assert_eq!(rust_is_prime_impl(2), true);
-        assert_eq!(rust_is_prime_impl(3), true);
-        assert_eq!(rust_is_prime_impl(4), false);
-        assert_eq!(rust_is_prime_impl(5), true);
-        assert_eq!(rust_is_prime_impl(6), false);
-        assert_eq!(rust_is_prime_impl(7), true);
-        assert_eq!(rust_is_prime_impl(8), false);
-        assert_eq!(rust_is_prime_impl(9), false);
-        assert_eq!(rust_is_prime_impl(10), false);
-        assert_eq!(rust_is_prime_impl(11), true);
-        assert_eq!(rust_is_prime_impl(1), false);
-        assert_eq!(rust_is_prime_impl(0), false);
-        assert_eq!(rust_is_prime_impl(-1), false);
+        let is_prime = |n| call(|result| unsafe { rust_is_prime_impl(n, result) }).1;
+        
+        assert!(is_prime(2));
+        assert!(is_prime(3));
+        assert!(!is_prime(4));
+        assert!(is_prime(5));
+        assert!(!is_prime(6));
+        assert!(is_prime(7));
+        assert!(!is_prime(8));
+        assert!(!is_prime(9));
+        assert!(!is_prime(10));
+        assert!(is_prime(11));
+        assert!(!is_prime(1));
+        assert!(!is_prime(0));
+        assert!(!is_prime(-1));
     }
     
     #[test]
     fn test_rust_gcd_impl() {
-        assert_eq!(rust_gcd_impl(12, 18), 6);
-        assert_eq!(rust_gcd_impl(7, 13), 1);
-        assert_eq!(rust_gcd_impl(0, 5), 5);
-        assert_eq!(rust_gcd_impl(-12, 18), 6);
+        let gcd = |a, b| call(|result| unsafe { rust_gcd_impl(a, b, result) });
+        
+        assert_eq!(gcd(12, 18), (MathError::Success, 6));
+        assert_eq!(gcd(7, 13), (MathError::Success, 1));
+        assert_eq!(gcd(0, 5), (MathError::Success, 5));
+        assert_eq!(gcd(-12, 18), (MathError::Success, 6));
+        assert_eq!(gcd(0, 0), (MathError::Success, 0));
+        assert_eq!(gcd(c_long::MIN, 6), (MathError::Success, 2));
+        assert_eq!(gcd(c_long::MIN, 0).0, MathError::Overflow);
+        assert_eq!(gcd(c_long::MIN, c_long::MIN).0, MathError::Overflow);
+        
+        // 二进制 GCD 与欧几里得算法的结果一致
+        let euclid = |mut a: u64, mut b: u64| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        let mut x: u64 = 0x9E37_79B9_7F4A_7C15;
+        for _ in 0..1000 {
+            x = x.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            let (a, b) = (x >> (x % 64), (x.rotate_left(17) >> (x % 61)) << (x % 5));
+            assert_eq!(utils::gcd_u64(a, b), euclid(a, b), "gcd({a}, {b})");
+        }
     }
     
     #[test]
     fn test_rust_lcm_impl() {
-        assert_eq!(rust_lcm_impl(12, 18), 36);
-        assert_eq!(rust_lcm_impl(7, 13), 91);
-        assert_eq!(rust_lcm_impl(0, 5), 0);
+        let lcm = |a, b| call(|result| unsafe { rust_lcm_impl(a, b, result) });
+        
+        assert_eq!(lcm(12, 18), (MathError::Success, 36));
+        assert_eq!(lcm(7, 13), (MathError::Success, 91));
+        assert_eq!(lcm(0, 5), (MathError::Success, 0));
+        assert_eq!(lcm(-4, 6), (MathError::Success, 12));
+        // 先除后乘，中间结果不会溢出
+        assert_eq!(lcm(1 << 62, 1 << 61), (MathError::Success, 1 << 62));
+        assert_eq!(lcm(3_037_000_493, 3_037_000_453), (MathError::Success, 9_223_371_873_002_223_329));
+        assert_eq!(lcm(c_long::MAX, c_long::MAX - 1).0, MathError::Overflow);
+        assert_eq!(lcm(c_long::MIN, 1).0, MathError::Overflow);
+        assert_eq!(lcm(c_long::MIN, 0), (MathError::Success, 0));
+    }
+    
+    #[test]
+    fn test_gcd_lcm_many() {
+        use math::{gcd_many, lcm_many};
+        
+        assert_eq!(gcd_many(&[12, 18, -30]).value, 6);
+        assert_eq!(gcd_many(&[0, 0, 7]).value, 7);
+        assert_eq!(gcd_many(&[]).value, 0);
+        assert_eq!(gcd_many(&[c_long::MIN, 0]).error, MathError::Overflow);
+        assert_eq!(gcd_many(&[c_long::MIN, 0, 3]).value, 1);
+        
+        assert_eq!(lcm_many(&[2, 3, 4, 5, 6]).value, 60);
+        assert_eq!(lcm_many(&[-3, 5]).value, 15);
+        assert_eq!(lcm_many(&[]).value, 1);
+        assert_eq!(lcm_many(&[c_long::MAX, 2, 0]).value, 0);
+        assert_eq!(lcm_many(&(1..=42).collect::<Vec<_>>()).value, 219_060_189_739_591_200);
+        assert_eq!(lcm_many(&(1..=43).collect::<Vec<_>>()).error, MathError::Overflow);
+        
+        let numbers = [84, 126, 210];
+        unsafe {
+            assert_eq!(call(|result| ffi::number_theory::rust_gcd_many_impl(numbers.as_ptr(), 3, result)), (MathError::Success, 42));
+            assert_eq!(call(|result| ffi::number_theory::rust_lcm_many_impl(numbers.as_ptr(), 3, result)), (MathError::Success, 1260));
+            assert_eq!(call(|result| ffi::number_theory::rust_lcm_many_impl(ptr::null(), 0, result)), (MathError::Success, 1));
+            assert_eq!(call(|result| ffi::number_theory::rust_gcd_many_impl(ptr::null(), 2, result)).0, MathError::InvalidParameter);
+        }
     }
     
     #[test]
     fn test_rust_power_impl() {
-        assert_eq!(rust_power_impl(2, 3), 8);
-        assert_eq!(rust_power_impl(5, 0), 1);
-        assert_eq!(rust_power_impl(2, 10), 1024);
-        assert_eq!(rust_power_impl(2, -1), -1); // 负数指数错误
+        let power = |base, exponent| call(|result| unsafe { rust_power_impl(base, exponent, result) });
+        
+        assert_eq!(power(2, 3), (MathError::Success, 8));
+        assert_eq!(power(5, 0), (MathError::Success, 1));
+        assert_eq!(power(2, 10), (MathError::Success, 1024));
+        assert_eq!(power(-1, 1), (MathError::Success, -1));
+        assert_eq!(power(2, -1).0, MathError::InvalidParameter); // 负数指数错误
+        assert_eq!(power(-1, -3), (MathError::Success, -1));
+        assert_eq!(power(0, -1).0, MathError::DivisionByZero);
+    }
+    
+    #[test]
+    fn test_ffi_basic() {
+        use ffi::basic::*;
+        
+        let mut result = 0;
+        unsafe {
+            assert_eq!(rust_subtract_int_impl(5, 8, &mut result), MathError::Success);
+            assert_eq!(result, -3);
+            assert_eq!(rust_multiply_int_impl(-4, 6, &mut result), MathError::Success);
+            assert_eq!(result, -24);
+            assert_eq!(rust_divide_int_impl(-7, 2, &mut result), MathError::Success);
+            assert_eq!(result, -3);
+            assert_eq!(rust_abs_int_impl(-9, &mut result), MathError::Success);
+            assert_eq!(result, 9);
+            assert_eq!(rust_abs_int_impl(0, ptr::null_mut()), MathError::InvalidParameter);
+        }
+        
+        unsafe {
+            assert_eq!(call(|r| rust_modulo_int_impl(-7, 3, r)), (MathError::Success, -1));
+            assert_eq!(call(|r| rust_max_int_impl(-7, 3, r)), (MathError::Success, 3));
+            assert_eq!(call(|r| rust_min_int_impl(-7, 3, r)), (MathError::Success, -7));
+            assert_eq!(call(|r| rust_add_float_impl(0.5, 0.25, r)), (MathError::Success, 0.75));
+            assert_eq!(call(|r| rust_subtract_float_impl(0.5, 0.25, r)), (MathError::Success, 0.25));
+            assert_eq!(call(|r| rust_divide_float_impl(1.0, 4.0, r)), (MathError::Success, 0.25));
+            assert_eq!(call(|r| rust_abs_float_impl(-2.5, r)), (MathError::Success, 2.5));
+            assert_eq!(call(|r| rust_max_float_impl(-2.5, 1.5, r)), (MathError::Success, 1.5));
+            assert_eq!(call(|r| rust_min_float_impl(-2.5, 1.5, r)), (MathError::Success, -2.5));
+        }
+    }
+    
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn test_gamma() {
+        use math::gamma::*;
+        
+        // 参考值来自高精度计算
+        assert_eq!(gamma(1.0).value, 1.0);
+        assert_eq!(gamma(23.0).value, 1_124_000_727_777_607_680_000.0);
+        assert_close(gamma(0.5).value, 1.772_453_850_905_516_027_3, 4e-16);
+        assert_close(gamma(1.5).value, 0.886_226_925_452_758_013_65, 4e-16);
+        assert_close(gamma(0.1).value, 9.513_507_698_668_731_839_7, 4e-16);
+        assert_close(gamma(5.5).value, 52.342_777_784_553_520_311, 1e-15);
+        assert_close(gamma(30.0).value, 8.841_761_993_739_701_954_5e30, 1e-15);
+        assert_close(gamma(170.0).value, 4.269_068_009_004_705_274_9e304, 1e-14);
+        assert_close(gamma(-0.5).value, -3.544_907_701_811_032_054_6, 1e-15);
+        assert_close(gamma(-1.5).value, 2.363_271_801_207_354_703_1, 1e-15);
+        assert_eq!(gamma(0.0).error, MathError::DomainError);
+        assert_eq!(gamma(-3.0).error, MathError::DomainError);
+        assert_eq!(gamma(172.0).error, MathError::Overflow);
+        
+        assert_eq!(lgamma(1.0).value, 0.0);
+        assert_close(lgamma(0.5).value, 0.572_364_942_924_700_087_07, 1e-15);
+        assert_close(lgamma(100.0).value, 359.134_205_369_575_398_78, 1e-15);
+        assert_close(lgamma(1000.0).value, 5_905.220_423_209_181_211_8, 1e-15);
+        assert_close(lgamma(-0.5).value, 1.265_512_123_484_645_396_5, 1e-15);
+        assert_eq!(lgamma(-2.0).error, MathError::DomainError);
+        
+        assert_close(digamma(1.0).value, -0.577_215_664_901_532_860_61, 1e-15);
+        assert_close(digamma(0.5).value, -1.963_510_026_021_423_479_4, 1e-15);
+        assert_close(digamma(10.0).value, 2.251_752_589_066_721_107_6, 1e-15);
+        assert_close(digamma(-0.5).value, 0.036_489_973_978_576_520_6, 1e-14);
+        assert_eq!(digamma(0.0).error, MathError::DomainError);
+        
+        assert_close(beta(2.0, 3.0).value, 1.0 / 12.0, 1e-15);
+        assert_close(beta(0.5, 0.5).value, std::f64::consts::PI, 1e-15);
+        assert_close(beta(200.0, 0.5).value, (lgamma(200.0).value + lgamma(0.5).value - lgamma(200.5).value).exp(), 1e-13);
+        assert_eq!(beta(-1.0, 2.0).error, MathError::DomainError);
+    }
+    
+    #[test]
+    fn test_bessel() {
+        use math::bessel::*;
+        
+        // 参考值来自高精度计算
+        assert_close(bessel_j0(1.0).value, 0.765_197_686_557_966_6, 1e-15);
+        assert_close(bessel_j1(1.0).value, 0.440_050_585_744_933_5, 1e-15);
+        assert_close(bessel_jn(2, 1.0).value, 0.114_903_484_931_900_5, 1e-15);
+        assert_close(bessel_jn(10, 1.0).value, 2.630_615_123_687_453e-10, 1e-14);
+        assert_close(bessel_j0(10.0).value, -0.245_935_764_451_348_3, 1e-14);
+        assert_close(bessel_j1(10.0).value, 0.043_472_746_168_861_44, 1e-13);
+        assert_close(bessel_jn(5, 10.0).value, -0.234_061_528_186_793_6, 1e-14);
+        assert_close(bessel_j0(100.0).value, 0.019_985_850_304_223_12, 1e-13);
+        assert_close(bessel_j1(100.0).value, -0.077_145_352_014_112_16, 1e-14);
+        assert_close(bessel_jn(-1, -1.0).value, 0.440_050_585_744_933_5, 1e-15);
+        assert_eq!(bessel_j0(0.0).value, 1.0);
+        
+        assert_close(bessel_y0(1.0).value, 0.088_256_964_215_676_96, 1e-14);
+        assert_close(bessel_y1(1.0).value, -0.781_212_821_300_288_7, 1e-14);
+        assert_close(bessel_yn(2, 1.0).value, -1.650_682_606_816_254_6, 1e-14);
+        assert_close(bessel_y0(10.0).value, 0.055_671_167_283_599_39, 1e-13);
+        assert_close(bessel_y1(10.0).value, 0.249_015_424_206_953_9, 1e-14);
+        // 朗斯基行列式 J1·Y0 - J0·Y1 = 2/(πx)，同时检查 Hankel 渐近展开的分界点两侧
+        for x in [24.9, 25.0, 100.0] {
+            let (j0, j1) = (bessel_j0(x).value, bessel_j1(x).value);
+            let (y0, y1) = (bessel_y0(x).value, bessel_y1(x).value);
+            assert_close(j1 * y0 - j0 * y1, 2.0 / (std::f64::consts::PI * x), 1e-14);
+        }
+        assert_eq!(bessel_y0(0.0).error, MathError::DomainError);
+        assert_eq!(bessel_y1(-1.0).error, MathError::DomainError);
+        
+        assert_close(bessel_i0(1.0).value, 1.266_065_877_752_008_2, 1e-15);
+        assert_close(bessel_i1(1.0).value, 0.565_159_103_992_485_1, 1e-15);
+        assert_close(bessel_in(2, 1.0).value, 0.135_747_669_767_038_3, 1e-15);
+        assert_close(bessel_i0(10.0).value, 2_815.716_628_466_254, 1e-14);
+        assert_eq!(bessel_i0(1000.0).error, MathError::Overflow);
+        
+        assert_close(bessel_k0(1.0).value, 0.421_024_438_240_708_3, 1e-14);
+        assert_close(bessel_k1(1.0).value, 0.601_907_230_197_234_6, 1e-14);
+        assert_close(bessel_kn(2, 1.0).value, 1.624_838_898_635_177_4, 1e-14);
+        // 朗斯基行列式 I0·K1 + I1·K0 = 1/x
+        for x in [0.001, 10.0, 50.0, 300.0] {
+            let (i0, i1) = (bessel_i0(x).value, bessel_i1(x).value);
+            let (k0, k1) = (bessel_k0(x).value, bessel_k1(x).value);
+            assert_close(i0 * k1 + i1 * k0, 1.0 / x, 1e-14);
+        }
+        assert_eq!(bessel_k0(0.0).error, MathError::DomainError);
+        
+        assert_eq!(bessel_jn(BESSEL_ORDER_LIMIT + 1, 1.0).error, MathError::InvalidParameter);
+    }
+    
+    #[test]
+    fn test_erf() {
+        use math::erf::*;
+        
+        // 参考值来自高精度计算
+        assert_close(erf(1e-10).value, 1.128_379_167_095_512_6e-10, 1e-15);
+        assert_close(erf(0.5).value, 0.520_499_877_813_046_5, 1e-15);
+        assert_close(erf(1.0).value, 0.842_700_792_949_714_9, 1e-15);
+        assert_close(erf(-2.0).value, -0.995_322_265_018_952_7, 1e-15);
+        assert_eq!(erf(c_double::INFINITY).value, 1.0);
+        
+        assert_close(erfc(1.0).value, 0.157_299_207_050_285_13, 1e-15);
+        assert_close(erfc(-1.0).value, 1.842_700_792_949_714_8, 1e-15);
+        assert_close(erfc(3.0).value, 2.209_049_699_858_544e-5, 1e-14);
+        assert_close(erfc(5.0).value, 1.537_459_794_428_035e-12, 1e-14);
+        assert_close(erfc(10.0).value, 2.088_487_583_762_545e-45, 1e-14);
+        
+        assert_close(erfcx(3.0).value, (9.0_f64).exp() * erfc(3.0).value, 1e-14);
+        assert_close(erfcx(1e10).value, 1.0 / (1e10 * std::f64::consts::PI.sqrt()), 1e-15);
+        assert_eq!(erfcx(-30.0).error, MathError::Overflow);
+        
+        assert_close(erfinv(0.5).value, 0.476_936_276_204_469_9, 1e-15);
+        assert_close(erfinv(-0.9).value, -1.163_087_153_676_674_3, 1e-15);
+        // erfc 在 x 处的条件数约为 2x²，尾部的往返误差会放大几百倍
+        for z in [1e-300, 1e-100, 1e-20, 1e-5, 0.3, 1.0, 1.7] {
+            assert_close(erfc(erfcinv(z).value).value, z, 1e-12);
+        }
+        assert_eq!(erfinv(1.0).error, MathError::DomainError);
+        assert_eq!(erfcinv(2.5).error, MathError::DomainError);
+        
+        assert_close(normal_pdf(0.0, 0.0, 1.0).value, 0.398_942_280_401_432_7, 1e-15);
+        assert_close(normal_cdf(1.96, 0.0, 1.0).value, 0.975_002_104_851_779_5, 1e-15);
+        assert_close(normal_cdf(-10.0, 0.0, 1.0).value, 7.619_853_024_160_527e-24, 1e-14);
+        assert_close(normal_quantile(0.975, 0.0, 1.0).value, 1.959_963_984_540_054, 1e-15);
+        assert_close(normal_quantile(0.5, 10.0, 2.0).value, 10.0, 1e-15);
+        assert_eq!(normal_pdf(0.0, 0.0, 0.0).error, MathError::InvalidParameter);
+        assert_eq!(normal_quantile(0.0, 0.0, 1.0).error, MathError::DomainError);
+    }
+    
+    #[test]
+    fn test_number_theory() {
+        use math::number_theory::*;
+        
+        assert_eq!(factorize(360).value, vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(1).value, vec![]);
+        assert_eq!(factorize(97).value, vec![(97, 1)]);
+        assert_eq!(
+            factorize(c_long::MAX).value,
+            vec![(7, 2), (73, 1), (127, 1), (337, 1), (92737, 1), (649657, 1)]
+        );
+        // 两个大质因子，需要 Pollard rho
+        assert_eq!(factorize(998_244_359_987_710_471).value, vec![(998_244_353, 1), (1_000_000_007, 1)]);
+        assert_eq!(factorize(9_223_372_021_822_390_277).value, vec![(2_147_483_647, 1), (4_294_967_291, 1)]);
+        assert_eq!(factorize(9_223_371_994_482_243_049).value, vec![(3_037_000_493, 2)]);
+        assert_eq!(factorize(0).error, MathError::InvalidParameter);
+        assert_eq!(factorize(-12).error, MathError::NegativeNumber);
+        
+        assert_eq!(divisors(12).value, vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors(1).value, vec![1]);
+        assert_eq!(divisors(c_long::MAX).value.len(), 96);
+        
+        assert_eq!(sigma(12, 0).value, 6);
+        assert_eq!(sigma(12, 1).value, 28);
+        assert_eq!(sigma(12, 2).value, 210);
+        assert_eq!(sigma(c_long::MAX, 1).error, MathError::Overflow);
+        assert_eq!(sigma(12, -1).error, MathError::NegativeNumber);
+        
+        assert_eq!(euler_phi(1).value, 1);
+        assert_eq!(euler_phi(36).value, 12);
+        assert_eq!(euler_phi(1_000_000_007).value, 1_000_000_006);
+        
+        assert_eq!(mobius(1).value, 1);
+        assert_eq!(mobius(30).value, -1);
+        assert_eq!(mobius(12).value, 0);
+        assert_eq!(mobius(0).error, MathError::InvalidParameter);
+        
+        unsafe {
+            let mut result = RustFactorizationResult { primes: ptr::null_mut(), exponents: ptr::null_mut(), length: 0 };
+            assert_eq!(ffi::number_theory::rust_factorize_impl(360, &mut result), MathError::Success);
+            assert_eq!(result.length, 3);
+            assert_eq!(*result.primes.add(1), 3);
+            assert_eq!(*result.exponents.add(1), 2);
+            ffi::number_theory::rust_free_factorization_result(result);
+            
+            let mut result = ffi::empty_integer_array_result();
+            assert_eq!(ffi::number_theory::rust_divisors_impl(28, &mut result), MathError::Success);
+            assert_eq!(std::slice::from_raw_parts(result.numbers, result.length as usize), [1, 2, 4, 7, 14, 28]);
+            ffi::rust_free_integer_array_result(result);
+            
+            let mut result = ffi::empty_integer_array_result();
+            assert_eq!(ffi::number_theory::rust_divisors_impl(-1, &mut result), MathError::NegativeNumber);
+            assert!(result.numbers.is_null());
+        }
+    }
+    
+    #[test]
+    fn test_sieve() {
+        use math::sieve::*;
+        
+        assert_eq!(primes_up_to(30).value, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes_up_to(1).value, vec![]);
+        assert_eq!(primes_up_to(-5).value, vec![]);
+        
+        // 跨越多个分段，与 is_prime 对照
+        let expected: Vec<c_long> = (0..200_000).filter(|&n| is_prime(n)).collect();
+        assert_eq!(primes_up_to(199_999).value, expected);
+        
+        assert_eq!(primes_between(10, 30).value, vec![11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes_between(30, 10).value, vec![]);
+        assert_eq!(primes_between(-10, 3).value, vec![2, 3]);
+        let expected: Vec<c_long> = (c_long::MAX - 2000..=c_long::MAX).filter(|&n| is_prime(n)).collect();
+        assert_eq!(primes_between(c_long::MAX - 2000, c_long::MAX).value, expected);
+        assert_eq!(primes_between(0, PRIME_RANGE_LIMIT).error, MathError::InvalidParameter);
+        
+        assert_eq!(prime_count(1).value, 0);
+        assert_eq!(prime_count(2).value, 1);
+        assert_eq!(prime_count(1_000_000).value, 78_498);
+        assert_eq!(prime_count(PRIME_COUNT_LIMIT + 1).error, MathError::InvalidParameter);
+        
+        assert_eq!(nth_prime(1).value, 2);
+        assert_eq!(nth_prime(5).value, 11);
+        assert_eq!(nth_prime(6).value, 13);
+        assert_eq!(nth_prime(10_000).value, 104_729);
+        assert_eq!(nth_prime(0).error, MathError::InvalidParameter);
+        
+        unsafe {
+            let mut result = ffi::empty_integer_array_result();
+            assert_eq!(ffi::sieve::rust_primes_between_impl(90, 110, &mut result), MathError::Success);
+            assert_eq!(std::slice::from_raw_parts(result.numbers, result.length as usize), [97, 101, 103, 107, 109]);
+            ffi::rust_free_integer_array_result(result);
+        }
+    }
+    
+    #[test]
+    fn test_modular() {
+        use math::modular::*;
+        
+        assert_eq!(mod_pow(2, 10, 1000).value, 24);
+        assert_eq!(mod_pow(-2, 3, 5).value, 2);
+        assert_eq!(mod_pow(5, 0, 1).value, 0);
+        assert_eq!(mod_pow(3, -1, 7).value, 5);
+        assert_eq!(mod_pow(2, -1, 4).error, MathError::DomainError);
+        // 模数接近 2^63 时中间结果需要 128 位
+        assert_eq!(mod_pow(c_long::MAX - 1, 2, c_long::MAX).value, 1);
+        assert_eq!(mod_pow(2, 10, 0).error, MathError::DivisionByZero);
+        assert_eq!(mod_pow(2, 10, -7).error, MathError::InvalidParameter);
+        
+        assert_eq!(mod_inverse(3, 11).value, 4);
+        assert_eq!(mod_inverse(-3, 11).value, 7);
+        assert_eq!(mod_inverse(6, 9).error, MathError::DomainError);
+        let m = 9_223_372_036_854_775_783;
+        let inverse = mod_inverse(123_456_789, m).value;
+        assert_eq!((inverse as i128 * 123_456_789) % m as i128, 1);
+        
+        assert_eq!(extended_gcd(240, 46).value, (2, -9, 47));
+        assert_eq!(extended_gcd(-4, 6).value, (2, 1, 1));
+        assert_eq!(extended_gcd(0, 0).value, (0, 1, 0));
+        assert_eq!(extended_gcd(c_long::MIN, 0).error, MathError::Overflow);
+        let (g, x, y) = extended_gcd(c_long::MAX, c_long::MIN).value;
+        assert_eq!(g, 1);
+        assert_eq!(c_long::MAX as i128 * x as i128 + c_long::MIN as i128 * y as i128, 1);
+        
+        assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]).value, (23, 105));
+        assert_eq!(crt(&[], &[]).value, (0, 1));
+        // 模数不互质
+        assert_eq!(crt(&[1, 3], &[4, 6]).value, (9, 12));
+        assert_eq!(crt(&[1, 2], &[4, 6]).error, MathError::DomainError);
+        assert_eq!(
+            crt(&[1, 2], &[2_147_483_647, 4_294_967_291]).value,
+            (6_148_914_682_646_582_617, 9_223_372_021_822_390_277)
+        );
+        assert_eq!(crt(&[0, 0], &[4_294_967_291, 4_294_967_279]).error, MathError::Overflow);
+        assert_eq!(crt(&[1], &[0]).error, MathError::DivisionByZero);
+        assert_eq!(crt(&[1, 2], &[3]).error, MathError::InvalidParameter);
+        
+        assert_eq!(jacobi(1001, 9907).value, -1);
+        assert_eq!(jacobi(19, 45).value, 1);
+        assert_eq!(jacobi(8, 21).value, -1);
+        assert_eq!(jacobi(3, 9).value, 0);
+        assert_eq!(jacobi(1, 8).error, MathError::InvalidParameter);
+        
+        assert_eq!(legendre(2, 7).value, 1);
+        assert_eq!(legendre(3, 7).value, -1);
+        assert_eq!(legendre(14, 7).value, 0);
+        assert_eq!(legendre(-1, 13).value, 1);
+        assert_eq!(legendre(2, 9).error, MathError::InvalidParameter);
+        
+        unsafe {
+            let remainders = [2, 3, 2];
+            let moduli = [3, 5, 7];
+            let mut result = RustCrtResult::default();
+            assert_eq!(ffi::modular::rust_crt_impl(remainders.as_ptr(), moduli.as_ptr(), 3, &mut result), MathError::Success);
+            assert_eq!(result, RustCrtResult { remainder: 23, modulus: 105 });
+            
+            let mut result = RustExtendedGcdResult::default();
+            assert_eq!(ffi::modular::rust_extended_gcd_impl(240, 46, &mut result), MathError::Success);
+            assert_eq!(result, RustExtendedGcdResult { gcd: 2, x: -9, y: 47 });
+        }
+    }
+    
+    #[test]
+    fn test_power() {
+        use math::power::*;
+        
+        assert_eq!(pow_float(2.0, 0.5).value, std::f64::consts::SQRT_2);
+        assert_eq!(pow_float(-8.0, 3.0).value, -512.0);
+        assert_eq!(pow_float(4.0, -0.5).value, 0.5);
+        assert_eq!(pow_float(-8.0, 1.0 / 3.0).error, MathError::DomainError);
+        assert_eq!(pow_float(0.0, -1.0).error, MathError::DivisionByZero);
+        assert_eq!(pow_float(10.0, 400.0).error, MathError::Overflow);
+        assert_eq!(pow_float(c_double::NAN, 2.0).error, MathError::NotANumber);
+        assert_eq!(pow_float(1.0, c_double::NAN).value, 1.0);
+        assert_eq!(pow_float(0.5, c_double::INFINITY).value, 0.0);
+        
+        assert_eq!(powi(2.0, -3).value, 0.125);
+        assert_eq!(powi(-2.0, -3).value, -0.125);
+        assert_eq!(powi(-2.0, 10).value, 1024.0);
+        assert_eq!(powi(1.5, 0).value, 1.0);
+        assert_close(powi(1.1, 100).value, 13780.61233982238, 1e-14);
+        // 指数超过 2^53 时仍然按奇偶性确定符号
+        assert_eq!(powi(-1.0, (1 << 60) + 1).value, -1.0);
+        assert_eq!(powi(-1.0, c_long::MIN).value, 1.0);
+        assert_eq!(powi(0.0, -2).error, MathError::DivisionByZero);
+        assert_eq!(powi(2.0, 1024).error, MathError::Overflow);
+        assert_eq!(powi(2.0, -1100).value, 0.0);
+        
+        rust_set_float_mode(FloatMode::Ieee as c_int);
+        assert_eq!(powi(-0.0, -1).value, c_double::NEG_INFINITY);
+        assert!(pow_float(-8.0, 0.5).value.is_nan());
+        rust_set_float_mode(FloatMode::Strict as c_int);
+        
+        let fraction = |numerator, denominator| RustFraction { numerator, denominator };
+        assert_eq!(power_fraction(2, 1, -3).value, fraction(1, 8));
+        assert_eq!(power_fraction(-2, 1, -3).value, fraction(-1, 8));
+        assert_eq!(power_fraction(2, -3, 3).value, fraction(-8, 27));
+        assert_eq!(power_fraction(6, -4, -2).value, fraction(4, 9));
+        assert_eq!(power_fraction(0, 5, 3).value, fraction(0, 1));
+        assert_eq!(power_fraction(7, 3, 0).value, fraction(1, 1));
+        assert_eq!(power_fraction(-2, 1, 63).value, fraction(c_long::MIN, 1));
+        assert_eq!(power_fraction(1, -1, c_long::MIN).value, fraction(1, 1));
+        // 分母是 2^63，超出 c_long 范围
+        assert_eq!(power_fraction(-2, 1, -63).error, MathError::Overflow);
+        assert_eq!(power_fraction(2, 1, 63).error, MathError::Overflow);
+        assert_eq!(power_fraction(c_long::MIN, c_long::MIN, 5).value, fraction(1, 1));
+        assert_eq!(power_fraction(1, 0, 2).error, MathError::DivisionByZero);
+        assert_eq!(power_fraction(0, 3, -1).error, MathError::DivisionByZero);
+        
+        let mut result = RustFraction::default();
+        unsafe {
+            assert_eq!(ffi::power::rust_power_fraction_impl(3, 1, -4, &mut result), MathError::Success);
+        }
+        assert_eq!(result, fraction(1, 81));
+    }
+    
+    #[test]
+    fn test_fibonacci_nth() {
+        use math::fibonacci::*;
+        
+        // 快速倍增法与逐项相加的结果一致
+        let sequence = fibonacci(93).value;
+        for (n, &expected) in sequence.iter().enumerate() {
+            assert_eq!(fibonacci_nth(n as c_long).value, expected);
+        }
+        assert_eq!(fibonacci_nth(FIBONACCI_MAX_INDEX + 1).error, MathError::Overflow);
+        assert_eq!(fibonacci_nth(-1).error, MathError::NegativeNumber);
+        
+        assert_eq!(lucas_nth(0).value, 2);
+        assert_eq!(lucas_nth(1).value, 1);
+        assert_eq!(lucas_nth(10).value, 123);
+        for n in 2..=LUCAS_MAX_INDEX {
+            // L(n) = F(n - 1) + F(n + 1)
+            assert_eq!(lucas_nth(n).value, sequence[n as usize - 1] + fibonacci_nth(n + 1).value);
+        }
+        assert_eq!(lucas_nth(LUCAS_MAX_INDEX).value, 6_440_026_026_380_244_498);
+        assert_eq!(lucas_nth(LUCAS_MAX_INDEX + 1).error, MathError::Overflow);
+        
+        assert_eq!(fibonacci_big(0).value.to_decimal_string(), "0");
+        assert_eq!(fibonacci_big(92).value.to_u64(), Some(7_540_113_804_746_346_429));
+        assert_eq!(fibonacci_big(93).value.to_u64(), Some(12_200_160_415_121_876_738));
+        assert_eq!(
+            fibonacci_big(200).value.to_decimal_string(),
+            "280571172992510140037611932413038677189525"
+        );
+        assert_eq!(lucas_big(100).value.to_decimal_string(), "792070839848372253127");
+        // F(1000) 有 209 位
+        assert_eq!(fibonacci_big(1000).value.to_decimal_string().len(), 209);
+        assert_eq!(fibonacci_big(FIBONACCI_BIG_LIMIT + 1).error, MathError::InvalidParameter);
+        assert_eq!(lucas_big(-5).error, MathError::NegativeNumber);
+        
+        let mut result = ffi::empty_string_result();
+        unsafe {
+            assert_eq!(ffi::fibonacci::rust_lucas_big_impl(5, &mut result), MathError::Success);
+            assert_eq!(std::ffi::CStr::from_ptr(result.data).to_str(), Ok("11"));
+        }
+        rust_free_string_result(result);
+    }
+    
+    #[test]
+    fn test_sequence() {
+        use math::sequence::{Sequence, SEQUENCE_TAKE_LIMIT};
+        
+        // 逐项生成的斐波那契数与 math::fibonacci 一致，超出范围的项在读取时报告溢出
+        let mut fib = Sequence::fibonacci();
+        assert_eq!(fib.take(93).value, fibonacci(93).value);
+        assert_eq!(fib.index(), 93);
+        assert_eq!(fib.current().error, MathError::Overflow);
+        assert!(!fib.is_finished());
+        fib.advance();
+        assert_eq!(fib.index(), 93);
+        fib.rewind();
+        let result = fib.take(100);
+        assert_eq!(result.error, MathError::Overflow);
+        assert_eq!(result.value.len(), 93);
+        
+        assert_eq!(Sequence::lucas().take(6).value, vec![2, 1, 3, 4, 7, 11]);
+        assert_eq!(Sequence::triangular().take(5).value, vec![1, 3, 6, 10, 15]);
+        assert_eq!(Sequence::primes().take(10).value, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        
+        let mut catalan = Sequence::catalan();
+        assert_eq!(catalan.take(7).value, vec![1, 1, 2, 5, 14, 42, 132]);
+        assert_eq!(catalan.take(29).value.last(), Some(&3_116_285_494_907_301_262));
+        assert_eq!(catalan.take(1).error, MathError::Overflow);
+        
+        // 考拉兹序列到达 1 后结束
+        let mut collatz = Sequence::collatz(6).value;
+        assert_eq!(collatz.take(20).value, vec![6, 3, 10, 5, 16, 8, 4, 2, 1]);
+        assert!(collatz.is_finished());
+        assert_eq!(collatz.current().value, None);
+        collatz.advance();
+        assert_eq!(collatz.index(), 9);
+        collatz.rewind();
+        assert_eq!(collatz.current().value, Some(6));
+        assert_eq!(Sequence::collatz(1).value.take(5).value, vec![1]);
+        assert_eq!(Sequence::collatz(0).error, MathError::InvalidParameter);
+        assert_eq!(Sequence::collatz(-3).error, MathError::NegativeNumber);
+        
+        // 佩尔数 P(n) = 2P(n-1) + P(n-2)
+        let mut pell = Sequence::linear_recurrence(&[2, 1], &[0, 1]).value;
+        assert_eq!(pell.take(8).value, vec![0, 1, 2, 5, 12, 29, 70, 169]);
+        // 三阶递推 a(n) = a(n-1) - a(n-3)，初始值 [1, 2, 3]
+        let mut third = Sequence::linear_recurrence(&[1, 0, -1], &[1, 2, 3]).value;
+        assert_eq!(third.take(6).value, vec![1, 2, 3, 2, 0, -3]);
+        assert_eq!(Sequence::linear_recurrence(&[], &[]).error, MathError::InvalidParameter);
+        assert_eq!(Sequence::linear_recurrence(&[1, 1], &[0]).error, MathError::InvalidParameter);
+        assert_eq!(pell.take(-1).error, MathError::NegativeNumber);
+        
+        // 无限序列一次取出的项数有上限，超过上限时序列不前进
+        let mut zeros = Sequence::linear_recurrence(&[1], &[0]).value;
+        assert_eq!(zeros.take(SEQUENCE_TAKE_LIMIT + 1).error, MathError::InvalidParameter);
+        assert_eq!(zeros.index(), 0);
+        assert_eq!(zeros.take(SEQUENCE_TAKE_LIMIT).value.len(), SEQUENCE_TAKE_LIMIT as usize);
+        assert_eq!(Sequence::primes().take(c_long::MAX).error, MathError::InvalidParameter);
+        
+        // 副本独立前进
+        let mut original = Sequence::primes();
+        original.take(3);
+        let mut copy = original.clone();
+        copy.take(2);
+        assert_eq!(original.current().value, Some(7));
+        assert_eq!(copy.current().value, Some(13));
+        
+        unsafe {
+            let mut handle = ptr::null_mut();
+            assert_eq!(ffi::sequence::rust_sequence_collatz(3, &mut handle), MathError::Success);
+            let mut current = 0;
+            assert_eq!(ffi::sequence::rust_sequence_current(handle, &mut current), MathError::Success);
+            assert_eq!(current, 3);
+            ffi::sequence::rust_sequence_next(handle);
+            assert_eq!(ffi::sequence::rust_sequence_key(handle), 1);
+            
+            let mut result = ffi::empty_integer_array_result();
+            assert_eq!(ffi::sequence::rust_sequence_take(handle, 10, &mut result), MathError::Success);
+            assert_eq!(std::slice::from_raw_parts(result.numbers, result.length as usize), &[10, 5, 16, 8, 4, 2, 1]);
+            ffi::rust_free_integer_array_result(result);
+            assert!(!ffi::sequence::rust_sequence_valid(handle));
+            assert_eq!(ffi::sequence::rust_sequence_current(handle, &mut current), MathError::InvalidParameter);
+            ffi::sequence::rust_sequence_free(handle);
+            
+            assert_eq!(ffi::sequence::rust_sequence_collatz(-1, &mut handle), MathError::NegativeNumber);
+            assert!(handle.is_null());
+            
+            let (coefficients, initial) = ([1, 1], [2, 1]);
+            assert_eq!(
+                ffi::sequence::rust_sequence_linear(coefficients.as_ptr(), initial.as_ptr(), 2, &mut handle),
+                MathError::Success
+            );
+            let mut copy = ptr::null_mut();
+            assert_eq!(ffi::sequence::rust_sequence_clone(handle, &mut copy), MathError::Success);
+            ffi::sequence::rust_sequence_next(handle);
+            assert_eq!(ffi::sequence::rust_sequence_key(copy), 0);
+            ffi::sequence::rust_sequence_free(handle);
+            ffi::sequence::rust_sequence_free(copy);
+        }
+    }
+    
+    #[test]
+    fn test_combinatorics() {
+        use math::combinatorics::*;
+        
+        assert_eq!(binomial(10, 3).value, 120);
+        assert_eq!(binomial(0, 0).value, 1);
+        assert_eq!(binomial(5, 7).value, 0);
+        assert_eq!(binomial(66, 33).value, 7_219_428_434_016_265_740);
+        assert_eq!(binomial(67, 33).error, MathError::Overflow);
+        // k 很小时 n 可以很大
+        assert_eq!(binomial(c_long::MAX, 1).value, c_long::MAX);
+        assert_eq!(binomial(4_294_967_296, 2).value, 9_223_372_034_707_292_160);
+        assert_eq!(binomial(-1, 2).error, MathError::NegativeNumber);
+        assert_eq!(binomial(5, -1).error, MathError::NegativeNumber);
+        // 帕斯卡恒等式
+        for n in 1..60 {
+            for k in 1..n {
+                assert_eq!(binomial(n, k).value, binomial(n - 1, k - 1).value + binomial(n - 1, k).value);
+            }
+        }
+        
+        assert_eq!(permutations(10, 3).value, 720);
+        assert_eq!(permutations(20, 20).value, factorial(20).value);
+        assert_eq!(permutations(21, 21).error, MathError::Overflow);
+        assert_eq!(permutations(c_long::MAX, 0).value, 1);
+        assert_eq!(permutations(3, 4).value, 0);
+        
+        assert_eq!(multinomial(&[3, 4, 5]).value, 27_720);
+        assert_eq!(multinomial(&[]).value, 1);
+        assert_eq!(multinomial(&[0, 7, 0]).value, 1);
+        assert_eq!(multinomial(&[2, -1]).error, MathError::NegativeNumber);
+        assert_eq!(multinomial(&[c_long::MAX, 1]).error, MathError::Overflow);
+        assert_eq!(multinomial(&[40, 40]).error, MathError::Overflow);
+        
+        let mut sequence = math::sequence::Sequence::catalan();
+        assert_eq!(
+            sequence.take(CATALAN_MAX_N + 1).value,
+            (0..=CATALAN_MAX_N).map(|n| catalan(n).value).collect::<Vec<_>>()
+        );
+        assert_eq!(catalan(CATALAN_MAX_N + 1).error, MathError::Overflow);
+        
+        assert_eq!(stirling_first(10, 3).value, 1_172_700);
+        assert_eq!(stirling_first(21, 1).value, 2_432_902_008_176_640_000);
+        assert_eq!(stirling_first(22, 1).error, MathError::Overflow);
+        assert_eq!(stirling_second(10, 3).value, 9330);
+        assert_eq!(stirling_second(63, 2).value, (1 << 62) - 1);
+        assert_eq!(stirling_second(65, 2).error, MathError::Overflow);
+        assert_eq!(stirling_second(c_long::MAX, 1).value, 1);
+        // s(n, n - 1) = C(n, 2)，n 很大时不需要递推
+        assert_eq!(stirling_first(1 << 32, (1 << 32) - 1).value, binomial(1 << 32, 2).value);
+        assert_eq!(stirling_second(1 << 40, (1 << 40) - 2).error, MathError::Overflow);
+        assert_eq!(stirling_first(0, 0).value, 1);
+        assert_eq!(stirling_second(5, 0).value, 0);
+        assert_eq!(stirling_second(-5, 2).error, MathError::NegativeNumber);
+        for n in 0..20 {
+            // 第一类斯特林数按行求和得到 n!，第二类斯特林数按行求和得到贝尔数
+            let first: c_long = (0..=n).map(|k| stirling_first(n, k).value).sum();
+            let second: c_long = (0..=n).map(|k| stirling_second(n, k).value).sum();
+            assert_eq!(first, factorial(n).value);
+            assert_eq!(second, bell(n).value);
+        }
+        
+        assert_eq!(bell(10).value, 115_975);
+        assert_eq!(bell(BELL_MAX_N).value, 4_638_590_332_229_999_353);
+        assert_eq!(bell(BELL_MAX_N + 1).error, MathError::Overflow);
+        
+        assert_eq!(partitions(0).value, 1);
+        assert_eq!(partitions(5).value, 7);
+        assert_eq!(partitions(100).value, 190_569_292);
+        assert_eq!(partitions(PARTITION_MAX_N).value, 9_147_679_068_859_117_602);
+        assert_eq!(partitions(PARTITION_MAX_N + 1).error, MathError::Overflow);
+        assert_eq!(partitions(-1).error, MathError::NegativeNumber);
+        
+        let counts = [1, 2, 3];
+        let mut result = 0;
+        unsafe {
+            assert_eq!(ffi::combinatorics::rust_multinomial_impl(counts.as_ptr(), 3, &mut result), MathError::Success);
+            assert_eq!(result, 60);
+            assert_eq!(ffi::combinatorics::rust_multinomial_impl(ptr::null(), 0, &mut result), MathError::Success);
+            assert_eq!(result, 1);
+            assert_eq!(ffi::combinatorics::rust_binomial_impl(70, 35, &mut result), MathError::Overflow);
+        }
+    }
+    
+    #[test]
+    fn test_enumeration() {
+        use math::enumeration::*;
+        
+        // 逐个生成的排列按字典序递增，rank、unrank 与下标一致，个数等于 count
+        let check = |mut e: Enumeration| {
+            let mut previous: Option<Vec<c_long>> = None;
+            let mut generated = 0;
+            while let Some(current) = e.current().map(<[c_long]>::to_vec) {
+                assert_eq!(e.index(), generated);
+                assert_eq!(e.rank(&current).value, generated);
+                assert_eq!(e.unrank(generated).value, current);
+                if let Some(previous) = previous {
+                    assert!(previous < current);
+                }
+                previous = Some(current);
+                generated += 1;
+                e.advance();
+            }
+            assert_eq!(e.count().value, generated);
+            assert_eq!(e.unrank(generated).error, MathError::InvalidParameter);
+            generated
+        };
+        for n in 0..6 {
+            for k in 0..6 {
+                assert_eq!(check(Enumeration::combinations(n, k).value), math::combinatorics::binomial(n, k).value);
+                check(Enumeration::combinations_with_replacement(n, k).value);
+                assert_eq!(check(Enumeration::permutations(n, k).value), math::combinatorics::permutations(n, k).value);
+                check(Enumeration::permutations_with_replacement(n, k).value);
+            }
+        }
+        assert_eq!(check(Enumeration::product(&[2, 3, 2]).value), 12);
+        assert_eq!(check(Enumeration::product(&[]).value), 1);
+        assert_eq!(check(Enumeration::product(&[3, 0]).value), 0);
+        
+        let mut combinations = Enumeration::combinations(4, 2).value;
+        let mut all = Vec::new();
+        while let Some(current) = combinations.current() {
+            all.push(current.to_vec());
+            combinations.advance();
+        }
+        assert_eq!(all, vec![vec![0, 1], vec![0, 2], vec![0, 3], vec![1, 2], vec![1, 3], vec![2, 3]]);
+        combinations.rewind();
+        assert_eq!(combinations.current(), Some(&[0, 1][..]));
+        
+        let mut multisets = Enumeration::combinations_with_replacement(3, 2).value;
+        multisets.advance();
+        multisets.advance();
+        multisets.advance();
+        assert_eq!(multisets.current(), Some(&[1, 1][..]));
+        
+        let mut permutations = Enumeration::permutations(3, 3).value;
+        assert_eq!(permutations.unrank(5).value, vec![2, 1, 0]);
+        assert!(permutations.seek(3).is_success());
+        assert_eq!(permutations.index(), 3);
+        assert_eq!(permutations.current(), Some(&[1, 2, 0][..]));
+        permutations.advance();
+        assert_eq!(permutations.current(), Some(&[2, 0, 1][..]));
+        assert_eq!(permutations.seek(6).error, MathError::InvalidParameter);
+        assert_eq!(permutations.seek(-1).error, MathError::NegativeNumber);
+        assert_eq!(permutations.index(), 4);
+        
+        // 排列总数很大时仍然可以直接定位
+        let large = Enumeration::combinations(1000, 5).value;
+        let last = large.count().value - 1;
+        assert_eq!(large.unrank(last).value, vec![995, 996, 997, 998, 999]);
+        assert_eq!(large.rank(&[995, 996, 997, 998, 999]).value, last);
+        assert_eq!(large.rank(&[1, 2, 3, 4, 5]).value, large.rank(&[0, 996, 997, 998, 999]).value + 1);
+        let tuples = Enumeration::permutations_with_replacement(10, 18).value;
+        assert_eq!(tuples.unrank(123_456_789).value, vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        
+        // 无效的排列和超出范围的总数
+        assert_eq!(large.rank(&[3, 2, 1, 0, 4]).error, MathError::InvalidParameter);
+        assert_eq!(large.rank(&[0, 1]).error, MathError::InvalidParameter);
+        assert_eq!(Enumeration::permutations(4, 2).value.rank(&[1, 1]).error, MathError::InvalidParameter);
+        let huge = Enumeration::combinations(200, 100).value;
+        assert_eq!(huge.count().error, MathError::Overflow);
+        assert_eq!(huge.unrank(0).error, MathError::Overflow);
+        // 总数超出范围时不再逐个检查排列中的位置
+        let all = Enumeration::permutations(ENUMERATION_MAX_LENGTH, ENUMERATION_MAX_LENGTH).value;
+        let identity: Vec<c_long> = (0..ENUMERATION_MAX_LENGTH).collect();
+        assert_eq!(all.rank(&identity).error, MathError::Overflow);
+        assert_eq!(huge.current().map(<[c_long]>::len), Some(100));
+        
+        assert_eq!(Enumeration::combinations(-1, 2).error, MathError::NegativeNumber);
+        assert_eq!(Enumeration::product(&[2, -1]).error, MathError::NegativeNumber);
+        assert_eq!(Enumeration::permutations(ENUMERATION_MAX_LENGTH + 1, 1).error, MathError::InvalidParameter);
+        assert_eq!(Enumeration::permutations_with_replacement(2, ENUMERATION_MAX_LENGTH + 1).error, MathError::InvalidParameter);
+        
+        unsafe {
+            let sizes = [2, 2];
+            let mut handle = ptr::null_mut();
+            assert_eq!(ffi::enumeration::rust_enumeration_product(sizes.as_ptr(), 2, &mut handle), MathError::Success);
+            let mut count = 0;
+            assert_eq!(ffi::enumeration::rust_enumeration_count(handle, &mut count), MathError::Success);
+            assert_eq!(count, 4);
+            
+            assert_eq!(ffi::enumeration::rust_enumeration_seek(handle, 2), MathError::Success);
+            let mut result = ffi::empty_integer_array_result();
+            assert_eq!(ffi::enumeration::rust_enumeration_current(handle, &mut result), MathError::Success);
+            assert_eq!(std::slice::from_raw_parts(result.numbers, result.length as usize), &[1, 0]);
+            ffi::rust_free_integer_array_result(result);
+            
+            let positions = [1, 1];
+            let mut rank = 0;
+            assert_eq!(ffi::enumeration::rust_enumeration_rank(handle, positions.as_ptr(), 2, &mut rank), MathError::Success);
+            assert_eq!(rank, 3);
+            
+            let mut copy = ptr::null_mut();
+            assert_eq!(ffi::enumeration::rust_enumeration_clone(handle, &mut copy), MathError::Success);
+            ffi::enumeration::rust_enumeration_next(handle);
+            ffi::enumeration::rust_enumeration_next(handle);
+            assert!(!ffi::enumeration::rust_enumeration_valid(handle));
+            assert_eq!(ffi::enumeration::rust_enumeration_key(handle), 4);
+            assert!(ffi::enumeration::rust_enumeration_valid(copy));
+            assert_eq!(ffi::enumeration::rust_enumeration_key(copy), 2);
+            ffi::enumeration::rust_enumeration_free(handle);
+            ffi::enumeration::rust_enumeration_free(copy);
+            
+            assert_eq!(ffi::enumeration::rust_enumeration_combinations(3, -1, &mut handle), MathError::NegativeNumber);
+            assert!(handle.is_null());
+        }
+    }
+    
+    #[test]
+    fn test_rational() {
+        use math::rational::Rational;
+        
+        let r = |n, d| Rational::new(n, d).value;
+        
+        // 总是约分到最简形式，分母为正
+        assert_eq!(r(6, -8), r(-3, 4));
+        assert_eq!((r(6, -8).numerator(), r(6, -8).denominator()), (-3, 4));
+        assert_eq!(r(0, -5), Rational::zero());
+        assert_eq!(Rational::new(1, 0).error, MathError::DivisionByZero);
+        assert_eq!(Rational::new(1, c_long::MIN).error, MathError::Overflow);
+        assert_eq!(r(c_long::MIN, -2), r(1 << 62, 1));
+        
+        // 四则运算
+        assert_eq!(r(1, 6).add(&r(1, 3)).value, r(1, 2));
+        assert_eq!(r(1, 6).sub(&r(1, 6)).value, Rational::zero());
+        assert_eq!(r(3, 10).sub(&r(1, 15)).value, r(7, 30));
+        assert_eq!(r(-4, 9).mul(&r(3, 8)).value, r(-1, 6));
+        assert_eq!(r(2, 3).div(&r(-4, 9)).value, r(-3, 2));
+        assert_eq!(r(2, 3).div(&Rational::zero()).error, MathError::DivisionByZero);
+        assert_eq!(r(2, 3).pow(-3).value, r(27, 8));
+        assert_eq!(Rational::zero().pow(-1).error, MathError::DivisionByZero);
+        assert_eq!(r(5, 7).reciprocal().value, r(7, 5));
+        assert_eq!(r(-5, 7).reciprocal().value, r(-7, 5));
+        
+        // 中间结果超出 c_long 但约分后在范围内的运算不会溢出
+        let big = r(c_long::MAX, 2);
+        assert_eq!(big.add(&big).value, Rational::from_integer(c_long::MAX));
+        assert_eq!(big.mul(&r(2, c_long::MAX)).value, Rational::one());
+        assert_eq!(Rational::from_integer(c_long::MAX).add(&Rational::one()).error, MathError::Overflow);
+        assert_eq!(r(1, c_long::MAX).add(&r(1, c_long::MAX - 1)).error, MathError::Overflow);
+        assert_eq!(Rational::from_integer(c_long::MIN).neg().error, MathError::Overflow);
+        
+        assert!(r(1, 3) < r(1, 2));
+        assert!(r(-1, 2) < r(-1, 3));
+        assert!(r(c_long::MAX, c_long::MAX - 1) < r(c_long::MAX - 1, c_long::MAX - 2));
+        assert_eq!((r(-7, 2).floor(), r(-7, 2).ceil()), (-4, -3));
+        assert_eq!((r(7, 2).floor(), r(7, 2).ceil()), (3, 4));
+        assert_eq!((r(-6, 2).floor(), r(-6, 2).ceil()), (-3, -3));
+        assert_eq!(r(1, 4).to_f64(), 0.25);
+        assert_eq!(r(-3, 4).to_string(), "-3/4");
+        assert_eq!(r(10, 5).to_string(), "2");
+        
+        // 解析
+        assert_eq!(Rational::parse(" 6/-8 ").value, r(-3, 4));
+        assert_eq!(Rational::parse("-1.250").value, r(-5, 4));
+        assert_eq!(Rational::parse(".5").value, r(1, 2));
+        assert_eq!(Rational::parse("+7").value, r(7, 1));
+        assert_eq!(Rational::parse("92233720368547758.08").value, r(2305843009213693952, 25));
+        assert_eq!(Rational::parse("0.1234567890123456789").error, MathError::Overflow);
+        assert_eq!(Rational::parse("1/0").error, MathError::DivisionByZero);
+        for invalid in ["", ".", "-", "1/", "1.2.3", "1e5", "a/b", "1/2/3", "--1"] {
+            assert_eq!(Rational::parse(invalid).error, MathError::InvalidParameter, "{invalid}");
+        }
+        
+        // 连分数
+        assert_eq!(r(415, 93).continued_fraction(), vec![4, 2, 6, 7]);
+        assert_eq!(r(-7, 3).continued_fraction(), vec![-3, 1, 2]);
+        assert_eq!(Rational::from_continued_fraction(&[4, 2, 6, 7]).value, r(415, 93));
+        assert_eq!(Rational::from_continued_fraction(&[-3, 1, 2]).value, r(-7, 3));
+        assert_eq!(Rational::from_continued_fraction(&[1, 0]).error, MathError::InvalidParameter);
+        assert_eq!(Rational::from_continued_fraction(&[]).error, MathError::InvalidParameter);
+        
+        // 最佳近似
+        let pi = Rational::parse("3.14159265358979").value;
+        assert_eq!(pi.limit_denominator(10).value, r(22, 7));
+        assert_eq!(pi.limit_denominator(100).value, r(311, 99));
+        assert_eq!(pi.limit_denominator(1000).value, r(355, 113));
+        assert_eq!(r(1, 3).limit_denominator(3).value, r(1, 3));
+        assert_eq!(r(3, 4).limit_denominator(0).error, MathError::InvalidParameter);
+        assert_eq!(Rational::from_f64(std::f64::consts::PI, 1000).value, r(355, 113));
+        assert_eq!(Rational::from_f64(-0.75, 1_000_000).value, r(-3, 4));
+        assert_eq!(Rational::from_f64(0.1, 1_000_000).value, r(1, 10));
+        assert_eq!(Rational::from_f64(2.5, 1).value, r(2, 1));
+        // 按浮点数的精确值求最佳近似，与 Python 的 Fraction(x).limit_denominator 一致
+        assert_eq!(Rational::from_f64(47703.20280364127, 79076).value, r(3678584781, 77114));
+        assert_eq!(Rational::from_f64(-0.1, 1_000_000_000_000_000_000).value, r(-3602879701896397, 36028797018963968));
+        assert_eq!(Rational::from_f64(-2.5e-7, 1_000_000).value, r(0, 1));
+        assert_eq!(Rational::from_f64(-2.5e-7, 3_000_000).value, r(-1, 3_000_000));
+        assert_eq!(Rational::from_f64(2f64.powi(-60), 1 << 62).value, r(1, 1 << 60));
+        assert_eq!(Rational::from_f64(1e-300, c_long::MAX).value, r(0, 1));
+        assert_eq!(Rational::from_f64(-9.223_372_036_854_776e18, 1).value, Rational::from_integer(c_long::MIN));
+        assert_eq!(Rational::from_f64(f64::NAN, 10).error, MathError::NotANumber);
+        assert_eq!(Rational::from_f64(f64::INFINITY, 10).error, MathError::Overflow);
+    }
+    
+    #[test]
+    fn test_ffi_rational() {
+        use ffi::rational::*;
+        
+        unsafe {
+            let mut a = ptr::null_mut();
+            let mut b = ptr::null_mut();
+            assert_eq!(rust_rational_new(1, 3, &mut a), MathError::Success);
+            assert_eq!(rust_rational_from_string(c"0.25".as_ptr(), &mut b), MathError::Success);
+            
+            let mut sum = ptr::null_mut();
+            assert_eq!(rust_rational_add(a, b, &mut sum), MathError::Success);
+            assert_eq!((rust_rational_numerator(sum), rust_rational_denominator(sum)), (7, 12));
+            assert_eq!(rust_rational_cmp(a, b), 1);
+            
+            let mut text = ffi::empty_string_result();
+            assert_eq!(rust_rational_to_string(sum, &mut text), MathError::Success);
+            assert_eq!(std::ffi::CStr::from_ptr(text.data).to_str(), Ok("7/12"));
+            rust_free_string_result(text);
+            
+            let mut zero = ptr::null_mut();
+            let mut quotient = ptr::null_mut();
+            assert_eq!(rust_rational_new(0, 1, &mut zero), MathError::Success);
+            assert_eq!(rust_rational_div(a, zero, &mut quotient), MathError::DivisionByZero);
+            assert!(quotient.is_null());
+            
+            let mut invalid = ptr::null_mut();
+            assert_eq!(rust_rational_from_string(c"1/x".as_ptr(), &mut invalid), MathError::InvalidParameter);
+            assert!(invalid.is_null());
+            
+            for handle in [a, b, sum, zero] {
+                rust_rational_free(handle);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_decimal() {
+        use math::decimal::{Decimal, MAX_SCALE};
+        use types::RoundingMode;
+        
+        let d = |s| Decimal::parse(s).value;
+        
+        // 解析和显示保留小数位数，比较只看数值
+        assert_eq!(d("-12.340").to_string(), "-12.340");
+        assert_eq!(d("+.05").to_string(), "0.05");
+        assert_eq!(d("-0.007").to_string(), "-0.007");
+        assert_eq!(d("42").to_string(), "42");
+        assert_eq!(d("1.50"), d("1.5"));
+        assert!(d("-0.1") < d("0.01"));
+        assert_eq!((d("1.500").mantissa(), d("1.500").scale()), (1500, 3));
+        assert_eq!(Decimal::parse("0.1234567890123456789012345678").error, MathError::Success);
+        assert_eq!(Decimal::parse("0.12345678901234567890123456789").error, MathError::InvalidParameter);
+        assert_eq!(Decimal::parse("170141183460469231731687303715884105728").error, MathError::Overflow);
+        assert_eq!(d("-170141183460469231731687303715884105728").mantissa(), i128::MIN);
+        for invalid in ["", ".", "-", "1.2.3", "1e5", "1,5", "--1"] {
+            assert_eq!(Decimal::parse(invalid).error, MathError::InvalidParameter, "{invalid}");
+        }
+        
+        // 精确的四则运算
+        assert_eq!(d("0.1").add(&d("0.2")).value.to_string(), "0.3");
+        assert_eq!(d("19.99").sub(&d("20")).value.to_string(), "-0.01");
+        assert_eq!(d("19.99").mul(&d("3")).value.to_string(), "59.97");
+        assert_eq!(d("1.10").mul(&d("0.5")).value.to_string(), "0.550");
+        assert_eq!(d("10").div(&d("3"), 4, RoundingMode::HalfEven).value.to_string(), "3.3333");
+        assert_eq!(d("-2").div(&d("3"), 2, RoundingMode::HalfUp).value.to_string(), "-0.67");
+        assert_eq!(d("1").div(&d("0.00"), 2, RoundingMode::HalfUp).error, MathError::DivisionByZero);
+        assert_eq!(d("1").div(&d("3"), MAX_SCALE + 1, RoundingMode::HalfUp).error, MathError::InvalidParameter);
+        // n × 10^k 超出 u128 范围但商不超出时，除法仍然成功
+        let quotient = d("12345678901234567890.12").div(&d("3.00"), 18, RoundingMode::HalfUp);
+        assert_eq!(quotient.value.to_string(), "4115226300411522630.040000000000000000");
+        let one = d("100000000000000000000").div(&d("100000000000000000000"), 20, RoundingMode::HalfUp);
+        assert_eq!(one.value.to_string(), "1.00000000000000000000");
+        assert_eq!(d("2").div(&d("3"), MAX_SCALE, RoundingMode::HalfEven).value.to_string(), "0.6666666666666666666666666667");
+        assert_eq!(d("12345678901234567890").div(&d("0.001"), 18, RoundingMode::HalfUp).error, MathError::Overflow);
+        let tiny = Decimal::new(5, MAX_SCALE).value;
+        assert_eq!(tiny.div(&d("100000000000"), 0, RoundingMode::Up).value, d("1"));
+        assert_eq!(tiny.div(&d("-100000000000"), 0, RoundingMode::HalfUp).value, d("0"));
+        assert_eq!(tiny.mul(&d("0.1")).error, MathError::Overflow);
+        assert_eq!(tiny.mul(&d("0.2")).value.to_string(), "0.0000000000000000000000000001");
+        assert_eq!(d("170141183460469231731687303715884105727").add(&d("1")).error, MathError::Overflow);
+        assert_eq!(d("-170141183460469231731687303715884105728").neg().error, MathError::Overflow);
+        
+        // 舍入模式
+        let cases = [
+            ("2.5", [3, 2, 3, 2, 3, 2, 2]),
+            ("3.5", [4, 3, 4, 3, 4, 3, 4]),
+            ("-2.5", [-3, -2, -2, -3, -3, -2, -2]),
+            ("2.51", [3, 2, 3, 2, 3, 3, 3]),
+            ("-2.49", [-3, -2, -2, -3, -2, -2, -2]),
+            ("-2.0", [-2, -2, -2, -2, -2, -2, -2]),
+        ];
+        let modes = [
+            RoundingMode::Up, RoundingMode::Down, RoundingMode::Ceiling, RoundingMode::Floor,
+            RoundingMode::HalfUp, RoundingMode::HalfDown, RoundingMode::HalfEven,
+        ];
+        for (text, expected) in cases {
+            for (mode, expected) in modes.iter().zip(expected) {
+                assert_eq!(d(text).round(0, *mode).value.mantissa(), expected, "{text} {mode:?}");
+                assert_eq!(Decimal::parse_rounded(text, 0, *mode).value.mantissa(), expected, "{text} {mode:?}");
+            }
+        }
+        assert_eq!(d("1.5").round(3, RoundingMode::Down).value.to_string(), "1.500");
+        assert_eq!(Decimal::parse_rounded("0.125000000000000000000000000000001", 2, RoundingMode::HalfEven).value, d("0.13"));
+        assert_eq!(Decimal::parse_rounded("-0.001", 2, RoundingMode::HalfUp).value.to_string(), "0.00");
+        
+        // 浮点数使用最短的十进制表示
+        assert_eq!(Decimal::from_f64(2.675, 2, RoundingMode::HalfUp).value.to_string(), "2.68");
+        assert_eq!(Decimal::from_f64(0.1 + 0.2, 2, RoundingMode::HalfEven).value.to_string(), "0.30");
+        assert_eq!(Decimal::from_f64(1e-30, 2, RoundingMode::Up).value.to_string(), "0.01");
+        assert_eq!(Decimal::from_f64(1e300, 2, RoundingMode::Up).error, MathError::Overflow);
+        assert_eq!(Decimal::from_f64(f64::NAN, 2, RoundingMode::Up).error, MathError::NotANumber);
+        assert_eq!(d("-12.345").to_f64(), -12.345);
+        
+        // 分配金额不会丢失最小单位
+        let parts = d("100.00").allocate(&[1, 1, 1]).value;
+        assert_eq!(parts.iter().map(Decimal::to_string).collect::<Vec<_>>(), ["33.34", "33.33", "33.33"]);
+        let parts = d("-0.05").allocate(&[3, 7, 0]).value;
+        assert_eq!(parts.iter().map(Decimal::to_string).collect::<Vec<_>>(), ["-0.02", "-0.03", "0.00"]);
+        let parts = d("10").allocate(&[1, 2, 2, 1]).value;
+        assert_eq!(parts.iter().map(Decimal::mantissa).collect::<Vec<_>>(), [2, 3, 3, 2]);
+        assert_eq!(d("1").allocate(&[]).error, MathError::InvalidParameter);
+        assert_eq!(d("1").allocate(&[0, 0]).error, MathError::InvalidParameter);
+        assert_eq!(d("1").allocate(&[1, -1]).error, MathError::NegativeNumber);
+    }
+    
+    #[test]
+    fn test_ffi_decimal() {
+        use ffi::decimal::*;
+        use types::RoundingMode;
+        
+        unsafe {
+            let mut price = ptr::null_mut();
+            let mut quantity = ptr::null_mut();
+            assert_eq!(rust_decimal_from_string(c"19.99".as_ptr(), &mut price), MathError::Success);
+            assert_eq!(rust_decimal_from_long(3, &mut quantity), MathError::Success);
+            
+            let mut total = ptr::null_mut();
+            assert_eq!(rust_decimal_mul(price, quantity, &mut total), MathError::Success);
+            assert_eq!(rust_decimal_scale(total), 2);
+            assert_eq!(rust_decimal_cmp(total, price), 1);
+            
+            let mut text = ffi::empty_string_result();
+            assert_eq!(rust_decimal_to_string(total, &mut text), MathError::Success);
+            assert_eq!(std::ffi::CStr::from_ptr(text.data).to_str(), Ok("59.97"));
+            rust_free_string_result(text);
+            
+            let mut parts = [ptr::null_mut(); 2];
+            assert_eq!(rust_decimal_allocate(total, [1, 1].as_ptr(), 2, parts.as_mut_ptr()), MathError::Success);
+            assert_eq!(rust_decimal_to_double(parts[0]), 29.99);
+            assert_eq!(rust_decimal_to_double(parts[1]), 29.98);
+            
+            // 舍入模式代码无效
+            let mut rounded = ptr::null_mut();
+            assert_eq!(rust_decimal_round(total, 1, 7, &mut rounded), MathError::InvalidParameter);
+            assert_eq!(rust_decimal_round(total, -1, RoundingMode::HalfUp as c_int, &mut rounded), MathError::InvalidParameter);
+            assert!(rounded.is_null());
+            assert_eq!(rust_decimal_round(total, 1, RoundingMode::HalfUp as c_int, &mut rounded), MathError::Success);
+            assert_eq!(rust_decimal_to_double(rounded), 60.0);
+            
+            for handle in [price, quantity, total, parts[0], parts[1], rounded] {
+                rust_decimal_free(handle);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_complex() {
+        use math::advanced::*;
+        use math::complex::Complex;
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        
+        let c = Complex::new;
+        let assert_near = |z: Complex, re: c_double, im: c_double| {
+            assert!((z.re - re).abs() <= 1e-12 && (z.im - im).abs() <= 1e-12, "{z} != {}", c(re, im));
+        };
+        
+        // 四则运算、极坐标和显示
+        assert_eq!(c(1.0, 2.0).mul(&c(3.0, -1.0)), c(5.0, 5.0));
+        assert_near(c(5.0, 5.0).div(&c(3.0, -1.0)).value, 1.0, 2.0);
+        assert_eq!(c(1.0, 1.0).div(&Complex::default()).error, MathError::DivisionByZero);
+        assert_eq!(c(0.0, 2.0).reciprocal().value, c(0.0, -0.5));
+        assert_eq!(c(3.0, 4.0).abs(), 5.0);
+        assert_eq!(c(1e300, 1e300).div(&c(1e300, 1e300)).value, c(1.0, 0.0));
+        assert_near(Complex::from_polar(2.0, FRAC_PI_4), 2f64.sqrt(), 2f64.sqrt());
+        assert_eq!(Complex::from_polar(2.0, 0.0), c(2.0, 0.0));
+        assert_eq!(c(-1.0, 0.0).arg(), PI);
+        assert_eq!(c(1.0, 1.0).powi(2).value, c(0.0, 2.0));
+        assert_eq!(c(0.0, 1.0).powi(-3).value, Complex::i());
+        assert_eq!(Complex::default().powi(-1).error, MathError::DivisionByZero);
+        assert_eq!(c(3.0, 4.0).to_string(), "3+4i");
+        assert_eq!(c(3.0, -4.5).to_string(), "3-4.5i");
+        assert_eq!(c(0.0, -1.0).to_string(), "-1i");
+        assert_eq!(c(-2.0, 0.0).to_string(), "-2");
+        
+        // 负数的平方根和对数不再是 NaN
+        assert_eq!(complex_sqrt(c(-4.0, 0.0)).value, c(0.0, 2.0));
+        assert_eq!(complex_sqrt(c(-4.0, -0.0)).value, c(0.0, -2.0));
+        assert_eq!(complex_sqrt(c(3.0, 4.0)).value, c(2.0, 1.0));
+        assert_eq!(complex_ln(c(-1.0, 0.0)).value, c(0.0, PI));
+        assert_near(complex_log10(c(-100.0, 0.0)).value, 2.0, PI / 10f64.ln());
+        assert_eq!(complex_ln(Complex::default()).error, MathError::DomainError);
+        assert_near(complex_exp(c(0.0, PI)).value, -1.0, 0.0);
+        // e^710 溢出，但 e^710·cos 1 = 1.2070e308 仍在范围内；虚部 e^710·sin 1 超出范围
+        let large = complex_exp(c(710.0, 1.0)).value;
+        assert!((large.re / 1.2070325234545283e308 - 1.0).abs() < 1e-14 && large.im == c_double::INFINITY);
+        assert!((complex_exp(c(710.0, 3.0)).value.im / 3.152613594064419e307 - 1.0).abs() < 1e-14);
+        
+        // 幂：整数指数走快速幂，i^i = e^(-π/2)
+        assert_eq!(complex_pow(c(1.0, 1.0), c(2.0, 0.0)).value, c(0.0, 2.0));
+        assert_near(complex_pow(Complex::i(), Complex::i()).value, (-FRAC_PI_2).exp(), 0.0);
+        assert_eq!(complex_pow(Complex::default(), Complex::default()).value, c(1.0, 0.0));
+        assert_eq!(complex_pow(Complex::default(), c(-1.0, 0.0)).error, MathError::DivisionByZero);
+        assert_eq!(complex_pow(Complex::default(), c(-0.5, 1.0)).error, MathError::DomainError);
+        
+        // 三角函数和双曲函数
+        assert_near(complex_sin(c(1.0, 1.0)).value, 1.2984575814159773, 0.6349639147847361);
+        assert_near(complex_cos(c(1.0, 1.0)).value, 0.8337300251311491, -0.9888977057628651);
+        assert_near(complex_tan(c(1.0, 1.0)).value, 0.2717525853195117, 1.0839233273386946);
+        assert_eq!(complex_tan(c(1.0, 1000.0)).value, Complex::i());
+        assert_near(complex_asin(c(2.0, 0.0)).value, FRAC_PI_2, 1.3169578969248166);
+        assert_near(complex_acos(c(2.0, 0.0)).value, 0.0, -1.3169578969248166);
+        assert_near(complex_atan(c(1.0, 1.0)).value, 1.0172219678978514, 0.4023594781085251);
+        assert_near(complex_atan(c(0.0, 2.0)).value, FRAC_PI_2, 0.5493061443340549);
+        assert_near(complex_atan(c(0.0, -2.0)).value, FRAC_PI_2, -0.5493061443340549);
+        // 实轴附近虚部的相对误差也很小
+        let near_axis = complex_atan(c(-0.7, 1e-12)).value;
+        assert!((near_axis.re + 0.6107259643892086).abs() < 1e-15);
+        assert!((near_axis.im / 6.711409395973154e-13 - 1.0).abs() < 1e-14);
+        let near_zero = complex_atan(c(1e-8, 1e-8)).value;
+        assert!((near_zero.re / 1.0000000000000002e-8 - 1.0).abs() < 1e-14);
+        assert!((near_zero.im / 1e-8 - 1.0).abs() < 1e-14);
+        assert_eq!(complex_atan(Complex::i()).error, MathError::DomainError);
+        assert_eq!(complex_atan(c(0.0, -1.0)).error, MathError::DomainError);
+        assert_near(complex_sinh(c(1.0, 1.0)).value, 0.6349639147847361, 1.2984575814159773);
+        assert_near(complex_cosh(c(1.0, 1.0)).value, 0.8337300251311491, 0.9888977057628651);
+        assert_near(complex_tanh(c(1.0, 1.0)).value, 1.0839233273386946, 0.2717525853195117);
+        
+        // IEEE 模式下原样返回
+        rust_set_float_mode(FloatMode::Ieee as c_int);
+        assert_eq!(c(1.0, 0.0).div(&Complex::default()).error, MathError::Success);
+        assert!(complex_ln(Complex::default()).value.re.is_infinite());
+        rust_set_float_mode(FloatMode::Strict as c_int);
+    }
+    
+    #[test]
+    fn test_ffi_complex() {
+        use ffi::complex::*;
+        
+        unsafe {
+            let mut z = RustComplex::default();
+            assert_eq!(rust_complex_from_polar(2.0, std::f64::consts::PI, &mut z), MathError::Success);
+            assert_eq!(rust_complex_abs(z), 2.0);
+            
+            let mut root = RustComplex::default();
+            assert_eq!(rust_complex_sqrt(RustComplex { re: -9.0, im: 0.0 }, &mut root), MathError::Success);
+            assert_eq!(root, RustComplex { re: 0.0, im: 3.0 });
+            
+            let mut square = RustComplex::default();
+            assert_eq!(rust_complex_powi(root, 2, &mut square), MathError::Success);
+            assert_eq!(square, RustComplex { re: -9.0, im: 0.0 });
+            assert_eq!(rust_complex_mul(root, root, &mut square), MathError::Success);
+            assert_eq!(square.re, -9.0);
+            
+            let mut quotient = RustComplex::default();
+            assert_eq!(rust_complex_div(root, RustComplex::default(), &mut quotient), MathError::DivisionByZero);
+            assert_eq!(rust_complex_ln(RustComplex::default(), &mut quotient), MathError::DomainError);
+            assert_eq!(rust_complex_exp(root, ptr::null_mut()), MathError::InvalidParameter);
+            
+            let mut text = ffi::empty_string_result();
+            assert_eq!(rust_complex_to_string(RustComplex { re: 1.5, im: -2.0 }, &mut text), MathError::Success);
+            assert_eq!(std::ffi::CStr::from_ptr(text.data).to_str(), Ok("1.5-2i"));
+            rust_free_string_result(text);
+        }
+    }
+    
+    #[test]
+    fn test_matrix() {
+        use math::matrix::Matrix;
+        
+        let m = |rows: &[&[c_double]]| Matrix::from_rows(rows).value;
+        let assert_near = |actual: &Matrix, expected: &Matrix| {
+            assert_eq!((actual.rows(), actual.cols()), (expected.rows(), expected.cols()));
+            for (x, y) in actual.data().iter().zip(expected.data()) {
+                assert!((x - y).abs() <= 1e-12, "{actual:?} != {expected:?}");
+            }
+        };
+        
+        // 构造、乘法和转置
+        let a = m(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]);
+        assert_eq!(a.transpose(), m(&[&[1.0, 4.0], &[2.0, 5.0], &[3.0, 6.0]]));
+        assert_eq!(a.mul(&a.transpose()).value, m(&[&[14.0, 32.0], &[32.0, 77.0]]));
+        assert_eq!(a.mul(&a).error, MathError::InvalidParameter);
+        assert_eq!(Matrix::from_rows(&[&[1.0, 2.0], &[3.0]]).error, MathError::InvalidParameter);
+        assert_eq!(Matrix::from_rows(&[]).error, MathError::InvalidParameter);
+        assert_eq!(Matrix::new(2, 2, vec![1.0; 3]).error, MathError::InvalidParameter);
+        
+        // 行列式和逆矩阵
+        let b = m(&[&[2.0, 1.0, 1.0], &[4.0, -6.0, 0.0], &[-2.0, 7.0, 2.0]]);
+        assert!((b.determinant().value + 16.0).abs() <= 1e-12);
+        assert_near(&b.mul(&b.inverse().value).value, &Matrix::identity(3));
+        assert_eq!(m(&[&[0.0, 1.0], &[1.0, 0.0]]).determinant().value, -1.0);
+        let singular = m(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], &[7.0, 8.0, 9.0]]);
+        assert!(singular.determinant().value.abs() < 1e-12);
+        assert_eq!(singular.inverse().error, MathError::DivisionByZero);
+        assert_eq!(a.determinant().error, MathError::InvalidParameter);
+        
+        // LU 分解：P·A = L·U
+        let lu = b.lu().value;
+        assert_eq!(lu.permutation(), &[1, 0, 2]);
+        let permuted = Matrix::from_rows(&lu.permutation().iter().map(|&i| b.row(i)).collect::<Vec<_>>()).value;
+        assert_near(&lu.lower().mul(&lu.upper()).value, &permuted);
+        
+        // QR 分解：Q 正交，R 上三角且对角线非负
+        let tall = m(&[&[12.0, -51.0, 4.0], &[6.0, 167.0, -68.0], &[-4.0, 24.0, -41.0], &[1.0, 1.0, 1.0]]);
+        let qr = tall.qr();
+        assert_near(&qr.q().transpose().mul(qr.q()).value, &Matrix::identity(4));
+        assert_near(&qr.q().mul(qr.r()).value, &tall);
+        for i in 0..4 {
+            for j in 0..i.min(3) {
+                assert_eq!(qr.r()[(i, j)], 0.0);
+            }
+        }
+        assert!((0..3).all(|i| qr.r()[(i, i)] > 0.0));
+        
+        // Cholesky 分解
+        let spd = m(&[&[4.0, 12.0, -16.0], &[12.0, 37.0, -43.0], &[-16.0, -43.0, 98.0]]);
+        assert_eq!(spd.cholesky().value, m(&[&[2.0, 0.0, 0.0], &[6.0, 1.0, 0.0], &[-8.0, 5.0, 3.0]]));
+        assert_eq!(m(&[&[1.0, 2.0], &[2.0, 1.0]]).cholesky().error, MathError::DomainError);
+        assert_eq!(m(&[&[4.0, 1.0], &[2.0, 4.0]]).cholesky().error, MathError::DomainError);
+        
+        // 方程组：方阵求精确解，超定方程组求最小二乘解
+        let rhs = m(&[&[5.0], &[-2.0], &[9.0]]);
+        assert_near(&b.solve(&rhs).value, &m(&[&[1.0], &[1.0], &[2.0]]));
+        assert_eq!(singular.solve(&rhs).error, MathError::DivisionByZero);
+        // 拟合 y = 1 + 2x：数据点 (0, 1.1), (1, 2.9), (2, 5.1), (3, 6.9)
+        let design = m(&[&[1.0, 0.0], &[1.0, 1.0], &[1.0, 2.0], &[1.0, 3.0]]);
+        let observed = m(&[&[1.1], &[2.9], &[5.1], &[6.9]]);
+        assert_near(&design.solve(&observed).value, &m(&[&[1.06], &[1.96]]));
+        assert_eq!(design.transpose().solve(&m(&[&[1.0], &[2.0]])).error, MathError::InvalidParameter);
+        assert_eq!(b.solve(&observed).error, MathError::InvalidParameter);
+        let rank_deficient = m(&[&[1.0, 2.0], &[2.0, 4.0], &[3.0, 6.0]]);
+        assert_eq!(rank_deficient.solve(&rhs).error, MathError::DivisionByZero);
+        
+        // 严格模式下 NaN 结果返回错误
+        let nan = m(&[&[c_double::NAN, 1.0], &[1.0, 1.0]]);
+        assert_eq!(nan.mul(&nan).error, MathError::NotANumber);
+        assert_eq!(nan.determinant().error, MathError::NotANumber);
+    }
+    
+    #[test]
+    fn test_ffi_matrix() {
+        use ffi::matrix::*;
+        
+        unsafe {
+            let a = [1.0, 2.0, 3.0, 4.0];
+            let mut product = [0.0; 4];
+            assert_eq!(rust_matrix_multiply_impl(a.as_ptr(), 2, 2, a.as_ptr(), 2, 2, product.as_mut_ptr()), MathError::Success);
+            assert_eq!(product, [7.0, 10.0, 15.0, 22.0]);
+            assert_eq!(rust_matrix_multiply_impl(a.as_ptr(), 1, 4, a.as_ptr(), 1, 4, product.as_mut_ptr()), MathError::InvalidParameter);
+            
+            let mut determinant = 0.0;
+            assert_eq!(rust_matrix_determinant_impl(a.as_ptr(), 2, 2, &mut determinant), MathError::Success);
+            assert!((determinant + 2.0).abs() < 1e-12);
+            
+            let (mut lower, mut upper, mut permutation) = ([0.0; 4], [0.0; 4], [0; 2]);
+            assert_eq!(
+                rust_matrix_lu_impl(a.as_ptr(), 2, 2, lower.as_mut_ptr(), upper.as_mut_ptr(), permutation.as_mut_ptr()),
+                MathError::Success
+            );
+            assert_eq!(permutation, [1, 0]);
+            assert_eq!(lower, [1.0, 0.0, 1.0 / 3.0, 1.0]);
+            
+            let b = [5.0, 11.0];
+            let mut x = [0.0; 2];
+            assert_eq!(rust_matrix_solve_impl(a.as_ptr(), 2, 2, b.as_ptr(), 1, x.as_mut_ptr()), MathError::Success);
+            assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 2.0).abs() < 1e-12);
+            
+            // 出错时不写入结果
+            let singular = [1.0, 2.0, 2.0, 4.0];
+            let mut inverse = [-1.0; 4];
+            assert_eq!(rust_matrix_inverse_impl(singular.as_ptr(), 2, 2, inverse.as_mut_ptr()), MathError::DivisionByZero);
+            assert_eq!(inverse, [-1.0; 4]);
+            assert_eq!(rust_matrix_cholesky_impl(singular.as_ptr(), 2, 2, inverse.as_mut_ptr()), MathError::DomainError);
+            assert_eq!(rust_matrix_transpose_impl(a.as_ptr(), 0, 4, inverse.as_mut_ptr()), MathError::InvalidParameter);
+            assert_eq!(rust_matrix_qr_impl(a.as_ptr(), 2, 2, ptr::null_mut(), inverse.as_mut_ptr()), MathError::InvalidParameter);
+        }
+    }
+    
+    #[test]
+    fn test_ffi_advanced() {
+        use ffi::advanced::*;
+        
+        let eval = |f: unsafe extern "C" fn(c_double, *mut c_double) -> MathError, x| {
+            call(|result| unsafe { f(x, result) }).1
+        };
+        
+        assert_eq!(eval(rust_sqrt_impl, 16.0), 4.0);
+        assert_eq!(eval(rust_cbrt_impl, -27.0), -3.0);
+        assert!((eval(rust_ln_impl, std::f64::consts::E) - 1.0).abs() < 1e-15);
+        assert_eq!(eval(rust_log10_impl, 1000.0), 3.0);
+        assert_eq!(eval(rust_sin_impl, 0.0), 0.0);
+        assert_eq!(eval(rust_cos_impl, 0.0), 1.0);
+        assert!((eval(rust_atan_impl, 1.0) - std::f64::consts::FRAC_PI_4).abs() < 1e-15);
+        assert_eq!(eval(rust_tanh_impl, 0.0), 0.0);
+        
+        let status = |f: unsafe extern "C" fn(c_double, *mut c_double) -> MathError, x| {
+            call(|result| unsafe { f(x, result) }).0
+        };
+        
+        assert_eq!(status(rust_sqrt_impl, -1.0), MathError::DomainError);
+        assert_eq!(status(rust_asin_impl, 1.5), MathError::DomainError);
+        assert_eq!(status(rust_acos_impl, -1.0), MathError::Success);
+        assert_eq!(status(rust_sin_impl, c_double::NAN), MathError::NotANumber);
+        assert_eq!(status(rust_sin_impl, c_double::INFINITY), MathError::NotANumber);
+        assert_eq!(status(rust_sqrt_impl, c_double::NAN), MathError::NotANumber);
+    }
+    
+    #[test]
+    fn test_rust_factorial_big_impl() {
+        let mut result = ffi::empty_string_result();
+        unsafe {
+            assert_eq!(rust_factorial_big_impl(25, &mut result), MathError::Success);
+            assert_eq!(result.length, 26);
+            let digits = std::ffi::CStr::from_ptr(result.data);
+            assert_eq!(digits.to_str().unwrap(), "15511210043330985984000000");
+            rust_free_string_result(result);
+            
+            let mut result = ffi::empty_string_result();
+            assert_eq!(rust_factorial_big_impl(-1, &mut result), MathError::NegativeNumber);
+            assert!(result.data.is_null());
+            assert_eq!(result.length, 0);
+            assert_eq!(rust_factorial_big_impl(5, ptr::null_mut()), MathError::InvalidParameter);
+        }
     }
     
     // 内部函数测试
@@ -244,28 +1904,217 @@ mod tests {
         assert_eq!(factorial(10).value, 3628800);
     }
     
+    #[test]
+    fn test_factorial_big() {
+        assert_eq!(factorial_big(0).value.to_string(), "1");
+        assert_eq!(factorial_big(20).value.to_string(), factorial(20).value.to_string());
+        assert_eq!(
+            factorial_big(50).value.to_string(),
+            "30414093201713378043612608166064768844377641568960512000000000000"
+        );
+        
+        // 1000! 共有 2568 位，末尾有 249 个零
+        let digits = factorial_big(1000).value.to_string();
+        assert_eq!(digits.len(), 2568);
+        assert!(digits.starts_with("402387260077"));
+        assert_eq!(digits.len() - digits.trim_end_matches('0').len(), 249);
+        
+        assert!(factorial_big(-1).is_error());
+        assert_eq!(factorial_big(math::FACTORIAL_BIG_LIMIT + 1).error, MathError::InvalidParameter);
+    }
+    
+    #[test]
+    fn test_bigint_arithmetic() {
+        use math::bigint::BigInt;
+        
+        let parse = |s: &str| BigInt::from_str_radix(s, 10).value;
+        let a = parse("123456789012345678901234567890");
+        let b = parse("-987654321098765432109876543210");
+        
+        assert_eq!((&a + &b).to_string(), "-864197532086419753208641975320");
+        assert_eq!((&a - &b).to_string(), "1111111110111111111011111111100");
+        assert_eq!(
+            (&a * &b).to_string(),
+            "-121932631137021795226185032733622923332237463801111263526900"
+        );
+        assert_eq!(BigInt::from_i64(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!((&BigInt::from_i64(i64::MAX) + &BigInt::one()).to_i64(), None);
+        assert!(b < a);
+        assert!(-&a < BigInt::zero());
+        
+        // Karatsuba 乘法与逐项展开的结果一致：(2^n - 1)² = 2^2n - 2^(n+1) + 1
+        let two = BigInt::from_i64(2);
+        let n = 40_000;
+        let x = &two.pow(n).value - &BigInt::one();
+        let expected = &(&two.pow(2 * n).value - &two.pow(n + 1).value) + &BigInt::one();
+        assert_eq!(x.checked_mul(&x).value, expected);
+        let y = &two.pow(12_345).value + &parse("987654321987654321");
+        assert_eq!(&(&x * &y) - &(&x * &two.pow(12_345).value), &x * &parse("987654321987654321"));
+        
+        // 结果超过 BIGINT_MAX_BITS 位时返回溢出错误
+        use math::bigint::BIGINT_MAX_BITS;
+        let max = &two.pow(BIGINT_MAX_BITS as i64).value - &BigInt::one();
+        assert_eq!(max.checked_add(&BigInt::one()).error, MathError::Overflow);
+        assert_eq!(max.checked_sub(&BigInt::from_i64(-1)).error, MathError::Overflow);
+        assert_eq!(max.checked_sub(&max).value, BigInt::zero());
+        assert_eq!(max.checked_mul(&two).error, MathError::Overflow);
+        assert_eq!(max.checked_mul(&BigInt::one()).value, max);
+        assert_eq!(BigInt::from_i64(3).pow(10_000_000).error, MathError::Overflow);
+        assert_eq!(BigInt::from_str_radix(&"9".repeat(400_000), 10).error, MathError::Overflow);
+        assert_eq!(BigInt::from_str_radix(&format!("-000{}", "1".repeat(1000)), 10).value.signum(), -1);
+    }
+    
+    #[test]
+    fn test_bigint_division() {
+        use math::bigint::BigInt;
+        
+        let parse = |s: &str| BigInt::from_str_radix(s, 10).value;
+        
+        // (a * b + r) / b 应该得到商 a 和余数 r
+        let a = parse("340282366920938463463374607431768211457");
+        let b = parse("18446744073709551629");
+        let r = parse("12345678901234567");
+        let (q, rem) = (&(&a * &b) + &r).div_rem(&b).value;
+        assert_eq!(q, a);
+        assert_eq!(rem, r);
+        
+        // 商向零取整，余数与被除数同号
+        let (q, rem) = BigInt::from_i64(-7).div_rem(&BigInt::from_i64(2)).value;
+        assert_eq!((q.to_i64(), rem.to_i64()), (Some(-3), Some(-1)));
+        
+        assert_eq!(a.div_rem(&BigInt::zero()).error, MathError::DivisionByZero);
+    }
+    
+    #[test]
+    fn test_bigint_pow_and_modpow() {
+        use math::bigint::BigInt;
+        
+        let two = BigInt::from_i64(2);
+        assert_eq!(
+            two.pow(100).value.to_string(),
+            "1267650600228229401496703205376"
+        );
+        assert_eq!(BigInt::from_i64(-3).pow(3).value.to_i64(), Some(-27));
+        assert!(two.pow(-1).is_error());
+        assert_eq!(two.pow(1 << 30).error, MathError::Overflow);
+        
+        // 2^(p-1) ≡ 1 (mod p)，p = 2^127 - 1 是梅森素数
+        let p = &two.pow(127).value - &BigInt::one();
+        let e = &p - &BigInt::one();
+        assert_eq!(two.mod_pow(&e, &p).value, BigInt::one());
+        
+        let result = BigInt::from_i64(-2).mod_pow(&BigInt::from_i64(3), &BigInt::from_i64(5));
+        assert_eq!(result.value.to_i64(), Some(2));
+        assert_eq!(two.mod_pow(&e, &BigInt::zero()).error, MathError::DivisionByZero);
+    }
+    
+    #[test]
+    fn test_bigint_radix() {
+        use math::bigint::BigInt;
+        
+        let value = BigInt::from_str_radix("-ff", 16).value;
+        assert_eq!(value.to_i64(), Some(-255));
+        assert_eq!(value.to_str_radix(2), "-11111111");
+        assert_eq!(BigInt::from_str_radix("Zz", 36).value.to_i64(), Some(1295));
+        
+        let text = "1234567890abcdefghijklmnopqrstuvwxyz1234567890";
+        assert_eq!(BigInt::from_str_radix(text, 36).value.to_str_radix(36), text);
+        assert_eq!(BigInt::from_str_radix("1000000000000", 10).value.to_str_radix(10), "1000000000000");
+        
+        assert!(BigInt::from_str_radix("12a", 10).is_error());
+        assert!(BigInt::from_str_radix("-", 10).is_error());
+        assert!(BigInt::from_str_radix("1", 37).is_error());
+    }
+    
+    #[test]
+    fn test_rust_bigint_handles() {
+        use ffi::bigint::*;
+        
+        unsafe {
+            let text = std::ffi::CString::new("-123456789012345678901234567890").unwrap();
+            let (error, a) = call(|result| rust_bigint_from_string(text.as_ptr(), 10, result));
+            assert_eq!(error, MathError::Success);
+            let (_, b) = call(|result| rust_bigint_from_long(11, result));
+            
+            let mut q = ptr::null_mut();
+            let mut r = ptr::null_mut();
+            assert_eq!(rust_bigint_divmod(a, b, &mut q, &mut r), MathError::Success);
+            assert_eq!(call(|result| rust_bigint_to_long(r, result)), (MathError::Success, -7));
+            assert_eq!(rust_bigint_cmp(q, a), 1);
+            assert_eq!(rust_bigint_sign(a), -1);
+            
+            let mut result = ffi::empty_string_result();
+            assert_eq!(rust_bigint_to_string(q, 10, &mut result), MathError::Success);
+            let digits = std::ffi::CStr::from_ptr(result.data);
+            assert_eq!(digits.to_str().unwrap(), "-11223344455667788991021324353");
+            rust_free_string_result(result);
+            
+            let (_, zero) = call(|result| rust_bigint_from_long(0, result));
+            assert_eq!(rust_bigint_divmod(a, zero, &mut q, &mut r), MathError::DivisionByZero);
+            
+            let invalid = std::ffi::CString::new("12x").unwrap();
+            let (error, handle) = call(|result| rust_bigint_from_string(invalid.as_ptr(), 10, result));
+            assert_eq!(error, MathError::InvalidParameter);
+            assert!(handle.is_null());
+            
+            let (_, big) = call(|result| rust_bigint_pow(b, 30, result));
+            assert_eq!(rust_bigint_to_long(big, &mut 0), MathError::Overflow);
+            rust_bigint_free(big);
+            
+            for handle in [a, b, q, r, zero] {
+                rust_bigint_free(handle);
+            }
+        }
+    }
+    
     #[test]
     fn test_fibonacci() {
-        assert_eq!(fibonacci(0), vec![]);
-        assert_eq!(fibonacci(1), vec![0]);
-        assert_eq!(fibonacci(2), vec![0, 1]);
-        assert_eq!(fibonacci(5), vec![0, 1, 1, 2, 3]);
+        assert_eq!(fibonacci(0).value, vec![]);
+        assert_eq!(fibonacci(1).value, vec![0]);
+        assert_eq!(fibonacci(2).value, vec![0, 1]);
+        assert_eq!(fibonacci(5).value, vec![0, 1, 1, 2, 3]);
     }
     
     #[test]
     fn test_is_prime() {
-        assert_eq!(is_prime(2), true);
-        assert_eq!(is_prime(3), true);
-        assert_eq!(is_prime(4), false);
-        assert_eq!(is_prime(5), true);
-        assert_eq!(is_prime(6), false);
-        assert_eq!(is_prime(7), true);
-        assert_eq!(is_prime(8), false);
-        assert_eq!(is_prime(9), false);
-        assert_eq!(is_prime(10), false);
-        assert_eq!(is_prime(11), true);
-        assert_eq!(is_prime(1), false);
-        assert_eq!(is_prime(0), false);
-        assert_eq!(is_prime(-1), false);
+        assert!(is_prime(2));
+        assert!(is_prime(3));
+        assert!(!is_prime(4));
+        assert!(is_prime(5));
+        assert!(!is_prime(6));
+        assert!(is_prime(7));
+        assert!(!is_prime(8));
+        assert!(!is_prime(9));
+        assert!(!is_prime(10));
+        assert!(is_prime(11));
+        assert!(!is_prime(1));
+        assert!(!is_prime(0));
+        assert!(!is_prime(-1));
+        
+        // 与试除法的结果对照
+        for n in 0..20_000 {
+            let expected = n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0);
+            assert_eq!(is_prime(n), expected, "{n}");
+        }
+        
+        // 大质数、Carmichael 数和对小底数的强伪质数
+        assert!(is_prime(4_294_967_291));
+        assert!(!is_prime(4_294_967_297));
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(41_041));
+        assert!(is_prime(2_305_843_009_213_693_951));
+        assert!(!is_prime(3_825_123_056_546_413_051));
+        assert!(is_prime(9_223_372_036_854_775_783));
+        assert!(!is_prime(c_long::MAX));
+    }
+    
+    #[test]
+    fn test_sqrt_int() {
+        assert_eq!(utils::sqrt_int(0), 0);
+        assert_eq!(utils::sqrt_int(15), 3);
+        assert_eq!(utils::sqrt_int(16), 4);
+        // 转换为 f64 时会向上舍入，浮点平方根会多 1
+        assert_eq!(utils::sqrt_int(9_223_372_030_926_249_000), 3_037_000_498);
+        assert_eq!(utils::sqrt_int(c_long::MAX), 3_037_000_499);
     }
 } 
\ No newline at end of file
@@ -2,8 +2,20 @@
 //! 
 //! 这个模块包含更复杂的数学函数
 //! 如三角函数、对数、统计函数等
+//! 
+//! 以 `complex_` 开头的函数是对应实数函数在复数上的推广，使用主值分支：
+//! 负数的平方根和对数不再超出定义域，例如 complex_sqrt(-4) = 2i
+
+use std::f64::consts::LN_10;
+use std::os::raw::{c_double, c_long};
+use crate::types::{MathError, MathResult};
+use crate::utils::{check_float, float_error};
+use super::complex::{check_complex, complex_error, Complex};
 
-use std::os::raw::c_double;
+// 伽马函数、贝塞尔函数和误差函数分别在 gamma、bessel 和 erf 模块中实现，这里保留原来的路径
+pub use super::bessel::bessel_j0;
+pub use super::erf::{erf, erfc};
+pub use super::gamma::gamma;
 
 /// 计算平方根
 /// 
@@ -11,9 +23,12 @@ use std::os::raw::c_double;
 /// * `n` - 要计算平方根的数字
 /// 
 /// # 返回值
-/// 平方根结果
-pub fn sqrt(n: c_double) -> c_double {
-    n.sqrt()
+/// 平方根结果；严格模式下输入为负数时返回 MathError::DomainError，输入为 NaN 时返回 MathError::NotANumber
+pub fn sqrt(n: c_double) -> MathResult<c_double> {
+    if n < 0.0 {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+    check_float(n.sqrt())
 }
 
 /// 计算立方根
@@ -33,12 +48,15 @@ pub fn cbrt(n: c_double) -> c_double {
 /// * `n` - 要计算对数的数字
 /// 
 /// # 返回值
-/// 自然对数结果，如果输入小于等于0则返回0.0
-pub fn ln(n: c_double) -> c_double {
-    if n <= 0.0 {
-        0.0
+/// 自然对数结果；输入小于等于0时，严格模式返回 MathError::DomainError，
+/// IEEE 模式对 0 返回 -inf、对负数返回 NaN
+pub fn ln(n: c_double) -> MathResult<c_double> {
+    if n == 0.0 {
+        float_error(MathError::DomainError, c_double::NEG_INFINITY)
+    } else if n < 0.0 {
+        float_error(MathError::DomainError, c_double::NAN)
     } else {
-        n.ln()
+        check_float(n.ln())
     }
 }
 
@@ -48,12 +66,15 @@ pub fn ln(n: c_double) -> c_double {
 /// * `n` - 要计算对数的数字
 /// 
 /// # 返回值
-/// 常用对数结果，如果输入小于等于0则返回0.0
-pub fn log10(n: c_double) -> c_double {
-    if n <= 0.0 {
-        0.0
+/// 常用对数结果；输入小于等于0时，严格模式返回 MathError::DomainError，
+/// IEEE 模式对 0 返回 -inf、对负数返回 NaN
+pub fn log10(n: c_double) -> MathResult<c_double> {
+    if n == 0.0 {
+        float_error(MathError::DomainError, c_double::NEG_INFINITY)
+    } else if n < 0.0 {
+        float_error(MathError::DomainError, c_double::NAN)
     } else {
-        n.log10()
+        check_float(n.log10())
     }
 }
 
@@ -96,9 +117,12 @@ pub fn tan(x: c_double) -> c_double {
 /// * `x` - 正弦值
 /// 
 /// # 返回值
-/// 角度（弧度）
-pub fn asin(x: c_double) -> c_double {
-    x.asin()
+/// 角度（弧度）；严格模式下输入不在 [-1, 1] 内时返回 MathError::DomainError，输入为 NaN 时返回 MathError::NotANumber
+pub fn asin(x: c_double) -> MathResult<c_double> {
+    if x.abs() > 1.0 {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+    check_float(x.asin())
 }
 
 /// 计算反余弦函数
@@ -107,9 +131,12 @@ pub fn asin(x: c_double) -> c_double {
 /// * `x` - 余弦值
 /// 
 /// # 返回值
-/// 角度（弧度）
-pub fn acos(x: c_double) -> c_double {
-    x.acos()
+/// 角度（弧度）；严格模式下输入不在 [-1, 1] 内时返回 MathError::DomainError，输入为 NaN 时返回 MathError::NotANumber
+pub fn acos(x: c_double) -> MathResult<c_double> {
+    if x.abs() > 1.0 {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+    check_float(x.acos())
 }
 
 /// 计算反正切函数
@@ -156,80 +183,218 @@ pub fn tanh(x: c_double) -> c_double {
     x.tanh()
 }
 
-/// 计算伽马函数（近似）
+/// 复数平方根的计算，不检查结果
 /// 
-/// 使用斯特林公式近似计算伽马函数
+/// 主值的实部非负；根据实部的符号选择不会相消的公式
+fn sqrt_unchecked(z: Complex) -> Complex {
+    if z.is_zero() {
+        return Complex::new(0.0, z.im);
+    }
+
+    // (|re| + |z|) / 2 分开计算，避免相加时溢出
+    let t = (z.re.abs() / 2.0 + z.abs() / 2.0).sqrt();
+    if z.re >= 0.0 {
+        Complex::new(t, z.im / (2.0 * t))
+    } else {
+        Complex::new(z.im.abs() / (2.0 * t), t.copysign(z.im))
+    }
+}
+
+/// 复数自然对数的计算，不检查 z 是否为 0
+fn ln_unchecked(z: Complex) -> Complex {
+    Complex::new(z.abs().ln(), z.arg())
+}
+
+/// 计算复数指数函数 e^z
 /// 
 /// # 参数
-/// * `n` - 输入值
+/// * `z` - 指数
 /// 
 /// # 返回值
-/// 伽马函数值
-pub fn gamma(n: c_double) -> c_double {
-    if n <= 0.0 {
-        return 0.0;
+/// e^z = e^re · (cos im + i sin im)；严格模式下结果为 NaN 时返回 MathError::NotANumber
+pub fn complex_exp(z: Complex) -> MathResult<Complex> {
+    let modulus = z.re.exp();
+    if z.im == 0.0 {
+        // 避免 e^re 溢出为 inf 时 inf · sin 0 得到 NaN
+        return check_complex(Complex::new(modulus, z.im));
     }
-    
-    // 使用斯特林公式近似
-    let x = n - 1.0;
-    let result = (2.0 * std::f64::consts::PI * x).sqrt() * 
-                 (x / std::f64::consts::E).powf(x) * 
-                 (1.0 + 1.0 / (12.0 * x) + 1.0 / (288.0 * x * x));
-    
-    result
+    if modulus.is_infinite() && z.re.is_finite() {
+        // re 略大于 ln(c_double::MAX) 时 e^re 溢出，但乘以 |cos im| 或 |sin im| 后仍可能在范围内，
+        // 分成两个 e^(re/2) 相乘
+        let half = (z.re / 2.0).exp();
+        return check_complex(Complex::new(half * z.im.cos() * half, half * z.im.sin() * half));
+    }
+    check_complex(Complex::new(modulus * z.im.cos(), modulus * z.im.sin()))
 }
 
-/// 计算贝塞尔函数 J0（零阶第一类贝塞尔函数）
+/// 计算复数自然对数的主值
 /// 
 /// # 参数
-/// * `x` - 输入值
+/// * `z` - 要计算对数的复数
 /// 
 /// # 返回值
-/// 贝塞尔函数值
-pub fn bessel_j0(x: c_double) -> c_double {
-    // 简单的近似实现
-    if x.abs() < 3.75 {
-        let y = (x / 3.75) * (x / 3.75);
-        1.0 + y * (3.5156229 + y * (3.0899424 + y * (1.2067492 + y * (0.2659732 + y * (0.0360768 + y * 0.0045813)))))
-    } else {
-        let z = 3.75 / x.abs();
-        let result = 0.39894228 + z * (0.01328592 + z * (0.00225319 + z * (-0.00157565 + z * (0.00916281 + z * (-0.02057706 + z * (0.02635537 + z * (-0.01647633 + z * 0.00392377)))))));
-        result * (x.abs().sqrt().recip()) * (x.abs() - 0.78539816).cos()
+/// ln|z| + i·arg z，虚部在 [-π, π] 内；z 为 0 时，严格模式返回 MathError::DomainError，IEEE 模式返回 -inf
+pub fn complex_ln(z: Complex) -> MathResult<Complex> {
+    if z.is_zero() {
+        return complex_error(MathError::DomainError, Complex::new(c_double::NEG_INFINITY, z.arg()));
     }
+    check_complex(ln_unchecked(z))
 }
 
-/// 计算误差函数
+/// 计算复数常用对数（以10为底）的主值
 /// 
 /// # 参数
-/// * `x` - 输入值
+/// * `z` - 要计算对数的复数
 /// 
 /// # 返回值
-/// 误差函数值
-pub fn erf(x: c_double) -> c_double {
-    // 使用近似公式
-    let a1 = 0.254829592;
-    let a2 = -0.284496736;
-    let a3 = 1.421413741;
-    let a4 = -1.453152027;
-    let a5 = 1.061405429;
-    let p = 0.3275911;
-    
-    let sign = if x < 0.0 { -1.0 } else { 1.0 };
-    let x = x.abs();
-    
-    let t = 1.0 / (1.0 + p * x);
-    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
-    
-    sign * y
+/// ln z / ln 10；z 为 0 时与 `complex_ln` 相同
+pub fn complex_log10(z: Complex) -> MathResult<Complex> {
+    let ln = complex_ln(z);
+    MathResult {
+        value: Complex::new(ln.value.re / LN_10, ln.value.im / LN_10),
+        error: ln.error,
+    }
 }
 
-/// 计算补误差函数
+/// 计算复数幂的主值
 /// 
 /// # 参数
-/// * `x` - 输入值
+/// * `z` - 底数
+/// * `w` - 指数
+/// 
+/// # 返回值
+/// e^(w·ln z)，指数是整数时使用更精确的 `Complex::powi`；
+/// 0^0 = 1，0 的负整数次幂与除以 0 相同；其他 0^w 在 w 的实部大于 0 时为 0，
+/// 否则严格模式返回 MathError::DomainError
+pub fn complex_pow(z: Complex, w: Complex) -> MathResult<Complex> {
+    // 绝对值小于 2^53 的整数可以精确转换为 c_long
+    if w.im == 0.0 && w.re.fract() == 0.0 && w.re.abs() < 9_007_199_254_740_992.0 {
+        return z.powi(w.re as c_long);
+    }
+
+    if z.is_zero() {
+        return if w.re > 0.0 {
+            MathResult::success(Complex::new(0.0, 0.0))
+        } else {
+            complex_error(MathError::DomainError, Complex::new(c_double::NAN, c_double::NAN))
+        };
+    }
+    complex_exp(w.mul(&ln_unchecked(z)))
+}
+
+/// 计算复数平方根的主值
+/// 
+/// # 参数
+/// * `z` - 要计算平方根的复数
 /// 
 /// # 返回值
-/// 补误差函数值
-pub fn erfc(x: c_double) -> c_double {
-    1.0 - erf(x)
-} 
\ No newline at end of file
+/// 实部非负的平方根，例如 complex_sqrt(-4) = 2i；严格模式下结果为 NaN 时返回 MathError::NotANumber
+pub fn complex_sqrt(z: Complex) -> MathResult<Complex> {
+    check_complex(sqrt_unchecked(z))
+}
+
+/// 计算复数正弦函数
+/// 
+/// # 返回值
+/// sin re · cosh im + i cos re · sinh im
+pub fn complex_sin(z: Complex) -> MathResult<Complex> {
+    check_complex(Complex::new(z.re.sin() * z.im.cosh(), z.re.cos() * z.im.sinh()))
+}
+
+/// 计算复数余弦函数
+/// 
+/// # 返回值
+/// cos re · cosh im - i sin re · sinh im
+pub fn complex_cos(z: Complex) -> MathResult<Complex> {
+    check_complex(Complex::new(z.re.cos() * z.im.cosh(), -(z.re.sin() * z.im.sinh())))
+}
+
+/// 计算复数正切函数
+/// 
+/// # 返回值
+/// (sin 2re + i sinh 2im) / (cos 2re + cosh 2im)；虚部的绝对值很大时趋于 ±i
+pub fn complex_tan(z: Complex) -> MathResult<Complex> {
+    let (x, y) = (2.0 * z.re, 2.0 * z.im);
+    let d = x.cos() + y.cosh();
+    if d.is_infinite() {
+        return check_complex(Complex::new(0.0, z.im.signum()));
+    }
+    check_complex(Complex::new(x.sin() / d, y.sinh() / d))
+}
+
+/// 计算复数反正弦函数的主值
+/// 
+/// 使用 Kahan 的公式，在分支切割（实轴上 |x| > 1 的部分）两侧都得到正确的符号
+/// 
+/// # 返回值
+/// 实部在 [-π/2, π/2] 内的反正弦值，例如 complex_asin(2) = π/2 + 1.3170i
+pub fn complex_asin(z: Complex) -> MathResult<Complex> {
+    let s1 = sqrt_unchecked(Complex::new(1.0 - z.re, -z.im));
+    let s2 = sqrt_unchecked(Complex::new(1.0 + z.re, z.im));
+    check_complex(Complex::new(
+        z.re.atan2(s1.re * s2.re - s1.im * s2.im),
+        (s1.re * s2.im - s1.im * s2.re).asinh(),
+    ))
+}
+
+/// 计算复数反余弦函数的主值
+/// 
+/// 使用 Kahan 的公式，在分支切割（实轴上 |x| > 1 的部分）两侧都得到正确的符号
+/// 
+/// # 返回值
+/// 实部在 [0, π] 内的反余弦值，例如 complex_acos(2) = -1.3170i
+pub fn complex_acos(z: Complex) -> MathResult<Complex> {
+    let s1 = sqrt_unchecked(Complex::new(1.0 - z.re, -z.im));
+    let s2 = sqrt_unchecked(Complex::new(1.0 + z.re, z.im));
+    check_complex(Complex::new(
+        2.0 * s1.re.atan2(s2.re),
+        (s2.re * s1.im - s2.im * s1.re).asinh(),
+    ))
+}
+
+/// 计算复数反正切函数的主值
+/// 
+/// # 返回值
+/// (i/2)·(ln(1 - iz) - ln(1 + iz))；z 为 ±i 时，严格模式返回 MathError::DomainError，IEEE 模式返回 ±inf·i
+pub fn complex_atan(z: Complex) -> MathResult<Complex> {
+    if z.re == 0.0 && z.im.abs() == 1.0 {
+        return complex_error(MathError::DomainError, Complex::new(0.0, c_double::INFINITY.copysign(z.im)));
+    }
+
+    // 直接相减两个对数在实轴附近会损失虚部的精度，改用 C99 catanh 的形式：
+    // 实部 = ½·atan2(2x, (1 - y)(1 + y) - x²)，虚部 = ¼·log1p(4y / (x² + (1 - y)²))
+    // atan2 的两个参数同时除以 2，避免 |x| 很大时 2x 溢出
+    let (x, y) = (z.re, z.im);
+    let re = x.atan2(0.5 * (1.0 - y) * (1.0 + y) - 0.5 * x * x) / 2.0;
+    let im = (4.0 * y / (x * x + (1.0 - y) * (1.0 - y))).ln_1p() / 4.0;
+    check_complex(Complex::new(re, im))
+}
+
+/// 计算复数双曲正弦函数
+/// 
+/// # 返回值
+/// sinh re · cos im + i cosh re · sin im
+pub fn complex_sinh(z: Complex) -> MathResult<Complex> {
+    check_complex(Complex::new(z.re.sinh() * z.im.cos(), z.re.cosh() * z.im.sin()))
+}
+
+/// 计算复数双曲余弦函数
+/// 
+/// # 返回值
+/// cosh re · cos im + i sinh re · sin im
+pub fn complex_cosh(z: Complex) -> MathResult<Complex> {
+    check_complex(Complex::new(z.re.cosh() * z.im.cos(), z.re.sinh() * z.im.sin()))
+}
+
+/// 计算复数双曲正切函数
+/// 
+/// # 返回值
+/// (sinh 2re + i sin 2im) / (cosh 2re + cos 2im)；实部的绝对值很大时趋于 ±1
+pub fn complex_tanh(z: Complex) -> MathResult<Complex> {
+    let (x, y) = (2.0 * z.re, 2.0 * z.im);
+    let d = x.cosh() + y.cos();
+    if d.is_infinite() {
+        return check_complex(Complex::new(z.re.signum(), 0.0));
+    }
+    check_complex(Complex::new(x.sinh() / d, y.sin() / d))
+}
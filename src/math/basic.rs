@@ -4,7 +4,8 @@
 //! 如加减乘除、取模、绝对值等
 
 use std::os::raw::{c_long, c_double};
-use crate::utils::{safe_add, safe_multiply};
+use crate::types::{MathError, MathResult};
+use crate::utils::{apply_overflow_mode, check_float, float_error, mode_add, mode_multiply, mode_subtract};
 
 /// 整数加法运算
 /// 
@@ -13,9 +14,9 @@ use crate::utils::{safe_add, safe_multiply};
 /// * `b` - 第二个加数
 /// 
 /// # 返回值
-/// 两个整数的和，防止溢出
-pub fn add_integers(a: c_long, b: c_long) -> c_long {
-    safe_add(a, b)
+/// 两个整数的和，溢出时按当前溢出模式处理
+pub fn add_integers(a: c_long, b: c_long) -> MathResult<c_long> {
+    mode_add(a, b)
 }
 
 /// 整数减法运算
@@ -25,9 +26,9 @@ pub fn add_integers(a: c_long, b: c_long) -> c_long {
 /// * `b` - 减数
 /// 
 /// # 返回值
-/// 两个整数的差，防止溢出
-pub fn subtract_integers(a: c_long, b: c_long) -> c_long {
-    a.saturating_sub(b)
+/// 两个整数的差，溢出时按当前溢出模式处理
+pub fn subtract_integers(a: c_long, b: c_long) -> MathResult<c_long> {
+    mode_subtract(a, b)
 }
 
 /// 整数乘法运算
@@ -37,9 +38,9 @@ pub fn subtract_integers(a: c_long, b: c_long) -> c_long {
 /// * `b` - 第二个乘数
 /// 
 /// # 返回值
-/// 两个整数的积，防止溢出
-pub fn multiply_integers(a: c_long, b: c_long) -> c_long {
-    safe_multiply(a, b)
+/// 两个整数的积，溢出时按当前溢出模式处理
+pub fn multiply_integers(a: c_long, b: c_long) -> MathResult<c_long> {
+    mode_multiply(a, b)
 }
 
 /// 整数除法运算
@@ -49,12 +50,13 @@ pub fn multiply_integers(a: c_long, b: c_long) -> c_long {
 /// * `b` - 除数
 /// 
 /// # 返回值
-/// 两个整数的商，如果除数为0则返回0
-pub fn divide_integers(a: c_long, b: c_long) -> c_long {
+/// 两个整数的商，如果除数为0则返回 MathError::DivisionByZero
+/// c_long::MIN / -1 会溢出，按当前溢出模式处理
+pub fn divide_integers(a: c_long, b: c_long) -> MathResult<c_long> {
     if b == 0 {
-        0
+        MathResult::error(MathError::DivisionByZero, 0)
     } else {
-        a / b
+        apply_overflow_mode(a.checked_div(b), a.wrapping_div(b), a.saturating_div(b))
     }
 }
 
@@ -65,12 +67,13 @@ pub fn divide_integers(a: c_long, b: c_long) -> c_long {
 /// * `b` - 除数
 /// 
 /// # 返回值
-/// 两个整数的余数，如果除数为0则返回0
-pub fn modulo_integers(a: c_long, b: c_long) -> c_long {
+/// 两个整数的余数，如果除数为0则返回 MathError::DivisionByZero
+pub fn modulo_integers(a: c_long, b: c_long) -> MathResult<c_long> {
     if b == 0 {
-        0
+        MathResult::error(MathError::DivisionByZero, 0)
     } else {
-        a % b
+        // c_long::MIN % -1 的数学结果是 0，但直接用 % 会触发溢出
+        MathResult::success(a.wrapping_rem(b))
     }
 }
 
@@ -117,12 +120,13 @@ pub fn multiply_floats(a: c_double, b: c_double) -> c_double {
 /// * `b` - 除数
 /// 
 /// # 返回值
-/// 两个浮点数的商，如果除数为0则返回0.0
-pub fn divide_floats(a: c_double, b: c_double) -> c_double {
+/// 两个浮点数的商；除数为0时，严格模式返回 MathError::DivisionByZero，
+/// IEEE 模式返回 inf、-inf 或 NaN（0 / 0）
+pub fn divide_floats(a: c_double, b: c_double) -> MathResult<c_double> {
     if b == 0.0 {
-        0.0
+        float_error(MathError::DivisionByZero, a / b)
     } else {
-        a / b
+        check_float(a / b)
     }
 }
 
@@ -132,9 +136,9 @@ pub fn divide_floats(a: c_double, b: c_double) -> c_double {
 /// * `n` - 要计算绝对值的整数
 /// 
 /// # 返回值
-/// 整数的绝对值
-pub fn abs_integer(n: c_long) -> c_long {
-    n.abs()
+/// 整数的绝对值，c_long::MIN 的绝对值会溢出，按当前溢出模式处理
+pub fn abs_integer(n: c_long) -> MathResult<c_long> {
+    apply_overflow_mode(n.checked_abs(), n.wrapping_abs(), n.saturating_abs())
 }
 
 /// 计算浮点数的绝对值
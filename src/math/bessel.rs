@@ -0,0 +1,524 @@
+//! 贝塞尔函数模块
+//! 
+//! 这个模块包含整数阶的贝塞尔函数：
+//! 
+//! - 第一类 J0、J1、Jn 和第二类 Y0、Y1、Yn
+//! - 修正的第一类 I0、I1、In 和第二类 K0、K1、Kn
+//! 
+//! 计算方法：
+//! 
+//! - J：|x| 较小或阶数大于 |x| 时使用 Miller 反向递推，并用 J0 + 2ΣJ2k = 1 归一化；
+//!   |x| 较大时使用 Hankel 渐近展开，再正向递推到所需阶数
+//! - Y：|x| 较小时使用以 J 表示的 Neumann 级数，|x| 较大时使用 Hankel 渐近展开，
+//!   再正向递推（对 Y 是稳定的）
+//! - I：Miller 反向递推，用 e^x = I0 + 2ΣIk 归一化，所有项都是正数，没有抵消误差
+//! - K：对积分表示 K_ν(x) = ∫ exp(-x cosh t) cosh(νt) dt 使用梯形公式，
+//!   被积函数衰减极快，梯形公式按指数速度收敛；更高的阶数正向递推
+//! 
+//! 在 |x| 较大和 x 较小的范围内相对误差都在 1e-14 量级（函数零点附近只能保证绝对误差）
+
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+use std::os::raw::{c_double, c_long};
+use crate::types::{MathError, MathResult};
+use crate::utils::{check_float, float_error};
+
+/// 欧拉常数 γ
+const EULER_GAMMA: c_double = 0.577_215_664_901_532_9;
+
+/// 大于等于这个值时 J 和 Y 使用 Hankel 渐近展开
+const ASYMPTOTIC_THRESHOLD: c_double = 25.0;
+
+/// 允许的最大阶数
+/// 
+/// 阶数过大时结果在 double 中只能是 0 或无穷大，并且递推的开销与阶数成正比
+pub const BESSEL_ORDER_LIMIT: c_long = 10_000;
+
+/// 反向递推时的重新缩放阈值，避免中间结果溢出
+const RESCALE_THRESHOLD: c_double = 1e250;
+
+/// 小于这个值时 J_n 和 I_n 直接使用级数的首项 (x/2)^n / n!
+/// 
+/// 此时首项之后的修正量 x²/4 远小于 double 的精度，而反向递推的系数 2k/x 会溢出
+const SMALL_ARGUMENT: c_double = 1e-40;
+
+/// 检查阶数是否在允许的范围内
+fn check_order(n: c_long) -> Option<MathError> {
+    if n.abs() > BESSEL_ORDER_LIMIT {
+        Some(MathError::InvalidParameter)
+    } else {
+        None
+    }
+}
+
+/// 计算 (x/2)^n / n!，即 J_n(x) 和 I_n(x) 在 x 趋于 0 时的首项
+fn leading_term(n: usize, x: c_double) -> c_double {
+    (1..=n).fold(1.0, |term, k| term * (x / 2.0) / k as c_double)
+}
+
+/// 负阶数和负参数的符号：n 为奇数时返回 -1
+fn odd_sign(n: c_long) -> c_double {
+    if n % 2 == 0 { 1.0 } else { -1.0 }
+}
+
+/// Miller 反向递推计算 J_0(x) 到 J_m(x)
+/// 
+/// 从足够高的阶数 m 开始以任意初值反向递推 J_{k-1} = (2k/x) J_k - J_{k+1}，
+/// 再用 J0 + 2ΣJ2k = 1 归一化；x 必须大于 0
+/// 
+/// # 返回值
+/// 长度为 m + 1 的数组，m 至少为 n + 1
+fn j_miller(n: usize, x: c_double) -> Vec<c_double> {
+    if x < SMALL_ARGUMENT {
+        return (0..=n + 1).map(|k| leading_term(k, x)).collect();
+    }
+
+    let top = (n + 1).max(x.ceil() as usize);
+    // 起始阶数需要远大于 max(n, x)，并且取偶数方便归一化
+    let m = (top + 20 + ((40 * top) as c_double).sqrt() as usize) / 2 * 2;
+
+    let mut values = vec![0.0; m + 2];
+    values[m] = 1e-300;
+    for k in (1..=m).rev() {
+        values[k - 1] = 2.0 * k as c_double / x * values[k] - values[k + 1];
+        if values[k - 1].abs() > RESCALE_THRESHOLD {
+            for value in &mut values[k - 1..] {
+                *value /= RESCALE_THRESHOLD;
+            }
+        }
+    }
+
+    let norm = values[0] + 2.0 * values[2..=m].iter().step_by(2).sum::<c_double>();
+    values.truncate(m + 1);
+    for value in &mut values {
+        *value /= norm;
+    }
+    values
+}
+
+/// Hankel 渐近展开中的 P(ν, x) 和 Q(ν, x)
+/// 
+/// 级数是发散的渐近级数，在项开始变大或足够小时停止
+fn hankel_pq(nu: c_double, x: c_double) -> (c_double, c_double) {
+    let mu = 4.0 * nu * nu;
+    let (mut p, mut q) = (1.0, 0.0);
+    let mut term: c_double = 1.0;
+
+    for k in 1..100 {
+        let odd = (2 * k - 1) as c_double;
+        let next = term * (mu - odd * odd) / (k as c_double * 8.0 * x);
+        if next.abs() >= term.abs() || next == 0.0 {
+            break;
+        }
+        term = next;
+
+        match k % 4 {
+            1 => q += term,
+            2 => p -= term,
+            3 => q -= term,
+            _ => p += term,
+        }
+
+        if term.abs() < 1e-17 * p.abs() {
+            break;
+        }
+    }
+
+    (p, q)
+}
+
+/// |x| 较大时用渐近展开计算 (J0, J1, Y0, Y1)，x 必须为正数
+fn asymptotic_j01_y01(x: c_double) -> (c_double, c_double, c_double, c_double) {
+    let (sin, cos) = x.sin_cos();
+    let scale = (2.0 / (PI * x)).sqrt();
+
+    // χ0 = x - π/4，χ1 = x - 3π/4，展开后避免直接计算 x - π/4 带来的舍入误差
+    let (cos0, sin0) = ((cos + sin) * FRAC_1_SQRT_2, (sin - cos) * FRAC_1_SQRT_2);
+    let (cos1, sin1) = ((sin - cos) * FRAC_1_SQRT_2, -(sin + cos) * FRAC_1_SQRT_2);
+
+    let (p0, q0) = hankel_pq(0.0, x);
+    let (p1, q1) = hankel_pq(1.0, x);
+
+    (
+        scale * (p0 * cos0 - q0 * sin0),
+        scale * (p1 * cos1 - q1 * sin1),
+        scale * (p0 * sin0 + q0 * cos0),
+        scale * (p1 * sin1 + q1 * cos1),
+    )
+}
+
+/// 计算 x > 0 时的 J_n(x)，n >= 0
+fn jn_positive(n: usize, x: c_double) -> c_double {
+    if x < ASYMPTOTIC_THRESHOLD || n as c_double >= x {
+        return j_miller(n, x)[n];
+    }
+
+    // 阶数小于 x 时正向递推是稳定的
+    let (j0, j1, _, _) = asymptotic_j01_y01(x);
+    if n == 0 {
+        return j0;
+    }
+
+    let (mut previous, mut current) = (j0, j1);
+    for k in 1..n {
+        let next = 2.0 * k as c_double / x * current - previous;
+        previous = current;
+        current = next;
+    }
+    current
+}
+
+/// 计算 x > 0 时的 (Y0, Y1)
+fn y01_positive(x: c_double) -> (c_double, c_double) {
+    if x >= ASYMPTOTIC_THRESHOLD {
+        let (_, _, y0, y1) = asymptotic_j01_y01(x);
+        return (y0, y1);
+    }
+
+    // Neumann 级数（A&S 9.1.88、9.1.89）：
+    // Y0 = (2/π)(ln(x/2) + γ) J0 - (4/π) Σ (-1)^k J_2k / k
+    // Y1 = -2 J0 / (πx) + (2/π)(ln(x/2) + γ - 1) J1 - (2/π) Σ (-1)^k (2k + 1) J_2k+1 / (k(k + 1))
+    let j = j_miller(1, x);
+    let log_term = (x / 2.0).ln() + EULER_GAMMA;
+
+    let (mut sum0, mut sum1) = (0.0, 0.0);
+    let mut sign = -1.0;
+    let mut k = 1;
+    while 2 * k + 1 < j.len() {
+        let kf = k as c_double;
+        sum0 += sign * j[2 * k] / kf;
+        sum1 += sign * (2.0 * kf + 1.0) * j[2 * k + 1] / (kf * (kf + 1.0));
+        sign = -sign;
+        k += 1;
+    }
+
+    let y0 = 2.0 / PI * log_term * j[0] - 4.0 / PI * sum0;
+    let y1 = -2.0 * j[0] / (PI * x) + 2.0 / PI * (log_term - 1.0) * j[1] - 2.0 / PI * sum1;
+    (y0, y1)
+}
+
+/// 计算 x > 0 时的 Y_n(x)，n >= 0，使用正向递推 Y_{k+1} = (2k/x) Y_k - Y_{k-1}
+fn yn_positive(n: usize, x: c_double) -> c_double {
+    let (y0, y1) = y01_positive(x);
+    if n == 0 {
+        return y0;
+    }
+
+    let (mut previous, mut current) = (y0, y1);
+    for k in 1..n {
+        let next = 2.0 * k as c_double / x * current - previous;
+        previous = current;
+        current = next;
+        if current.is_infinite() {
+            break;
+        }
+    }
+    current
+}
+
+/// 计算 x > 0 时的 e^(-x) · I_n(x)，n >= 0
+/// 
+/// Miller 反向递推 I_{k-1} = (2k/x) I_k + I_{k+1}，用 e^x = I0 + 2ΣIk 归一化
+fn in_scaled_positive(n: usize, x: c_double) -> c_double {
+    if x < SMALL_ARGUMENT {
+        return leading_term(n, x);
+    }
+
+    // I_k(x) 大约按 exp(-k² / 2x) 衰减，起始阶数取到该因子小于 1e-40
+    let m = n + 30 + (80.0 * x).sqrt() as usize;
+
+    let mut values = vec![0.0; m + 2];
+    values[m] = 1e-300;
+    let mut sum = 0.0;
+    for k in (1..=m).rev() {
+        values[k - 1] = 2.0 * k as c_double / x * values[k] + values[k + 1];
+        sum += values[k];
+        if values[k - 1] > RESCALE_THRESHOLD {
+            for value in &mut values[k - 1..] {
+                *value /= RESCALE_THRESHOLD;
+            }
+            sum /= RESCALE_THRESHOLD;
+        }
+    }
+
+    values[n] / (values[0] + 2.0 * sum)
+}
+
+/// 计算 x > 0 时的 e^x · K_ν(x)，ν 为 0 或 1
+/// 
+/// 对 ∫_0^∞ exp(-x(cosh t - 1)) cosh(νt) dt 使用梯形公式
+/// 
+/// x 较大时被积函数近似为宽度 1/√x 的高斯函数，步长需要随之缩小
+fn k01_scaled_positive(nu: c_double, x: c_double) -> c_double {
+    let step = (0.5 / x.sqrt()).min(0.1);
+
+    let mut sum = 0.5;
+    for k in 1.. {
+        let t = k as c_double * step;
+        let term = (-x * (t.cosh() - 1.0)).exp() * (nu * t).cosh();
+        sum += term;
+        if term < 1e-18 * sum {
+            break;
+        }
+    }
+    sum * step
+}
+
+/// 计算 x > 0 时的 K_n(x)，n >= 0，使用正向递推 K_{k+1} = K_{k-1} + (2k/x) K_k
+fn kn_positive(n: usize, x: c_double) -> c_double {
+    let scale = (-x).exp();
+    let k0 = k01_scaled_positive(0.0, x);
+    if n == 0 {
+        return k0 * scale;
+    }
+
+    let (mut previous, mut current) = (k0, k01_scaled_positive(1.0, x));
+    for k in 1..n {
+        let next = previous + 2.0 * k as c_double / x * current;
+        previous = current;
+        current = next;
+        if current.is_infinite() {
+            break;
+        }
+    }
+    current * scale
+}
+
+/// 计算第一类贝塞尔函数 J_n(x)
+/// 
+/// # 参数
+/// * `n` - 阶数，负阶数满足 J_{-n} = (-1)^n J_n
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// J_n(x) 的值；|n| 超过 BESSEL_ORDER_LIMIT 时返回 MathError::InvalidParameter
+pub fn bessel_jn(n: c_long, x: c_double) -> MathResult<c_double> {
+    if let Some(error) = check_order(n) {
+        return MathResult::error(error, 0.0);
+    }
+
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if x.is_infinite() {
+        return MathResult::success(0.0);
+    }
+
+    if x == 0.0 {
+        return MathResult::success(if n == 0 { 1.0 } else { 0.0 });
+    }
+
+    // J_n(-x) = (-1)^n J_n(x)，J_{-n}(x) = (-1)^n J_n(x)
+    let mut sign = if x < 0.0 { odd_sign(n) } else { 1.0 };
+    if n < 0 {
+        sign *= odd_sign(n);
+    }
+
+    check_float(sign * jn_positive(n.unsigned_abs() as usize, x.abs()))
+}
+
+/// 计算零阶第一类贝塞尔函数 J0(x)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// J0(x) 的值
+pub fn bessel_j0(x: c_double) -> MathResult<c_double> {
+    bessel_jn(0, x)
+}
+
+/// 计算一阶第一类贝塞尔函数 J1(x)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// J1(x) 的值
+pub fn bessel_j1(x: c_double) -> MathResult<c_double> {
+    bessel_jn(1, x)
+}
+
+/// 计算第二类贝塞尔函数 Y_n(x)
+/// 
+/// # 参数
+/// * `n` - 阶数，负阶数满足 Y_{-n} = (-1)^n Y_n
+/// * `x` - 输入值，必须大于 0
+/// 
+/// # 返回值
+/// Y_n(x) 的值；x 为 0 时返回 MathError::DomainError（IEEE 浮点模式下返回 -inf），
+/// x 为负数时返回 MathError::DomainError（IEEE 浮点模式下返回 NaN），
+/// 结果超出 double 范围时返回 MathError::Overflow
+pub fn bessel_yn(n: c_long, x: c_double) -> MathResult<c_double> {
+    if let Some(error) = check_order(n) {
+        return MathResult::error(error, 0.0);
+    }
+
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if x == 0.0 {
+        return float_error(MathError::DomainError, c_double::NEG_INFINITY);
+    }
+
+    if x < 0.0 {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+
+    if x.is_infinite() {
+        return MathResult::success(0.0);
+    }
+
+    let sign = if n < 0 { odd_sign(n) } else { 1.0 };
+    let value = sign * yn_positive(n.unsigned_abs() as usize, x);
+
+    if value.is_infinite() {
+        return float_error(MathError::Overflow, value);
+    }
+    check_float(value)
+}
+
+/// 计算零阶第二类贝塞尔函数 Y0(x)
+/// 
+/// # 参数
+/// * `x` - 输入值，必须大于 0
+/// 
+/// # 返回值
+/// Y0(x) 的值
+pub fn bessel_y0(x: c_double) -> MathResult<c_double> {
+    bessel_yn(0, x)
+}
+
+/// 计算一阶第二类贝塞尔函数 Y1(x)
+/// 
+/// # 参数
+/// * `x` - 输入值，必须大于 0
+/// 
+/// # 返回值
+/// Y1(x) 的值
+pub fn bessel_y1(x: c_double) -> MathResult<c_double> {
+    bessel_yn(1, x)
+}
+
+/// 计算修正的第一类贝塞尔函数 I_n(x)
+/// 
+/// # 参数
+/// * `n` - 阶数，负阶数满足 I_{-n} = I_n
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// I_n(x) 的值；结果超出 double 范围（|x| 大约超过 713）时返回 MathError::Overflow
+pub fn bessel_in(n: c_long, x: c_double) -> MathResult<c_double> {
+    if let Some(error) = check_order(n) {
+        return MathResult::error(error, 0.0);
+    }
+
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if x == 0.0 {
+        return MathResult::success(if n == 0 { 1.0 } else { 0.0 });
+    }
+
+    // I_n(-x) = (-1)^n I_n(x)
+    let sign = if x < 0.0 { odd_sign(n) } else { 1.0 };
+    let x = x.abs();
+
+    let value = if x.is_infinite() {
+        c_double::INFINITY
+    } else {
+        let scaled = in_scaled_positive(n.unsigned_abs() as usize, x);
+        // 分两次乘以 e^(x/2)，避免 e^x 先溢出而结果本身没有溢出
+        let half = (x / 2.0).exp();
+        scaled * half * half
+    };
+
+    if value.is_infinite() {
+        return float_error(MathError::Overflow, sign * value);
+    }
+    check_float(sign * value)
+}
+
+/// 计算零阶修正的第一类贝塞尔函数 I0(x)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// I0(x) 的值
+pub fn bessel_i0(x: c_double) -> MathResult<c_double> {
+    bessel_in(0, x)
+}
+
+/// 计算一阶修正的第一类贝塞尔函数 I1(x)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// I1(x) 的值
+pub fn bessel_i1(x: c_double) -> MathResult<c_double> {
+    bessel_in(1, x)
+}
+
+/// 计算修正的第二类贝塞尔函数 K_n(x)
+/// 
+/// # 参数
+/// * `n` - 阶数，负阶数满足 K_{-n} = K_n
+/// * `x` - 输入值，必须大于 0
+/// 
+/// # 返回值
+/// K_n(x) 的值；x 为 0 时返回 MathError::DomainError（IEEE 浮点模式下返回 inf），
+/// x 为负数时返回 MathError::DomainError（IEEE 浮点模式下返回 NaN），
+/// 结果超出 double 范围时返回 MathError::Overflow
+pub fn bessel_kn(n: c_long, x: c_double) -> MathResult<c_double> {
+    if let Some(error) = check_order(n) {
+        return MathResult::error(error, 0.0);
+    }
+
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if x == 0.0 {
+        return float_error(MathError::DomainError, c_double::INFINITY);
+    }
+
+    if x < 0.0 {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+
+    if x.is_infinite() {
+        return MathResult::success(0.0);
+    }
+
+    let value = kn_positive(n.unsigned_abs() as usize, x);
+    if value.is_infinite() {
+        return float_error(MathError::Overflow, value);
+    }
+    check_float(value)
+}
+
+/// 计算零阶修正的第二类贝塞尔函数 K0(x)
+/// 
+/// # 参数
+/// * `x` - 输入值，必须大于 0
+/// 
+/// # 返回值
+/// K0(x) 的值
+pub fn bessel_k0(x: c_double) -> MathResult<c_double> {
+    bessel_kn(0, x)
+}
+
+/// 计算一阶修正的第二类贝塞尔函数 K1(x)
+/// 
+/// # 参数
+/// * `x` - 输入值，必须大于 0
+/// 
+/// # 返回值
+/// K1(x) 的值
+pub fn bessel_k1(x: c_double) -> MathResult<c_double> {
+    bessel_kn(1, x)
+}
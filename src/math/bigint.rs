@@ -0,0 +1,929 @@
+//! 大整数模块
+//!
+//! 这个模块实现了任意精度的整数类型
+//! - `BigUint`: 无符号大整数，内部使用 u32 作为基本单元（limb），低位在前存储
+//! - `BigInt`: 有符号大整数，由符号和 `BigUint` 绝对值组成
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use crate::types::{MathError, MathResult};
+
+/// 十进制转换时每次处理的位数
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+/// 十进制转换时每次除以的基数（10^9）
+const DECIMAL_CHUNK_BASE: u32 = 1_000_000_000;
+
+/// 大整数允许的最大位数（约 31.5 万位十进制数）
+///
+/// 解析、加减、乘法和幂运算的结果超过这个位数时返回溢出错误，
+/// 防止一次不可中断的调用耗尽内存或长时间阻塞；这个大小的乘法约需几十毫秒
+pub const BIGINT_MAX_BITS: u64 = 1 << 20;
+
+/// 两个乘数都至少有这么多 limb 时使用 Karatsuba 乘法，否则使用竖式乘法
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// 数字字符对应的值，支持 0-9、a-z、A-Z
+fn digit_value(c: u8) -> Option<u32> {
+    match c {
+        b'0'..=b'9' => Some((c - b'0') as u32),
+        b'a'..=b'z' => Some((c - b'a') as u32 + 10),
+        b'A'..=b'Z' => Some((c - b'A') as u32 + 10),
+        _ => None,
+    }
+}
+
+/// 计算某个进制下一个 u32 能容纳的最多位数及对应的基数
+///
+/// 例如十进制返回 (9, 10^9)
+fn radix_chunk(radix: u32) -> (usize, u32) {
+    let mut digits = 1;
+    let mut base = radix;
+    while let Some(next) = base.checked_mul(radix) {
+        base = next;
+        digits += 1;
+    }
+    (digits, base)
+}
+
+/// 任意精度无符号整数
+///
+/// 内部表示为 u32 数组，低位在前，并且始终不包含前导零
+/// 零用空数组表示
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BigUint {
+    /// 各个 limb，低位在前
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    /// 创建值为 0 的大整数
+    pub fn zero() -> Self {
+        Self { limbs: Vec::new() }
+    }
+
+    /// 创建值为 1 的大整数
+    pub fn one() -> Self {
+        Self { limbs: vec![1] }
+    }
+
+    /// 从 u64 创建大整数
+    ///
+    /// # 参数
+    /// * `n` - 初始值
+    pub fn from_u64(n: u64) -> Self {
+        Self::from_limbs(vec![n as u32, (n >> 32) as u32])
+    }
+
+    /// 从 limb 数组创建大整数，会自动去掉前导零
+    fn from_limbs(limbs: Vec<u32>) -> Self {
+        let mut result = Self { limbs };
+        result.normalize();
+        result
+    }
+
+    /// 转换为 u64
+    ///
+    /// # 返回值
+    /// 如果数值超出 u64 范围返回 None
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u64),
+            2 => Some(((self.limbs[1] as u64) << 32) | self.limbs[0] as u64),
+            _ => None,
+        }
+    }
+
+    /// 检查是否为零
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// 检查是否为奇数
+    pub fn is_odd(&self) -> bool {
+        self.limbs.first().is_some_and(|&limb| limb & 1 == 1)
+    }
+
+    /// 有效二进制位数，零的位数为 0
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(&top) => self.limbs.len() as u64 * 32 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// 去掉高位的前导零，保持内部表示唯一
+    fn normalize(&mut self) {
+        while let Some(&0) = self.limbs.last() {
+            self.limbs.pop();
+        }
+    }
+
+    /// 原地乘以一个小整数
+    ///
+    /// # 参数
+    /// * `m` - 乘数
+    pub fn mul_small(&mut self, m: u32) {
+        if m == 0 {
+            self.limbs.clear();
+            return;
+        }
+
+        let mut carry: u64 = 0;
+        for limb in self.limbs.iter_mut() {
+            let product = (*limb as u64) * (m as u64) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    /// 原地加上一个小整数
+    ///
+    /// # 参数
+    /// * `a` - 加数
+    pub fn add_small(&mut self, a: u32) {
+        let mut carry = a as u64;
+        for limb in self.limbs.iter_mut() {
+            if carry == 0 {
+                return;
+            }
+            let sum = *limb as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    /// 原地除以一个小整数
+    ///
+    /// # 参数
+    /// * `d` - 除数，不能为 0
+    ///
+    /// # 返回值
+    /// 除法的余数
+    pub fn div_small(&mut self, d: u32) -> u32 {
+        assert!(d != 0, "除数不能为 0");
+
+        let mut remainder: u64 = 0;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (remainder << 32) | (*limb as u64);
+            *limb = (current / d as u64) as u32;
+            remainder = current % d as u64;
+        }
+
+        self.normalize();
+        remainder as u32
+    }
+
+    /// 大整数加法
+    fn add_big(&self, other: &BigUint) -> BigUint {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() {
+            (&self.limbs, &other.limbs)
+        } else {
+            (&other.limbs, &self.limbs)
+        };
+
+        let mut result = Vec::with_capacity(long.len() + 1);
+        let mut carry: u64 = 0;
+        for (i, &limb) in long.iter().enumerate() {
+            let sum = limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+            result.push(sum as u32);
+            carry = sum >> 32;
+        }
+        if carry > 0 {
+            result.push(carry as u32);
+        }
+
+        Self::from_limbs(result)
+    }
+
+    /// 大整数减法
+    ///
+    /// # 参数
+    /// * `other` - 减数，不能大于被减数
+    fn sub_big(&self, other: &BigUint) -> BigUint {
+        assert!(*self >= *other, "无符号减法的结果不能为负数");
+
+        let mut result = Vec::with_capacity(self.limbs.len());
+        let mut borrow: i64 = 0;
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            let mut diff = limb as i64 - other.limbs.get(i).copied().unwrap_or(0) as i64 - borrow;
+            if diff < 0 {
+                diff += 1 << 32;
+                borrow = 1;
+            } else {
+                borrow = 0;
+            }
+            result.push(diff as u32);
+        }
+
+        Self::from_limbs(result)
+    }
+
+    /// 大整数乘法，较大的乘数使用 Karatsuba 算法
+    fn mul_big(&self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+
+        Self::from_limbs(mul_limbs(&self.limbs, &other.limbs))
+    }
+
+    /// 大整数带余除法
+    ///
+    /// 使用 Knuth 的算法 D（《计算机程序设计艺术》4.3.1）
+    ///
+    /// # 参数
+    /// * `divisor` - 除数，不能为 0
+    ///
+    /// # 返回值
+    /// (商, 余数)
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "除数不能为 0");
+
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+
+        if divisor.limbs.len() == 1 {
+            let mut quotient = self.clone();
+            let remainder = quotient.div_small(divisor.limbs[0]);
+            return (quotient, BigUint::from_u64(remainder as u64));
+        }
+
+        // 规格化：左移使除数最高位为 1，保证商的估计误差不超过 2
+        let shift = divisor.limbs[divisor.limbs.len() - 1].leading_zeros();
+        let v = shift_left_limbs(&divisor.limbs, shift);
+        let mut u = shift_left_limbs(&self.limbs, shift);
+        let n = divisor.limbs.len();
+        let m = self.limbs.len() - n;
+
+        let base: u64 = 1 << 32;
+        let v_top = v[n - 1] as u64;
+        let v_second = v[n - 2] as u64;
+        let mut quotient = vec![0u32; m + 1];
+
+        for j in (0..=m).rev() {
+            // 估计商的当前位
+            let numerator = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+            let mut q_hat = numerator / v_top;
+            let mut r_hat = numerator % v_top;
+            while q_hat >= base || q_hat * v_second > ((r_hat << 32) | u[j + n - 2] as u64) {
+                q_hat -= 1;
+                r_hat += v_top;
+                if r_hat >= base {
+                    break;
+                }
+            }
+
+            // 从被除数中减去 q_hat * 除数
+            let mut borrow: i64 = 0;
+            let mut carry: u64 = 0;
+            for i in 0..n {
+                let product = q_hat * v[i] as u64 + carry;
+                carry = product >> 32;
+                let diff = u[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+                u[i + j] = diff as u32;
+                borrow = if diff < 0 { 1 } else { 0 };
+            }
+            let diff = u[j + n] as i64 - borrow - carry as i64;
+            u[j + n] = diff as u32;
+
+            // 估计值大了 1，需要加回一次除数
+            if diff < 0 {
+                q_hat -= 1;
+                let mut carry: u64 = 0;
+                for i in 0..n {
+                    let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                    u[i + j] = sum as u32;
+                    carry = sum >> 32;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+
+            quotient[j] = q_hat as u32;
+        }
+
+        // 余数需要右移回去，抵消规格化时的左移
+        let mut remainder = Vec::with_capacity(n);
+        for i in 0..n {
+            let low = u[i] >> shift;
+            let high = if shift == 0 { 0 } else { u[i + 1] << (32 - shift) };
+            remainder.push(low | high);
+        }
+
+        (Self::from_limbs(quotient), Self::from_limbs(remainder))
+    }
+
+    /// 幂运算（快速幂）
+    ///
+    /// # 参数
+    /// * `exponent` - 指数
+    pub fn pow(&self, mut exponent: u64) -> BigUint {
+        let mut result = BigUint::one();
+        let mut base = self.clone();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    /// 模幂运算：计算 self^exponent mod modulus
+    ///
+    /// # 参数
+    /// * `exponent` - 指数
+    /// * `modulus` - 模数，不能为 0
+    pub fn mod_pow(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        assert!(!modulus.is_zero(), "模数不能为 0");
+
+        let mut result = BigUint::one().div_rem(modulus).1;
+        let mut base = self.div_rem(modulus).1;
+
+        for i in 0..exponent.bits() {
+            if exponent.limbs[(i / 32) as usize] >> (i % 32) & 1 == 1 {
+                result = (&result * &base).div_rem(modulus).1;
+            }
+            base = (&base * &base).div_rem(modulus).1;
+        }
+
+        result
+    }
+
+    /// 按指定进制解析字符串
+    ///
+    /// # 参数
+    /// * `digits` - 数字字符串，不含符号
+    /// * `radix` - 进制，范围 2 到 36
+    ///
+    /// # 返回值
+    /// 解析结果，字符串为空或包含无效字符时返回错误码
+    pub fn from_str_radix(digits: &str, radix: u32) -> MathResult<BigUint> {
+        if !(2..=36).contains(&radix) || digits.is_empty() {
+            return MathResult::error(MathError::InvalidParameter, BigUint::zero());
+        }
+
+        let (chunk_digits, chunk_base) = radix_chunk(radix);
+        let mut result = BigUint::zero();
+
+        for chunk in digits.as_bytes().chunks(chunk_digits) {
+            let mut value: u32 = 0;
+            for &c in chunk {
+                match digit_value(c) {
+                    Some(d) if d < radix => value = value * radix + d,
+                    _ => return MathResult::error(MathError::InvalidParameter, BigUint::zero()),
+                }
+            }
+
+            // 最后一块可能不满，需要按实际位数放大
+            let multiplier = if chunk.len() == chunk_digits {
+                chunk_base
+            } else {
+                radix.pow(chunk.len() as u32)
+            };
+            result.mul_small(multiplier);
+            result.add_small(value);
+        }
+
+        MathResult::success(result)
+    }
+
+    /// 按指定进制转换为字符串，字母使用小写
+    ///
+    /// # 参数
+    /// * `radix` - 进制，范围 2 到 36
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "进制必须在 2 到 36 之间");
+
+        if self.is_zero() {
+            return "0".to_string();
+        }
+
+        let (chunk_digits, chunk_base) = radix_chunk(radix);
+        let mut value = self.clone();
+        let mut chunks = Vec::new();
+        while !value.is_zero() {
+            chunks.push(value.div_small(chunk_base));
+        }
+
+        // 每一块从低位到高位展开，最后整体反转
+        let mut digits = Vec::with_capacity(chunks.len() * chunk_digits);
+        let last = chunks.len() - 1;
+        for (i, mut chunk) in chunks.into_iter().enumerate() {
+            let mut count = 0;
+            while chunk > 0 || (i < last && count < chunk_digits) {
+                let d = chunk % radix;
+                digits.push(std::char::from_digit(d, radix).unwrap_or('0') as u8);
+                chunk /= radix;
+                count += 1;
+            }
+        }
+        digits.reverse();
+
+        String::from_utf8(digits).unwrap_or_default()
+    }
+
+    /// 转换为十进制字符串
+    ///
+    /// 每次除以 10^9 取出 9 位十进制数字，避免逐位相除
+    pub fn to_decimal_string(&self) -> String {
+        if self.is_zero() {
+            return "0".to_string();
+        }
+
+        let mut value = self.clone();
+        let mut chunks = Vec::new();
+        while !value.is_zero() {
+            chunks.push(value.div_small(DECIMAL_CHUNK_BASE));
+        }
+
+        // 最高位的块不补零，其余的块补足 9 位
+        let mut result = String::with_capacity(chunks.len() * DECIMAL_CHUNK_DIGITS);
+        let mut iter = chunks.iter().rev();
+        if let Some(first) = iter.next() {
+            result.push_str(&first.to_string());
+        }
+        for chunk in iter {
+            result.push_str(&format!("{:0width$}", chunk, width = DECIMAL_CHUNK_DIGITS));
+        }
+
+        result
+    }
+}
+
+/// 竖式乘法，结果的长度为 a.len() + b.len()
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry: u64 = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let current = result[i + j] as u64 + (x as u64) * (y as u64) + carry;
+            result[i + j] = current as u32;
+            carry = current >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    result
+}
+
+/// 把 src 加到 dst 上，调用者保证结果不超出 dst 的长度（src 的高位可以是 0）
+fn add_into(dst: &mut [u32], src: &[u32]) {
+    let mut carry: u64 = 0;
+    for (i, slot) in dst.iter_mut().enumerate() {
+        if i >= src.len() && carry == 0 {
+            break;
+        }
+        let sum = *slot as u64 + src.get(i).copied().unwrap_or(0) as u64 + carry;
+        *slot = sum as u32;
+        carry = sum >> 32;
+    }
+}
+
+/// 从 dst 中减去 src，调用者保证 dst 不小于 src
+fn sub_into(dst: &mut [u32], src: &[u32]) {
+    let mut borrow = false;
+    for (i, slot) in dst.iter_mut().enumerate() {
+        if i >= src.len() && !borrow {
+            break;
+        }
+        let (diff, b1) = slot.overflowing_sub(src.get(i).copied().unwrap_or(0));
+        let (diff, b2) = diff.overflowing_sub(borrow as u32);
+        *slot = diff;
+        borrow = b1 || b2;
+    }
+}
+
+/// 两个 limb 数组的和，结果比较长的输入多一个 limb
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = long.to_vec();
+    result.push(0);
+    add_into(&mut result, short);
+    result
+}
+
+/// limb 数组的乘积，结果的长度为 a.len() + b.len()
+///
+/// Karatsuba 算法：a = a1·B^m + a0，b = b1·B^m + b0 时
+/// a·b = z2·B^2m + z1·B^m + z0，z1 = (a0 + a1)(b0 + b1) - z0 - z2，只需要三次一半长度的乘法
+fn mul_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    if a.len() < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(a, b);
+    }
+
+    let mut result = vec![0u32; a.len() + b.len()];
+
+    // 长度相差一倍以上时，把较长的乘数按较短的长度分块相乘
+    if 2 * a.len() <= b.len() {
+        for (i, chunk) in b.chunks(a.len()).enumerate() {
+            add_into(&mut result[i * a.len()..], &mul_limbs(a, chunk));
+        }
+        return result;
+    }
+
+    let m = b.len() / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let z0 = mul_limbs(a0, b0);
+    let z2 = mul_limbs(a1, b1);
+    let mut z1 = mul_limbs(&add_limbs(a0, a1), &add_limbs(b0, b1));
+    sub_into(&mut z1, &z0);
+    sub_into(&mut z1, &z2);
+
+    add_into(&mut result, &z0);
+    add_into(&mut result[2 * m..], &z2);
+    add_into(&mut result[m..], &z1);
+    result
+}
+
+/// 把 limb 数组左移 shift 位（shift < 32），结果比输入多一个 limb
+fn shift_left_limbs(limbs: &[u32], shift: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(limbs.len() + 1);
+    let mut carry: u32 = 0;
+    for &limb in limbs {
+        if shift == 0 {
+            result.push(limb);
+        } else {
+            result.push((limb << shift) | carry);
+            carry = limb >> (32 - shift);
+        }
+    }
+    result.push(carry);
+    result
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        self.add_big(other)
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.sub_big(other)
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        self.mul_big(other)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_decimal_string())
+    }
+}
+
+/// 任意精度有符号整数
+///
+/// 由符号和绝对值组成，零始终为非负数
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BigInt {
+    /// 是否为负数
+    negative: bool,
+
+    /// 绝对值
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    /// 创建值为 0 的大整数
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// 创建值为 1 的大整数
+    pub fn one() -> Self {
+        Self::from_biguint(false, BigUint::one())
+    }
+
+    /// 由符号和绝对值创建大整数
+    ///
+    /// # 参数
+    /// * `negative` - 是否为负数
+    /// * `magnitude` - 绝对值
+    pub fn from_biguint(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// 从 i64 创建大整数
+    pub fn from_i64(n: i64) -> Self {
+        Self::from_biguint(n < 0, BigUint::from_u64(n.unsigned_abs()))
+    }
+
+    /// 转换为 i64
+    ///
+    /// # 返回值
+    /// 如果数值超出 i64 范围返回 None
+    pub fn to_i64(&self) -> Option<i64> {
+        let magnitude = self.magnitude.to_u64()?;
+        if self.negative {
+            if magnitude <= i64::MAX as u64 + 1 {
+                Some((magnitude as i64).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i64::try_from(magnitude).ok()
+        }
+    }
+
+    /// 绝对值部分
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// 检查是否为零
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// 检查是否为负数
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// 符号：负数返回 -1，零返回 0，正数返回 1
+    pub fn signum(&self) -> i32 {
+        if self.negative {
+            -1
+        } else if self.is_zero() {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// 绝对值
+    pub fn abs(&self) -> BigInt {
+        Self::from_biguint(false, self.magnitude.clone())
+    }
+
+    /// 带余除法，商向零取整，余数与被除数同号（与 PHP 的 intdiv 和 % 一致）
+    ///
+    /// # 参数
+    /// * `divisor` - 除数
+    ///
+    /// # 返回值
+    /// (商, 余数)，如果除数为 0 则返回 MathError::DivisionByZero
+    pub fn div_rem(&self, divisor: &BigInt) -> MathResult<(BigInt, BigInt)> {
+        if divisor.is_zero() {
+            return MathResult::error(MathError::DivisionByZero, (BigInt::zero(), BigInt::zero()));
+        }
+
+        let (quotient, remainder) = self.magnitude.div_rem(&divisor.magnitude);
+        MathResult::success((
+            Self::from_biguint(self.negative != divisor.negative, quotient),
+            Self::from_biguint(self.negative, remainder),
+        ))
+    }
+
+    /// 检查结果的位数不超过 `BIGINT_MAX_BITS`
+    fn check_size(value: BigInt) -> MathResult<BigInt> {
+        if value.magnitude.bits() > BIGINT_MAX_BITS {
+            return MathResult::error(MathError::Overflow, BigInt::zero());
+        }
+        MathResult::success(value)
+    }
+
+    /// 加法
+    ///
+    /// # 返回值
+    /// 和，结果超过 `BIGINT_MAX_BITS` 位时返回溢出错误
+    pub fn checked_add(&self, other: &BigInt) -> MathResult<BigInt> {
+        Self::check_size(self + other)
+    }
+
+    /// 减法
+    ///
+    /// # 返回值
+    /// 差，结果超过 `BIGINT_MAX_BITS` 位时返回溢出错误
+    pub fn checked_sub(&self, other: &BigInt) -> MathResult<BigInt> {
+        Self::check_size(self - other)
+    }
+
+    /// 乘法
+    ///
+    /// # 返回值
+    /// 积，结果超过 `BIGINT_MAX_BITS` 位时返回溢出错误
+    pub fn checked_mul(&self, other: &BigInt) -> MathResult<BigInt> {
+        // 积至少有 a + b - 1 位，明显超出时不做乘法
+        let (a, b) = (self.magnitude.bits(), other.magnitude.bits());
+        if a > 0 && b > 0 && a + b - 1 > BIGINT_MAX_BITS {
+            return MathResult::error(MathError::Overflow, BigInt::zero());
+        }
+        Self::check_size(self * other)
+    }
+
+    /// 幂运算
+    ///
+    /// # 参数
+    /// * `exponent` - 指数，不能为负数
+    ///
+    /// # 返回值
+    /// 幂运算结果，结果超过 `BIGINT_MAX_BITS` 位时返回溢出错误
+    pub fn pow(&self, exponent: i64) -> MathResult<BigInt> {
+        if exponent < 0 {
+            return MathResult::error(MathError::NegativeNumber, BigInt::zero());
+        }
+
+        let exponent = exponent as u64;
+        let bits = self.magnitude.bits();
+        if bits > 1 && (bits - 1).saturating_mul(exponent) > BIGINT_MAX_BITS {
+            return MathResult::error(MathError::Overflow, BigInt::zero());
+        }
+
+        let negative = self.negative && exponent % 2 == 1;
+        MathResult::success(Self::from_biguint(negative, self.magnitude.pow(exponent)))
+    }
+
+    /// 模幂运算：计算 self^exponent mod modulus
+    ///
+    /// # 参数
+    /// * `exponent` - 指数，不能为负数
+    /// * `modulus` - 模数，必须为正数
+    ///
+    /// # 返回值
+    /// 范围在 [0, modulus) 内的结果
+    pub fn mod_pow(&self, exponent: &BigInt, modulus: &BigInt) -> MathResult<BigInt> {
+        if exponent.is_negative() {
+            return MathResult::error(MathError::NegativeNumber, BigInt::zero());
+        }
+
+        if modulus.is_zero() {
+            return MathResult::error(MathError::DivisionByZero, BigInt::zero());
+        }
+
+        if modulus.is_negative() {
+            return MathResult::error(MathError::InvalidParameter, BigInt::zero());
+        }
+
+        let mut result = self.magnitude.mod_pow(&exponent.magnitude, &modulus.magnitude);
+
+        // 负数底数的奇数次幂为负，需要换算到 [0, modulus) 区间
+        if self.negative && exponent.magnitude.is_odd() && !result.is_zero() {
+            result = &modulus.magnitude - &result;
+        }
+
+        MathResult::success(Self::from_biguint(false, result))
+    }
+
+    /// 按指定进制解析字符串
+    ///
+    /// 支持可选的 '+' 或 '-' 前缀，字母不区分大小写
+    ///
+    /// # 参数
+    /// * `s` - 要解析的字符串
+    /// * `radix` - 进制，范围 2 到 36
+    ///
+    /// # 返回值
+    /// 解析结果，格式无效时返回错误码，结果超过 `BIGINT_MAX_BITS` 位时返回溢出错误
+    pub fn from_str_radix(s: &str, radix: u32) -> MathResult<BigInt> {
+        let s = s.trim();
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        // 解析的耗时与位数的平方成正比：d 位数至少是 radix^(d-1)，至少有 ⌊log2 radix⌋·(d-1) + 1 位，
+        // 去掉前导零后已经超出上限时不解析
+        let significant = digits.trim_start_matches('0').len() as u64;
+        if (2..=36).contains(&radix) && radix.ilog2() as u64 * significant.saturating_sub(1) >= BIGINT_MAX_BITS {
+            return MathResult::error(MathError::Overflow, BigInt::zero());
+        }
+
+        let magnitude = BigUint::from_str_radix(digits, radix);
+        if magnitude.is_error() {
+            return MathResult::error(magnitude.error, BigInt::zero());
+        }
+
+        Self::check_size(Self::from_biguint(negative, magnitude.value))
+    }
+
+    /// 按指定进制转换为字符串，字母使用小写
+    ///
+    /// # 参数
+    /// * `radix` - 进制，范围 2 到 36
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let digits = self.magnitude.to_str_radix(radix);
+        if self.negative {
+            format!("-{}", digits)
+        } else {
+            digits
+        }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_biguint(self.negative, &self.magnitude + &other.magnitude);
+        }
+
+        // 异号相加：用绝对值大的减去绝对值小的，符号跟随绝对值大的一方
+        if self.magnitude >= other.magnitude {
+            BigInt::from_biguint(self.negative, &self.magnitude - &other.magnitude)
+        } else {
+            BigInt::from_biguint(other.negative, &other.magnitude - &self.magnitude)
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &(-other)
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::from_biguint(
+            self.negative != other.negative,
+            &self.magnitude * &other.magnitude,
+        )
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_biguint(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        f.write_str(&self.magnitude.to_decimal_string())
+    }
+}
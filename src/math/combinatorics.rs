@@ -0,0 +1,332 @@
+//! 组合数学模块
+//! 
+//! 这个模块精确计算常用的组合计数，不经过阶乘，因此 n 远大于 20 时也能得到结果：
+//! 
+//! - `binomial`、`permutations`、`multinomial`: 组合数、排列数和多项式系数
+//! - `catalan`: 卡塔兰数
+//! - `stirling_first`、`stirling_second`: 第一类（无符号）和第二类斯特林数
+//! - `bell`: 贝尔数
+//! - `partitions`: 整数分拆数
+//! 
+//! 结果超出 c_long 范围时总是返回 MathError::Overflow，与溢出模式无关；
+//! 饱和或回绕后的计数没有意义
+
+use std::os::raw::c_long;
+use crate::types::{MathError, MathResult};
+
+/// 结果在 c_long 范围内的最大卡塔兰数下标，C(35) = 3116285494907301262
+pub const CATALAN_MAX_N: c_long = 35;
+
+/// 结果在 c_long 范围内的最大贝尔数下标，B(25) = 4638590332229999353
+pub const BELL_MAX_N: c_long = 25;
+
+/// 结果在 c_long 范围内的最大分拆数参数，p(405) = 9147679068859117602
+pub const PARTITION_MAX_N: c_long = 405;
+
+/// 把 u128 中间结果转换为 c_long，超出范围时返回 None
+fn to_long(value: u128) -> Option<c_long> {
+    c_long::try_from(value).ok()
+}
+
+/// 组合数 C(n, k)，从 n 个元素中选出 k 个的方法数
+/// 
+/// # 参数
+/// * `n` - 元素个数
+/// * `k` - 选出的个数
+/// 
+/// # 返回值
+/// C(n, k)，k > n 时为 0；n 或 k 为负数时返回 MathError::NegativeNumber，
+/// 结果超出 c_long 范围时返回 MathError::Overflow
+pub fn binomial(n: c_long, k: c_long) -> MathResult<c_long> {
+    if n < 0 || k < 0 {
+        return MathResult::error(MathError::NegativeNumber, 0);
+    }
+    if k > n {
+        return MathResult::success(0);
+    }
+
+    // C(n, k) = C(n, n - k)，取较小的 k 减少循环次数
+    let k = k.min(n - k) as u128;
+    let base = n as u128 - k;
+    let mut result: u128 = 1;
+    for i in 1..=k {
+        // 乘之前 result = C(base + i - 1, i - 1)，乘以 (base + i) 后一定能被 i 整除；
+        // 中间的组合数都不超过最终结果，一旦超出范围就可以直接返回
+        result = result * (base + i) / i;
+        if to_long(result).is_none() {
+            return MathResult::error(MathError::Overflow, 0);
+        }
+    }
+
+    MathResult::success(result as c_long)
+}
+
+/// 排列数 P(n, k) = n! / (n - k)!，从 n 个元素中选出 k 个排成一列的方法数
+/// 
+/// # 参数
+/// * `n` - 元素个数
+/// * `k` - 选出的个数
+/// 
+/// # 返回值
+/// P(n, k)，k > n 时为 0；n 或 k 为负数时返回 MathError::NegativeNumber，
+/// 结果超出 c_long 范围时返回 MathError::Overflow
+pub fn permutations(n: c_long, k: c_long) -> MathResult<c_long> {
+    if n < 0 || k < 0 {
+        return MathResult::error(MathError::NegativeNumber, 0);
+    }
+    if k > n {
+        return MathResult::success(0);
+    }
+
+    // k 个连续整数的乘积，除了 n - k + 1 = 1 的情况外每个因子至少为 2，最多 63 次乘法就会溢出
+    let mut result: c_long = 1;
+    for factor in (n - k)..n {
+        result = match result.checked_mul(factor + 1) {
+            Some(product) => product,
+            None => return MathResult::error(MathError::Overflow, 0),
+        };
+    }
+
+    MathResult::success(result)
+}
+
+/// 多项式系数 (k1 + k2 + ... + km)! / (k1! · k2! · ... · km!)
+/// 
+/// 按 C(k1, k1) · C(k1 + k2, k2) · ... 逐个相乘，不计算阶乘
+/// 
+/// # 参数
+/// * `counts` - 各组的元素个数
+/// 
+/// # 返回值
+/// 多项式系数，counts 为空时为 1；含有负数时返回 MathError::NegativeNumber，
+/// 结果超出 c_long 范围时返回 MathError::Overflow
+pub fn multinomial(counts: &[c_long]) -> MathResult<c_long> {
+    if counts.iter().any(|&k| k < 0) {
+        return MathResult::error(MathError::NegativeNumber, 0);
+    }
+
+    let mut total: c_long = 0;
+    let mut result: c_long = 1;
+    for &k in counts {
+        total = match total.checked_add(k) {
+            Some(total) => total,
+            None => return MathResult::error(MathError::Overflow, 0),
+        };
+
+        let factor = binomial(total, k);
+        if factor.is_error() {
+            return MathResult::error(factor.error, 0);
+        }
+        result = match result.checked_mul(factor.value) {
+            Some(product) => product,
+            None => return MathResult::error(MathError::Overflow, 0),
+        };
+    }
+
+    MathResult::success(result)
+}
+
+/// 第 n 个卡塔兰数 C(n) = C(2n, n) / (n + 1)
+/// 
+/// # 参数
+/// * `n` - 下标
+/// 
+/// # 返回值
+/// C(n)；n 为负数时返回 MathError::NegativeNumber，超过 CATALAN_MAX_N 时返回 MathError::Overflow
+pub fn catalan(n: c_long) -> MathResult<c_long> {
+    if n < 0 {
+        return MathResult::error(MathError::NegativeNumber, 0);
+    }
+    if n > CATALAN_MAX_N {
+        return MathResult::error(MathError::Overflow, 0);
+    }
+
+    // C(i + 1) = C(i) · 2(2i + 1) / (i + 2)，除法总是整除
+    let mut result: u128 = 1;
+    for i in 0..n as u128 {
+        result = result * 2 * (2 * i + 1) / (i + 2);
+    }
+
+    MathResult::success(result as c_long)
+}
+
+/// 按三角递推计算斯特林数
+/// 
+/// 两类斯特林数都满足 s(i, j) = w(i, j)·s(i - 1, j) + s(i - 1, j - 1)，s(0, 0) = 1，
+/// 只是权重 w 不同。s(n, k) 只依赖 j ≤ k 且 i - j ≤ n - k 的格子，
+/// 所以每行只需保存这条带状区域，宽度不超过 min(k, n - k) + 1。
+/// 带状区域中的值都不超过 s(n, k)，任何一格超出范围时可以直接报告溢出，
+/// 这也保证了 n 很大时循环很快结束
+/// 
+/// 调用者负责处理 k > n、k = n、k = 0 和 n - k = 1 的情况
+fn stirling(n: c_long, k: c_long, weight: fn(u128, u128) -> u128) -> MathResult<c_long> {
+    let (n, k) = (n as u128, k as u128);
+    let width = n - k;
+
+    // row[x] 是当前行第 lo + x 列的值，从第 0 行 s(0, 0) = 1 开始
+    let mut lo: u128 = 0;
+    let mut row: Vec<u128> = vec![1];
+    for i in 1..=n {
+        let get = |j: u128| {
+            if j >= lo && j - lo < row.len() as u128 {
+                row[(j - lo) as usize]
+            } else {
+                0
+            }
+        };
+
+        let next_lo = i.saturating_sub(width);
+        let mut next = Vec::with_capacity(row.len() + 1);
+        for j in next_lo..=i.min(k) {
+            let below = if j > 0 { get(j - 1) } else { 0 };
+            let value = weight(i, j) * get(j) + below;
+            if to_long(value).is_none() {
+                return MathResult::error(MathError::Overflow, 0);
+            }
+            next.push(value);
+        }
+
+        lo = next_lo;
+        row = next;
+    }
+
+    MathResult::success(row[(k - lo) as usize] as c_long)
+}
+
+/// 检查斯特林数的参数并处理边界情况，需要递推时返回 None
+fn stirling_trivial(n: c_long, k: c_long) -> Option<MathResult<c_long>> {
+    if n < 0 || k < 0 {
+        return Some(MathResult::error(MathError::NegativeNumber, 0));
+    }
+
+    if k > n {
+        Some(MathResult::success(0))
+    } else if k == n {
+        Some(MathResult::success(1))
+    } else if k == 0 {
+        Some(MathResult::success(0))
+    } else if n - k == 1 {
+        // 两类斯特林数都有 s(n, n - 1) = C(n, 2)
+        Some(binomial(n, 2))
+    } else {
+        None
+    }
+}
+
+/// 无符号第一类斯特林数 c(n, k)，n 个元素的排列中恰好有 k 个轮换的个数
+/// 
+/// # 参数
+/// * `n` - 元素个数
+/// * `k` - 轮换个数
+/// 
+/// # 返回值
+/// c(n, k)，k > n 时为 0；n 或 k 为负数时返回 MathError::NegativeNumber，
+/// 结果超出 c_long 范围时返回 MathError::Overflow
+pub fn stirling_first(n: c_long, k: c_long) -> MathResult<c_long> {
+    if let Some(result) = stirling_trivial(n, k) {
+        return result;
+    }
+
+    // c(i, j) = (i - 1)·c(i - 1, j) + c(i - 1, j - 1)
+    stirling(n, k, |i, _| i - 1)
+}
+
+/// 第二类斯特林数 S(n, k)，把 n 个元素划分成 k 个非空子集的方法数
+/// 
+/// # 参数
+/// * `n` - 元素个数
+/// * `k` - 子集个数
+/// 
+/// # 返回值
+/// S(n, k)，k > n 时为 0；n 或 k 为负数时返回 MathError::NegativeNumber，
+/// 结果超出 c_long 范围时返回 MathError::Overflow
+pub fn stirling_second(n: c_long, k: c_long) -> MathResult<c_long> {
+    if let Some(result) = stirling_trivial(n, k) {
+        return result;
+    }
+    if k == 1 {
+        return MathResult::success(1);
+    }
+
+    // S(i, j) = j·S(i - 1, j) + S(i - 1, j - 1)
+    stirling(n, k, |_, j| j)
+}
+
+/// 第 n 个贝尔数 B(n)，把 n 个元素划分成非空子集的方法总数
+/// 
+/// # 参数
+/// * `n` - 元素个数
+/// 
+/// # 返回值
+/// B(n)；n 为负数时返回 MathError::NegativeNumber，超过 BELL_MAX_N 时返回 MathError::Overflow
+pub fn bell(n: c_long) -> MathResult<c_long> {
+    if n < 0 {
+        return MathResult::error(MathError::NegativeNumber, 0);
+    }
+    if n > BELL_MAX_N {
+        return MathResult::error(MathError::Overflow, 0);
+    }
+
+    if n == 0 {
+        return MathResult::success(1);
+    }
+
+    // 贝尔三角：每行以上一行的最后一个数开始，之后每个数等于左边的数加左上方的数，
+    // 第 i 行的最后一个数是 B(i + 1)，只需算到第 n - 1 行，其中的数都不超过 B(n)
+    let mut row: Vec<c_long> = vec![1];
+    for _ in 1..n {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(row[row.len() - 1]);
+        for &above in &row {
+            next.push(next[next.len() - 1] + above);
+        }
+        row = next;
+    }
+
+    MathResult::success(row[row.len() - 1])
+}
+
+/// 整数分拆数 p(n)，把 n 写成不计顺序的正整数之和的方法数
+/// 
+/// 使用欧拉五边形数定理的递推
+/// p(n) = Σ (-1)^(k+1) · [p(n - k(3k-1)/2) + p(n - k(3k+1)/2)]，
+/// 需要 O(n^1.5) 次加法
+/// 
+/// # 参数
+/// * `n` - 要分拆的整数
+/// 
+/// # 返回值
+/// p(n)，p(0) = 1；n 为负数时返回 MathError::NegativeNumber，
+/// 超过 PARTITION_MAX_N 时返回 MathError::Overflow
+pub fn partitions(n: c_long) -> MathResult<c_long> {
+    if n < 0 {
+        return MathResult::error(MathError::NegativeNumber, 0);
+    }
+    if n > PARTITION_MAX_N {
+        return MathResult::error(MathError::Overflow, 0);
+    }
+
+    let n = n as usize;
+    // 正负项交替，部分和可能暂时超过 c_long 范围，用 i128 累加
+    let mut p: Vec<i128> = vec![0; n + 1];
+    p[0] = 1;
+    for m in 1..=n {
+        let mut sum: i128 = 0;
+        for k in 1.. {
+            let first = k * (3 * k - 1) / 2;
+            if first > m {
+                break;
+            }
+
+            let term = p[m - first] + if first + k <= m { p[m - first - k] } else { 0 };
+            if k % 2 == 1 {
+                sum += term;
+            } else {
+                sum -= term;
+            }
+        }
+        p[m] = sum;
+    }
+
+    MathResult::success(p[n] as c_long)
+}
@@ -0,0 +1,179 @@
+//! 复数模块
+//!
+//! `Complex` 就是 C 接口中的 `RustComplex`，这里为它实现四则运算、极坐标转换和整数次幂；
+//! exp、ln、sqrt、三角函数和双曲函数等初等函数在 `math::advanced` 中实现
+//!
+//! 与实数浮点函数一样，结果的实部或虚部为 NaN 时，严格模式下返回 MathError::NotANumber，
+//! IEEE 模式下原样返回
+
+use std::fmt;
+use std::os::raw::{c_double, c_long};
+use crate::types::{FloatMode, MathError, MathResult};
+use crate::utils::float_mode;
+
+pub use crate::types::RustComplex as Complex;
+
+/// 按当前浮点模式报告复数运算的错误，规则与 `utils::float_error` 相同
+///
+/// # 参数
+/// * `error` - 严格模式下返回的错误码
+/// * `value` - IEEE 模式下作为成功结果返回的值
+pub fn complex_error(error: MathError, value: Complex) -> MathResult<Complex> {
+    match float_mode() {
+        FloatMode::Strict => MathResult::error(error, value),
+        FloatMode::Ieee => MathResult::success(value),
+    }
+}
+
+/// 检查复数运算的结果
+///
+/// # 返回值
+/// 严格模式下实部或虚部为 NaN 时返回 MathError::NotANumber，否则原样返回
+pub fn check_complex(z: Complex) -> MathResult<Complex> {
+    if z.is_nan() {
+        complex_error(MathError::NotANumber, z)
+    } else {
+        MathResult::success(z)
+    }
+}
+
+impl Complex {
+    /// 由实部和虚部创建复数
+    pub fn new(re: c_double, im: c_double) -> Self {
+        Self { re, im }
+    }
+
+    /// 实数
+    pub fn from_real(re: c_double) -> Self {
+        Self { re, im: 0.0 }
+    }
+
+    /// 虚数单位 i
+    pub fn i() -> Self {
+        Self { re: 0.0, im: 1.0 }
+    }
+
+    /// 由模和辐角创建复数
+    pub fn from_polar(modulus: c_double, argument: c_double) -> Self {
+        if argument == 0.0 {
+            return Self::from_real(modulus);
+        }
+        Self::new(modulus * argument.cos(), modulus * argument.sin())
+    }
+
+    /// 模 |z|，使用 hypot 避免中间结果溢出
+    pub fn abs(&self) -> c_double {
+        self.re.hypot(self.im)
+    }
+
+    /// 辐角，范围为 [-π, π]
+    pub fn arg(&self) -> c_double {
+        self.im.atan2(self.re)
+    }
+
+    /// 共轭复数
+    pub fn conj(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// 相反数
+    pub fn neg(&self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+
+    /// 实部或虚部是否为 NaN
+    pub fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// 是否为 0
+    pub fn is_zero(&self) -> bool {
+        self.re == 0.0 && self.im == 0.0
+    }
+
+    /// 加法
+    pub fn add(&self, other: &Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+
+    /// 减法
+    pub fn sub(&self, other: &Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+
+    /// 乘法
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+
+    /// 除法，使用 Smith 算法避免中间结果溢出
+    ///
+    /// # 返回值
+    /// 商；除数为 0 时，严格模式返回 MathError::DivisionByZero，IEEE 模式返回 inf 或 NaN
+    pub fn div(&self, other: &Self) -> MathResult<Self> {
+        if other.is_zero() {
+            return complex_error(MathError::DivisionByZero, Self::new(self.re / 0.0, self.im / 0.0));
+        }
+
+        let quotient = if other.re.abs() >= other.im.abs() {
+            let r = other.im / other.re;
+            let d = other.re + other.im * r;
+            Self::new((self.re + self.im * r) / d, (self.im - self.re * r) / d)
+        } else {
+            let r = other.re / other.im;
+            let d = other.re * r + other.im;
+            Self::new((self.re * r + self.im) / d, (self.im * r - self.re) / d)
+        };
+        check_complex(quotient)
+    }
+
+    /// 倒数
+    ///
+    /// # 返回值
+    /// 1 / z；z 为 0 时与 `div` 相同
+    pub fn reciprocal(&self) -> MathResult<Self> {
+        Self::from_real(1.0).div(self)
+    }
+
+    /// 整数次幂，使用二进制快速幂，结果比 exp(n·ln z) 更精确
+    ///
+    /// # 返回值
+    /// z^n；0 的负数次幂与除以 0 相同，0^0 = 1
+    pub fn powi(&self, exponent: c_long) -> MathResult<Self> {
+        let mut base = *self;
+        let mut n = exponent.unsigned_abs();
+        let mut result = Self::from_real(1.0);
+
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.mul(&base);
+            }
+            base = base.mul(&base);
+            n >>= 1;
+        }
+
+        if exponent < 0 {
+            result.reciprocal()
+        } else {
+            check_complex(result)
+        }
+    }
+}
+
+impl fmt::Display for Complex {
+    /// 显示为 "3+4i"、"3-4i"、"4i" 或 "3"，数字使用能还原出同一个浮点数的最短表示
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im == 0.0 {
+            write!(f, "{}", self.re)
+        } else if self.re == 0.0 {
+            write!(f, "{}i", self.im)
+        } else if self.im.is_sign_negative() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
@@ -0,0 +1,469 @@
+//! 定点小数模块
+//!
+//! `Decimal` 用 128 位整数 mantissa 和小数位数 scale 表示 mantissa / 10^scale，
+//! 适合金额等需要精确十进制运算的场景：0.1 + 0.2 恰好等于 0.3
+//!
+//! 加法、减法和乘法是精确的，结果的小数位数分别为两者的较大值和两者之和；
+//! 除法和 `round` 需要指定结果的小数位数和舍入模式（见 `RoundingMode`）
+//! 小数位数不超过 `MAX_SCALE`；结果超出 128 位整数范围时返回 MathError::Overflow，与溢出模式无关
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::os::raw::{c_double, c_long};
+use crate::types::{MathError, MathResult, RoundingMode};
+
+/// 允许的最大小数位数
+pub const MAX_SCALE: u32 = 28;
+
+/// 定点小数
+///
+/// 保留小数位数，"1.50" 和 "1.5" 相等但显示不同
+#[derive(Clone, Copy, Debug)]
+pub struct Decimal {
+    /// 去掉小数点后的整数，带有符号
+    mantissa: i128,
+
+    /// 小数位数
+    scale: u32,
+}
+
+/// 10^n，超出 i128 范围时返回 None
+fn pow10(n: u32) -> Option<i128> {
+    10i128.checked_pow(n)
+}
+
+/// 被舍去的部分不为 0 时，是否应该向远离零的方向进一
+///
+/// # 参数
+/// * `negative` - 结果是否为负数
+/// * `odd` - 截断后的结果是否为奇数
+/// * `half` - 被舍去的部分与一个单位的一半比较的结果
+fn rounds_away(mode: RoundingMode, negative: bool, odd: bool, half: Ordering) -> bool {
+    match mode {
+        RoundingMode::Up => true,
+        RoundingMode::Down => false,
+        RoundingMode::Ceiling => !negative,
+        RoundingMode::Floor => negative,
+        RoundingMode::HalfUp => half != Ordering::Less,
+        RoundingMode::HalfDown => half == Ordering::Greater,
+        RoundingMode::HalfEven => half == Ordering::Greater || (half == Ordering::Equal && odd),
+    }
+}
+
+/// 按符号把绝对值转换为 i128，超出范围时返回 None
+fn signed(magnitude: u128, negative: bool) -> Option<i128> {
+    if negative {
+        0i128.checked_sub_unsigned(magnitude)
+    } else {
+        i128::try_from(magnitude).ok()
+    }
+}
+
+/// 按舍入模式计算 ±n / d，n 和 d 是绝对值，d 大于 0；超出 i128 范围时返回 None
+fn round_quotient(n: u128, d: u128, negative: bool, mode: RoundingMode) -> Option<i128> {
+    let (mut q, r) = (n / d, n % d);
+
+    // 比较 r 和 d - r，避免计算 2r 时溢出
+    if r != 0 && rounds_away(mode, negative, q % 2 == 1, r.cmp(&(d - r))) {
+        q += 1;
+    }
+    signed(q, negative)
+}
+
+/// 按舍入模式计算 ±n × 10^exponent / d，n 和 d 是绝对值，d 大于 0；超出 i128 范围时返回 None
+///
+/// n × 10^exponent 可能超出 u128 范围而商不会，所以逐位做长除法：余数始终小于 d，
+/// 乘以 10 时用累加代替乘法，d 不超过 2^127，累加的和不会溢出
+fn round_scaled_quotient(n: u128, exponent: u32, d: u128, negative: bool, mode: RoundingMode) -> Option<i128> {
+    let (mut q, mut r) = (n / d, n % d);
+    for _ in 0..exponent {
+        let (mut digit, mut next) = (0, 0u128);
+        for _ in 0..10 {
+            next += r;
+            if next >= d {
+                next -= d;
+                digit += 1;
+            }
+        }
+        q = q.checked_mul(10)?.checked_add(digit)?;
+        r = next;
+    }
+
+    if r != 0 && rounds_away(mode, negative, q % 2 == 1, r.cmp(&(d - r))) {
+        q = q.checked_add(1)?;
+    }
+    signed(q, negative)
+}
+
+/// 把十进制数字串转换为整数，超出 u128 范围时返回 None
+fn parse_digits(mut digits: impl Iterator<Item = u8>) -> Option<u128> {
+    digits.try_fold(0u128, |n, c| n.checked_mul(10)?.checked_add((c - b'0') as u128))
+}
+
+/// 把 "-12.340" 拆分为符号、整数部分和小数部分，格式无效时返回 None
+fn split_number(s: &str) -> Option<(bool, &str, &str)> {
+    let s = s.trim();
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+    let valid = |part: &str| part.bytes().all(|c| c.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !valid(integer) || !valid(fraction) {
+        return None;
+    }
+    Some((negative, integer, fraction))
+}
+
+impl Decimal {
+    /// 0，小数位数为 0
+    pub fn zero() -> Self {
+        Self { mantissa: 0, scale: 0 }
+    }
+
+    /// 由整数创建定点小数，小数位数为 0
+    pub fn from_integer(n: c_long) -> Self {
+        Self { mantissa: n as i128, scale: 0 }
+    }
+
+    /// 由 mantissa / 10^scale 创建定点小数
+    ///
+    /// # 返回值
+    /// 定点小数；scale 超过 MAX_SCALE 时返回 MathError::InvalidParameter
+    pub fn new(mantissa: i128, scale: u32) -> MathResult<Self> {
+        if scale > MAX_SCALE {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        }
+        MathResult::success(Self { mantissa, scale })
+    }
+
+    /// 去掉小数点后的整数，例如 -12.34 返回 -1234
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// 小数位数
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// 符号：负数返回 -1，零返回 0，正数返回 1
+    pub fn signum(&self) -> i32 {
+        self.mantissa.signum() as i32
+    }
+
+    /// 是否为 0
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// 把 mantissa 扩大到更多的小数位数，超出 i128 范围时返回 None
+    fn mantissa_at(&self, scale: u32) -> Option<i128> {
+        self.mantissa.checked_mul(pow10(scale - self.scale)?)
+    }
+
+    /// 按结果创建定点小数，超出范围时返回 MathError::Overflow
+    fn from_parts(mantissa: Option<i128>, scale: u32) -> MathResult<Self> {
+        match mantissa {
+            Some(mantissa) => MathResult::success(Self { mantissa, scale }),
+            None => MathResult::error(MathError::Overflow, Self::zero()),
+        }
+    }
+
+    /// 舍入到指定的小数位数
+    ///
+    /// 小数位数比当前多时只补 0，不会改变数值
+    ///
+    /// # 返回值
+    /// 定点小数；scale 超过 MAX_SCALE 时返回 MathError::InvalidParameter，超出范围时返回 MathError::Overflow
+    pub fn round(&self, scale: u32, mode: RoundingMode) -> MathResult<Self> {
+        if scale > MAX_SCALE {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        }
+
+        if scale >= self.scale {
+            return Self::from_parts(self.mantissa_at(scale), scale);
+        }
+
+        // 10^(self.scale - scale) 不超过 10^MAX_SCALE，不会溢出
+        let divisor = pow10(self.scale - scale).unwrap_or(i128::MAX) as u128;
+        Self::from_parts(round_quotient(self.mantissa.unsigned_abs(), divisor, self.mantissa < 0, mode), scale)
+    }
+
+    /// 对齐小数位数后对 mantissa 执行运算
+    fn aligned(&self, other: &Self, op: fn(i128, i128) -> Option<i128>) -> MathResult<Self> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = match (self.mantissa_at(scale), other.mantissa_at(scale)) {
+            (Some(a), Some(b)) => op(a, b),
+            _ => None,
+        };
+        Self::from_parts(mantissa, scale)
+    }
+
+    /// 精确加法，结果的小数位数为两者的较大值
+    ///
+    /// # 返回值
+    /// 和；超出范围时返回 MathError::Overflow
+    pub fn add(&self, other: &Self) -> MathResult<Self> {
+        self.aligned(other, i128::checked_add)
+    }
+
+    /// 精确减法，结果的小数位数为两者的较大值
+    ///
+    /// # 返回值
+    /// 差；超出范围时返回 MathError::Overflow
+    pub fn sub(&self, other: &Self) -> MathResult<Self> {
+        self.aligned(other, i128::checked_sub)
+    }
+
+    /// 精确乘法，结果的小数位数为两者之和
+    ///
+    /// 小数位数之和超过 MAX_SCALE 时去掉末尾的 0，仍然超过时说明结果无法精确表示
+    ///
+    /// # 返回值
+    /// 积；超出范围或无法精确表示时返回 MathError::Overflow
+    pub fn mul(&self, other: &Self) -> MathResult<Self> {
+        let Some(mut mantissa) = self.mantissa.checked_mul(other.mantissa) else {
+            return MathResult::error(MathError::Overflow, Self::zero());
+        };
+
+        let mut scale = self.scale + other.scale;
+        while scale > MAX_SCALE && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+
+        if scale > MAX_SCALE {
+            return MathResult::error(MathError::Overflow, Self::zero());
+        }
+        MathResult::success(Self { mantissa, scale })
+    }
+
+    /// 除法，结果舍入到指定的小数位数
+    ///
+    /// # 返回值
+    /// 商；除数为 0 时返回 MathError::DivisionByZero，scale 超过 MAX_SCALE 时返回 MathError::InvalidParameter，
+    /// 超出范围时返回 MathError::Overflow
+    pub fn div(&self, other: &Self, scale: u32, mode: RoundingMode) -> MathResult<Self> {
+        if other.is_zero() {
+            return MathResult::error(MathError::DivisionByZero, Self::zero());
+        }
+        if scale > MAX_SCALE {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        }
+        if self.is_zero() {
+            return MathResult::success(Self { mantissa: 0, scale });
+        }
+
+        // 商的 mantissa = a / 10^sa ÷ (b / 10^sb) × 10^scale = a × 10^(scale + sb - sa) / b
+        let negative = (self.mantissa < 0) != (other.mantissa < 0);
+        let (n, d) = (self.mantissa.unsigned_abs(), other.mantissa.unsigned_abs());
+        if scale + other.scale >= self.scale {
+            let exponent = scale + other.scale - self.scale;
+            return Self::from_parts(round_scaled_quotient(n, exponent, d, negative, mode), scale);
+        }
+
+        let factor = pow10(self.scale - scale - other.scale).map(|f| f as u128);
+        match factor.and_then(|f| d.checked_mul(f)) {
+            Some(d) => Self::from_parts(round_quotient(n, d, negative, mode), scale),
+            // 除数扩大后超出 u128 范围时，商的绝对值不到半个单位
+            None => {
+                let away = rounds_away(mode, negative, false, Ordering::Less);
+                Self::from_parts(signed(away as u128, negative), scale)
+            }
+        }
+    }
+
+    /// 取反
+    ///
+    /// # 返回值
+    /// 相反数；超出范围时返回 MathError::Overflow
+    pub fn neg(&self) -> MathResult<Self> {
+        Self::from_parts(self.mantissa.checked_neg(), self.scale)
+    }
+
+    /// 绝对值
+    ///
+    /// # 返回值
+    /// 绝对值；超出范围时返回 MathError::Overflow
+    pub fn abs(&self) -> MathResult<Self> {
+        Self::from_parts(self.mantissa.checked_abs(), self.scale)
+    }
+
+    /// 按比例分配金额，各部分之和恰好等于原值
+    ///
+    /// 每一部分先按比例向零截断到当前的最小单位（10^-scale），
+    /// 剩下的单位逐个分给截断部分最大的几项（相同时分给靠前的项），比例为 0 的项总是得到 0
+    ///
+    /// # 参数
+    /// * `ratios` - 各部分的比例
+    ///
+    /// # 返回值
+    /// 与 ratios 等长的各部分金额；ratios 为空或全为 0 时返回 MathError::InvalidParameter，
+    /// 有负数时返回 MathError::NegativeNumber，计算超出范围时返回 MathError::Overflow
+    pub fn allocate(&self, ratios: &[c_long]) -> MathResult<Vec<Self>> {
+        if ratios.iter().any(|&r| r < 0) {
+            return MathResult::error(MathError::NegativeNumber, Vec::new());
+        }
+
+        let total: u128 = ratios.iter().map(|&r| r as u128).sum();
+        if total == 0 {
+            return MathResult::error(MathError::InvalidParameter, Vec::new());
+        }
+
+        let amount = self.mantissa.unsigned_abs();
+        let mut shares = Vec::with_capacity(ratios.len());
+        let mut remainders = Vec::with_capacity(ratios.len());
+        for &ratio in ratios {
+            let Some(product) = amount.checked_mul(ratio as u128) else {
+                return MathResult::error(MathError::Overflow, Vec::new());
+            };
+            shares.push(product / total);
+            remainders.push(product % total);
+        }
+
+        // 剩余的单位数小于余数不为 0 的项数，每项最多多分一个
+        let leftover = amount - shares.iter().sum::<u128>();
+        let mut order: Vec<usize> = (0..ratios.len()).collect();
+        order.sort_by(|&i, &j| remainders[j].cmp(&remainders[i]).then(i.cmp(&j)));
+        for &i in order.iter().take(leftover as usize) {
+            shares[i] += 1;
+        }
+
+        // 每一部分都不超过原值的绝对值，转换回 i128 不会溢出
+        let negative = self.mantissa < 0;
+        let parts = shares
+            .into_iter()
+            .map(|share| Self { mantissa: signed(share, negative).unwrap_or(0), scale: self.scale })
+            .collect();
+        MathResult::success(parts)
+    }
+
+    /// 转换为最接近的浮点数
+    pub fn to_f64(&self) -> c_double {
+        // 经过十进制字符串转换可以得到正确舍入的结果
+        self.to_string().parse().unwrap_or(0.0)
+    }
+
+    /// 按舍入模式把浮点数转换为定点小数
+    ///
+    /// 使用能还原出同一个浮点数的最短十进制表示，所以 2.675 按四舍五入得到 2.68，
+    /// 而不是按它实际的二进制值 2.67499999... 得到 2.67
+    ///
+    /// # 返回值
+    /// 定点小数；x 为 NaN 时返回 MathError::NotANumber，无穷大或超出范围时返回 MathError::Overflow，
+    /// scale 超过 MAX_SCALE 时返回 MathError::InvalidParameter
+    pub fn from_f64(x: c_double, scale: u32, mode: RoundingMode) -> MathResult<Self> {
+        if x.is_nan() {
+            return MathResult::error(MathError::NotANumber, Self::zero());
+        }
+        if x.is_infinite() {
+            return MathResult::error(MathError::Overflow, Self::zero());
+        }
+        Self::parse_rounded(&x.to_string(), scale, mode)
+    }
+
+    /// 解析 "-12.340" 形式的字符串，保留全部小数位数
+    ///
+    /// # 返回值
+    /// 定点小数；格式无效或小数位数超过 MAX_SCALE 时返回 MathError::InvalidParameter，
+    /// 超出范围时返回 MathError::Overflow
+    pub fn parse(s: &str) -> MathResult<Self> {
+        let Some((negative, integer, fraction)) = split_number(s) else {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        };
+        if fraction.len() > MAX_SCALE as usize {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        }
+
+        let magnitude = parse_digits(integer.bytes().chain(fraction.bytes()));
+        Self::from_parts(magnitude.and_then(|m| signed(m, negative)), fraction.len() as u32)
+    }
+
+    /// 解析字符串并舍入到指定的小数位数，字符串的小数位数可以超过 MAX_SCALE
+    ///
+    /// # 返回值
+    /// 定点小数；格式无效或 scale 超过 MAX_SCALE 时返回 MathError::InvalidParameter，
+    /// 超出范围时返回 MathError::Overflow
+    pub fn parse_rounded(s: &str, scale: u32, mode: RoundingMode) -> MathResult<Self> {
+        let Some((negative, integer, fraction)) = split_number(s) else {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        };
+        if scale > MAX_SCALE {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        }
+
+        if fraction.len() <= scale as usize {
+            let exact = Self::parse(s);
+            return if exact.is_error() { exact } else { exact.value.round(scale, mode) };
+        }
+
+        let (kept, dropped) = fraction.split_at(scale as usize);
+        let mut magnitude = parse_digits(integer.bytes().chain(kept.bytes()));
+
+        // 舍去的数字与 "5000..." 比较，得到舍去部分与半个单位的大小关系
+        if dropped.bytes().any(|c| c != b'0') {
+            let half = match dropped.as_bytes()[0].cmp(&b'5') {
+                Ordering::Equal if dropped.bytes().skip(1).all(|c| c == b'0') => Ordering::Equal,
+                Ordering::Equal => Ordering::Greater,
+                ordering => ordering,
+            };
+            let odd = magnitude.is_some_and(|m| m % 2 == 1);
+            if rounds_away(mode, negative, odd, half) {
+                magnitude = magnitude.and_then(|m| m.checked_add(1));
+            }
+        }
+
+        Self::from_parts(magnitude.and_then(|m| signed(m, negative)), scale)
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // 小数位数较少的一方扩大后超出 i128 范围时，它的绝对值一定更大，由它的符号决定大小
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => self.mantissa.cmp(&other.mantissa),
+            Ordering::Less => match self.mantissa_at(other.scale) {
+                Some(a) => a.cmp(&other.mantissa),
+                None => self.mantissa.cmp(&0),
+            },
+            Ordering::Greater => match other.mantissa_at(self.scale) {
+                Some(b) => self.mantissa.cmp(&b),
+                None => 0.cmp(&other.mantissa),
+            },
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Decimal {
+    /// 按数值比较，1.50 等于 1.5
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl fmt::Display for Decimal {
+    /// 保留全部小数位数，例如 "-0.050"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = self.scale as usize;
+        let digits = format!("{:0width$}", self.mantissa.unsigned_abs(), width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+
+        if scale == 0 {
+            write!(f, "{}{}", sign, integer)
+        } else {
+            write!(f, "{}{}.{}", sign, integer, fraction)
+        }
+    }
+}
@@ -0,0 +1,509 @@
+//! 组合枚举模块
+//! 
+//! `Enumeration` 按字典序逐个生成组合、排列和笛卡尔积，只保存当前的一个排列：
+//! 
+//! - `combinations`: 从 n 个元素中选出 k 个的组合，位置严格递增
+//! - `combinations_with_replacement`: 可重复组合，位置单调不减
+//! - `permutations`: 从 n 个元素中选出 k 个的排列，位置互不相同
+//! - `permutations_with_replacement`: 可重复排列，即 n 个元素的 k 元组
+//! - `product`: 多个集合的笛卡尔积，最后一个位置变化最快
+//! 
+//! 生成的是元素的位置（从 0 开始），调用者再用位置去取实际的元素。
+//! `rank` 和 `unrank` 在排列和它的字典序下标之间转换，可以直接跳到任意一个排列；
+//! 它们要求排列总数在 c_long 范围内，否则返回 MathError::Overflow
+
+use std::os::raw::c_long;
+use crate::types::{MathError, MathResult};
+use super::combinatorics::{binomial, permutations};
+
+/// 排列长度 k 允许的最大值；不重复的排列还要求 n 不超过这个值，因为需要保存全部 n 个位置
+pub const ENUMERATION_MAX_LENGTH: c_long = 1 << 20;
+
+/// 枚举的种类和参数
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    /// 不重复的组合
+    Combinations { n: c_long, k: c_long },
+    /// 可重复的组合
+    CombinationsWithReplacement { n: c_long, k: c_long },
+    /// 不重复的排列
+    Permutations { n: c_long, k: c_long },
+    /// 可重复的排列
+    PermutationsWithReplacement { n: c_long, k: c_long },
+    /// 笛卡尔积，sizes[i] 是第 i 个集合的元素个数
+    Product { sizes: Vec<c_long> },
+}
+
+/// 按字典序逐个生成的组合或排列
+/// 
+/// 从第 0 个排列开始，`current` 返回当前排列，`advance` 移动到下一个
+#[derive(Clone, Debug)]
+pub struct Enumeration {
+    /// 枚举的种类
+    kind: Kind,
+
+    /// 当前排列的下标
+    index: c_long,
+
+    /// 当前排列的位置，全部生成完后为 None；
+    /// 不重复的排列保存全部 n 个位置，前 k 个是当前排列，其余的按升序排列
+    positions: Option<Vec<c_long>>,
+}
+
+/// 检查 n 和 k，合法时返回 None
+fn check_size(n: c_long, k: c_long) -> Option<MathError> {
+    if n < 0 || k < 0 {
+        Some(MathError::NegativeNumber)
+    } else if k > ENUMERATION_MAX_LENGTH {
+        Some(MathError::InvalidParameter)
+    } else {
+        None
+    }
+}
+
+/// 移动到下一个严格递增的组合，c 中的值都小于 n
+fn next_combination(c: &mut [c_long], n: c_long) -> bool {
+    let k = c.len() as c_long;
+    for i in (0..c.len()).rev() {
+        // 第 i 个位置最大可以是 n - k + i
+        if c[i] < n - k + i as c_long {
+            c[i] += 1;
+            for j in i + 1..c.len() {
+                c[j] = c[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+/// 移动到下一个单调不减的组合，c 中的值都小于 n
+fn next_multiset(c: &mut [c_long], n: c_long) -> bool {
+    for i in (0..c.len()).rev() {
+        if c[i] < n - 1 {
+            c[i] += 1;
+            let value = c[i];
+            c[i + 1..].fill(value);
+            return true;
+        }
+    }
+    false
+}
+
+/// 移动到下一个全排列，已经是最后一个时返回 false
+fn next_permutation(a: &mut [c_long]) -> bool {
+    // 从右往左找到第一个比右边小的位置
+    let pivot = match (1..a.len()).rev().find(|&i| a[i - 1] < a[i]) {
+        Some(i) => i - 1,
+        None => return false,
+    };
+
+    // 与右边比它大的最小值交换，再把右边反转成升序
+    let mut j = a.len() - 1;
+    while a[j] <= a[pivot] {
+        j -= 1;
+    }
+    a.swap(pivot, j);
+    a[pivot + 1..].reverse();
+    true
+}
+
+/// 按混合进制加一，第 i 位的进制是 radix(i)，最后一位变化最快
+fn next_tuple(c: &mut [c_long], radix: impl Fn(usize) -> c_long) -> bool {
+    for i in (0..c.len()).rev() {
+        c[i] += 1;
+        if c[i] < radix(i) {
+            return true;
+        }
+        c[i] = 0;
+    }
+    false
+}
+
+/// 组合在字典序中的下标
+/// 
+/// 下标 = C(n, k) - 1 - Σ C(n - 1 - c[i], k - i)，其中的和是补集的组合数表示（combinadic）
+fn rank_combination(c: &[c_long], n: c_long, total: c_long) -> c_long {
+    let k = c.len() as c_long;
+    let tail: c_long = c
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| binomial(n - 1 - v, k - i as c_long).value)
+        .sum();
+    total - 1 - tail
+}
+
+/// 字典序中第 rank 个组合，rank_combination 的逆运算
+fn unrank_combination(rank: c_long, n: c_long, k: c_long, total: c_long) -> Vec<c_long> {
+    let mut rest = total - 1 - rank;
+    let mut upper = n;
+    let mut c = Vec::with_capacity(k as usize);
+
+    for i in 0..k {
+        let j = k - i;
+        // 二分查找小于 upper 且 C(b, j) ≤ rest 的最大 b；C(j - 1, j) = 0 总是满足条件
+        let (mut lo, mut hi) = (j - 1, upper - 1);
+        while lo < hi {
+            let mid = lo + (hi - lo + 1) / 2;
+            let value = binomial(mid, j);
+            if !value.is_error() && value.value <= rest {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+
+        rest -= binomial(lo, j).value;
+        c.push(n - 1 - lo);
+        upper = lo;
+    }
+
+    c
+}
+
+impl Enumeration {
+    /// 用种类和第 0 个排列创建枚举
+    fn new(kind: Kind) -> Self {
+        let mut enumeration = Self {
+            kind,
+            index: 0,
+            positions: None,
+        };
+        enumeration.rewind();
+        enumeration
+    }
+
+    /// 参数检查通过时创建枚举，否则返回错误和一个空的枚举
+    fn create(error: Option<MathError>, kind: Kind) -> MathResult<Self> {
+        match error {
+            Some(error) => MathResult::error(error, Self::new(Kind::Combinations { n: 0, k: 1 })),
+            None => MathResult::success(Self::new(kind)),
+        }
+    }
+
+    /// 从 n 个元素中选出 k 个的组合，k > n 时没有任何组合
+    /// 
+    /// # 返回值
+    /// 枚举；n 或 k 为负数时返回 MathError::NegativeNumber，
+    /// k 超过 ENUMERATION_MAX_LENGTH 时返回 MathError::InvalidParameter
+    pub fn combinations(n: c_long, k: c_long) -> MathResult<Self> {
+        Self::create(check_size(n, k), Kind::Combinations { n, k })
+    }
+
+    /// 从 n 个元素中可重复地选出 k 个的组合
+    /// 
+    /// # 返回值
+    /// 枚举；n 或 k 为负数时返回 MathError::NegativeNumber，
+    /// k 超过 ENUMERATION_MAX_LENGTH 时返回 MathError::InvalidParameter
+    pub fn combinations_with_replacement(n: c_long, k: c_long) -> MathResult<Self> {
+        Self::create(check_size(n, k), Kind::CombinationsWithReplacement { n, k })
+    }
+
+    /// 从 n 个元素中选出 k 个的排列，k > n 时没有任何排列
+    /// 
+    /// # 返回值
+    /// 枚举；n 或 k 为负数时返回 MathError::NegativeNumber，
+    /// n 或 k 超过 ENUMERATION_MAX_LENGTH 时返回 MathError::InvalidParameter
+    pub fn permutations(n: c_long, k: c_long) -> MathResult<Self> {
+        let error = check_size(n, k).or((n > ENUMERATION_MAX_LENGTH).then_some(MathError::InvalidParameter));
+        Self::create(error, Kind::Permutations { n, k })
+    }
+
+    /// n 个元素的 k 元组，每个位置都可以取任意元素
+    /// 
+    /// # 返回值
+    /// 枚举；n 或 k 为负数时返回 MathError::NegativeNumber，
+    /// k 超过 ENUMERATION_MAX_LENGTH 时返回 MathError::InvalidParameter
+    pub fn permutations_with_replacement(n: c_long, k: c_long) -> MathResult<Self> {
+        Self::create(check_size(n, k), Kind::PermutationsWithReplacement { n, k })
+    }
+
+    /// 多个集合的笛卡尔积
+    /// 
+    /// # 参数
+    /// * `sizes` - 每个集合的元素个数；为空时只有一个空元组
+    /// 
+    /// # 返回值
+    /// 枚举；含有负数时返回 MathError::NegativeNumber，
+    /// 集合个数超过 ENUMERATION_MAX_LENGTH 时返回 MathError::InvalidParameter
+    pub fn product(sizes: &[c_long]) -> MathResult<Self> {
+        let error = if sizes.iter().any(|&size| size < 0) {
+            Some(MathError::NegativeNumber)
+        } else if sizes.len() as c_long > ENUMERATION_MAX_LENGTH {
+            Some(MathError::InvalidParameter)
+        } else {
+            None
+        };
+
+        Self::create(error, Kind::Product { sizes: sizes.to_vec() })
+    }
+
+    /// 每个排列的长度
+    pub fn width(&self) -> c_long {
+        match &self.kind {
+            Kind::Combinations { k, .. }
+            | Kind::CombinationsWithReplacement { k, .. }
+            | Kind::Permutations { k, .. }
+            | Kind::PermutationsWithReplacement { k, .. } => *k,
+            Kind::Product { sizes } => sizes.len() as c_long,
+        }
+    }
+
+    /// 当前排列的下标，从 0 开始
+    pub fn index(&self) -> c_long {
+        self.index
+    }
+
+    /// 当前排列中元素的位置，全部生成完后返回 None
+    pub fn current(&self) -> Option<&[c_long]> {
+        let positions = self.positions.as_deref()?;
+        Some(&positions[..self.width() as usize])
+    }
+
+    /// 排列总数
+    /// 
+    /// # 返回值
+    /// 排列总数；超出 c_long 范围时返回 MathError::Overflow
+    pub fn count(&self) -> MathResult<c_long> {
+        match &self.kind {
+            Kind::Combinations { n, k } => binomial(*n, *k),
+            Kind::CombinationsWithReplacement { n, k } => {
+                // 可重复组合与 C(n + k - 1, k) 个不重复组合一一对应；没有元素时只有空组合
+                if *n == 0 {
+                    return MathResult::success(if *k == 0 { 1 } else { 0 });
+                }
+                match (n - 1).checked_add(*k) {
+                    Some(m) => binomial(m, *k),
+                    None => MathResult::error(MathError::Overflow, 0),
+                }
+            }
+            Kind::Permutations { n, k } => permutations(*n, *k),
+            Kind::PermutationsWithReplacement { n, k } => {
+                let mut total: c_long = 1;
+                for _ in 0..*k {
+                    total = match total.checked_mul(*n) {
+                        Some(total) if total != 0 => total,
+                        Some(_) => return MathResult::success(0),
+                        None => return MathResult::error(MathError::Overflow, 0),
+                    };
+                }
+                MathResult::success(total)
+            }
+            Kind::Product { sizes } => {
+                if sizes.contains(&0) {
+                    return MathResult::success(0);
+                }
+                let mut total: c_long = 1;
+                for &size in sizes {
+                    total = match total.checked_mul(size) {
+                        Some(total) => total,
+                        None => return MathResult::error(MathError::Overflow, 0),
+                    };
+                }
+                MathResult::success(total)
+            }
+        }
+    }
+
+    /// 回到第 0 个排列
+    pub fn rewind(&mut self) {
+        self.index = 0;
+        self.positions = match &self.kind {
+            Kind::Combinations { n, k } => (k <= n).then(|| (0..*k).collect()),
+            Kind::CombinationsWithReplacement { n, k } | Kind::PermutationsWithReplacement { n, k } => {
+                (*n > 0 || *k == 0).then(|| vec![0; *k as usize])
+            }
+            Kind::Permutations { n, k } => (k <= n).then(|| (0..*n).collect()),
+            Kind::Product { sizes } => (!sizes.contains(&0)).then(|| vec![0; sizes.len()]),
+        };
+    }
+
+    /// 移动到下一个排列，全部生成完后调用不做任何事
+    pub fn advance(&mut self) {
+        let positions = match self.positions.as_mut() {
+            Some(positions) => positions,
+            None => return,
+        };
+
+        let more = match &self.kind {
+            Kind::Combinations { n, .. } => next_combination(positions, *n),
+            Kind::CombinationsWithReplacement { n, .. } => next_multiset(positions, *n),
+            Kind::Permutations { k, .. } if *k > 0 => {
+                // 后面 n - k 个位置保持升序，反转成降序后求下一个全排列，前 k 个位置就是下一个 k 排列
+                positions[*k as usize..].reverse();
+                next_permutation(positions)
+            }
+            Kind::Permutations { .. } => false,
+            Kind::PermutationsWithReplacement { n, .. } => next_tuple(positions, |_| *n),
+            Kind::Product { sizes } => next_tuple(positions, |i| sizes[i]),
+        };
+
+        self.index += 1;
+        if !more {
+            self.positions = None;
+        }
+    }
+
+    /// 计算排列总数，超出范围时返回错误
+    fn checked_count(&self) -> Result<c_long, MathError> {
+        let total = self.count();
+        if total.is_error() {
+            Err(total.error)
+        } else {
+            Ok(total.value)
+        }
+    }
+
+    /// 排列在字典序中的下标
+    /// 
+    /// # 参数
+    /// * `positions` - 元素的位置，必须是这个枚举会生成的排列
+    /// 
+    /// # 返回值
+    /// 下标；排列总数超出 c_long 范围时返回 MathError::Overflow，
+    /// 否则 positions 的长度不对、位置超出范围或不满足组合的顺序要求时返回 MathError::InvalidParameter
+    pub fn rank(&self, positions: &[c_long]) -> MathResult<c_long> {
+        if positions.len() as c_long != self.width() {
+            return MathResult::error(MathError::InvalidParameter, 0);
+        }
+        // 先检查总数：总数在 c_long 范围内时不重复排列的长度不超过 20，下面的逐项计算都很快
+        let total = match self.checked_count() {
+            Ok(total) => total,
+            Err(error) => return MathResult::error(error, 0),
+        };
+        if !self.contains(positions) {
+            return MathResult::error(MathError::InvalidParameter, 0);
+        }
+
+        let rank = match &self.kind {
+            Kind::Combinations { n, .. } => rank_combination(positions, *n, total),
+            Kind::CombinationsWithReplacement { n, k } => {
+                // c[i] + i 是从 n + k - 1 个元素中选出的不重复组合
+                let shifted: Vec<c_long> = positions.iter().enumerate().map(|(i, &v)| v + i as c_long).collect();
+                rank_combination(&shifted, n + k - 1, total)
+            }
+            Kind::Permutations { n, k } => {
+                // 每个位置的权重是后面剩余位置的排列数 P(n - 1 - i, k - 1 - i)，k = n 时就是阶乘进制
+                let mut rank: c_long = 0;
+                for (i, &v) in positions.iter().enumerate() {
+                    let used_smaller = positions[..i].iter().filter(|&&u| u < v).count() as c_long;
+                    let weight = permutations(n - 1 - i as c_long, k - 1 - i as c_long).value;
+                    rank += (v - used_smaller) * weight;
+                }
+                rank
+            }
+            Kind::PermutationsWithReplacement { n, .. } => positions.iter().fold(0, |rank, &v| rank * n + v),
+            Kind::Product { sizes } => positions.iter().zip(sizes).fold(0, |rank, (&v, &size)| rank * size + v),
+        };
+
+        MathResult::success(rank)
+    }
+
+    /// 字典序中第 rank 个排列的位置，不移动当前排列
+    /// 
+    /// # 返回值
+    /// 元素的位置；rank 为负数时返回 MathError::NegativeNumber，不小于排列总数时返回 MathError::InvalidParameter，
+    /// 排列总数超出 c_long 范围时返回 MathError::Overflow
+    pub fn unrank(&self, rank: c_long) -> MathResult<Vec<c_long>> {
+        let width = self.width() as usize;
+        let result = self.unrank_state(rank);
+        MathResult {
+            value: result.value.into_iter().take(width).collect(),
+            error: result.error,
+        }
+    }
+
+    /// 移动到字典序中的第 rank 个排列
+    /// 
+    /// # 返回值
+    /// 错误与 `unrank` 相同，出错时当前排列不变
+    pub fn seek(&mut self, rank: c_long) -> MathResult<()> {
+        let result = self.unrank_state(rank);
+        if result.is_error() {
+            return MathResult::error(result.error, ());
+        }
+
+        self.index = rank;
+        self.positions = Some(result.value);
+        MathResult::success(())
+    }
+
+    /// 第 rank 个排列对应的完整状态，不重复的排列包括后面升序的 n - k 个位置
+    fn unrank_state(&self, rank: c_long) -> MathResult<Vec<c_long>> {
+        if rank < 0 {
+            return MathResult::error(MathError::NegativeNumber, Vec::new());
+        }
+        let total = match self.checked_count() {
+            Ok(total) => total,
+            Err(error) => return MathResult::error(error, Vec::new()),
+        };
+        if rank >= total {
+            return MathResult::error(MathError::InvalidParameter, Vec::new());
+        }
+
+        let state = match &self.kind {
+            Kind::Combinations { n, k } => unrank_combination(rank, *n, *k, total),
+            Kind::CombinationsWithReplacement { n, k } => unrank_combination(rank, n + k - 1, *k, total)
+                .into_iter()
+                .enumerate()
+                .map(|(i, v)| v - i as c_long)
+                .collect(),
+            Kind::Permutations { n, k } => {
+                let mut available: Vec<c_long> = (0..*n).collect();
+                let mut state = Vec::with_capacity(*n as usize);
+                let mut rest = rank;
+                for i in 0..*k {
+                    let weight = permutations(n - 1 - i, k - 1 - i).value;
+                    state.push(available.remove((rest / weight) as usize));
+                    rest %= weight;
+                }
+                state.extend(available);
+                state
+            }
+            Kind::PermutationsWithReplacement { n, k } => {
+                let mut state = vec![0; *k as usize];
+                let mut rest = rank;
+                for slot in state.iter_mut().rev() {
+                    *slot = rest % n;
+                    rest /= n;
+                }
+                state
+            }
+            Kind::Product { sizes } => {
+                let mut state = vec![0; sizes.len()];
+                let mut rest = rank;
+                for (slot, &size) in state.iter_mut().zip(sizes).rev() {
+                    *slot = rest % size;
+                    rest /= size;
+                }
+                state
+            }
+        };
+
+        MathResult::success(state)
+    }
+
+    /// positions 是否是这个枚举会生成的排列，调用者已经检查过长度
+    fn contains(&self, positions: &[c_long]) -> bool {
+        let in_range = |n: c_long| positions.iter().all(|&v| v >= 0 && v < n);
+        match &self.kind {
+            Kind::Combinations { n, .. } => in_range(*n) && positions.windows(2).all(|w| w[0] < w[1]),
+            Kind::CombinationsWithReplacement { n, .. } => in_range(*n) && positions.windows(2).all(|w| w[0] <= w[1]),
+            Kind::Permutations { n, .. } => {
+                if !in_range(*n) {
+                    return false;
+                }
+                // 用位图记录出现过的位置，检查没有重复
+                let mut seen = vec![0u64; (*n as usize).div_ceil(64)];
+                positions.iter().all(|&v| {
+                    let (word, bit) = (v as usize / 64, 1 << (v % 64));
+                    let fresh = seen[word] & bit == 0;
+                    seen[word] |= bit;
+                    fresh
+                })
+            }
+            Kind::PermutationsWithReplacement { n, .. } => in_range(*n),
+            Kind::Product { sizes } => positions.iter().zip(sizes).all(|(&v, &size)| v >= 0 && v < size),
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! 误差函数模块
+//! 
+//! 这个模块包含误差函数及正态分布的相关函数：
+//! 
+//! - erf、erfc 和缩放的补误差函数 erfcx(x) = exp(x²) · erfc(x)
+//! - 反函数 erfinv、erfcinv
+//! - 正态分布的概率密度、累积分布和分位数函数
+//! 
+//! erf 在 |x| 较小时使用全部为正项的级数 erf(x) = 2/√π · exp(-x²) Σ 2ⁿx²ⁿ⁺¹ / (2n+1)!!，
+//! 没有交错级数的抵消误差；erfc 在 x 较大时使用 Laplace 连分式直接计算，
+//! 不经过 1 - erf(x)，所以尾部也能保持完整的相对精度
+//! 
+//! 反函数先用 Giles 的近似公式得到初值，再用 Halley 迭代修正到机器精度
+
+use std::f64::consts::{FRAC_2_SQRT_PI, SQRT_2};
+use std::os::raw::c_double;
+use crate::types::{MathError, MathResult};
+use crate::utils::{check_float, float_error};
+
+/// 1 / √π
+const FRAC_1_SQRT_PI: c_double = FRAC_2_SQRT_PI / 2.0;
+
+/// 1 / √(2π)
+const FRAC_1_SQRT_2PI: c_double = FRAC_1_SQRT_PI / SQRT_2;
+
+/// 小于这个值时 erf 使用正项级数，否则使用 1 - erfc
+const ERF_SERIES_LIMIT: c_double = 2.5;
+
+/// 大于等于这个值时 erfc 使用连分式，否则使用 1 - erf
+/// 
+/// x < 1 时 erfc(x) > 0.157，1 - erf(x) 最多损失不到 3 位二进制精度
+const ERFC_FRACTION_LIMIT: c_double = 1.0;
+
+/// 正项级数 Σ 2ⁿx²ⁿ⁺¹ / (2n+1)!!，即 erf(x) · exp(x²) · √π / 2
+fn erf_series(x: c_double) -> c_double {
+    let x2 = 2.0 * x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut k = 1.0;
+
+    loop {
+        term *= x2 / (2.0 * k + 1.0);
+        sum += term;
+        if term.abs() <= 1e-17 * sum.abs() {
+            break;
+        }
+        k += 1.0;
+    }
+
+    sum
+}
+
+/// 用 Laplace 连分式计算 erfcx(x) = exp(x²) · erfc(x)，x 必须大于等于 ERFC_FRACTION_LIMIT
+/// 
+/// erfc(x) = exp(-x²)/√π · 1/(x + (1/2)/(x + 1/(x + (3/2)/(x + 2/(x + ...)))))
+/// 
+/// 从固定的深度反向求值，舍入误差比正向的 Lentz 算法小；
+/// 收敛所需的项数大约与 1/x² 成正比，x = 1 时约 200 项
+fn erfcx_fraction(x: c_double) -> c_double {
+    let depth = 20 + (200.0 / (x * x)) as usize;
+
+    let mut t = x;
+    for k in (1..=depth).rev() {
+        t = x + (k as c_double / 2.0) / t;
+    }
+
+    FRAC_1_SQRT_PI / t
+}
+
+/// 计算误差函数 erf(x)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// erf(x) 的值，范围在 [-1, 1] 内
+pub fn erf(x: c_double) -> MathResult<c_double> {
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if x.is_infinite() {
+        return MathResult::success(1.0_f64.copysign(x));
+    }
+
+    let absx = x.abs();
+    let value = if absx < ERF_SERIES_LIMIT {
+        FRAC_2_SQRT_PI * (-x * x).exp() * erf_series(x)
+    } else {
+        let tail = (-absx * absx).exp() * erfcx_fraction(absx);
+        (1.0 - tail).copysign(x)
+    };
+
+    check_float(value)
+}
+
+/// 计算补误差函数 erfc(x) = 1 - erf(x)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// erfc(x) 的值，范围在 [0, 2] 内；x 很大时仍然保持完整的相对精度
+pub fn erfc(x: c_double) -> MathResult<c_double> {
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if x.is_infinite() {
+        return MathResult::success(if x > 0.0 { 0.0 } else { 2.0 });
+    }
+
+    let value = if x < ERFC_FRACTION_LIMIT {
+        if x > -ERFC_FRACTION_LIMIT {
+            1.0 - FRAC_2_SQRT_PI * (-x * x).exp() * erf_series(x)
+        } else {
+            // erfc(x) = 2 - erfc(-x)
+            2.0 - (-x * x).exp() * erfcx_fraction(-x)
+        }
+    } else {
+        (-x * x).exp() * erfcx_fraction(x)
+    };
+
+    check_float(value)
+}
+
+/// 计算缩放的补误差函数 erfcx(x) = exp(x²) · erfc(x)
+/// 
+/// x 很大时 erfc(x) 会下溢为 0，erfcx(x) 仍然约等于 1 / (x√π)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// erfcx(x) 的值；x 为很大的负数时结果超出 double 范围，返回 MathError::Overflow
+pub fn erfcx(x: c_double) -> MathResult<c_double> {
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if x == c_double::INFINITY {
+        return MathResult::success(0.0);
+    }
+
+    let value = if x >= ERFC_FRACTION_LIMIT {
+        erfcx_fraction(x)
+    } else {
+        (x * x).exp() * erfc(x).value
+    };
+
+    if value.is_infinite() {
+        return float_error(MathError::Overflow, value);
+    }
+    check_float(value)
+}
+
+/// Giles 的反误差函数近似公式（单精度），作为 Halley 迭代的初值
+/// 
+/// # 参数
+/// * `y` - 反误差函数的参数
+/// * `w` - -ln((1 - y)(1 + y))，由调用者用不会损失精度的方式计算
+fn erfinv_guess(y: c_double, w: c_double) -> c_double {
+    let p = if w < 5.0 {
+        let w = w - 2.5;
+        [
+            2.810_226_36e-08, 3.432_739_39e-07, -3.523_387_7e-06, -4.391_506_54e-06, 0.000_218_580_87,
+            -0.001_253_725_03, -0.004_177_681_64, 0.246_640_727, 1.501_409_41,
+        ]
+        .iter()
+        .fold(0.0, |p, c| c + p * w)
+    } else {
+        let w = w.sqrt() - 3.0;
+        [
+            -0.000_200_214_257, 0.000_100_950_558, 0.001_349_343_22, -0.003_673_428_44, 0.005_739_507_73,
+            -0.007_622_461_3, 0.009_438_870_47, 1.001_674_06, 2.832_976_82,
+        ]
+        .iter()
+        .fold(0.0, |p, c| c + p * w)
+    };
+    p * y
+}
+
+/// Halley 迭代求解 f(x) = target，其中 f 为 erf 或 erfc
+/// 
+/// 两者的导数只差一个符号：erf'(x) = 2/√π · exp(-x²)，并且 f''(x) = -2x · f'(x)
+fn halley(mut x: c_double, target: c_double, f: fn(c_double) -> MathResult<c_double>, sign: c_double) -> c_double {
+    for _ in 0..10 {
+        let residual = f(x).value - target;
+        let derivative = sign * FRAC_2_SQRT_PI * (-x * x).exp();
+        if derivative == 0.0 {
+            break;
+        }
+
+        let step = residual / (derivative + x * residual);
+        x -= step;
+        if step.abs() <= 1e-16 * x.abs() {
+            break;
+        }
+    }
+    x
+}
+
+/// 计算 0 < z <= 1 时的 erfcinv(z)
+fn erfcinv_upper(z: c_double) -> c_double {
+    let guess = if z > 1e-10 {
+        erfinv_guess(1.0 - z, -(z * (2.0 - z)).ln())
+    } else {
+        // 尾部：erfc(x) ≈ exp(-x²) / (x√π)，用不动点迭代得到初值
+        let mut x = (-z.ln()).sqrt();
+        for _ in 0..3 {
+            x = (-(z * x / FRAC_1_SQRT_PI).ln()).sqrt();
+        }
+        x
+    };
+
+    halley(guess, z, erfc, -1.0)
+}
+
+/// 计算反误差函数 erfinv(y)，即满足 erf(x) = y 的 x
+/// 
+/// # 参数
+/// * `y` - 输入值，范围在 [-1, 1] 内
+/// 
+/// # 返回值
+/// erfinv(y) 的值；y 为 ±1 时返回 MathError::DomainError（IEEE 浮点模式下返回 ±inf），
+/// |y| > 1 时返回 MathError::DomainError（IEEE 浮点模式下返回 NaN）
+pub fn erfinv(y: c_double) -> MathResult<c_double> {
+    if y.is_nan() {
+        return check_float(y);
+    }
+
+    if y.abs() > 1.0 {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+
+    if y.abs() == 1.0 {
+        return float_error(MathError::DomainError, c_double::INFINITY.copysign(y));
+    }
+
+    if y == 0.0 {
+        return MathResult::success(y);
+    }
+
+    let value = if y.abs() <= 0.5 {
+        halley(erfinv_guess(y, -((1.0 - y) * (1.0 + y)).ln()), y, erf, 1.0)
+    } else {
+        // 1 - |y| 在这个范围内是精确的，转换为 erfcinv 以保持尾部的精度
+        erfcinv_upper(1.0 - y.abs()).copysign(y)
+    };
+
+    check_float(value)
+}
+
+/// 计算反补误差函数 erfcinv(z)，即满足 erfc(x) = z 的 x
+/// 
+/// # 参数
+/// * `z` - 输入值，范围在 [0, 2] 内
+/// 
+/// # 返回值
+/// erfcinv(z) 的值；z 为 0 或 2 时返回 MathError::DomainError（IEEE 浮点模式下返回 ±inf），
+/// z 超出 [0, 2] 时返回 MathError::DomainError（IEEE 浮点模式下返回 NaN）
+pub fn erfcinv(z: c_double) -> MathResult<c_double> {
+    if z.is_nan() {
+        return check_float(z);
+    }
+
+    if !(0.0..=2.0).contains(&z) {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+
+    if z == 0.0 || z == 2.0 {
+        return float_error(MathError::DomainError, c_double::INFINITY.copysign(1.0 - z));
+    }
+
+    let value = if z <= 1.0 {
+        erfcinv_upper(z)
+    } else {
+        // erfcinv(z) = -erfcinv(2 - z)
+        -erfcinv_upper(2.0 - z)
+    };
+
+    check_float(value)
+}
+
+/// 检查正态分布的标准差
+fn check_sigma(sigma: c_double) -> Option<MathError> {
+    if sigma > 0.0 && sigma.is_finite() {
+        None
+    } else {
+        Some(MathError::InvalidParameter)
+    }
+}
+
+/// 计算正态分布的概率密度函数
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// * `mu` - 均值
+/// * `sigma` - 标准差，必须大于 0
+/// 
+/// # 返回值
+/// 概率密度；sigma 不是正数时返回 MathError::InvalidParameter
+pub fn normal_pdf(x: c_double, mu: c_double, sigma: c_double) -> MathResult<c_double> {
+    if let Some(error) = check_sigma(sigma) {
+        return MathResult::error(error, c_double::NAN);
+    }
+
+    let z = (x - mu) / sigma;
+    check_float(FRAC_1_SQRT_2PI * (-0.5 * z * z).exp() / sigma)
+}
+
+/// 计算正态分布的累积分布函数 P(X <= x)
+/// 
+/// 通过 erfc 计算，左尾的小概率（例如 x = mu - 10·sigma）也能保持完整的相对精度
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// * `mu` - 均值
+/// * `sigma` - 标准差，必须大于 0
+/// 
+/// # 返回值
+/// 累积概率；sigma 不是正数时返回 MathError::InvalidParameter
+pub fn normal_cdf(x: c_double, mu: c_double, sigma: c_double) -> MathResult<c_double> {
+    if let Some(error) = check_sigma(sigma) {
+        return MathResult::error(error, c_double::NAN);
+    }
+
+    let z = (x - mu) / sigma;
+    check_float(0.5 * erfc(-z / SQRT_2).value)
+}
+
+/// 计算正态分布的分位数函数（累积分布函数的反函数）
+/// 
+/// # 参数
+/// * `p` - 概率，范围在 [0, 1] 内
+/// * `mu` - 均值
+/// * `sigma` - 标准差，必须大于 0
+/// 
+/// # 返回值
+/// 满足 P(X <= x) = p 的 x；sigma 不是正数时返回 MathError::InvalidParameter，
+/// p 为 0 或 1 时返回 MathError::DomainError（IEEE 浮点模式下返回 ∓inf），
+/// p 超出 [0, 1] 时返回 MathError::DomainError（IEEE 浮点模式下返回 NaN）
+pub fn normal_quantile(p: c_double, mu: c_double, sigma: c_double) -> MathResult<c_double> {
+    if let Some(error) = check_sigma(sigma) {
+        return MathResult::error(error, c_double::NAN);
+    }
+
+    // x = mu - sigma · √2 · erfcinv(2p)
+    let inverse = erfcinv(2.0 * p);
+    let value = mu - sigma * SQRT_2 * inverse.value;
+
+    if inverse.is_error() {
+        return MathResult::error(inverse.error, value);
+    }
+    check_float(value)
+}
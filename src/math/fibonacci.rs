@@ -0,0 +1,134 @@
+//! 斐波那契数和卢卡斯数模块
+//! 
+//! 这个模块用快速倍增法（fast doubling）在 O(log n) 次乘法内计算单个斐波那契数和卢卡斯数：
+//! 
+//! - `fibonacci_nth`、`lucas_nth`: 结果在 c_long 范围内的第 n 项，超出范围时返回 MathError::Overflow
+//! - `fibonacci_big`、`lucas_big`: 任意精度的第 n 项
+//! 
+//! 快速倍增法基于恒等式
+//! F(2k) = F(k)·(2F(k+1) − F(k))，F(2k+1) = F(k)² + F(k+1)²，
+//! 卢卡斯数由 L(n) = 2F(n+1) − F(n) 得到
+
+use std::os::raw::c_long;
+use crate::types::{MathError, MathResult};
+use super::bigint::BigUint;
+
+/// 结果在 c_long 范围内的最大斐波那契数下标，F(92) = 7540113804746346429
+pub const FIBONACCI_MAX_INDEX: c_long = 92;
+
+/// 结果在 c_long 范围内的最大卢卡斯数下标，L(90) = 6440026026380244498
+pub const LUCAS_MAX_INDEX: c_long = 90;
+
+/// 大整数斐波那契数和卢卡斯数允许的最大下标
+/// 
+/// F(100000) 有 20899 位十进制数字；大整数乘法是 O(n²) 的，下标再大计算时间会明显变长
+pub const FIBONACCI_BIG_LIMIT: c_long = 100_000;
+
+/// 用快速倍增法计算 (F(n), F(n+1))
+/// 
+/// n 不超过 FIBONACCI_MAX_INDEX 时所有中间结果都小于 2^128
+fn fibonacci_pair(n: u64) -> (u128, u128) {
+    let (mut a, mut b): (u128, u128) = (0, 1);
+
+    // 从最高位开始，每一步把下标加倍，遇到 1 时再加一
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        let c = a * (2 * b - a);
+        let d = a * a + b * b;
+        (a, b) = if (n >> bit) & 1 == 1 { (d, c + d) } else { (c, d) };
+    }
+
+    (a, b)
+}
+
+/// 用快速倍增法计算大整数 (F(n), F(n+1))
+fn fibonacci_pair_big(n: u64) -> (BigUint, BigUint) {
+    let (mut a, mut b) = (BigUint::zero(), BigUint::one());
+
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        let c = &a * &(&(&b + &b) - &a);
+        let d = &(&a * &a) + &(&b * &b);
+        (a, b) = if (n >> bit) & 1 == 1 {
+            let sum = &c + &d;
+            (d, sum)
+        } else {
+            (c, d)
+        };
+    }
+
+    (a, b)
+}
+
+/// 检查下标，合法时返回 None
+fn check_index(n: c_long, limit: c_long, error: MathError) -> Option<MathError> {
+    if n < 0 {
+        Some(MathError::NegativeNumber)
+    } else if n > limit {
+        Some(error)
+    } else {
+        None
+    }
+}
+
+/// 计算第 n 个斐波那契数 F(n)，F(0) = 0，F(1) = 1
+/// 
+/// # 参数
+/// * `n` - 下标
+/// 
+/// # 返回值
+/// F(n)；n 为负数时返回 MathError::NegativeNumber，
+/// n 超过 FIBONACCI_MAX_INDEX 时返回 MathError::Overflow（与溢出模式无关）
+pub fn fibonacci_nth(n: c_long) -> MathResult<c_long> {
+    if let Some(error) = check_index(n, FIBONACCI_MAX_INDEX, MathError::Overflow) {
+        return MathResult::error(error, 0);
+    }
+
+    MathResult::success(fibonacci_pair(n as u64).0 as c_long)
+}
+
+/// 计算第 n 个卢卡斯数 L(n)，L(0) = 2，L(1) = 1
+/// 
+/// # 参数
+/// * `n` - 下标
+/// 
+/// # 返回值
+/// L(n)；n 为负数时返回 MathError::NegativeNumber，
+/// n 超过 LUCAS_MAX_INDEX 时返回 MathError::Overflow（与溢出模式无关）
+pub fn lucas_nth(n: c_long) -> MathResult<c_long> {
+    if let Some(error) = check_index(n, LUCAS_MAX_INDEX, MathError::Overflow) {
+        return MathResult::error(error, 0);
+    }
+
+    let (a, b) = fibonacci_pair(n as u64);
+    MathResult::success((2 * b - a) as c_long)
+}
+
+/// 计算任意精度的第 n 个斐波那契数
+/// 
+/// # 参数
+/// * `n` - 下标，不能超过 FIBONACCI_BIG_LIMIT
+/// 
+/// # 返回值
+/// F(n)；n 为负数时返回 MathError::NegativeNumber，超过 FIBONACCI_BIG_LIMIT 时返回 MathError::InvalidParameter
+pub fn fibonacci_big(n: c_long) -> MathResult<BigUint> {
+    if let Some(error) = check_index(n, FIBONACCI_BIG_LIMIT, MathError::InvalidParameter) {
+        return MathResult::error(error, BigUint::zero());
+    }
+
+    MathResult::success(fibonacci_pair_big(n as u64).0)
+}
+
+/// 计算任意精度的第 n 个卢卡斯数
+/// 
+/// # 参数
+/// * `n` - 下标，不能超过 FIBONACCI_BIG_LIMIT
+/// 
+/// # 返回值
+/// L(n)；n 为负数时返回 MathError::NegativeNumber，超过 FIBONACCI_BIG_LIMIT 时返回 MathError::InvalidParameter
+pub fn lucas_big(n: c_long) -> MathResult<BigUint> {
+    if let Some(error) = check_index(n, FIBONACCI_BIG_LIMIT, MathError::InvalidParameter) {
+        return MathResult::error(error, BigUint::zero());
+    }
+
+    let (a, b) = fibonacci_pair_big(n as u64);
+    MathResult::success(&(&b + &b) - &a)
+}
@@ -0,0 +1,323 @@
+//! 伽马函数模块
+//! 
+//! 这个模块包含伽马函数及相关的特殊函数：gamma、lgamma、digamma 和 beta
+//! 
+//! gamma 使用 Lanczos 近似（g ≈ 6.0247，13 项有理函数形式），
+//! 负数参数通过反射公式 Γ(x)Γ(1-x) = π / sin(πx) 计算，
+//! 正数范围内的相对误差在 1e-15 量级
+
+// Lanczos 系数保留了原始文献中的全部有效数字，方便与文献核对
+#![allow(clippy::excessive_precision)]
+
+use std::f64::consts::PI;
+use std::os::raw::c_double;
+use crate::types::{MathError, MathResult};
+use crate::utils::{check_float, float_error};
+
+/// Lanczos 近似的参数 g
+const LANCZOS_G: c_double = 6.024_680_040_776_729_583_740_234_375;
+
+/// g - 0.5
+const LANCZOS_G_MINUS_HALF: c_double = 5.524_680_040_776_729_583_740_234_375;
+
+/// Lanczos 有理函数的分子系数（按升幂排列）
+const LANCZOS_NUM_COEFFS: [c_double; 13] = [
+    23531376880.410759688572007674451636754734846804940,
+    42919803642.649098768957899047001988850926355848959,
+    35711959237.355668049440185451547166705960488635843,
+    17921034426.037209699919755754458931112671403265390,
+    6039542586.3520280050642916443072979210699388420708,
+    1439720407.3117216736632230727949123939715485786772,
+    248874557.86205415651146038641322942321632125127801,
+    31426415.585400194380614231628318205362874684987640,
+    2876370.6289353724412254090516208496135991145378768,
+    186056.26539522349504029498971604569928220784236328,
+    8071.6720023658162106380029022722506138218516325024,
+    210.82427775157934587250973392071336271166969580291,
+    2.5066282746310002701649081771338373386264310793408,
+];
+
+/// Lanczos 有理函数的分母系数（按升幂排列），即 x(x+1)...(x+11) 展开后的系数
+const LANCZOS_DEN_COEFFS: [c_double; 13] = [
+    0.0, 39916800.0, 120543840.0, 150917976.0, 105258076.0, 45995730.0,
+    13339535.0, 2637558.0, 357423.0, 32670.0, 1925.0, 66.0, 1.0,
+];
+
+/// 可以直接用连乘精确计算的最大整数参数
+/// 
+/// 23! 以内的阶乘在 double 中可以精确表示，且连乘的每一步都没有舍入
+const EXACT_INTEGER_LIMIT: c_double = 23.0;
+
+/// 超过这个值时 Γ(x) 超出 double 的范围
+const GAMMA_OVERFLOW_LIMIT: c_double = 171.624_376_956_302_7;
+
+/// 计算 Lanczos 有理函数的值
+/// 
+/// x 较小时按升幂用 Horner 法则求值，x 较大时按 1/x 的升幂求值，避免中间结果溢出
+fn lanczos_sum(x: c_double) -> c_double {
+    let (mut num, mut den) = (0.0, 0.0);
+
+    if x < 5.0 {
+        for i in (0..LANCZOS_NUM_COEFFS.len()).rev() {
+            num = num * x + LANCZOS_NUM_COEFFS[i];
+            den = den * x + LANCZOS_DEN_COEFFS[i];
+        }
+    } else {
+        for i in 0..LANCZOS_NUM_COEFFS.len() {
+            num = num / x + LANCZOS_NUM_COEFFS[i];
+            den = den / x + LANCZOS_DEN_COEFFS[i];
+        }
+    }
+
+    num / den
+}
+
+/// 计算 sin(πx)
+/// 
+/// 先把 x 约简到 [0, 2) 再计算，比直接计算 (PI * x).sin() 更精确，
+/// 并且在整数处得到精确的 0
+pub(crate) fn sin_pi(x: c_double) -> c_double {
+    let y = x.abs() % 2.0;
+    let n = (2.0 * y).round() as i32;
+
+    let r = match n {
+        0 => (PI * y).sin(),
+        1 => (PI * (y - 0.5)).cos(),
+        2 => (PI * (1.0 - y)).sin(),
+        3 => -(PI * (y - 1.5)).cos(),
+        _ => (PI * (y - 2.0)).sin(),
+    };
+
+    if x < 0.0 { -r } else { r }
+}
+
+/// 检查 x 是否为伽马函数的极点（0 或负整数）
+fn is_pole(x: c_double) -> bool {
+    x <= 0.0 && x == x.floor()
+}
+
+/// 计算伽马函数 Γ(x)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// Γ(x) 的值；x 为 0 或负整数时返回 MathError::DomainError，
+/// 结果超出 double 范围时返回 MathError::Overflow（IEEE 浮点模式下分别返回 NaN 和 inf）
+pub fn gamma(x: c_double) -> MathResult<c_double> {
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if is_pole(x) {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+
+    if x >= GAMMA_OVERFLOW_LIMIT {
+        return float_error(MathError::Overflow, c_double::INFINITY);
+    }
+
+    // 小的正整数直接计算阶乘，结果是精确的
+    if x == x.floor() && x <= EXACT_INTEGER_LIMIT {
+        let mut product = 1.0;
+        let mut k = 2.0;
+        while k < x {
+            product *= k;
+            k += 1.0;
+        }
+        return MathResult::success(product);
+    }
+
+    let absx = x.abs();
+
+    // 极小的参数：Γ(x) ≈ 1/x
+    if absx < 1e-20 {
+        return MathResult::success(1.0 / x);
+    }
+
+    // 负数的绝对值很大时结果下溢为 0，符号与 sin(πx) 相同
+    if x < -200.0 {
+        return MathResult::success(0.0 / sin_pi(x));
+    }
+
+    // z 是计算 y = absx + g - 0.5 时的舍入误差修正量
+    let y = absx + LANCZOS_G_MINUS_HALF;
+    let z = if absx > LANCZOS_G_MINUS_HALF {
+        (y - absx) - LANCZOS_G_MINUS_HALF
+    } else {
+        (y - LANCZOS_G_MINUS_HALF) - absx
+    };
+    let z = z * LANCZOS_G / y;
+
+    let mut r;
+    if x < 0.0 {
+        // 反射公式：Γ(x) = -π / (sin(π|x|) · |x| · Γ(|x|))
+        r = -PI / sin_pi(absx) / absx * y.exp() / lanczos_sum(absx);
+        r -= z * r;
+        if absx < 140.0 {
+            r /= y.powf(absx - 0.5);
+        } else {
+            let half_pow = y.powf(absx / 2.0 - 0.25);
+            r /= half_pow;
+            r /= half_pow;
+        }
+    } else {
+        r = lanczos_sum(absx) / y.exp();
+        r += z * r;
+        if absx < 140.0 {
+            r *= y.powf(absx - 0.5);
+        } else {
+            // 分两次乘以 y^((x-0.5)/2)，避免中间结果溢出
+            let half_pow = y.powf(absx / 2.0 - 0.25);
+            r *= half_pow;
+            r *= half_pow;
+        }
+    }
+
+    check_float(r)
+}
+
+/// 计算正数参数的 ln Γ(x)
+fn lgamma_positive(x: c_double) -> c_double {
+    // 1 和 2 处的结果精确为 0
+    if x == 1.0 || x == 2.0 {
+        return 0.0;
+    }
+
+    // Γ(x) 不会溢出时直接取对数，精度最好
+    if x < 30.0 {
+        return gamma(x).value.ln();
+    }
+
+    // ln Γ(x) = ln(lanczos_sum(x)) - g + (x - 0.5)(ln(x + g - 0.5) - 1)
+    lanczos_sum(x).ln() - LANCZOS_G + (x - 0.5) * ((x + LANCZOS_G_MINUS_HALF).ln() - 1.0)
+}
+
+/// 计算 ln|Γ(x)| 和 Γ(x) 的符号
+/// 
+/// 调用者需要先排除 NaN、无穷大和极点
+fn lgamma_with_sign(x: c_double) -> (c_double, c_double) {
+    if x > 0.0 {
+        return (lgamma_positive(x), 1.0);
+    }
+
+    // 反射公式：|Γ(x)| = π / (|sin(πx)| · |x| · Γ(|x|))，Γ(x) 的符号与 sin(πx) 相同
+    let absx = x.abs();
+    let sin = sin_pi(x);
+    ((PI / (sin * absx).abs()).ln() - lgamma_positive(absx), sin.signum())
+}
+
+/// 计算伽马函数绝对值的自然对数 ln|Γ(x)|
+/// 
+/// 在 Γ(x) 超出 double 范围时仍然可以使用，例如 lgamma(1000.0)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// ln|Γ(x)| 的值；x 为 0 或负整数时返回 MathError::DomainError（IEEE 浮点模式下返回 inf）
+pub fn lgamma(x: c_double) -> MathResult<c_double> {
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if x.is_infinite() {
+        return MathResult::success(c_double::INFINITY);
+    }
+
+    if is_pole(x) {
+        return float_error(MathError::DomainError, c_double::INFINITY);
+    }
+
+    check_float(lgamma_with_sign(x).0)
+}
+
+/// 计算双伽马函数 ψ(x) = Γ'(x) / Γ(x)
+/// 
+/// 使用递推公式 ψ(x) = ψ(x + 1) - 1/x 把参数增大到 10 以上，再使用渐近展开；
+/// 负数参数使用反射公式 ψ(1 - x) - ψ(x) = π / tan(πx)
+/// 
+/// # 参数
+/// * `x` - 输入值
+/// 
+/// # 返回值
+/// ψ(x) 的值；x 为 0 或负整数时返回 MathError::DomainError（IEEE 浮点模式下返回 NaN）
+pub fn digamma(x: c_double) -> MathResult<c_double> {
+    if x.is_nan() {
+        return check_float(x);
+    }
+
+    if is_pole(x) {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+
+    if x == c_double::INFINITY {
+        return MathResult::success(c_double::INFINITY);
+    }
+
+    if x < 0.0 {
+        // π / tan(πx) = π · cos(πx) / sin(πx)，其中 cos(πx) = sin(π(x + 0.5))
+        let cot = sin_pi(x + 0.5) / sin_pi(x);
+        return check_float(digamma_positive(1.0 - x) - PI * cot);
+    }
+
+    check_float(digamma_positive(x))
+}
+
+/// 计算正数参数的双伽马函数
+fn digamma_positive(mut x: c_double) -> c_double {
+    let mut result = 0.0;
+
+    while x < 10.0 {
+        result -= 1.0 / x;
+        x += 1.0;
+    }
+
+    // 渐近展开：ψ(x) ≈ ln x - 1/(2x) - Σ B_2k / (2k · x^2k)
+    let inv2 = 1.0 / (x * x);
+    let series = inv2 * (1.0 / 12.0
+        - inv2 * (1.0 / 120.0
+        - inv2 * (1.0 / 252.0
+        - inv2 * (1.0 / 240.0
+        - inv2 * (1.0 / 132.0
+        - inv2 * (691.0 / 32760.0
+        - inv2 / 12.0))))));
+
+    result + x.ln() - 0.5 / x - series
+}
+
+/// 计算贝塔函数 B(a, b) = Γ(a)Γ(b) / Γ(a + b)
+/// 
+/// 结果在 double 范围内时直接用伽马函数计算，否则通过 lgamma 计算以避免中间结果溢出
+/// 
+/// # 参数
+/// * `a` - 第一个参数
+/// * `b` - 第二个参数
+/// 
+/// # 返回值
+/// B(a, b) 的值；a 或 b 为 0 或负整数时返回 MathError::DomainError（IEEE 浮点模式下返回 NaN）
+pub fn beta(a: c_double, b: c_double) -> MathResult<c_double> {
+    if a.is_nan() || b.is_nan() {
+        return check_float(c_double::NAN);
+    }
+
+    if is_pole(a) || is_pole(b) {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+
+    let sum = a + b;
+
+    // Γ(a + b) 在极点处为无穷大，B(a, b) 为 0
+    if is_pole(sum) {
+        return MathResult::success(0.0);
+    }
+
+    if a.abs() < EXACT_INTEGER_LIMIT && b.abs() < EXACT_INTEGER_LIMIT && sum.abs() < EXACT_INTEGER_LIMIT {
+        return check_float(gamma(a).value * gamma(b).value / gamma(sum).value);
+    }
+
+    let (la, sa) = lgamma_with_sign(a);
+    let (lb, sb) = lgamma_with_sign(b);
+    let (ls, ss) = lgamma_with_sign(sum);
+    check_float(sa * sb * ss * (la + lb - ls).exp())
+}
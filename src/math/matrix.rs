@@ -0,0 +1,484 @@
+//! 稠密矩阵模块
+//!
+//! `Matrix` 是按行优先顺序存放 c_double 元素的 rows × cols 矩阵，行数和列数都大于 0
+//! 提供乘法、转置、行列式和逆矩阵，LU（部分选主元）、QR（Householder 变换）和 Cholesky 分解，
+//! 以及线性方程组 A·X = B 的求解：方阵使用 LU 分解，行数多于列数时用 QR 分解求最小二乘解
+//!
+//! 维数不匹配时返回 MathError::InvalidParameter；矩阵奇异（主元的绝对值不超过
+//! max(rows, cols)·ε·max|aᵢⱼ|）时返回 MathError::DivisionByZero，与浮点模式无关——
+//! 奇异矩阵的逆没有有意义的 inf 或 NaN 结果；Cholesky 分解的矩阵不是对称正定矩阵时返回
+//! MathError::DomainError。与实数浮点函数一样，结果中出现 NaN 时严格模式返回 MathError::NotANumber
+
+use std::ops::{Index, IndexMut};
+use std::os::raw::c_double;
+use crate::types::{FloatMode, MathError, MathResult};
+use crate::utils::{check_float, float_mode};
+
+/// 按行优先顺序存放的稠密矩阵
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Matrix {
+    /// 行数
+    rows: usize,
+
+    /// 列数
+    cols: usize,
+
+    /// rows × cols 个元素，第 i 行第 j 列在 data[i * cols + j]
+    data: Vec<c_double>,
+}
+
+/// 检查矩阵运算的结果
+///
+/// # 返回值
+/// 严格模式下有元素为 NaN 时返回 MathError::NotANumber，否则原样返回
+fn check_matrix(matrix: Matrix) -> MathResult<Matrix> {
+    if matrix.data.iter().any(|x| x.is_nan()) && float_mode() == FloatMode::Strict {
+        return MathResult::error(MathError::NotANumber, matrix);
+    }
+    MathResult::success(matrix)
+}
+
+/// 两个切片的内积
+fn dot(a: &[c_double], b: &[c_double]) -> c_double {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+impl Matrix {
+    /// 由按行优先顺序排列的元素创建矩阵
+    ///
+    /// # 返回值
+    /// 矩阵；行数或列数为 0，或元素个数不等于 rows × cols 时返回 MathError::InvalidParameter
+    pub fn new(rows: usize, cols: usize, data: Vec<c_double>) -> MathResult<Self> {
+        if rows == 0 || cols == 0 || rows.checked_mul(cols) != Some(data.len()) {
+            return MathResult::error(MathError::InvalidParameter, Self::default());
+        }
+        MathResult::success(Self { rows, cols, data })
+    }
+
+    /// 由各行创建矩阵
+    ///
+    /// # 返回值
+    /// 矩阵；没有行、行为空或各行长度不同时返回 MathError::InvalidParameter
+    pub fn from_rows(rows: &[&[c_double]]) -> MathResult<Self> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            return MathResult::error(MathError::InvalidParameter, Self::default());
+        }
+        Self::new(rows.len(), cols, rows.concat())
+    }
+
+    /// 全 0 矩阵
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self { rows, cols, data: vec![0.0; rows * cols] }
+    }
+
+    /// n 阶单位矩阵
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::zeros(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = 1.0;
+        }
+        matrix
+    }
+
+    /// 行数
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 列数
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// 是否为方阵
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// 按行优先顺序排列的全部元素
+    pub fn data(&self) -> &[c_double] {
+        &self.data
+    }
+
+    /// 第 i 行
+    pub fn row(&self, i: usize) -> &[c_double] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// 转置矩阵
+    pub fn transpose(&self) -> Self {
+        let mut result = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(j, i)] = self[(i, j)];
+            }
+        }
+        result
+    }
+
+    /// 矩阵乘法
+    ///
+    /// # 返回值
+    /// self · other；self 的列数不等于 other 的行数时返回 MathError::InvalidParameter
+    pub fn mul(&self, other: &Self) -> MathResult<Self> {
+        if self.cols != other.rows {
+            return MathResult::error(MathError::InvalidParameter, Self::default());
+        }
+
+        // 按 i-k-j 顺序累加，内层循环连续访问 other 和 result 的同一行
+        let mut result = Self::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                for j in 0..other.cols {
+                    result[(i, j)] += a * other[(k, j)];
+                }
+            }
+        }
+        check_matrix(result)
+    }
+
+    /// 判断奇异和秩亏时使用的容差：max(rows, cols)·ε·max|aᵢⱼ|
+    fn tolerance(&self) -> c_double {
+        let max = self.data.iter().fold(0.0, |max: c_double, x| max.max(x.abs()));
+        self.rows.max(self.cols) as c_double * c_double::EPSILON * max
+    }
+
+    /// 交换两行
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for j in 0..self.cols {
+            self.data.swap(a * self.cols + j, b * self.cols + j);
+        }
+    }
+
+    /// LU 分解，使用部分选主元：P·A = L·U
+    ///
+    /// # 返回值
+    /// 分解结果，奇异矩阵也可以分解；不是方阵时返回 MathError::InvalidParameter
+    pub fn lu(&self) -> MathResult<Lu> {
+        if !self.is_square() {
+            return MathResult::error(MathError::InvalidParameter, Lu::default());
+        }
+
+        let n = self.rows;
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+
+        for k in 0..n {
+            // 选择第 k 列中绝对值最大的元素作为主元，减小舍入误差
+            let mut pivot = k;
+            for i in k + 1..n {
+                if lu[(i, k)].abs() > lu[(pivot, k)].abs() {
+                    pivot = i;
+                }
+            }
+            if pivot != k {
+                lu.swap_rows(pivot, k);
+                permutation.swap(pivot, k);
+                sign = -sign;
+            }
+
+            let diagonal = lu[(k, k)];
+            if diagonal == 0.0 {
+                // 这一列已经全为 0，不需要消元
+                continue;
+            }
+            for i in k + 1..n {
+                let factor = lu[(i, k)] / diagonal;
+                lu[(i, k)] = factor;
+                for j in k + 1..n {
+                    lu[(i, j)] -= factor * lu[(k, j)];
+                }
+            }
+        }
+
+        MathResult::success(Lu { lu, permutation, sign, tolerance: self.tolerance() })
+    }
+
+    /// 行列式
+    ///
+    /// # 返回值
+    /// det(A)，由 LU 分解的对角线元素相乘得到；不是方阵时返回 MathError::InvalidParameter
+    pub fn determinant(&self) -> MathResult<c_double> {
+        let lu = self.lu();
+        if lu.is_error() {
+            return MathResult::error(lu.error, 0.0);
+        }
+        check_float(lu.value.determinant())
+    }
+
+    /// 逆矩阵
+    ///
+    /// # 返回值
+    /// A⁻¹；不是方阵时返回 MathError::InvalidParameter，奇异矩阵返回 MathError::DivisionByZero
+    pub fn inverse(&self) -> MathResult<Self> {
+        let lu = self.lu();
+        if lu.is_error() {
+            return MathResult::error(lu.error, Self::default());
+        }
+        lu.value.solve(&Self::identity(self.rows))
+    }
+
+    /// QR 分解，使用 Householder 变换：A = Q·R
+    ///
+    /// # 返回值
+    /// rows × rows 的正交矩阵 Q 和 rows × cols 的上三角矩阵 R，R 的对角线元素非负
+    pub fn qr(&self) -> Qr {
+        let (m, n) = (self.rows, self.cols);
+        let mut q = Self::identity(m);
+        let mut r = self.clone();
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            // v = x - α·e₁，α 与 x₀ 反号，避免 x₀ - α 相消
+            let mut v: Vec<c_double> = (k..m).map(|i| r[(i, k)]).collect();
+            let norm = v.iter().fold(0.0, |norm: c_double, x| norm.hypot(*x));
+            if norm == 0.0 {
+                continue;
+            }
+            let alpha = if v[0] >= 0.0 { -norm } else { norm };
+            v[0] -= alpha;
+            let scale = 2.0 / dot(&v, &v);
+
+            // R ← H·R，H = I - 2·v·vᵀ / (vᵀ·v)，只有第 k 行以下受影响
+            for j in k..n {
+                let s = scale * (k..m).map(|i| r[(i, j)] * v[i - k]).sum::<c_double>();
+                for i in k..m {
+                    r[(i, j)] -= s * v[i - k];
+                }
+            }
+            // Q ← Q·H
+            for i in 0..m {
+                let s = scale * dot(&q.row(i)[k..], &v);
+                for l in k..m {
+                    q[(i, l)] -= s * v[l - k];
+                }
+            }
+            // 对角线以下在数学上是 0，直接置 0 消除舍入误差
+            for i in k + 1..m {
+                r[(i, k)] = 0.0;
+            }
+        }
+
+        // 同时改变 R 的第 k 行和 Q 的第 k 列的符号，使 R 的对角线元素非负，分解唯一
+        for k in 0..m.min(n) {
+            if r[(k, k)] < 0.0 {
+                for j in 0..n {
+                    r[(k, j)] = -r[(k, j)];
+                }
+                for i in 0..m {
+                    q[(i, k)] = -q[(i, k)];
+                }
+            }
+        }
+
+        Qr { q, r, tolerance: self.tolerance() }
+    }
+
+    /// Cholesky 分解：A = L·Lᵀ
+    ///
+    /// # 返回值
+    /// 对角线元素为正的下三角矩阵 L；不是方阵时返回 MathError::InvalidParameter，
+    /// 不是对称正定矩阵时返回 MathError::DomainError
+    pub fn cholesky(&self) -> MathResult<Self> {
+        if !self.is_square() {
+            return MathResult::error(MathError::InvalidParameter, Self::default());
+        }
+
+        let n = self.rows;
+        let tolerance = self.tolerance();
+        for i in 0..n {
+            for j in 0..i {
+                if (self[(i, j)] - self[(j, i)]).abs() > tolerance {
+                    return MathResult::error(MathError::DomainError, Self::default());
+                }
+            }
+        }
+
+        // 只使用下三角部分
+        let mut l = Self::zeros(n, n);
+        for j in 0..n {
+            let d = self[(j, j)] - dot(&l.row(j)[..j], &l.row(j)[..j]);
+            // NaN 也按非正定处理
+            if d <= 0.0 || d.is_nan() {
+                return MathResult::error(MathError::DomainError, Self::default());
+            }
+            let diagonal = d.sqrt();
+            l[(j, j)] = diagonal;
+            for i in j + 1..n {
+                l[(i, j)] = (self[(i, j)] - dot(&l.row(i)[..j], &l.row(j)[..j])) / diagonal;
+            }
+        }
+        MathResult::success(l)
+    }
+
+    /// 解线性方程组 A·X = B，B 的每一列是一个右端向量
+    ///
+    /// # 返回值
+    /// 方阵使用 LU 分解求精确解；行数多于列数时用 QR 分解求使 ‖A·X - B‖ 最小的最小二乘解
+    /// B 的行数与 A 不同或 A 的行数少于列数时返回 MathError::InvalidParameter，
+    /// A 奇异（或最小二乘时列不满秩）时返回 MathError::DivisionByZero
+    pub fn solve(&self, b: &Self) -> MathResult<Self> {
+        if b.rows != self.rows || self.rows < self.cols {
+            return MathResult::error(MathError::InvalidParameter, Self::default());
+        }
+
+        if self.is_square() {
+            self.lu().value.solve(b)
+        } else {
+            self.qr().solve(b)
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = c_double;
+
+    fn index(&self, (i, j): (usize, usize)) -> &c_double {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut c_double {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+/// LU 分解的结果：P·A = L·U
+#[derive(Clone, Debug, Default)]
+pub struct Lu {
+    /// 对角线以下是 L（对角线上的 1 不保存），对角线及以上是 U
+    lu: Matrix,
+
+    /// P·A 的第 i 行是 A 的第 permutation[i] 行
+    permutation: Vec<usize>,
+
+    /// 置换的符号，行交换次数为偶数时为 1，否则为 -1
+    sign: c_double,
+
+    /// 主元的绝对值不超过这个值时认为矩阵奇异
+    tolerance: c_double,
+}
+
+impl Lu {
+    /// 对角线元素为 1 的下三角矩阵 L
+    pub fn lower(&self) -> Matrix {
+        let n = self.lu.rows;
+        let mut l = Matrix::identity(n);
+        for i in 0..n {
+            for j in 0..i {
+                l[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        l
+    }
+
+    /// 上三角矩阵 U
+    pub fn upper(&self) -> Matrix {
+        let n = self.lu.rows;
+        let mut u = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in i..n {
+                u[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        u
+    }
+
+    /// 行置换：P·A 的第 i 行是 A 的第 permutation()[i] 行
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// 行列式，等于置换的符号乘以 U 的对角线元素
+    pub fn determinant(&self) -> c_double {
+        (0..self.lu.rows).fold(self.sign, |det, i| det * self.lu[(i, i)])
+    }
+
+    /// 矩阵是否奇异
+    pub fn is_singular(&self) -> bool {
+        (0..self.lu.rows).any(|i| self.lu[(i, i)].abs() <= self.tolerance)
+    }
+
+    /// 解 A·X = B
+    ///
+    /// # 返回值
+    /// X；B 的行数不对时返回 MathError::InvalidParameter，A 奇异时返回 MathError::DivisionByZero
+    pub fn solve(&self, b: &Matrix) -> MathResult<Matrix> {
+        let n = self.lu.rows;
+        if b.rows != n {
+            return MathResult::error(MathError::InvalidParameter, Matrix::default());
+        }
+        if self.is_singular() {
+            return MathResult::error(MathError::DivisionByZero, Matrix::default());
+        }
+
+        let mut x = Matrix::zeros(n, b.cols);
+        for c in 0..b.cols {
+            // L·y = P·b，前代
+            for i in 0..n {
+                let s = (0..i).map(|k| self.lu[(i, k)] * x[(k, c)]).sum::<c_double>();
+                x[(i, c)] = b[(self.permutation[i], c)] - s;
+            }
+            // U·x = y，回代
+            for i in (0..n).rev() {
+                let s = (i + 1..n).map(|k| self.lu[(i, k)] * x[(k, c)]).sum::<c_double>();
+                x[(i, c)] = (x[(i, c)] - s) / self.lu[(i, i)];
+            }
+        }
+        check_matrix(x)
+    }
+}
+
+/// QR 分解的结果：A = Q·R
+#[derive(Clone, Debug, Default)]
+pub struct Qr {
+    /// rows × rows 的正交矩阵
+    q: Matrix,
+
+    /// rows × cols 的上三角矩阵
+    r: Matrix,
+
+    /// R 的对角线元素不超过这个值时认为列不满秩
+    tolerance: c_double,
+}
+
+impl Qr {
+    /// 正交矩阵 Q
+    pub fn q(&self) -> &Matrix {
+        &self.q
+    }
+
+    /// 上三角矩阵 R
+    pub fn r(&self) -> &Matrix {
+        &self.r
+    }
+
+    /// 求 A·X = B 的最小二乘解，要求 A 的行数不少于列数
+    ///
+    /// # 返回值
+    /// 使 ‖A·X - B‖ 最小的 X；B 的行数不对或 A 的行数少于列数时返回 MathError::InvalidParameter，
+    /// A 的列不满秩时返回 MathError::DivisionByZero
+    pub fn solve(&self, b: &Matrix) -> MathResult<Matrix> {
+        let (m, n) = (self.r.rows, self.r.cols);
+        if b.rows != m || m < n {
+            return MathResult::error(MathError::InvalidParameter, Matrix::default());
+        }
+        if (0..n).any(|i| self.r[(i, i)] <= self.tolerance) {
+            return MathResult::error(MathError::DivisionByZero, Matrix::default());
+        }
+
+        // R·x = Qᵀ·b 的前 n 行，回代
+        let qt_b = self.q.transpose().mul(b).value;
+        let mut x = Matrix::zeros(n, b.cols);
+        for c in 0..b.cols {
+            for i in (0..n).rev() {
+                let s = (i + 1..n).map(|k| self.r[(i, k)] * x[(k, c)]).sum::<c_double>();
+                x[(i, c)] = (qt_b[(i, c)] - s) / self.r[(i, i)];
+            }
+        }
+        check_matrix(x)
+    }
+}
@@ -4,13 +4,51 @@
 //! 包括基本运算、阶乘、斐波那契数列、质数检查等
 
 use std::os::raw::{c_long, c_double};
-use crate::types::{MathError, MathResult};
-use crate::utils::{sqrt_int, safe_multiply, safe_add};
+use crate::types::{MathError, MathResult, OverflowMode};
+use crate::utils::{gcd_u64, mode_add, mul_mod, overflow_mode, safe_multiply};
+use bigint::BigUint;
 
 /// 基本数学运算模块
 pub mod basic;
 /// 高级数学运算模块
 pub mod advanced;
+/// 大整数模块
+pub mod bigint;
+/// 伽马函数模块
+pub mod gamma;
+/// 贝塞尔函数模块
+pub mod bessel;
+/// 误差函数模块
+pub mod erf;
+/// 数论模块（整数分解、因数、欧拉函数等）
+pub mod number_theory;
+/// 质数筛模块
+pub mod sieve;
+/// 模运算模块
+pub mod modular;
+/// 浮点幂和分数幂模块
+pub mod power;
+/// 斐波那契数和卢卡斯数模块
+pub mod fibonacci;
+/// 整数序列模块
+pub mod sequence;
+/// 组合数学模块
+pub mod combinatorics;
+/// 组合枚举模块
+pub mod enumeration;
+/// 有理数模块
+pub mod rational;
+/// 定点小数模块
+pub mod decimal;
+/// 复数模块
+pub mod complex;
+/// 稠密矩阵模块
+pub mod matrix;
+
+/// 大整数阶乘允许的最大输入
+///
+/// 超过这个值计算时间会明显变长，因此直接拒绝
+pub const FACTORIAL_BIG_LIMIT: c_long = 100_000;
 
 /// 计算两个整数的和
 /// 
@@ -19,9 +57,9 @@ pub mod advanced;
 /// * `b` - 第二个整数
 /// 
 /// # 返回值
-/// 两个整数的和
-pub fn add(a: c_long, b: c_long) -> c_long {
-    safe_add(a, b)
+/// 两个整数的和，溢出时按当前溢出模式处理
+pub fn add(a: c_long, b: c_long) -> MathResult<c_long> {
+    mode_add(a, b)
 }
 
 /// 计算两个浮点数的乘积
@@ -46,11 +84,11 @@ pub fn multiply(a: c_double, b: c_double) -> c_double {
 pub fn factorial(n: c_long) -> MathResult<c_long> {
     // 参数验证
     if n < 0 {
-        return MathResult::error(MathError::NegativeNumber, -1);
+        return MathResult::error(MathError::NegativeNumber, 0);
     }
     
     if n > 20 {
-        return MathResult::error(MathError::Overflow, -2);
+        return MathResult::error(MathError::Overflow, 0);
     }
     
     // 计算阶乘
@@ -67,24 +105,61 @@ pub fn factorial(n: c_long) -> MathResult<c_long> {
     MathResult::success(result)
 }
 
+/// 计算任意精度阶乘
+/// 
+/// 与 `factorial` 不同，结果使用大整数表示，不会在 n > 20 时溢出
+/// 
+/// # 参数
+/// * `n` - 要计算阶乘的非负整数，不能超过 `FACTORIAL_BIG_LIMIT`
+/// 
+/// # 返回值
+/// 阶乘结果，如果输入无效则返回错误码
+pub fn factorial_big(n: c_long) -> MathResult<BigUint> {
+    // 参数验证
+    if n < 0 {
+        return MathResult::error(MathError::NegativeNumber, BigUint::zero());
+    }
+    
+    if n > FACTORIAL_BIG_LIMIT {
+        return MathResult::error(MathError::InvalidParameter, BigUint::zero());
+    }
+    
+    // 将多个连续的因子先在 u32 范围内相乘，减少大整数乘法的次数
+    let mut result = BigUint::one();
+    let mut chunk: u64 = 1;
+    for i in 2..=n as u64 {
+        if chunk * i > u32::MAX as u64 {
+            result.mul_small(chunk as u32);
+            chunk = 1;
+        }
+        chunk *= i;
+    }
+    result.mul_small(chunk as u32);
+    
+    MathResult::success(result)
+}
+
 /// 生成斐波那契数列
 /// 
 /// # 参数
 /// * `n` - 要生成的数列长度
 /// 
+/// 单个斐波那契数见 `fibonacci::fibonacci_nth` 和 `fibonacci::fibonacci_big`
+/// 
 /// # 返回值
-/// 包含前 n 个斐波那契数的向量
-pub fn fibonacci(n: c_long) -> Vec<c_long> {
+/// 包含前 n 个斐波那契数的向量；n 超过 93 时第 94 项 F(93) 超出 c_long 范围，
+/// 返回 MathError::Overflow（与溢出模式无关，不会返回饱和或回绕的错误值），值为已经生成的部分
+pub fn fibonacci(n: c_long) -> MathResult<Vec<c_long>> {
     if n <= 0 {
-        return Vec::new();
+        return MathResult::success(Vec::new());
     }
     
     if n == 1 {
-        return vec![0];
+        return MathResult::success(vec![0]);
     }
     
     if n == 2 {
-        return vec![0, 1];
+        return MathResult::success(vec![0, 1]);
     }
     
     // 使用迭代方法生成斐波那契数列
@@ -93,40 +168,104 @@ pub fn fibonacci(n: c_long) -> Vec<c_long> {
     for i in 2..n {
         let prev1: c_long = sequence[(i - 1) as usize];
         let prev2: c_long = sequence[(i - 2) as usize];
-        let next = safe_add(prev1, prev2);
-        sequence.push(next);
+        match prev1.checked_add(prev2) {
+            Some(next) => sequence.push(next),
+            None => return MathResult::error(MathError::Overflow, sequence),
+        }
     }
     
-    sequence
+    MathResult::success(sequence)
 }
 
+/// 试除使用的小质数
+/// 
+/// 先用它们排除大部分合数，再对剩下的数做 Miller–Rabin 测试
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// 对 2^32 以下的数做确定性 Miller–Rabin 测试所需的底数
+const WITNESSES_32: [u64; 3] = [2, 7, 61];
+
+/// 对 2^64 以下的所有数做确定性 Miller–Rabin 测试所需的底数（Sinclair 的 7 个底数）
+const WITNESSES_64: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
 /// 检查一个数是否为质数
 /// 
+/// 先用小质数试除，再做确定性的 Miller–Rabin 测试：2^32 以下使用 3 个底数，
+/// 其余使用 7 个底数，对整个 64 位范围都是准确的。
+/// 2^32 以下的数乘积不会超过 u64，乘法取模直接用 64 位运算，更大的数使用 128 位运算
+/// 
 /// # 参数
 /// * `n` - 要检查的数
 /// 
 /// # 返回值
 /// 如果是质数返回 true，否则返回 false
 pub fn is_prime(n: c_long) -> bool {
-    // 2 是唯一的偶质数
-    if n == 2 {
-        return true;
+    if n < 2 {
+        return false;
     }
     
-    // 小于 2 的数或偶数（除了 2）都不是质数
-    if n < 2 || n % 2 == 0 {
-        return false;
+    let n = n as u64;
+    for &p in SMALL_PRIMES.iter() {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
     }
     
-    // 检查奇数因子，只需要检查到 sqrt(n)
-    let sqrt_n = sqrt_int(n);
-    let mut i: c_long = 3;
+    // 没有小于等于 37 的因子，且小于 41² 的数一定是质数
+    if n < 41 * 41 {
+        return true;
+    }
     
-    while i <= sqrt_n {
-        if n % i == 0 {
-            return false;
+    if n < 1 << 32 {
+        miller_rabin(n, &WITNESSES_32, |a, b| a * b % n)
+    } else {
+        miller_rabin(n, &WITNESSES_64, |a, b| mul_mod(a, b, n))
+    }
+}
+
+/// 用给定的底数对奇数 n 做 Miller–Rabin 测试
+/// 
+/// # 参数
+/// * `n` - 要检查的奇数，没有 37 以内的质因子
+/// * `witnesses` - 测试使用的底数
+/// * `mul` - 计算 (a · b) mod n 的函数
+/// 
+/// # 返回值
+/// 通过所有底数的测试返回 true，否则返回 false
+fn miller_rabin(n: u64, witnesses: &[u64], mul: impl Fn(u64, u64) -> u64) -> bool {
+    // n - 1 = d · 2^s，d 为奇数
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    
+    'witness: for &a in witnesses {
+        let a = a % n;
+        if a == 0 {
+            continue;
+        }
+        
+        // 计算 x = a^d mod n
+        let mut x = 1;
+        let (mut base, mut exp) = (a, d);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                x = mul(x, base);
+            }
+            base = mul(base, base);
+            exp >>= 1;
+        }
+        
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        
+        for _ in 1..s {
+            x = mul(x, x);
+            if x == n - 1 {
+                continue 'witness;
+            }
         }
-        i += 2; // 只检查奇数
+        
+        return false;
     }
     
     true
@@ -139,8 +278,8 @@ pub fn is_prime(n: c_long) -> bool {
 /// * `b` - 第二个数字
 /// 
 /// # 返回值
-/// 最大公约数
-pub fn gcd(a: c_long, b: c_long) -> c_long {
+/// 非负的最大公约数；gcd(c_long::MIN, 0) = 2^63 超出范围，返回 MathError::Overflow
+pub fn gcd(a: c_long, b: c_long) -> MathResult<c_long> {
     crate::utils::gcd(a, b)
 }
 
@@ -151,39 +290,119 @@ pub fn gcd(a: c_long, b: c_long) -> c_long {
 /// * `b` - 第二个数字
 /// 
 /// # 返回值
-/// 最小公倍数
-pub fn lcm(a: c_long, b: c_long) -> c_long {
+/// 非负的最小公倍数；结果超出 c_long 范围时返回 MathError::Overflow（与溢出模式无关）
+pub fn lcm(a: c_long, b: c_long) -> MathResult<c_long> {
     crate::utils::lcm(a, b)
 }
 
+/// 计算多个数字的最大公约数
+/// 
+/// # 参数
+/// * `numbers` - 任意多个整数
+/// 
+/// # 返回值
+/// 非负的最大公约数，空列表的结果为 0；结果为 2^63 时返回 MathError::Overflow
+pub fn gcd_many(numbers: &[c_long]) -> MathResult<c_long> {
+    let mut g: u64 = 0;
+    for &n in numbers {
+        g = gcd_u64(g, n.unsigned_abs());
+        if g == 1 {
+            break;
+        }
+    }
+    
+    match c_long::try_from(g) {
+        Ok(g) => MathResult::success(g),
+        Err(_) => MathResult::error(MathError::Overflow, c_long::MAX),
+    }
+}
+
+/// 计算多个数字的最小公倍数
+/// 
+/// # 参数
+/// * `numbers` - 任意多个整数
+/// 
+/// # 返回值
+/// 非负的最小公倍数，空列表的结果为 1，包含 0 时结果为 0；
+/// 结果超出 c_long 范围时返回 MathError::Overflow（与溢出模式无关）
+pub fn lcm_many(numbers: &[c_long]) -> MathResult<c_long> {
+    if numbers.contains(&0) {
+        return MathResult::success(0);
+    }
+    
+    // 不含 0 时部分结果单调不减，一旦超出范围最终结果也一定超出范围
+    let mut l: c_long = 1;
+    for &n in numbers {
+        let next = lcm(l, n);
+        if next.is_error() {
+            return next;
+        }
+        l = next.value;
+    }
+    
+    MathResult::success(l)
+}
+
 /// 计算幂运算
 /// 
+/// 负指数的结果只有在底数为 1 或 -1 时才是整数；其他情况请使用 `power::power_fraction` 得到精确的分数，
+/// 或使用 `power::powi` 得到浮点数
+/// 
 /// # 参数
 /// * `base` - 底数
 /// * `exponent` - 指数
 /// 
 /// # 返回值
-/// 幂运算结果
+/// 幂运算结果；溢出时只有回绕模式返回回绕后的值，饱和模式和检查模式都返回 MathError::Overflow——
+/// 与 `fibonacci_nth` 和 `lcm` 一样，截断后的幂没有意义；
+/// 指数为负数时，底数为 0 返回 MathError::DivisionByZero，底数不是 ±1 返回 MathError::InvalidParameter
 pub fn power(base: c_long, exponent: c_long) -> MathResult<c_long> {
     if exponent < 0 {
-        return MathResult::error(MathError::InvalidParameter, 0);
+        return match base {
+            1 => MathResult::success(1),
+            -1 => MathResult::success(if exponent % 2 == 0 { 1 } else { -1 }),
+            0 => MathResult::error(MathError::DivisionByZero, 0),
+            _ => MathResult::error(MathError::InvalidParameter, 0),
+        };
     }
     
-    if exponent == 0 {
-        return MathResult::success(1);
+    match checked_power(base, exponent as u64) {
+        Some(value) => MathResult::success(value),
+        None if overflow_mode() == OverflowMode::Wrapping => MathResult::success(wrapping_power(base, exponent as u64)),
+        None => MathResult::error(MathError::Overflow, 0),
     }
+}
+
+/// 检查溢出的快速幂
+/// 
+/// 只有在还需要用到时才对底数平方，避免最后一次多余的平方误报溢出
+fn checked_power(mut base: c_long, mut exponent: u64) -> Option<c_long> {
+    let mut result: c_long = 1;
     
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = result.checked_mul(base)?;
+        }
+        exponent /= 2;
+        if exponent > 0 {
+            base = base.checked_mul(base)?;
+        }
+    }
+    
+    Some(result)
+}
+
+/// 回绕的快速幂，结果等于真实结果对 2^64 取模
+fn wrapping_power(mut base: c_long, mut exponent: u64) -> c_long {
     let mut result: c_long = 1;
-    let mut base = base;
-    let mut exp = exponent;
     
-    while exp > 0 {
-        if exp % 2 == 1 {
-            result = safe_multiply(result, base);
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = result.wrapping_mul(base);
         }
-        base = safe_multiply(base, base);
-        exp /= 2;
+        exponent /= 2;
+        base = base.wrapping_mul(base);
     }
     
-    MathResult::success(result)
+    result
 } 
\ No newline at end of file
@@ -0,0 +1,232 @@
+//! 模运算模块
+//! 
+//! 这个模块包含常用的模运算：
+//! 
+//! - `mod_pow`: 模幂 base^exp mod m，支持负指数（使用模逆元）
+//! - `mod_inverse`: 模逆元
+//! - `extended_gcd`: 扩展欧几里得算法，返回最大公约数和 Bézout 系数
+//! - `crt`: 中国剩余定理，模数不必两两互质
+//! - `legendre`、`jacobi`: 勒让德符号和雅可比符号
+//! 
+//! 中间结果都使用 128 位整数，任何 64 位参数都不会溢出；
+//! 模数为 0 时返回 MathError::DivisionByZero，为负数时返回 MathError::InvalidParameter，
+//! 与 `BigInt::mod_pow` 一致
+
+use std::os::raw::c_long;
+use crate::types::{MathError, MathResult};
+use crate::utils::pow_mod;
+use super::is_prime;
+
+/// 检查模数，合法时返回 None
+fn check_modulus(m: c_long) -> Option<MathError> {
+    if m == 0 {
+        Some(MathError::DivisionByZero)
+    } else if m < 0 {
+        Some(MathError::InvalidParameter)
+    } else {
+        None
+    }
+}
+
+/// 在 128 位整数上执行扩展欧几里得算法
+/// 
+/// # 返回值
+/// (g, x, y)，满足 a·x + b·y = g，g 为非负的最大公约数
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// 计算 a 在模 m 下的逆元，m 必须大于 0
+fn inverse_wide(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd_wide(a.rem_euclid(m), m);
+    if g == 1 {
+        Some(x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+/// 扩展欧几里得算法
+/// 
+/// # 参数
+/// * `a` - 第一个整数
+/// * `b` - 第二个整数
+/// 
+/// # 返回值
+/// (g, x, y)，满足 a·x + b·y = g，其中 g 是非负的最大公约数；
+/// g 超出 c_long 范围时（例如 a = c_long::MIN, b = 0）返回 MathError::Overflow
+pub fn extended_gcd(a: c_long, b: c_long) -> MathResult<(c_long, c_long, c_long)> {
+    let (g, x, y) = extended_gcd_wide(a as i128, b as i128);
+
+    match (c_long::try_from(g), c_long::try_from(x), c_long::try_from(y)) {
+        (Ok(g), Ok(x), Ok(y)) => MathResult::success((g, x, y)),
+        _ => MathResult::error(MathError::Overflow, (0, 0, 0)),
+    }
+}
+
+/// 计算模逆元，即满足 a·x ≡ 1 (mod m) 的 x
+/// 
+/// # 参数
+/// * `a` - 整数，可以为负数
+/// * `m` - 模数，必须大于 0
+/// 
+/// # 返回值
+/// 范围在 [0, m) 内的逆元；a 与 m 不互质时逆元不存在，返回 MathError::DomainError
+pub fn mod_inverse(a: c_long, m: c_long) -> MathResult<c_long> {
+    if let Some(error) = check_modulus(m) {
+        return MathResult::error(error, 0);
+    }
+
+    match inverse_wide(a as i128, m as i128) {
+        Some(x) => MathResult::success(x as c_long),
+        None => MathResult::error(MathError::DomainError, 0),
+    }
+}
+
+/// 计算模幂 base^exp mod m
+/// 
+/// # 参数
+/// * `base` - 底数，可以为负数
+/// * `exp` - 指数，为负数时计算 base 的逆元的 |exp| 次幂
+/// * `m` - 模数，必须大于 0
+/// 
+/// # 返回值
+/// 范围在 [0, m) 内的结果；指数为负数且 base 与 m 不互质时返回 MathError::DomainError
+pub fn mod_pow(base: c_long, exp: c_long, m: c_long) -> MathResult<c_long> {
+    if let Some(error) = check_modulus(m) {
+        return MathResult::error(error, 0);
+    }
+
+    let base = if exp < 0 {
+        match inverse_wide(base as i128, m as i128) {
+            Some(inverse) => inverse as u64,
+            None => return MathResult::error(MathError::DomainError, 0),
+        }
+    } else {
+        base.rem_euclid(m) as u64
+    };
+
+    MathResult::success(pow_mod(base, exp.unsigned_abs(), m as u64) as c_long)
+}
+
+/// 用中国剩余定理求解同余方程组 x ≡ remainders[i] (mod moduli[i])
+/// 
+/// 模数不必两两互质：依次合并两个方程，公约数不整除余数之差时方程组无解
+/// 
+/// # 参数
+/// * `remainders` - 余数，可以为负数或大于模数
+/// * `moduli` - 模数，必须都大于 0，长度与 remainders 相同
+/// 
+/// # 返回值
+/// (x, M)，方程组的全部解为 x + k·M，其中 0 <= x < M，M 是所有模数的最小公倍数；
+/// 方程组为空时返回 (0, 1)。
+/// 长度不同时返回 MathError::InvalidParameter，方程组无解时返回 MathError::DomainError，
+/// M 超出 c_long 范围时返回 MathError::Overflow
+pub fn crt(remainders: &[c_long], moduli: &[c_long]) -> MathResult<(c_long, c_long)> {
+    if remainders.len() != moduli.len() {
+        return MathResult::error(MathError::InvalidParameter, (0, 1));
+    }
+
+    let (mut x, mut modulus): (i128, i128) = (0, 1);
+
+    for (&r, &m) in remainders.iter().zip(moduli) {
+        if let Some(error) = check_modulus(m) {
+            return MathResult::error(error, (0, 1));
+        }
+
+        let (r, m) = ((r as i128).rem_euclid(m as i128), m as i128);
+
+        // 求 t 使 x + modulus·t ≡ r (mod m)，即 modulus·t ≡ r - x (mod m)
+        let (g, _, _) = extended_gcd_wide(modulus, m);
+        let difference = r - x;
+        if difference % g != 0 {
+            return MathResult::error(MathError::DomainError, (0, 1));
+        }
+
+        let step = m / g;
+        let lcm = modulus * step;
+        if lcm > c_long::MAX as i128 {
+            return MathResult::error(MathError::Overflow, (0, 1));
+        }
+
+        // modulus / g 与 step 互质，逆元一定存在；各项都小于 2^63，乘积不会超出 i128
+        let inverse = inverse_wide(modulus / g, step).unwrap_or(0);
+        let t = (difference / g).rem_euclid(step) * inverse % step;
+
+        x = (x + modulus * t).rem_euclid(lcm);
+        modulus = lcm;
+    }
+
+    MathResult::success((x as c_long, modulus as c_long))
+}
+
+/// 计算雅可比符号 (a/n)
+/// 
+/// # 参数
+/// * `a` - 整数，可以为负数
+/// * `n` - 正奇数
+/// 
+/// # 返回值
+/// -1、0 或 1；n 不是正奇数时返回 MathError::InvalidParameter
+pub fn jacobi(a: c_long, n: c_long) -> MathResult<c_long> {
+    if n <= 0 || n % 2 == 0 {
+        return MathResult::error(MathError::InvalidParameter, 0);
+    }
+
+    let mut a = a.rem_euclid(n) as u64;
+    let mut n = n as u64;
+    let mut result = 1;
+
+    while a != 0 {
+        // 提取因子 2：(2/n) = -1 当且仅当 n ≡ 3, 5 (mod 8)
+        while a.is_multiple_of(2) {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+
+        // 二次互反律：a 和 n 都 ≡ 3 (mod 4) 时符号取反
+        (a, n) = (n, a);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+
+    MathResult::success(if n == 1 { result } else { 0 })
+}
+
+/// 计算勒让德符号 (a/p)
+/// 
+/// 结果表示 a 是否为模 p 的二次剩余：1 为二次剩余，-1 为非二次剩余，0 表示 p 整除 a
+/// 
+/// # 参数
+/// * `a` - 整数，可以为负数
+/// * `p` - 奇质数
+/// 
+/// # 返回值
+/// -1、0 或 1；p 不是奇质数时返回 MathError::InvalidParameter
+pub fn legendre(a: c_long, p: c_long) -> MathResult<c_long> {
+    if p == 2 || !is_prime(p) {
+        return MathResult::error(MathError::InvalidParameter, 0);
+    }
+
+    // 模数为质数时雅可比符号就是勒让德符号
+    jacobi(a, p)
+}
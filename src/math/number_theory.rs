@@ -0,0 +1,265 @@
+//! 数论模块
+//! 
+//! 这个模块包含整数分解以及基于分解结果的数论函数：
+//! 
+//! - `factorize`: 质因数分解，返回 (质数, 指数) 列表
+//! - `divisors`: 全部正因数
+//! - `sigma`: 因数幂和函数 σ_k(n)
+//! - `euler_phi`: 欧拉函数 φ(n)
+//! - `mobius`: 莫比乌斯函数 μ(n)
+//! 
+//! 分解先用试除法去掉小因子，剩下的部分用 Miller–Rabin 判断是否为质数，
+//! 不是质数时用 Pollard rho 算法（Brent 的变体）找出一个因子后递归分解
+
+use std::os::raw::{c_int, c_long};
+use crate::types::{MathError, MathResult};
+use crate::utils::{gcd_u64, mul_mod};
+use super::is_prime;
+
+/// 试除法检查的因子上限
+/// 
+/// 去掉这些小因子后，剩下的数如果小于 TRIAL_LIMIT² 就一定是质数
+const TRIAL_LIMIT: u64 = 1000;
+
+/// Brent 算法中每计算一次 gcd 之前累乘的差值个数
+const RHO_BATCH: u64 = 128;
+
+/// 用 Pollard rho 算法（Brent 的变体）找出合数 n 的一个非平凡因子
+/// 
+/// 迭代函数为 f(x) = x² + c，把多个差值 |x - y| 累乘后再求 gcd 以减少 gcd 的次数；
+/// 某个 c 失败（gcd 等于 n）时换下一个 c 重试
+/// 
+/// # 参数
+/// * `n` - 奇合数
+/// 
+/// # 返回值
+/// n 的一个因子，大于 1 且小于 n
+fn pollard_brent(n: u64) -> u64 {
+    let gcd_n = |a: u64| gcd_u64(a, n);
+
+    let mut c = 0;
+    loop {
+        c += 1;
+        let f = |x: u64| (mul_mod(x, x, n) + c) % n;
+
+        let (mut x, mut y, mut ys) = (2, 2, 2);
+        let (mut r, mut q, mut g) = (1, 1, 1);
+
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..RHO_BATCH.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd_n(q);
+                k += RHO_BATCH;
+            }
+
+            r *= 2;
+        }
+
+        // 累乘的差值中混入了 n 的全部因子，从上一批的起点逐个重新检查
+        if g == n {
+            loop {
+                ys = f(ys);
+                g = gcd_n(x.abs_diff(ys));
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+
+        if g != n {
+            return g;
+        }
+    }
+}
+
+/// 把没有小因子的数 n 分解为质因子，结果追加到 factors 中（不排序）
+fn split(n: u64, factors: &mut Vec<u64>) {
+    if n == 1 {
+        return;
+    }
+
+    if is_prime(n as c_long) {
+        factors.push(n);
+        return;
+    }
+
+    let d = pollard_brent(n);
+    split(d, factors);
+    split(n / d, factors);
+}
+
+/// 把正整数分解为质因数
+/// 
+/// # 参数
+/// * `n` - 要分解的正整数
+/// 
+/// # 返回值
+/// 按质数从小到大排列的 (质数, 指数) 列表，1 的分解结果为空列表；
+/// n 为负数时返回 MathError::NegativeNumber，n 为 0 时返回 MathError::InvalidParameter
+pub fn factorize(n: c_long) -> MathResult<Vec<(c_long, c_int)>> {
+    if n < 0 {
+        return MathResult::error(MathError::NegativeNumber, Vec::new());
+    }
+
+    if n == 0 {
+        return MathResult::error(MathError::InvalidParameter, Vec::new());
+    }
+
+    let mut n = n as u64;
+
+    let mut primes = Vec::new();
+
+    let mut d = 2;
+    while d < TRIAL_LIMIT && d * d <= n {
+        while n.is_multiple_of(d) {
+            primes.push(d);
+            n /= d;
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+
+    split(n, &mut primes);
+    primes.sort_unstable();
+
+    let mut factors: Vec<(c_long, c_int)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, exponent)) if *last == p as c_long => *exponent += 1,
+            _ => factors.push((p as c_long, 1)),
+        }
+    }
+
+    MathResult::success(factors)
+}
+
+/// 计算正整数的全部正因数
+/// 
+/// # 参数
+/// * `n` - 正整数
+/// 
+/// # 返回值
+/// 从小到大排列的因数列表；n 为负数时返回 MathError::NegativeNumber，
+/// n 为 0 时返回 MathError::InvalidParameter
+pub fn divisors(n: c_long) -> MathResult<Vec<c_long>> {
+    let factors = factorize(n);
+    if factors.is_error() {
+        return MathResult::error(factors.error, Vec::new());
+    }
+
+    let mut result = vec![1];
+    for (p, exponent) in factors.value {
+        let count = result.len();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= p;
+            for i in 0..count {
+                result.push(result[i] * power);
+            }
+        }
+    }
+
+    result.sort_unstable();
+    MathResult::success(result)
+}
+
+/// 计算因数幂和函数 σ_k(n)，即 n 的所有正因数的 k 次幂之和
+/// 
+/// σ_0(n) 是因数的个数，σ_1(n) 是因数之和
+/// 
+/// # 参数
+/// * `n` - 正整数
+/// * `k` - 幂次，不能为负数
+/// 
+/// # 返回值
+/// σ_k(n) 的值；n 或 k 为负数时返回 MathError::NegativeNumber，
+/// n 为 0 时返回 MathError::InvalidParameter，结果超出范围时返回 MathError::Overflow
+pub fn sigma(n: c_long, k: c_long) -> MathResult<c_long> {
+    if k < 0 {
+        return MathResult::error(MathError::NegativeNumber, 0);
+    }
+
+    let factors = factorize(n);
+    if factors.is_error() {
+        return MathResult::error(factors.error, 0);
+    }
+
+    let overflow = MathResult::error(MathError::Overflow, c_long::MAX);
+
+    // σ_k 是积性函数：σ_k(p^e) = 1 + p^k + p^2k + ... + p^ek
+    let mut result: c_long = 1;
+    for (p, exponent) in factors.value {
+        let Some(pk) = u32::try_from(k).ok().and_then(|k| p.checked_pow(k)) else {
+            return overflow;
+        };
+
+        let (mut term, mut sum): (c_long, c_long) = (1, 1);
+        for _ in 0..exponent {
+            match term.checked_mul(pk).and_then(|t| sum.checked_add(t).map(|s| (t, s))) {
+                Some((t, s)) => (term, sum) = (t, s),
+                None => return overflow,
+            }
+        }
+
+        match result.checked_mul(sum) {
+            Some(value) => result = value,
+            None => return overflow,
+        }
+    }
+
+    MathResult::success(result)
+}
+
+/// 计算欧拉函数 φ(n)，即 1 到 n 中与 n 互质的数的个数
+/// 
+/// # 参数
+/// * `n` - 正整数
+/// 
+/// # 返回值
+/// φ(n) 的值；n 为负数时返回 MathError::NegativeNumber，n 为 0 时返回 MathError::InvalidParameter
+pub fn euler_phi(n: c_long) -> MathResult<c_long> {
+    let factors = factorize(n);
+    if factors.is_error() {
+        return MathResult::error(factors.error, 0);
+    }
+
+    // φ(n) = n · Π (1 - 1/p)，先除后乘不会溢出
+    let mut result = n;
+    for (p, _) in factors.value {
+        result = result / p * (p - 1);
+    }
+
+    MathResult::success(result)
+}
+
+/// 计算莫比乌斯函数 μ(n)
+/// 
+/// n 含有平方因子时为 0，否则为 (-1)^k，k 是 n 的不同质因子个数
+/// 
+/// # 参数
+/// * `n` - 正整数
+/// 
+/// # 返回值
+/// μ(n) 的值（-1、0 或 1）；n 为负数时返回 MathError::NegativeNumber，
+/// n 为 0 时返回 MathError::InvalidParameter
+pub fn mobius(n: c_long) -> MathResult<c_long> {
+    let factors = factorize(n);
+    if factors.is_error() {
+        return MathResult::error(factors.error, 0);
+    }
+
+    if factors.value.iter().any(|&(_, exponent)| exponent > 1) {
+        return MathResult::success(0);
+    }
+
+    MathResult::success(if factors.value.len().is_multiple_of(2) { 1 } else { -1 })
+}
@@ -0,0 +1,129 @@
+//! 浮点幂和分数幂模块
+//! 
+//! `math::power` 只处理整数底数和非负整数指数，这个模块补充负指数和非整数指数的情况：
+//! 
+//! - `pow_float`: 浮点底数的浮点次幂
+//! - `powi`: 浮点底数的整数次幂，指数可以为负数
+//! - `power_fraction`: 分数的整数次幂，结果是精确的最简分数
+//! 
+//! 浮点函数按当前浮点模式报告错误：0 的负数次幂返回 MathError::DivisionByZero，
+//! 负数的非整数次幂返回 MathError::DomainError，有限参数的结果超出 double 范围时返回 MathError::Overflow
+
+use std::os::raw::{c_double, c_long};
+use crate::types::{MathError, MathResult, RustFraction};
+use crate::utils::{check_float, float_error, gcd_u64};
+
+/// 检查溢出的无符号整数幂
+fn checked_pow_u64(base: u64, exponent: u64) -> Option<u64> {
+    match base {
+        0 => Some(if exponent == 0 { 1 } else { 0 }),
+        1 => Some(1),
+        // 底数至少为 2 时，指数超过 63 一定溢出
+        _ => base.checked_pow(u32::try_from(exponent).ok()?),
+    }
+}
+
+/// 按 IEEE 754 的规则计算幂运算后检查错误
+/// 
+/// # 参数
+/// * `base` - 底数
+/// * `value` - 幂运算的结果
+/// * `negative_exponent` - 指数是否为负数
+/// * `finite_exponent` - 指数是否为有限值
+fn check_power(base: c_double, value: c_double, negative_exponent: bool, finite_exponent: bool) -> MathResult<c_double> {
+    if base == 0.0 && negative_exponent {
+        return float_error(MathError::DivisionByZero, value);
+    }
+
+    if value.is_infinite() && base.is_finite() && finite_exponent {
+        return float_error(MathError::Overflow, value);
+    }
+
+    check_float(value)
+}
+
+/// 计算浮点数的浮点次幂 base^exponent
+/// 
+/// # 参数
+/// * `base` - 底数
+/// * `exponent` - 指数
+/// 
+/// # 返回值
+/// 幂运算结果；base 为负数且 exponent 不是整数时返回 MathError::DomainError，
+/// base 为 0 且 exponent 为负数时返回 MathError::DivisionByZero，
+/// 结果超出 double 范围时返回 MathError::Overflow（IEEE 浮点模式下分别返回 NaN、±inf 和 ±inf）
+pub fn pow_float(base: c_double, exponent: c_double) -> MathResult<c_double> {
+    if base < 0.0 && exponent.is_finite() && exponent != exponent.trunc() {
+        return float_error(MathError::DomainError, c_double::NAN);
+    }
+
+    check_power(base, base.powf(exponent), exponent < 0.0, exponent.is_finite())
+}
+
+/// 计算浮点数的整数次幂 base^exponent
+/// 
+/// 与 `pow_float` 不同，指数是精确的整数，超过 2^53 的奇数指数也能得到正确的符号
+/// 
+/// # 参数
+/// * `base` - 底数
+/// * `exponent` - 指数，可以为负数
+/// 
+/// # 返回值
+/// 幂运算结果；base 为 0 且 exponent 为负数时返回 MathError::DivisionByZero，
+/// 结果超出 double 范围时返回 MathError::Overflow（IEEE 浮点模式下返回 ±inf）
+pub fn powi(base: c_double, exponent: c_long) -> MathResult<c_double> {
+    let magnitude = base.abs().powf(exponent as c_double);
+    let value = if base.is_sign_negative() && exponent % 2 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    };
+
+    check_power(base, value, exponent < 0, true)
+}
+
+/// 计算分数的整数次幂 (numerator / denominator)^exponent
+/// 
+/// 结果是精确的最简分数，整数底数的负数次幂可以把 denominator 设为 1，例如 2^-3 = 1/8
+/// 
+/// # 参数
+/// * `numerator` - 底数的分子
+/// * `denominator` - 底数的分母，不能为 0
+/// * `exponent` - 指数，可以为负数
+/// 
+/// # 返回值
+/// 最简分数，分母大于 0；分母为 0 或底数为 0 且指数为负数时返回 MathError::DivisionByZero，
+/// 分子或分母超出 c_long 范围时返回 MathError::Overflow（与溢出模式无关）
+pub fn power_fraction(numerator: c_long, denominator: c_long, exponent: c_long) -> MathResult<RustFraction> {
+    let zero = RustFraction::default();
+    if denominator == 0 {
+        return MathResult::error(MathError::DivisionByZero, zero);
+    }
+
+    let negative = (numerator < 0) != (denominator < 0) && exponent % 2 != 0;
+    let (mut p, mut q) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+    let g = gcd_u64(p, q);
+    (p, q) = (p / g, q / g);
+
+    if exponent < 0 {
+        if p == 0 {
+            return MathResult::error(MathError::DivisionByZero, zero);
+        }
+        (p, q) = (q, p);
+    }
+
+    // p 和 q 互质，它们的幂也互质，不需要再约分
+    let n = exponent.unsigned_abs();
+    let fraction = checked_pow_u64(p, n).zip(checked_pow_u64(q, n)).and_then(|(p, q)| {
+        let p = if negative { -(p as i128) } else { p as i128 };
+        Some(RustFraction {
+            numerator: c_long::try_from(p).ok()?,
+            denominator: c_long::try_from(q).ok()?,
+        })
+    });
+
+    match fraction {
+        Some(fraction) => MathResult::success(fraction),
+        None => MathResult::error(MathError::Overflow, zero),
+    }
+}
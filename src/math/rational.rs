@@ -0,0 +1,463 @@
+//! 有理数模块
+//!
+//! `Rational` 是分子和分母都在 c_long 范围内的精确分数，总是保持最简形式：
+//! 分母大于 0，分子和分母互质（用 `utils::gcd_u64` 约分），0 表示为 0/1
+//!
+//! 运算的中间结果使用 128 位整数，约分后仍然超出 c_long 范围时返回 MathError::Overflow，
+//! 与溢出模式无关——有理数没有合理的饱和或回绕结果
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::os::raw::{c_double, c_long};
+use crate::types::{MathError, MathResult};
+use crate::utils::gcd_u64;
+use super::power::power_fraction;
+
+/// 精确的有理数
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    /// 分子，带有分数的符号
+    numerator: c_long,
+
+    /// 分母，大于 0
+    denominator: c_long,
+}
+
+/// 两个非负整数的最大公约数，第二个数必须在 u64 范围内
+///
+/// gcd(a, b) = gcd(a mod b, b)，先取模就可以使用 64 位的 `gcd_u64`
+fn gcd_wide(a: u128, b: u64) -> u64 {
+    if b == 0 {
+        // gcd(a, 0) = a，调用者保证这时 a 也在 u64 范围内
+        return a as u64;
+    }
+    gcd_u64((a % b as u128) as u64, b)
+}
+
+/// 比较两个非负分数 a / b 和 c / d，b 和 d 大于 0
+///
+/// 逐项比较连分数展开，不计算可能溢出的交叉乘积
+fn cmp_fractions(mut a: i128, mut b: i128, mut c: i128, mut d: i128) -> Ordering {
+    loop {
+        let (qa, qc) = (a / b, c / d);
+        if qa != qc {
+            return qa.cmp(&qc);
+        }
+        let (ra, rc) = (a % b, c % d);
+        match (ra == 0, rc == 0) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            // ra / b 和 rc / d 的大小关系与 d / rc 和 b / ra 相同
+            (false, false) => (a, b, c, d) = (d, rc, b, ra),
+        }
+    }
+}
+
+/// n / d 的分母不超过 max 的最佳近似，d 必须大于 max，max 必须大于 0
+///
+/// 先取连分数的渐近分数，再检查最后一个中间分数（semiconvergent）是否更接近；
+/// 渐近分数和中间分数的分子绝对值都不超过 |n| + 1，n 在 c_long 范围内时结果不会溢出
+fn best_approximation(n: i128, d: i128, max: i128) -> MathResult<Rational> {
+    let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+    let (mut n, mut d) = (n, d);
+
+    // 分母大于 max，展开一定会在 d 变为 0 之前停止；第一项的分母为 1，总是保留
+    loop {
+        let a = n.div_euclid(d);
+        match a.checked_mul(q1).and_then(|q| q.checked_add(q0)) {
+            Some(q2) if q2 <= max => {
+                (p0, q0, p1, q1) = (p1, q1, p0 + a * p1, q2);
+                (n, d) = (d, n - a * d);
+            }
+            _ => break,
+        }
+    }
+
+    // 剩余部分的完全商是 n / d，中间分数 (p0 + k·p1) / (q0 + k·q1) 更接近的条件是
+    // (q0 + 2k·q1) / q1 > n / d，距离相同时取分母较小的渐近分数
+    let k = (max - q0) / q1;
+    if cmp_fractions(q0 + 2 * k * q1, q1, n, d) == Ordering::Greater {
+        Rational::from_reduced(p0 + k * p1, q0 + k * q1)
+    } else {
+        Rational::from_reduced(p1, q1)
+    }
+}
+
+impl Rational {
+    /// 0/1
+    pub fn zero() -> Self {
+        Self { numerator: 0, denominator: 1 }
+    }
+
+    /// 1/1
+    pub fn one() -> Self {
+        Self { numerator: 1, denominator: 1 }
+    }
+
+    /// 由整数创建有理数
+    pub fn from_integer(n: c_long) -> Self {
+        Self { numerator: n, denominator: 1 }
+    }
+
+    /// 由分子和分母创建有理数，结果约分到最简形式
+    ///
+    /// # 返回值
+    /// 有理数；分母为 0 时返回 MathError::DivisionByZero，
+    /// 约分后分子或分母超出 c_long 范围时（例如 1 / c_long::MIN）返回 MathError::Overflow
+    pub fn new(numerator: c_long, denominator: c_long) -> MathResult<Self> {
+        Self::reduce(numerator as i128, denominator as i128)
+    }
+
+    /// 约分 n / d，d 的绝对值必须在 u64 范围内
+    fn reduce(n: i128, d: i128) -> MathResult<Self> {
+        if d == 0 {
+            return MathResult::error(MathError::DivisionByZero, Self::zero());
+        }
+
+        let g = gcd_wide(n.unsigned_abs(), d.unsigned_abs() as u64) as i128;
+        let (n, d) = if d < 0 { (-n / g, -d / g) } else { (n / g, d / g) };
+        Self::from_reduced(n, d)
+    }
+
+    /// 由已经约分、分母为正的 n / d 创建有理数，超出 c_long 范围时返回 MathError::Overflow
+    fn from_reduced(n: i128, d: i128) -> MathResult<Self> {
+        match (c_long::try_from(n), c_long::try_from(d)) {
+            (Ok(numerator), Ok(denominator)) => MathResult::success(Self { numerator, denominator }),
+            _ => MathResult::error(MathError::Overflow, Self::zero()),
+        }
+    }
+
+    /// 分子，带有分数的符号
+    pub fn numerator(&self) -> c_long {
+        self.numerator
+    }
+
+    /// 分母，总是大于 0
+    pub fn denominator(&self) -> c_long {
+        self.denominator
+    }
+
+    /// 是否为整数
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// 符号：负数返回 -1，零返回 0，正数返回 1
+    pub fn signum(&self) -> c_long {
+        self.numerator.signum()
+    }
+
+    /// 分数 a/b + c/d，分子以 128 位整数传入，这样减法可以直接对 c 取反
+    ///
+    /// 使用 Knuth 的方法：先用 gcd(b, d) 约去公共部分，最后只需要再约一个较小的因子，
+    /// 得到的结果已经是最简形式（《计算机程序设计艺术》第 2 卷 4.5.1 节）
+    fn add_parts(a: i128, b: c_long, c: i128, d: c_long) -> MathResult<Self> {
+        let (b, d) = (b as i128, d as i128);
+        let g = gcd_u64(b as u64, d as u64) as i128;
+
+        if g == 1 {
+            return Self::from_reduced(a * d + c * b, b * d);
+        }
+
+        let t = a * (d / g) + c * (b / g);
+        if t == 0 {
+            return MathResult::success(Self::zero());
+        }
+        let g2 = gcd_u64(t.rem_euclid(g) as u64, g as u64) as i128;
+        Self::from_reduced(t / g2, (b / g) * (d / g2))
+    }
+
+    /// 分数 a/b · c/d，分母为正；先交叉约分，乘积已经是最简形式
+    fn mul_parts(a: i128, b: i128, c: i128, d: i128) -> MathResult<Self> {
+        if a == 0 || c == 0 {
+            return MathResult::success(Self::zero());
+        }
+
+        let g1 = gcd_u64(a.unsigned_abs() as u64, d as u64) as i128;
+        let g2 = gcd_u64(c.unsigned_abs() as u64, b as u64) as i128;
+        Self::from_reduced((a / g1) * (c / g2), (b / g2) * (d / g1))
+    }
+
+    /// 加法，结果超出 c_long 范围时返回 MathError::Overflow
+    pub fn add(&self, other: &Self) -> MathResult<Self> {
+        Self::add_parts(self.numerator as i128, self.denominator, other.numerator as i128, other.denominator)
+    }
+
+    /// 减法，结果超出 c_long 范围时返回 MathError::Overflow
+    pub fn sub(&self, other: &Self) -> MathResult<Self> {
+        Self::add_parts(self.numerator as i128, self.denominator, -(other.numerator as i128), other.denominator)
+    }
+
+    /// 乘法，结果超出 c_long 范围时返回 MathError::Overflow
+    pub fn mul(&self, other: &Self) -> MathResult<Self> {
+        Self::mul_parts(
+            self.numerator as i128,
+            self.denominator as i128,
+            other.numerator as i128,
+            other.denominator as i128,
+        )
+    }
+
+    /// 除法
+    ///
+    /// # 返回值
+    /// 商；除数为 0 时返回 MathError::DivisionByZero，结果超出 c_long 范围时返回 MathError::Overflow
+    pub fn div(&self, other: &Self) -> MathResult<Self> {
+        if other.numerator == 0 {
+            return MathResult::error(MathError::DivisionByZero, Self::zero());
+        }
+
+        // 除以 c/d 就是乘以 d/c，把符号移到分子上
+        let (c, d) = (other.numerator as i128, other.denominator as i128);
+        let (c, d) = if c < 0 { (-d, -c) } else { (d, c) };
+        Self::mul_parts(self.numerator as i128, self.denominator as i128, c, d)
+    }
+
+    /// 相反数，分子为 c_long::MIN 时返回 MathError::Overflow
+    pub fn neg(&self) -> MathResult<Self> {
+        Self::from_reduced(-(self.numerator as i128), self.denominator as i128)
+    }
+
+    /// 绝对值，分子为 c_long::MIN 时返回 MathError::Overflow
+    pub fn abs(&self) -> MathResult<Self> {
+        Self::from_reduced(self.numerator.unsigned_abs() as i128, self.denominator as i128)
+    }
+
+    /// 倒数
+    ///
+    /// # 返回值
+    /// 倒数；值为 0 时返回 MathError::DivisionByZero，分子为 c_long::MIN 时返回 MathError::Overflow
+    pub fn reciprocal(&self) -> MathResult<Self> {
+        Self::one().div(self)
+    }
+
+    /// 整数次幂，指数可以为负数
+    ///
+    /// # 返回值
+    /// 幂；值为 0 且指数为负数时返回 MathError::DivisionByZero，结果超出 c_long 范围时返回 MathError::Overflow
+    pub fn pow(&self, exponent: c_long) -> MathResult<Self> {
+        let result = power_fraction(self.numerator, self.denominator, exponent);
+        if result.is_error() {
+            return MathResult::error(result.error, Self::zero());
+        }
+
+        MathResult::success(Self {
+            numerator: result.value.numerator,
+            denominator: result.value.denominator,
+        })
+    }
+
+    /// 不大于这个数的最大整数
+    pub fn floor(&self) -> c_long {
+        self.numerator.div_euclid(self.denominator)
+    }
+
+    /// 不小于这个数的最小整数
+    pub fn ceil(&self) -> c_long {
+        // 分母至少为 1，向上取整的结果不会超出分子的范围
+        let floor = self.floor();
+        if floor as i128 * self.denominator as i128 == self.numerator as i128 {
+            floor
+        } else {
+            floor + 1
+        }
+    }
+
+    /// 最接近的浮点数
+    pub fn to_f64(&self) -> c_double {
+        self.numerator as c_double / self.denominator as c_double
+    }
+
+    /// 连分数展开 [a0; a1, a2, ...]，除 a0 外的各项都大于 0，整数只有一项
+    pub fn continued_fraction(&self) -> Vec<c_long> {
+        let (mut n, mut d) = (self.numerator as i128, self.denominator as i128);
+        let mut terms = Vec::new();
+
+        while d != 0 {
+            let a = n.div_euclid(d);
+            terms.push(a as c_long);
+            (n, d) = (d, n - a * d);
+        }
+
+        terms
+    }
+
+    /// 由连分数 [a0; a1, a2, ...] 计算有理数
+    ///
+    /// # 返回值
+    /// 有理数；terms 为空或 a0 以外的项不大于 0 时返回 MathError::InvalidParameter，
+    /// 中间结果超出 c_long 范围时返回 MathError::Overflow
+    pub fn from_continued_fraction(terms: &[c_long]) -> MathResult<Self> {
+        let (last, rest) = match terms.split_last() {
+            Some(split) => split,
+            None => return MathResult::error(MathError::InvalidParameter, Self::zero()),
+        };
+        if terms.iter().skip(1).any(|&a| a <= 0) {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        }
+
+        // 从最后一项往前计算 a + 1 / value
+        let mut value = Self::from_integer(*last);
+        for &a in rest.iter().rev() {
+            let result = value.reciprocal().value.add(&Self::from_integer(a));
+            if result.is_error() {
+                return result;
+            }
+            value = result.value;
+        }
+
+        MathResult::success(value)
+    }
+
+    /// 分母不超过 max_denominator 的最佳近似
+    ///
+    /// 先取连分数的渐近分数，再检查最后一个中间分数（semiconvergent）是否更接近
+    ///
+    /// # 返回值
+    /// 最接近的有理数，距离相同时取分母较小的；max_denominator 小于 1 时返回 MathError::InvalidParameter
+    pub fn limit_denominator(&self, max_denominator: c_long) -> MathResult<Self> {
+        if max_denominator < 1 {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        }
+        if self.denominator <= max_denominator {
+            return MathResult::success(*self);
+        }
+
+        best_approximation(self.numerator as i128, self.denominator as i128, max_denominator as i128)
+    }
+
+    /// 分母不超过 max_denominator 的浮点数的最佳近似
+    ///
+    /// # 返回值
+    /// 有理数；x 为 NaN 时返回 MathError::NotANumber，
+    /// x 为无穷大或超出 c_long 范围时返回 MathError::Overflow，
+    /// max_denominator 小于 1 时返回 MathError::InvalidParameter
+    pub fn from_f64(x: c_double, max_denominator: c_long) -> MathResult<Self> {
+        if x.is_nan() {
+            return MathResult::error(MathError::NotANumber, Self::zero());
+        }
+        if !(-9.223_372_036_854_776e18..9.223_372_036_854_776e18).contains(&x) {
+            return MathResult::error(MathError::Overflow, Self::zero());
+        }
+        if max_denominator < 1 {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        }
+
+        // 浮点数恰好等于 mantissa · 2^exponent，按这个精确的分数求最佳近似
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as i128;
+        let (mut mantissa, exponent) = if biased == 0 { (fraction, -1074) } else { (fraction | 1 << 52, biased - 1075) };
+        if x < 0.0 {
+            mantissa = -mantissa;
+        }
+
+        if exponent >= 0 {
+            // |x| 小于 2^63，左移不会溢出 i128
+            return Self::from_reduced(mantissa << exponent, 1);
+        }
+        if mantissa == 0 {
+            return MathResult::success(Self::zero());
+        }
+
+        // 去掉分子和分母的公因子 2 后分数已经是最简形式
+        let shift = mantissa.trailing_zeros().min(exponent.unsigned_abs());
+        let (n, k) = (mantissa >> shift, exponent.unsigned_abs() - shift);
+        if k > 126 {
+            // |x| < 2^53 / 2^127，小于 1 / (2·max_denominator)，最接近的是 0
+            return MathResult::success(Self::zero());
+        }
+
+        let (d, max) = (1i128 << k, max_denominator as i128);
+        if d <= max {
+            Self::from_reduced(n, d)
+        } else {
+            best_approximation(n, d, max)
+        }
+    }
+
+    /// 解析字符串
+    ///
+    /// 支持整数（"-7"）、分数（"3/4"、"6/-8"）和十进制小数（"-1.25"、".5"），两端可以有空白
+    ///
+    /// # 返回值
+    /// 有理数；格式无效时返回 MathError::InvalidParameter，分母为 0 时返回 MathError::DivisionByZero，
+    /// 约分后超出 c_long 范围时返回 MathError::Overflow
+    pub fn parse(s: &str) -> MathResult<Self> {
+        let s = s.trim();
+
+        if let Some((numerator, denominator)) = s.split_once('/') {
+            return match (parse_integer(numerator.trim()), parse_integer(denominator.trim())) {
+                (Ok(n), Ok(d)) => Self::reduce(n, d),
+                (Err(error), _) | (_, Err(error)) => MathResult::error(error, Self::zero()),
+            };
+        }
+
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        // 小数末尾的 0 不影响数值，去掉后分母更小
+        let fraction = fraction.trim_end_matches('0');
+
+        let valid = |part: &str| part.bytes().all(|c| c.is_ascii_digit());
+        if !digits.contains(|c: char| c.is_ascii_digit()) || !valid(integer) || !valid(fraction) {
+            return MathResult::error(MathError::InvalidParameter, Self::zero());
+        }
+
+        // 分母 10^k 超出 u64 范围时，约分后也不可能在 c_long 范围内
+        if fraction.len() > 19 {
+            return MathResult::error(MathError::Overflow, Self::zero());
+        }
+
+        let mut n: i128 = 0;
+        for c in integer.bytes().chain(fraction.bytes()) {
+            n = match n.checked_mul(10).and_then(|n| n.checked_add((c - b'0') as i128)) {
+                Some(n) if n <= u64::MAX as i128 => n,
+                _ => return MathResult::error(MathError::Overflow, Self::zero()),
+            };
+        }
+
+        let d = 10i128.pow(fraction.len() as u32);
+        Self::reduce(if negative { -n } else { n }, d)
+    }
+}
+
+/// 解析分数中的整数部分，允许正负号
+fn parse_integer(s: &str) -> Result<i128, MathError> {
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(MathError::InvalidParameter);
+    }
+
+    s.parse::<c_long>().map(i128::from).map_err(|_| MathError::Overflow)
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // 分母都为正，交叉相乘不改变大小关系
+        let left = self.numerator as i128 * other.denominator as i128;
+        let right = other.numerator as i128 * self.denominator as i128;
+        left.cmp(&right)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    /// 整数显示为 "n"，其余显示为 "n/d"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
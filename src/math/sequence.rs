@@ -0,0 +1,302 @@
+//! 整数序列模块
+//! 
+//! `Sequence` 按需逐项生成整数序列，只保存生成下一项所需的状态，不会把整个序列放进内存：
+//! 
+//! - `fibonacci`、`lucas`: 斐波那契数和卢卡斯数
+//! - `catalan`: 卡塔兰数
+//! - `triangular`: 三角形数
+//! - `primes`: 质数
+//! - `collatz`: 从给定起点出发的考拉兹（3n + 1）序列，到达 1 后结束
+//! - `linear_recurrence`: 自定义的常系数线性递推
+//! 
+//! 某一项超出 c_long 范围时，读取这一项（`current`）返回 MathError::Overflow，
+//! 不会饱和或回绕，也不会静默地结束序列；超出范围后序列不能继续前进
+//! 
+//! 质数序列和不会溢出的递推（例如全为 0 的常数序列）是无限的，所以 `take` 一次最多取出
+//! `SEQUENCE_TAKE_LIMIT` 项
+
+use std::collections::VecDeque;
+use std::os::raw::c_long;
+use crate::types::{MathError, MathResult};
+use super::is_prime;
+
+/// `take` 一次允许取出的最大项数
+/// 
+/// 约 100 万项，返回的数组约占 8 MB
+pub const SEQUENCE_TAKE_LIMIT: c_long = 1 << 20;
+
+/// 序列的生成规则
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Rule {
+    /// 常系数线性递推 a(n) = c1·a(n-1) + c2·a(n-2) + ... + ck·a(n-k)，前 k 项为初始值
+    Linear {
+        coefficients: Vec<c_long>,
+        initial: Vec<c_long>,
+    },
+    /// 卡塔兰数 C(n) = C(2n, n) / (n + 1)
+    Catalan,
+    /// 三角形数 T(n) = n(n + 1) / 2，从 T(1) = 1 开始
+    Triangular,
+    /// 从 2 开始的质数
+    Primes,
+    /// 考拉兹序列，偶数除以 2，奇数乘 3 加 1
+    Collatz { start: c_long },
+}
+
+/// 逐项生成的整数序列
+/// 
+/// 序列从第 0 项开始，`current` 返回当前项，`advance` 移动到下一项
+#[derive(Clone, Debug)]
+pub struct Sequence {
+    /// 生成规则
+    rule: Rule,
+
+    /// 当前项的下标
+    index: c_long,
+
+    /// 最近的若干项，最后一项是当前项；线性递推保留 k 项，其他规则只保留当前项。
+    /// 有限序列结束后或当前项超出范围时为空
+    recent: VecDeque<c_long>,
+
+    /// 当前项是否超出了 c_long 范围
+    overflowed: bool,
+}
+
+impl Sequence {
+    /// 用规则和第 0 项创建序列
+    fn new(rule: Rule, first: c_long) -> Self {
+        Self {
+            rule,
+            index: 0,
+            recent: VecDeque::from([first]),
+            overflowed: false,
+        }
+    }
+
+    /// 斐波那契数列 0, 1, 1, 2, 3, 5, ...
+    pub fn fibonacci() -> Self {
+        Self::new(
+            Rule::Linear {
+                coefficients: vec![1, 1],
+                initial: vec![0, 1],
+            },
+            0,
+        )
+    }
+
+    /// 卢卡斯数列 2, 1, 3, 4, 7, 11, ...
+    pub fn lucas() -> Self {
+        Self::new(
+            Rule::Linear {
+                coefficients: vec![1, 1],
+                initial: vec![2, 1],
+            },
+            2,
+        )
+    }
+
+    /// 卡塔兰数 1, 1, 2, 5, 14, 42, ...
+    pub fn catalan() -> Self {
+        Self::new(Rule::Catalan, 1)
+    }
+
+    /// 三角形数 1, 3, 6, 10, 15, ...
+    pub fn triangular() -> Self {
+        Self::new(Rule::Triangular, 1)
+    }
+
+    /// 质数 2, 3, 5, 7, 11, ...
+    pub fn primes() -> Self {
+        Self::new(Rule::Primes, 2)
+    }
+
+    /// 从 start 出发的考拉兹序列，第一次到达 1 后结束
+    /// 
+    /// # 参数
+    /// * `start` - 起点，必须大于 0
+    /// 
+    /// # 返回值
+    /// 序列；start 为负数时返回 MathError::NegativeNumber，为 0 时返回 MathError::InvalidParameter
+    pub fn collatz(start: c_long) -> MathResult<Self> {
+        if start < 0 {
+            return MathResult::error(MathError::NegativeNumber, Self::primes());
+        }
+        if start == 0 {
+            return MathResult::error(MathError::InvalidParameter, Self::primes());
+        }
+
+        MathResult::success(Self::new(Rule::Collatz { start }, start))
+    }
+
+    /// 常系数线性递推 a(n) = coefficients[0]·a(n-1) + ... + coefficients[k-1]·a(n-k)
+    /// 
+    /// 例如 coefficients = [1, 1]、initial = [0, 1] 就是斐波那契数列
+    /// 
+    /// # 参数
+    /// * `coefficients` - 递推系数，长度 k 为递推的阶数
+    /// * `initial` - 前 k 项 a(0), ..., a(k-1)
+    /// 
+    /// # 返回值
+    /// 序列；系数为空或两个数组长度不同时返回 MathError::InvalidParameter
+    pub fn linear_recurrence(coefficients: &[c_long], initial: &[c_long]) -> MathResult<Self> {
+        if coefficients.is_empty() || coefficients.len() != initial.len() {
+            return MathResult::error(MathError::InvalidParameter, Self::fibonacci());
+        }
+
+        let rule = Rule::Linear {
+            coefficients: coefficients.to_vec(),
+            initial: initial.to_vec(),
+        };
+        MathResult::success(Self::new(rule, initial[0]))
+    }
+
+    /// 当前项的下标，从 0 开始
+    pub fn index(&self) -> c_long {
+        self.index
+    }
+
+    /// 获取当前项
+    /// 
+    /// # 返回值
+    /// 当前项，有限序列结束后为 None；当前项超出 c_long 范围时返回 MathError::Overflow
+    pub fn current(&self) -> MathResult<Option<c_long>> {
+        if self.overflowed {
+            MathResult::error(MathError::Overflow, None)
+        } else {
+            MathResult::success(self.recent.back().copied())
+        }
+    }
+
+    /// 有限序列是否已经结束
+    pub fn is_finished(&self) -> bool {
+        self.recent.is_empty() && !self.overflowed
+    }
+
+    /// 回到第 0 项
+    pub fn rewind(&mut self) {
+        let first = match &self.rule {
+            Rule::Linear { initial, .. } => initial[0],
+            Rule::Catalan | Rule::Triangular => 1,
+            Rule::Primes => 2,
+            Rule::Collatz { start } => *start,
+        };
+
+        self.index = 0;
+        self.recent.clear();
+        self.recent.push_back(first);
+        self.overflowed = false;
+    }
+
+    /// 移动到下一项
+    /// 
+    /// 下一项超出 c_long 范围时仍然会移动，之后读取当前项返回 MathError::Overflow；
+    /// 有限序列结束后或当前项已经超出范围时调用不做任何事
+    pub fn advance(&mut self) {
+        let current = match self.recent.back() {
+            Some(&current) if !self.overflowed => current,
+            _ => return,
+        };
+
+        let next = match &self.rule {
+            Rule::Linear { coefficients, initial } => {
+                let next_index = (self.index + 1) as usize;
+                if next_index < initial.len() {
+                    Some(initial[next_index])
+                } else {
+                    // recent 中保存了 a(n-k), ..., a(n-1)，系数从 a(n-1) 开始对应
+                    coefficients
+                        .iter()
+                        .zip(self.recent.iter().rev())
+                        .try_fold(0i128, |sum, (&c, &a)| sum.checked_add(c as i128 * a as i128))
+                        .and_then(|sum| c_long::try_from(sum).ok())
+                }
+            }
+            Rule::Catalan => {
+                // C(n + 1) = C(n) · 2(2n + 1) / (n + 2)，除法总是整除
+                let n = self.index as i128;
+                c_long::try_from(current as i128 * 2 * (2 * n + 1) / (n + 2)).ok()
+            }
+            Rule::Triangular => {
+                let n = self.index as i128 + 2;
+                c_long::try_from(n * (n + 1) / 2).ok()
+            }
+            Rule::Primes => next_prime(current),
+            Rule::Collatz { .. } => {
+                if current == 1 {
+                    self.index += 1;
+                    self.recent.clear();
+                    return;
+                }
+                if current % 2 == 0 {
+                    Some(current / 2)
+                } else {
+                    current.checked_mul(3).and_then(|n| n.checked_add(1))
+                }
+            }
+        };
+
+        self.index += 1;
+        let next = match next {
+            Some(next) => next,
+            None => {
+                self.recent.clear();
+                self.overflowed = true;
+                return;
+            }
+        };
+
+        let keep = match &self.rule {
+            Rule::Linear { coefficients, .. } => coefficients.len(),
+            _ => 1,
+        };
+        self.recent.push_back(next);
+        if self.recent.len() > keep {
+            self.recent.pop_front();
+        }
+    }
+
+    /// 从当前项开始取出最多 count 项，并把序列移动到取出的最后一项之后
+    /// 
+    /// # 参数
+    /// * `count` - 要取出的项数，必须非负并且不超过 SEQUENCE_TAKE_LIMIT
+    /// 
+    /// # 返回值
+    /// 取出的项，有限序列提前结束时少于 count 项；count 为负数时返回 MathError::NegativeNumber，
+    /// 超过 SEQUENCE_TAKE_LIMIT 时返回 MathError::InvalidParameter，序列不会前进；
+    /// 需要取出的某一项超出范围时返回 MathError::Overflow，值为在它之前取出的项
+    pub fn take(&mut self, count: c_long) -> MathResult<Vec<c_long>> {
+        if count < 0 {
+            return MathResult::error(MathError::NegativeNumber, Vec::new());
+        }
+        if count > SEQUENCE_TAKE_LIMIT {
+            return MathResult::error(MathError::InvalidParameter, Vec::new());
+        }
+
+        let mut terms = Vec::new();
+        while (terms.len() as c_long) < count {
+            let current = self.current();
+            match current.value {
+                _ if current.is_error() => return MathResult::error(current.error, terms),
+                Some(term) => terms.push(term),
+                None => break,
+            }
+            self.advance();
+        }
+
+        MathResult::success(terms)
+    }
+}
+
+/// 大于 n 的最小质数，超出 c_long 范围时返回 None
+fn next_prime(n: c_long) -> Option<c_long> {
+    if n < 2 {
+        return Some(2);
+    }
+
+    // 从下一个奇数开始逐个测试
+    let mut candidate = if n % 2 == 0 { n + 1 } else { n.checked_add(2)? };
+    while !is_prime(candidate) {
+        candidate = candidate.checked_add(2)?;
+    }
+    Some(candidate)
+}
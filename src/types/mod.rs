@@ -3,7 +3,8 @@
 //! 这个模块定义了与 C 代码交互的数据结构
 //! 所有结构体都使用 #[repr(C)] 确保内存布局与 C 兼容
 
-use std::os::raw::{c_long, c_int};
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_double, c_long, c_int};
 
 /// 斐波那契数列结果结构体
 /// 
@@ -19,10 +20,110 @@ pub struct RustFibonacciResult {
     pub length: c_int,
 }
 
+/// 字符串结果结构体
+/// 
+/// 用于把 Rust 生成的字符串（例如大整数的十进制表示）传递给 C 代码
+/// 字符串以 NUL 结尾，length 不包含结尾的 NUL
+#[repr(C)]
+pub struct RustStringResult {
+    /// 字符串数据的指针，出错时为空指针
+    /// 注意：这个指针指向的内存需要调用 rust_free_string_result 释放
+    pub data: *mut c_char,
+    
+    /// 字符串的长度（字节数）
+    pub length: c_long,
+}
+
+/// 整数数组结果结构体
+/// 
+/// 用于把 Rust 生成的整数列表（例如一个数的全部因数）传递给 C 代码
+#[repr(C)]
+pub struct RustIntegerArrayResult {
+    /// 数组的指针，出错或数组为空时为空指针
+    /// 注意：这个指针指向的内存需要调用 rust_free_integer_array_result 释放
+    pub numbers: *mut c_long,
+    
+    /// 数组的长度
+    pub length: c_long,
+}
+
+/// 质因数分解结果结构体
+/// 
+/// primes 和 exponents 是长度相同的两个数组，primes[i] 的指数为 exponents[i]
+#[repr(C)]
+pub struct RustFactorizationResult {
+    /// 质因数数组的指针，按从小到大排列
+    /// 注意：这个指针指向的内存需要调用 rust_free_factorization_result 释放
+    pub primes: *mut c_long,
+    
+    /// 指数数组的指针
+    pub exponents: *mut c_int,
+    
+    /// 两个数组的长度
+    pub length: c_int,
+}
+
+/// 扩展欧几里得算法结果结构体
+/// 
+/// 满足 a · x + b · y = gcd
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RustExtendedGcdResult {
+    /// 非负的最大公约数
+    pub gcd: c_long,
+    
+    /// a 的 Bézout 系数
+    pub x: c_long,
+    
+    /// b 的 Bézout 系数
+    pub y: c_long,
+}
+
+/// 中国剩余定理结果结构体
+/// 
+/// 同余方程组的全部解为 remainder + k · modulus
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RustCrtResult {
+    /// 最小的非负解
+    pub remainder: c_long,
+    
+    /// 所有模数的最小公倍数
+    pub modulus: c_long,
+}
+
+/// 分数结果结构体
+/// 
+/// 表示 numerator / denominator，总是约分到最简形式，分母大于 0
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RustFraction {
+    /// 分子，带有分数的符号
+    pub numerator: c_long,
+    
+    /// 分母，大于 0
+    pub denominator: c_long,
+}
+
+/// 复数结构体
+/// 
+/// 表示 re + im·i，按值在 Rust 和 C 之间传递；运算和初等函数见 `math::complex` 和 `math::advanced`
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RustComplex {
+    /// 实部
+    pub re: c_double,
+    
+    /// 虚部
+    pub im: c_double,
+}
+
 /// 数学运算错误码
 /// 
 /// 用于表示数学运算中的各种错误情况
+/// 每个错误码都有一个稳定的符号名称（见 `name`），可以在日志和文档中引用
 #[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MathError {
     /// 成功
     Success = 0,
@@ -35,16 +136,222 @@ pub enum MathError {
     
     /// 无效参数错误
     InvalidParameter = -3,
+    
+    /// 除数为零
+    DivisionByZero = -4,
+    
+    /// 参数超出函数的定义域（如 asin(2.0)）
+    DomainError = -5,
+    
+    /// 参数或结果不是数字（NaN）
+    NotANumber = -6,
 }
 
 impl MathError {
-    /// 将错误码转换为字符串描述
+    /// 将错误码转换为当前语言的字符串描述
+    /// 
+    /// 当前语言由 `utils::set_language` 设置，默认为英文
     pub fn to_string(&self) -> &'static str {
+        self.message().to_str().unwrap_or_default()
+    }
+    
+    /// 当前语言的错误描述的 C 字符串形式
+    /// 
+    /// 以 NUL 结尾的静态字符串，可以直接把指针交给 C 代码
+    pub fn message(&self) -> &'static CStr {
+        self.message_in(crate::utils::language())
+    }
+    
+    /// 指定语言的错误描述
+    /// 
+    /// # 参数
+    /// * `language` - 描述使用的语言
+    pub fn message_in(&self, language: MessageLanguage) -> &'static CStr {
+        match language {
+            MessageLanguage::English => match self {
+                MathError::Success => c"Success",
+                MathError::NegativeNumber => c"Negative number not allowed",
+                MathError::Overflow => c"Result out of range",
+                MathError::InvalidParameter => c"Invalid parameter",
+                MathError::DivisionByZero => c"Division by zero",
+                MathError::DomainError => c"Argument outside the function domain",
+                MathError::NotANumber => c"Not a number",
+            },
+            MessageLanguage::Chinese => match self {
+                MathError::Success => c"成功",
+                MathError::NegativeNumber => c"负数错误",
+                MathError::Overflow => c"溢出错误",
+                MathError::InvalidParameter => c"无效参数",
+                MathError::DivisionByZero => c"除数为零",
+                MathError::DomainError => c"参数超出定义域",
+                MathError::NotANumber => c"不是数字",
+            },
+        }
+    }
+    
+    /// 错误码的符号名称
+    /// 
+    /// 名称不随语言变化，也不会在以后的版本中修改
+    pub fn name(&self) -> &'static CStr {
         match self {
-            MathError::Success => "成功",
-            MathError::NegativeNumber => "负数错误",
-            MathError::Overflow => "溢出错误",
-            MathError::InvalidParameter => "无效参数",
+            MathError::Success => c"SUCCESS",
+            MathError::NegativeNumber => c"NEGATIVE_NUMBER",
+            MathError::Overflow => c"OVERFLOW",
+            MathError::InvalidParameter => c"INVALID_PARAMETER",
+            MathError::DivisionByZero => c"DIVISION_BY_ZERO",
+            MathError::DomainError => c"DOMAIN",
+            MathError::NotANumber => c"NOT_A_NUMBER",
+        }
+    }
+    
+    /// 从整数代码转换为错误码
+    /// 
+    /// # 返回值
+    /// 代码无效时返回 None
+    pub fn from_code(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(MathError::Success),
+            -1 => Some(MathError::NegativeNumber),
+            -2 => Some(MathError::Overflow),
+            -3 => Some(MathError::InvalidParameter),
+            -4 => Some(MathError::DivisionByZero),
+            -5 => Some(MathError::DomainError),
+            -6 => Some(MathError::NotANumber),
+            _ => None,
+        }
+    }
+}
+
+/// 浮点数运算的错误处理模式
+/// 
+/// 决定浮点函数遇到除数为零、参数超出定义域或 NaN 时的行为
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatMode {
+    /// 严格：返回 DivisionByZero、DomainError 或 NotANumber 错误码（默认）
+    #[default]
+    Strict = 0,
+    
+    /// IEEE 754：按标准返回 inf、-inf 或 NaN，不报告错误
+    Ieee = 1,
+}
+
+impl FloatMode {
+    /// 从整数代码转换为浮点模式
+    /// 
+    /// # 返回值
+    /// 代码无效时返回 None
+    pub fn from_code(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(FloatMode::Strict),
+            1 => Some(FloatMode::Ieee),
+            _ => None,
+        }
+    }
+}
+
+/// 错误描述使用的语言
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageLanguage {
+    /// 英文（默认）
+    #[default]
+    English = 0,
+    
+    /// 中文
+    Chinese = 1,
+}
+
+impl MessageLanguage {
+    /// 从整数代码转换为语言
+    /// 
+    /// # 返回值
+    /// 代码无效时返回 None
+    pub fn from_code(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(MessageLanguage::English),
+            1 => Some(MessageLanguage::Chinese),
+            _ => None,
+        }
+    }
+}
+
+/// 整数溢出处理模式
+/// 
+/// 决定整数运算结果超出 c_long 范围时的行为
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowMode {
+    /// 饱和：结果截断到 c_long::MAX 或 c_long::MIN（默认，与旧版本行为一致）
+    #[default]
+    Saturating = 0,
+    
+    /// 回绕：按二进制补码回绕，与 C 语言的无符号运算类似
+    Wrapping = 1,
+    
+    /// 检查：溢出时返回 MathError::Overflow
+    Checked = 2,
+}
+
+impl OverflowMode {
+    /// 从整数代码转换为溢出模式
+    /// 
+    /// # 返回值
+    /// 代码无效时返回 None
+    pub fn from_code(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(OverflowMode::Saturating),
+            1 => Some(OverflowMode::Wrapping),
+            2 => Some(OverflowMode::Checked),
+            _ => None,
+        }
+    }
+}
+
+/// 定点小数的舍入模式
+/// 
+/// 决定 `Decimal` 减少小数位数时如何处理被舍去的部分
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// 远离零：只要舍去的部分不为 0 就进位
+    Up = 0,
+    
+    /// 趋向零：直接截断
+    Down = 1,
+    
+    /// 向正无穷
+    Ceiling = 2,
+    
+    /// 向负无穷
+    Floor = 3,
+    
+    /// 四舍五入：恰好一半时远离零
+    HalfUp = 4,
+    
+    /// 五舍六入：恰好一半时趋向零
+    HalfDown = 5,
+    
+    /// 银行家舍入：恰好一半时舍入到偶数（默认）
+    #[default]
+    HalfEven = 6,
+}
+
+impl RoundingMode {
+    /// 从整数代码转换为舍入模式
+    /// 
+    /// # 返回值
+    /// 代码无效时返回 None
+    pub fn from_code(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(RoundingMode::Up),
+            1 => Some(RoundingMode::Down),
+            2 => Some(RoundingMode::Ceiling),
+            3 => Some(RoundingMode::Floor),
+            4 => Some(RoundingMode::HalfUp),
+            5 => Some(RoundingMode::HalfDown),
+            6 => Some(RoundingMode::HalfEven),
+            _ => None,
         }
     }
 }
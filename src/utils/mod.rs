@@ -2,7 +2,92 @@
 //! 
 //! 这个模块包含一些通用的辅助函数，用于支持数学运算
 
-use std::os::raw::c_long;
+use std::cell::Cell;
+use std::os::raw::{c_double, c_long};
+use crate::types::{FloatMode, MessageLanguage, MathError, MathResult, OverflowMode};
+
+thread_local! {
+    /// 当前线程的整数溢出处理模式
+    /// 
+    /// 使用线程局部变量，这样在 PHP 的线程安全（ZTS）模式下
+    /// 每个请求线程都可以有自己的设置
+    static OVERFLOW_MODE: Cell<OverflowMode> = const { Cell::new(OverflowMode::Saturating) };
+    
+    /// 当前线程的浮点数错误处理模式
+    static FLOAT_MODE: Cell<FloatMode> = const { Cell::new(FloatMode::Strict) };
+    
+    /// 当前线程的错误描述语言
+    static LANGUAGE: Cell<MessageLanguage> = const { Cell::new(MessageLanguage::English) };
+}
+
+/// 设置当前线程的整数溢出处理模式
+/// 
+/// # 参数
+/// * `mode` - 新的溢出处理模式
+pub fn set_overflow_mode(mode: OverflowMode) {
+    OVERFLOW_MODE.with(|current| current.set(mode));
+}
+
+/// 获取当前线程的整数溢出处理模式
+pub fn overflow_mode() -> OverflowMode {
+    OVERFLOW_MODE.with(|current| current.get())
+}
+
+/// 设置当前线程的浮点数错误处理模式
+/// 
+/// # 参数
+/// * `mode` - 新的浮点模式
+pub fn set_float_mode(mode: FloatMode) {
+    FLOAT_MODE.with(|current| current.set(mode));
+}
+
+/// 获取当前线程的浮点数错误处理模式
+pub fn float_mode() -> FloatMode {
+    FLOAT_MODE.with(|current| current.get())
+}
+
+/// 设置当前线程的错误描述语言
+/// 
+/// # 参数
+/// * `language` - 新的语言
+pub fn set_language(language: MessageLanguage) {
+    LANGUAGE.with(|current| current.set(language));
+}
+
+/// 获取当前线程的错误描述语言
+pub fn language() -> MessageLanguage {
+    LANGUAGE.with(|current| current.get())
+}
+
+/// 检查浮点运算的结果
+/// 
+/// # 参数
+/// * `value` - 运算结果
+/// 
+/// # 返回值
+/// 严格模式下结果为 NaN 时返回 MathError::NotANumber，否则原样返回
+pub fn check_float(value: c_double) -> MathResult<c_double> {
+    if value.is_nan() {
+        float_error(MathError::NotANumber, value)
+    } else {
+        MathResult::success(value)
+    }
+}
+
+/// 按当前浮点模式报告浮点运算的错误
+/// 
+/// # 参数
+/// * `error` - 严格模式下返回的错误码
+/// * `value` - IEEE 754 规定的结果（inf、-inf 或 NaN）
+/// 
+/// # 返回值
+/// 严格模式返回错误码，IEEE 模式把 value 作为成功结果返回
+pub fn float_error(error: MathError, value: c_double) -> MathResult<c_double> {
+    match float_mode() {
+        FloatMode::Strict => MathResult::error(error, value),
+        FloatMode::Ieee => MathResult::success(value),
+    }
+}
 
 /// 检查数字是否为偶数
 /// 
@@ -38,48 +123,131 @@ pub fn sqrt_int(n: c_long) -> c_long {
         return 0;
     }
     
-    let n_f64 = n as f64;
-    n_f64.sqrt() as c_long
+    // 大于 2^53 的数转换为 f64 时会舍入，浮点平方根可能差 1，需要用整数运算修正
+    let n = n as u64;
+    let mut root = (n as f64).sqrt() as u64;
+    while root * root > n {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    
+    root as c_long
 }
 
-/// 计算两个数字的最大公约数
+/// 计算 (a · b) mod m，中间结果使用 128 位整数，不会溢出
+/// 
+/// # 参数
+/// * `a` - 第一个乘数
+/// * `b` - 第二个乘数
+/// * `m` - 模数，必须大于 0
+/// 
+/// # 返回值
+/// 乘积对 m 取模的结果
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// 计算 base^exp mod m（快速幂），中间结果使用 128 位整数，不会溢出
 /// 
-/// 使用欧几里得算法计算最大公约数
+/// # 参数
+/// * `base` - 底数
+/// * `exp` - 指数
+/// * `m` - 模数，必须大于 0
+/// 
+/// # 返回值
+/// 幂对 m 取模的结果
+pub fn pow_mod(base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    
+    result
+}
+
+/// 计算两个无符号整数的最大公约数
+/// 
+/// 使用二进制 GCD 算法（Stein 算法）：只用移位和减法，不需要除法，
+/// 对 64 位整数比欧几里得算法的取模循环更快
 /// 
 /// # 参数
 /// * `a` - 第一个数字
 /// * `b` - 第二个数字
 /// 
 /// # 返回值
-/// 最大公约数
-pub fn gcd(mut a: c_long, mut b: c_long) -> c_long {
-    // 处理负数
-    a = a.abs();
-    b = b.abs();
+/// 最大公约数，gcd(0, 0) = 0
+pub fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+    
+    // 两个数共同的因子 2 最后再乘回去
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
     
-    while b != 0 {
-        let temp = b;
-        b = a % b;
-        a = temp;
+    loop {
+        // 此时 a 是奇数，b 的因子 2 不会出现在最大公约数中
+        b >>= b.trailing_zeros();
+        if a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
     }
+}
+
+/// 计算两个数字的最大公约数
+/// 
+/// # 参数
+/// * `a` - 第一个数字
+/// * `b` - 第二个数字
+/// 
+/// # 返回值
+/// 非负的最大公约数；结果为 2^63 时（a、b 为 c_long::MIN 或 0）超出 c_long 范围，返回 MathError::Overflow
+pub fn gcd(a: c_long, b: c_long) -> MathResult<c_long> {
+    let g = gcd_u64(a.unsigned_abs(), b.unsigned_abs());
     
-    a
+    match c_long::try_from(g) {
+        Ok(g) => MathResult::success(g),
+        Err(_) => MathResult::error(MathError::Overflow, c_long::MAX),
+    }
 }
 
 /// 计算两个数字的最小公倍数
 /// 
+/// 先除以最大公约数再相乘，只有结果本身超出范围时才会溢出
+/// 
 /// # 参数
 /// * `a` - 第一个数字
 /// * `b` - 第二个数字
 /// 
 /// # 返回值
-/// 最小公倍数
-pub fn lcm(a: c_long, b: c_long) -> c_long {
+/// 非负的最小公倍数，任一参数为 0 时结果为 0；结果超出 c_long 范围时返回 MathError::Overflow
+pub fn lcm(a: c_long, b: c_long) -> MathResult<c_long> {
     if a == 0 || b == 0 {
-        return 0;
+        return MathResult::success(0);
     }
     
-    (a * b).abs() / gcd(a, b)
+    let (a, b) = (a.unsigned_abs(), b.unsigned_abs());
+    let l = (a / gcd_u64(a, b)).checked_mul(b).and_then(|l| c_long::try_from(l).ok());
+    
+    match l {
+        Some(l) => MathResult::success(l),
+        None => MathResult::error(MathError::Overflow, c_long::MAX),
+    }
 }
 
 /// 检查数字是否在指定范围内
@@ -117,4 +285,98 @@ pub fn safe_multiply(a: c_long, b: c_long) -> c_long {
 /// 加法结果，如果溢出则返回最大/最小值
 pub fn safe_add(a: c_long, b: c_long) -> c_long {
     a.saturating_add(b)
-} 
\ No newline at end of file
+} 
+
+/// 检查溢出的整数加法
+/// 
+/// # 参数
+/// * `a` - 第一个加数
+/// * `b` - 第二个加数
+/// 
+/// # 返回值
+/// 加法结果，如果溢出则返回 MathError::Overflow（值为饱和结果）
+pub fn checked_add(a: c_long, b: c_long) -> MathResult<c_long> {
+    match a.checked_add(b) {
+        Some(value) => MathResult::success(value),
+        None => MathResult::error(MathError::Overflow, a.saturating_add(b)),
+    }
+}
+
+/// 检查溢出的整数减法
+/// 
+/// # 参数
+/// * `a` - 被减数
+/// * `b` - 减数
+/// 
+/// # 返回值
+/// 减法结果，如果溢出则返回 MathError::Overflow（值为饱和结果）
+pub fn checked_subtract(a: c_long, b: c_long) -> MathResult<c_long> {
+    match a.checked_sub(b) {
+        Some(value) => MathResult::success(value),
+        None => MathResult::error(MathError::Overflow, a.saturating_sub(b)),
+    }
+}
+
+/// 检查溢出的整数乘法
+/// 
+/// # 参数
+/// * `a` - 第一个乘数
+/// * `b` - 第二个乘数
+/// 
+/// # 返回值
+/// 乘法结果，如果溢出则返回 MathError::Overflow（值为饱和结果）
+pub fn checked_multiply(a: c_long, b: c_long) -> MathResult<c_long> {
+    match a.checked_mul(b) {
+        Some(value) => MathResult::success(value),
+        None => MathResult::error(MathError::Overflow, a.saturating_mul(b)),
+    }
+}
+
+/// 按当前溢出模式处理可能溢出的运算结果
+/// 
+/// # 参数
+/// * `checked` - 检查溢出的结果，溢出时为 None
+/// * `wrapping` - 回绕的结果
+/// * `saturating` - 饱和的结果
+/// 
+/// # 返回值
+/// 根据 `overflow_mode()` 选择的结果，检查模式下溢出返回 MathError::Overflow
+pub fn apply_overflow_mode(
+    checked: Option<c_long>,
+    wrapping: c_long,
+    saturating: c_long,
+) -> MathResult<c_long> {
+    match (checked, overflow_mode()) {
+        (Some(value), _) => MathResult::success(value),
+        (None, OverflowMode::Saturating) => MathResult::success(saturating),
+        (None, OverflowMode::Wrapping) => MathResult::success(wrapping),
+        (None, OverflowMode::Checked) => MathResult::error(MathError::Overflow, saturating),
+    }
+}
+
+/// 按当前溢出模式进行整数加法
+/// 
+/// # 参数
+/// * `a` - 第一个加数
+/// * `b` - 第二个加数
+pub fn mode_add(a: c_long, b: c_long) -> MathResult<c_long> {
+    apply_overflow_mode(a.checked_add(b), a.wrapping_add(b), a.saturating_add(b))
+}
+
+/// 按当前溢出模式进行整数减法
+/// 
+/// # 参数
+/// * `a` - 被减数
+/// * `b` - 减数
+pub fn mode_subtract(a: c_long, b: c_long) -> MathResult<c_long> {
+    apply_overflow_mode(a.checked_sub(b), a.wrapping_sub(b), a.saturating_sub(b))
+}
+
+/// 按当前溢出模式进行整数乘法
+/// 
+/// # 参数
+/// * `a` - 第一个乘数
+/// * `b` - 第二个乘数
+pub fn mode_multiply(a: c_long, b: c_long) -> MathResult<c_long> {
+    apply_overflow_mode(a.checked_mul(b), a.wrapping_mul(b), a.saturating_mul(b))
+}
@@ -115,7 +115,7 @@ foreach ($tests as $function => $test_info) {
             echo "结果: {$result_str} ";
             echo "期望: {$expected_str}\n";
             
-        } catch (Exception $e) {
+        } catch (Throwable $e) {
             echo "  ✗ 参数: (" . implode(', ', $args) . ") 异常: " . $e->getMessage() . "\n";
         }
     }